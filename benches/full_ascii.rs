@@ -0,0 +1,78 @@
+//! Benchmarks for full Unicode case mapping over ASCII-only input.
+//!
+//! The public iterators detect ASCII-only slices when constructed in full
+//! Unicode mode and dispatch to the ASCII iterators, which do not need to
+//! decode and re-encode each UTF-8 scalar. These benchmarks compare an
+//! ASCII-only corpus against the same corpus with a single trailing non-ASCII
+//! character, which uses the full Unicode iterators, and a mostly-ASCII corpus
+//! with a non-ASCII character in every sentence.
+//!
+//! The full lowercase and uppercase iterators map ASCII bytes directly and only
+//! decode UTF-8 scalars at non-ASCII bytes.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use roe::{LowercaseMode, TitlecaseMode, UppercaseMode};
+
+const SENTENCE: &str = "The Quick Brown Fox Jumps Over The Lazy Dog. ";
+
+fn corpora() -> [(&'static str, Vec<u8>); 3] {
+    let ascii = SENTENCE.repeat(256).into_bytes();
+    let mut non_ascii_tail = ascii.clone();
+    non_ascii_tail.extend_from_slice("É".as_bytes());
+    let mostly_ascii = format!("{SENTENCE}É ").repeat(256).into_bytes();
+    [
+        ("ascii", ascii),
+        ("non-ascii-tail", non_ascii_tail),
+        ("mostly-ascii", mostly_ascii),
+    ]
+}
+
+fn bench_lowercase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lowercase full");
+    for (name, corpus) in &corpora() {
+        group.throughput(Throughput::Bytes(corpus.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), corpus, |b, corpus| {
+            b.iter(|| {
+                roe::lowercase(black_box(corpus), LowercaseMode::Full).for_each(|byte| {
+                    black_box(byte);
+                });
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_uppercase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("uppercase full");
+    for (name, corpus) in &corpora() {
+        group.throughput(Throughput::Bytes(corpus.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), corpus, |b, corpus| {
+            b.iter(|| {
+                roe::uppercase(black_box(corpus), UppercaseMode::Full).for_each(|byte| {
+                    black_box(byte);
+                });
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_titlecase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("titlecase full");
+    for (name, corpus) in &corpora() {
+        group.throughput(Throughput::Bytes(corpus.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), corpus, |b, corpus| {
+            b.iter(|| {
+                roe::titlecase(black_box(corpus), TitlecaseMode::Full).for_each(|byte| {
+                    black_box(byte);
+                });
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_lowercase, bench_uppercase, bench_titlecase);
+criterion_main!(benches);
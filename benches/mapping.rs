@@ -0,0 +1,124 @@
+//! Benchmarks for mapping throughput over ASCII-heavy, Greek, and invalid UTF-8
+//! input.
+//!
+//! Each corpus is mapped with the streaming iterators and with the allocating
+//! helpers. The allocating helpers count the mapped bytes in a first pass and
+//! fill an exactly sized buffer in a second pass. The `collect` benchmarks
+//! collect the streaming iterators instead, which skips the counting pass but
+//! may reallocate and leave spare capacity for non-ASCII input.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use roe::{LowercaseMode, TitlecaseMode, UppercaseMode};
+
+fn corpora() -> [(&'static str, Vec<u8>); 3] {
+    let ascii_heavy = "The Quick Brown Fox Jumps Over The Lazy Dog, naïvely. "
+        .repeat(256)
+        .into_bytes();
+    let greek = "Η ΑΎΡΙΟ ΟΔΟΣ είναι μακριά, αλλά ΐ και ΰ επεκτείνονται. "
+        .repeat(256)
+        .into_bytes();
+    let mut invalid = Vec::new();
+    for _ in 0..256 {
+        invalid.extend_from_slice(b"ABC\xFF\xFExyz\xF0\x9F\x87 \xC3 Stra\xC3\x9Fe \xE2\x82 ");
+    }
+    [
+        ("ascii-heavy", ascii_heavy),
+        ("greek", greek),
+        ("invalid-utf8", invalid),
+    ]
+}
+
+fn bench_lowercase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lowercase");
+    for (name, corpus) in &corpora() {
+        group.throughput(Throughput::Bytes(corpus.len() as u64));
+        group.bench_with_input(BenchmarkId::new("iter", name), corpus, |b, corpus| {
+            b.iter(|| {
+                roe::lowercase(black_box(corpus), LowercaseMode::Full).for_each(|byte| {
+                    black_box(byte);
+                });
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("vec", name), corpus, |b, corpus| {
+            b.iter(|| black_box(roe::to_lowercase(black_box(corpus), LowercaseMode::Full)));
+        });
+        group.bench_with_input(BenchmarkId::new("collect", name), corpus, |b, corpus| {
+            b.iter(|| {
+                black_box(
+                    roe::lowercase(black_box(corpus), LowercaseMode::Full).collect::<Vec<_>>(),
+                )
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_uppercase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("uppercase");
+    for (name, corpus) in &corpora() {
+        group.throughput(Throughput::Bytes(corpus.len() as u64));
+        group.bench_with_input(BenchmarkId::new("iter", name), corpus, |b, corpus| {
+            b.iter(|| {
+                roe::uppercase(black_box(corpus), UppercaseMode::Full).for_each(|byte| {
+                    black_box(byte);
+                });
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("vec", name), corpus, |b, corpus| {
+            b.iter(|| black_box(roe::to_uppercase(black_box(corpus), UppercaseMode::Full)));
+        });
+        group.bench_with_input(BenchmarkId::new("collect", name), corpus, |b, corpus| {
+            b.iter(|| {
+                black_box(
+                    roe::uppercase(black_box(corpus), UppercaseMode::Full).collect::<Vec<_>>(),
+                )
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_titlecase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("titlecase");
+    for (name, corpus) in &corpora() {
+        group.throughput(Throughput::Bytes(corpus.len() as u64));
+        group.bench_with_input(BenchmarkId::new("iter", name), corpus, |b, corpus| {
+            b.iter(|| {
+                roe::titlecase(black_box(corpus), TitlecaseMode::Full).for_each(|byte| {
+                    black_box(byte);
+                });
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("vec", name), corpus, |b, corpus| {
+            b.iter(|| {
+                black_box(roe::to_titlecase_string(
+                    black_box(corpus),
+                    TitlecaseMode::Full,
+                ))
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_reserve_hint(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reserve_hint");
+    for (name, corpus) in &corpora() {
+        group.throughput(Throughput::Bytes(corpus.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), corpus, |b, corpus| {
+            b.iter(|| roe::reserve_hint(black_box(corpus), UppercaseMode::Full.into()));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_lowercase,
+    bench_uppercase,
+    bench_titlecase,
+    bench_reserve_hint
+);
+criterion_main!(benches);
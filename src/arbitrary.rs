@@ -0,0 +1,108 @@
+//! [`Arbitrary`] implementations for the case mapping mode enums.
+//!
+//! Modes are chosen uniformly among all variants, including modes which are
+//! not yet implemented. Fuzz targets which map with a generated mode should use
+//! [`try_lowercase`] to handle them without panicking.
+//!
+//! [`try_lowercase`]: crate::try_lowercase()
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{LowercaseMode, UppercaseMode};
+
+const LOWERCASE_MODES: [LowercaseMode; 6] = [
+    LowercaseMode::Full,
+    LowercaseMode::Ascii,
+    LowercaseMode::Turkic,
+    LowercaseMode::Lithuanian,
+    LowercaseMode::Fold,
+    LowercaseMode::TurkicFold,
+];
+
+const UPPERCASE_MODES: [UppercaseMode; 6] = [
+    UppercaseMode::Full,
+    UppercaseMode::Ascii,
+    UppercaseMode::Turkic,
+    UppercaseMode::Lithuanian,
+    UppercaseMode::Fold,
+    UppercaseMode::CapitalSharpS,
+];
+
+impl<'a> Arbitrary<'a> for LowercaseMode {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&LOWERCASE_MODES).copied()
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for UppercaseMode {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&UPPERCASE_MODES).copied()
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    use super::{LOWERCASE_MODES, UPPERCASE_MODES};
+    use crate::{LowercaseMode, UppercaseMode};
+
+    #[test]
+    fn every_mode_is_generated() {
+        let data = (0..=u8::MAX).collect::<Vec<_>>();
+        let mut lowercase = [false; LOWERCASE_MODES.len()];
+        let mut uppercase = [false; UPPERCASE_MODES.len()];
+        // Draw each mode type from its own stream so that the byte stride does
+        // not depend on how many modes the other type has.
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let mode = LowercaseMode::arbitrary(&mut u).unwrap();
+            let idx = LOWERCASE_MODES.iter().position(|&m| m == mode).unwrap();
+            lowercase[idx] = true;
+        }
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let mode = UppercaseMode::arbitrary(&mut u).unwrap();
+            let idx = UPPERCASE_MODES.iter().position(|&m| m == mode).unwrap();
+            uppercase[idx] = true;
+        }
+        assert!(lowercase.iter().all(|&seen| seen));
+        assert!(uppercase.iter().all(|&seen| seen));
+    }
+
+    #[test]
+    fn generated_modes_map() {
+        let mut u = Unstructured::new(b"\x00\x01\x02\x03\x04\xFF");
+        while !u.is_empty() {
+            let mode = LowercaseMode::arbitrary(&mut u).unwrap();
+            if let Ok(iter) = crate::try_lowercase(b"ABC", mode) {
+                assert_eq!(iter.count(), 3);
+            }
+            let mode = UppercaseMode::arbitrary(&mut u).unwrap();
+            assert_eq!(crate::uppercase(b"abc", mode).count(), 3);
+        }
+    }
+
+    #[test]
+    fn empty_input_generates_a_mode() {
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(
+            LowercaseMode::arbitrary(&mut u).unwrap(),
+            LowercaseMode::Full
+        );
+        assert_eq!(
+            UppercaseMode::arbitrary(&mut u).unwrap(),
+            UppercaseMode::Full
+        );
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for [u8] {}
+
+    #[cfg(feature = "alloc")]
+    impl Sealed for alloc::vec::Vec<u8> {}
+}
+
+/// Extension trait for ASCII case mapping byte strings with method call syntax.
+///
+/// This trait provides method forms of the ASCII titlecase free functions in
+/// this crate. It is implemented for [`[u8]`][slice] and, with the `alloc`
+/// feature, [`Vec<u8>`].
+///
+/// The standard library already provides inherent `make_ascii_lowercase`,
+/// `make_ascii_uppercase`, `to_ascii_lowercase`, and `to_ascii_uppercase`
+/// methods on byte slices, so this trait only adds the titlecase methods.
+/// Inherent methods take precedence over trait methods, so importing this trait
+/// never changes which method is called for the lowercase and uppercase forms.
+///
+/// This trait is sealed and cannot be implemented outside of `roe`.
+///
+/// # Examples
+///
+/// ```
+/// use roe::AsciiCasing;
+///
+/// let mut buf = *b"hELLO, wORLD";
+/// buf.make_ascii_titlecase();
+/// assert_eq!(&buf, b"Hello, world");
+///
+/// // The standard library's inherent methods are still available.
+/// buf.make_ascii_uppercase();
+/// assert_eq!(&buf, b"HELLO, WORLD");
+/// ```
+#[cfg_attr(feature = "alloc", doc = "[`Vec<u8>`]: alloc::vec::Vec")]
+#[cfg_attr(
+    not(feature = "alloc"),
+    doc = "[`Vec<u8>`]: https://doc.rust-lang.org/alloc/vec/struct.Vec.html"
+)]
+#[allow(clippy::module_name_repetitions)]
+pub trait AsciiCasing: private::Sealed {
+    /// Converts this byte string to its ASCII title case equivalent in-place.
+    ///
+    /// This method is equivalent to calling [`make_ascii_titlecase`] on `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use roe::AsciiCasing;
+    ///
+    /// let mut buf = b"ABC, XYZ".to_vec();
+    /// buf.make_ascii_titlecase();
+    /// assert_eq!(buf, b"Abc, xyz");
+    ///
+    /// let buf = &mut [b'x', b'Y', 0xFF][..];
+    /// buf.make_ascii_titlecase();
+    /// assert_eq!(buf, [b'X', b'y', 0xFF]);
+    /// ```
+    ///
+    /// [`make_ascii_titlecase`]: crate::make_ascii_titlecase()
+    fn make_ascii_titlecase(&mut self);
+
+    /// Returns a vector containing a copy of this byte string where each byte
+    /// is mapped to its ASCII title case equivalent.
+    ///
+    /// This method is equivalent to calling [`to_ascii_titlecase`] on `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use roe::AsciiCasing;
+    ///
+    /// let buf = b"1234%&*abcXYZ";
+    /// assert_eq!(buf[..].to_ascii_titlecase(), b"1234%&*abcxyz");
+    /// assert_eq!(b"ABCxyz".to_vec().to_ascii_titlecase(), b"Abcxyz");
+    /// ```
+    ///
+    /// [`to_ascii_titlecase`]: crate::to_ascii_titlecase()
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn to_ascii_titlecase(&self) -> Vec<u8>;
+}
+
+impl AsciiCasing for [u8] {
+    #[inline]
+    fn make_ascii_titlecase(&mut self) {
+        super::make_ascii_titlecase(&mut &mut *self);
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn to_ascii_titlecase(&self) -> Vec<u8> {
+        super::to_ascii_titlecase(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsciiCasing for Vec<u8> {
+    #[inline]
+    fn make_ascii_titlecase(&mut self) {
+        super::make_ascii_titlecase(self);
+    }
+
+    #[inline]
+    fn to_ascii_titlecase(&self) -> Vec<u8> {
+        super::to_ascii_titlecase(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AsciiCasing;
+
+    #[test]
+    fn slice_make_ascii_titlecase() {
+        let cases: [(&[u8], &[u8]); 5] = [
+            (b"", b""),
+            (b"abc", b"Abc"),
+            (b"ABC, XYZ", b"Abc, xyz"),
+            (b"1234%&*abcXYZ", b"1234%&*abcxyz"),
+            (b"\xFFaBc", b"\xFFabc"),
+        ];
+        for (input, expected) in cases {
+            let mut buf = [0; 16];
+            let buf = &mut buf[..input.len()];
+            buf.copy_from_slice(input);
+            buf.make_ascii_titlecase();
+            assert_eq!(buf, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn matches_free_functions() {
+        let inputs: [&[u8]; 5] = [b"", b"abc", b"ABC, XYZ", b"1234%&*abcXYZ", b"\xFFaBc"];
+        for input in inputs {
+            assert_eq!(input.to_ascii_titlecase(), crate::to_ascii_titlecase(input));
+            let mut buf = input.to_vec();
+            buf.make_ascii_titlecase();
+            assert_eq!(buf, crate::to_ascii_titlecase(input));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn inherent_methods_are_not_shadowed() {
+        let mut buf = b"hELLO wORLD".to_vec();
+        assert_eq!(buf.to_ascii_titlecase(), b"Hello world");
+        buf.make_ascii_titlecase();
+        assert_eq!(buf, b"Hello world");
+        buf.make_ascii_lowercase();
+        assert_eq!(buf, b"hello world");
+        assert_eq!(buf.to_ascii_uppercase(), b"HELLO WORLD");
+    }
+}
@@ -0,0 +1,139 @@
+use core::iter::FusedIterator;
+
+/// Returns an iterator adaptor that yields the ASCII bytes of the given byte
+/// iterator and drops all other bytes.
+///
+/// This adaptor composes with the case mapping iterators in this crate to build
+/// lazy pipelines which do not allocate between steps. For example, case
+/// folding a string and then stripping it to ASCII maps and filters one byte at
+/// a time.
+///
+/// Non-ASCII bytes are dropped individually, so every byte of a multi-byte
+/// UTF-8 scalar and every byte of an invalid UTF-8 byte sequence is removed.
+/// The output is always valid ASCII.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// let folded = roe::lowercase("Straße".as_bytes(), LowercaseMode::Fold);
+/// assert_eq!(roe::ascii_filter(folded).collect::<Vec<_>>(), b"strasse");
+///
+/// // Scalars which do not fold to ASCII are dropped.
+/// let folded = roe::lowercase("Crème Brûlée".as_bytes(), LowercaseMode::Fold);
+/// assert_eq!(roe::ascii_filter(folded).collect::<Vec<_>>(), b"crme brle");
+/// ```
+pub fn ascii_filter<I>(iter: I) -> AsciiFilter<I::IntoIter>
+where
+    I: IntoIterator<Item = u8>,
+{
+    AsciiFilter {
+        iter: iter.into_iter(),
+    }
+}
+
+/// An iterator adaptor that yields only the ASCII bytes of another byte
+/// iterator.
+///
+/// This struct is created by the [`ascii_filter`] function. See its
+/// documentation for more.
+///
+/// [`ascii_filter`]: crate::ascii_filter()
+#[derive(Debug, Clone)]
+#[must_use = "AsciiFilter is a Iterator and must be used"]
+pub struct AsciiFilter<I> {
+    iter: I,
+}
+
+impl<I> AsciiFilter<I> {
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut filter = roe::ascii_filter("aé".bytes());
+    /// assert_eq!(filter.next(), Some(b'a'));
+    ///
+    /// // The unfiltered bytes of `é` remain in the underlying iterator.
+    /// assert_eq!(filter.into_inner().collect::<Vec<_>>(), "é".as_bytes());
+    /// ```
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I> Iterator for AsciiFilter<I>
+where
+    I: Iterator<Item = u8>,
+{
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find(u8::is_ascii)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, max) = self.iter.size_hint();
+        (0, max)
+    }
+}
+
+impl<I> DoubleEndedIterator for AsciiFilter<I>
+where
+    I: DoubleEndedIterator<Item = u8>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.rfind(u8::is_ascii)
+    }
+}
+
+impl<I> FusedIterator for AsciiFilter<I> where I: FusedIterator<Item = u8> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::ascii_filter;
+    use crate::{LowercaseMode, UppercaseMode};
+
+    #[test]
+    fn fold_then_filter() {
+        let folded = crate::lowercase("Straße".as_bytes(), LowercaseMode::Fold);
+        assert_eq!(ascii_filter(folded).collect::<Vec<_>>(), b"strasse");
+
+        let folded = crate::lowercase("ﬃ \u{212A}elvin".as_bytes(), LowercaseMode::Fold);
+        assert_eq!(ascii_filter(folded).collect::<Vec<_>>(), b"ffi kelvin");
+    }
+
+    #[test]
+    fn drops_every_byte_of_non_ascii_scalars() {
+        let upper = crate::uppercase("aύb\u{1F600}c".as_bytes(), UppercaseMode::Full);
+        assert_eq!(ascii_filter(upper).collect::<Vec<_>>(), b"ABC");
+
+        let lower = crate::lowercase(b"A\xFF\xE2\x82B", LowercaseMode::Full);
+        assert_eq!(ascii_filter(lower).collect::<Vec<_>>(), b"ab");
+
+        assert_eq!(ascii_filter(*b"").next(), None);
+        assert_eq!(ascii_filter(*b"\xFF\xFE").next(), None);
+    }
+
+    #[test]
+    fn rev() {
+        let upper = crate::uppercase("Straße".as_bytes(), UppercaseMode::Full);
+        let mut rev = ascii_filter(upper).rev().collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev, b"STRASSE");
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        let inputs = ["", "abc", "Straße", "ΑΎΡΙΟ abc"];
+        for input in inputs {
+            let filter = ascii_filter(crate::lowercase(input.as_bytes(), LowercaseMode::Fold));
+            let (min, max) = filter.size_hint();
+            let count = filter.clone().count();
+            assert!(min <= count);
+            assert!(count <= max.unwrap());
+        }
+    }
+}
@@ -1,10 +1,14 @@
+mod casing;
+mod filter;
 mod lowercase;
 mod titlecase;
 mod uppercase;
 
+pub use casing::AsciiCasing;
+pub use filter::{ascii_filter, AsciiFilter};
 pub use lowercase::make_ascii_lowercase;
-pub use titlecase::make_ascii_titlecase;
-pub use uppercase::make_ascii_uppercase;
+pub use titlecase::{make_ascii_titlecase, make_ascii_titlecase_words};
+pub use uppercase::{make_ascii_uppercase, make_ascii_uppercase_iter, MakeAsciiUppercase};
 
 #[cfg(feature = "alloc")]
 pub use lowercase::to_ascii_lowercase;
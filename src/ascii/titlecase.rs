@@ -1,3 +1,5 @@
+use core::mem;
+
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
@@ -51,6 +53,61 @@ pub fn make_ascii_titlecase<T: AsMut<[u8]>>(slice: &mut T) {
     }
 }
 
+/// Converts each whitespace-delimited word of the given slice to its ASCII
+/// title case equivalent in-place.
+///
+/// The first ASCII letter of each word is mapped to uppercase and all other
+/// ASCII letters are mapped to lowercase. Bytes which precede the first letter
+/// of a word, like digits and punctuation, are unchanged, as are non-ASCII
+/// bytes. Words are separated by ASCII whitespace.
+///
+/// This is the ASCII, in-place counterpart of [`titlecase_words`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::make_ascii_titlecase_words;
+/// let mut buf = *b"hELLO wORLD";
+/// make_ascii_titlecase_words(&mut buf);
+/// assert_eq!(buf, *b"Hello World");
+///
+/// let mut buf = *b"123abc def";
+/// make_ascii_titlecase_words(&mut buf);
+/// assert_eq!(buf, *b"123Abc Def");
+///
+/// let mut buf = *b"  (abc)\t\txyz  ";
+/// make_ascii_titlecase_words(&mut buf);
+/// assert_eq!(buf, *b"  (Abc)\t\tXyz  ");
+/// ```
+///
+/// [`titlecase_words`]: crate::titlecase_words()
+#[inline]
+#[allow(clippy::module_name_repetitions)]
+pub fn make_ascii_titlecase_words<T: AsMut<[u8]>>(slice: &mut T) {
+    let mut rest = slice.as_mut();
+    // Whitespace and the bytes which precede the first letter of a word are
+    // unchanged, so skip directly to the next letter.
+    while let Some(start) = rest.iter().position(u8::is_ascii_alphabetic) {
+        let (_, word) = mem::take(&mut rest).split_at_mut(start);
+        let (head, tail) = word.split_at_mut(1);
+        head[0].make_ascii_uppercase();
+        let end = find_ascii_whitespace(tail).unwrap_or(tail.len());
+        let (interior, remainder) = tail.split_at_mut(end);
+        interior.make_ascii_lowercase();
+        rest = remainder;
+    }
+}
+
+/// Returns the offset of the first byte in `haystack` which is ASCII
+/// whitespace as defined by [`u8::is_ascii_whitespace`].
+fn find_ascii_whitespace(haystack: &[u8]) -> Option<usize> {
+    let end = memchr::memchr3(b' ', b'\t', b'\n', haystack);
+    // Form feeds and carriage returns only need to be searched for in the
+    // bytes before the first space, tab, or line feed.
+    let prefix = end.map_or(haystack, |end| &haystack[..end]);
+    memchr::memchr2(b'\x0C', b'\r', prefix).or(end)
+}
+
 /// Returns a vector containing a copy of the given slice where each byte is
 /// mapped to its ASCII title case equivalent.
 ///
@@ -98,6 +155,107 @@ mod tests {
         assert_eq!(buf, *b"");
     }
 
+    #[test]
+    fn make_ascii_titlecase_words_empty() {
+        let mut buf = *b"";
+        super::make_ascii_titlecase_words(&mut buf);
+        assert_eq!(buf, *b"");
+
+        let mut buf = *b" \t\n ";
+        super::make_ascii_titlecase_words(&mut buf);
+        assert_eq!(buf, *b" \t\n ");
+    }
+
+    #[test]
+    fn make_ascii_titlecase_words_whitespace() {
+        let mut buf = *b"abc   DEF\t\tgHi";
+        super::make_ascii_titlecase_words(&mut buf);
+        assert_eq!(buf, *b"Abc   Def\t\tGhi");
+
+        let mut buf = *b"  abc def  ";
+        super::make_ascii_titlecase_words(&mut buf);
+        assert_eq!(buf, *b"  Abc Def  ");
+
+        let mut buf = *b"\nabc\r\nXYZ\n";
+        super::make_ascii_titlecase_words(&mut buf);
+        assert_eq!(buf, *b"\nAbc\r\nXyz\n");
+    }
+
+    #[test]
+    fn make_ascii_titlecase_words_non_letter_word_starts() {
+        let mut buf = *b"123abc def";
+        super::make_ascii_titlecase_words(&mut buf);
+        assert_eq!(buf, *b"123Abc Def");
+
+        let mut buf = *b"1234 %&* 'QUOTED'";
+        super::make_ascii_titlecase_words(&mut buf);
+        assert_eq!(buf, *b"1234 %&* 'Quoted'");
+
+        // Underscores do not separate words.
+        let mut buf = *b"snake_CASE";
+        super::make_ascii_titlecase_words(&mut buf);
+        assert_eq!(buf, *b"Snake_case");
+    }
+
+    #[test]
+    fn make_ascii_titlecase_words_non_ascii_unchanged() {
+        // `é` and `É` are not ASCII letters, so `C` begins each word.
+        let mut buf = *b"\xC3\xA9COLE \xC3\x89COLE";
+        super::make_ascii_titlecase_words(&mut buf);
+        assert_eq!(&buf[..], "éCole ÉCole".as_bytes());
+
+        let mut buf = *b"\xFFABC \xFEdef";
+        super::make_ascii_titlecase_words(&mut buf);
+        assert_eq!(buf, *b"\xFFAbc \xFEDef");
+    }
+
+    // The straightforward byte-at-a-time implementation.
+    fn make_ascii_titlecase_words_reference(slice: &mut [u8]) {
+        let mut at_word_start = true;
+        for byte in slice {
+            if byte.is_ascii_whitespace() {
+                at_word_start = true;
+            } else if at_word_start && byte.is_ascii_alphabetic() {
+                byte.make_ascii_uppercase();
+                at_word_start = false;
+            } else {
+                byte.make_ascii_lowercase();
+            }
+        }
+    }
+
+    #[test]
+    fn make_ascii_titlecase_words_matches_reference() {
+        // Weight the alphabet toward letters and every kind of ASCII
+        // whitespace so that generated inputs have many short words.
+        const ALPHABET: &[u8] = b"aAzZmMqQ  \t\n\x0C\r_09'(\x0B\x00\x7F\xC3\xA9\xFF";
+
+        // A xorshift PRNG with a fixed seed keeps the test deterministic.
+        let mut state = 0x2545_F491_4F6C_DD1D_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..10_000 {
+            let mut buf = [0; 64];
+            #[allow(clippy::cast_possible_truncation)]
+            let len = (next() % 65) as usize;
+            for byte in &mut buf[..len] {
+                #[allow(clippy::cast_possible_truncation)]
+                let idx = (next() % ALPHABET.len() as u64) as usize;
+                *byte = ALPHABET[idx];
+            }
+            let input = buf;
+            let mut expected = buf;
+            make_ascii_titlecase_words_reference(&mut expected[..len]);
+            super::make_ascii_titlecase_words(&mut &mut buf[..len]);
+            assert_eq!(buf[..len], expected[..len], "{:?}", &input[..len]);
+        }
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn to_ascii_titlecase_empty() {
@@ -1,5 +1,7 @@
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::slice;
 
 /// Converts the given slice to its ASCII upper case equivalent in-place.
 ///
@@ -37,6 +39,81 @@ pub fn make_ascii_uppercase<T: AsMut<[u8]>>(slice: &mut T) {
     slice.make_ascii_uppercase();
 }
 
+/// Returns an iterator that converts the given slice to its ASCII upper case
+/// equivalent in-place, one byte at a time, and yields each converted byte.
+///
+/// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but non-ASCII letters are
+/// unchanged.
+///
+/// The conversion is lazy: a byte is uppercased in the slice when the iterator
+/// yields it. Once the iterator is exhausted, the slice is uppercased the same
+/// as by [`make_ascii_uppercase`]. Bytes which are not yet yielded when the
+/// iterator is dropped are left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::make_ascii_uppercase_iter;
+/// let mut buf = *b"abc, xyz";
+/// let yielded = make_ascii_uppercase_iter(&mut buf).collect::<Vec<_>>();
+/// assert_eq!(yielded, b"ABC, XYZ");
+/// assert_eq!(buf, *b"ABC, XYZ");
+///
+/// let mut buf = *b"abc, xyz";
+/// let first_word = make_ascii_uppercase_iter(&mut buf)
+///     .take_while(|&byte| byte != b',')
+///     .count();
+/// assert_eq!(first_word, 3);
+/// assert_eq!(buf, *b"ABC, xyz");
+/// ```
+#[inline]
+#[allow(clippy::module_name_repetitions)]
+pub fn make_ascii_uppercase_iter(slice: &mut [u8]) -> MakeAsciiUppercase<'_> {
+    MakeAsciiUppercase {
+        iter: slice.iter_mut(),
+    }
+}
+
+/// An iterator that converts a slice to its ASCII upper case equivalent
+/// in-place and yields each converted byte.
+///
+/// This struct is created by the [`make_ascii_uppercase_iter`] function. See
+/// its documentation for more.
+#[derive(Debug)]
+#[must_use = "MakeAsciiUppercase is a Iterator and must be used"]
+pub struct MakeAsciiUppercase<'a> {
+    iter: slice::IterMut<'a, u8>,
+}
+
+impl Iterator for MakeAsciiUppercase<'_> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let byte = self.iter.next()?;
+        byte.make_ascii_uppercase();
+        Some(*byte)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for MakeAsciiUppercase<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let byte = self.iter.next_back()?;
+        byte.make_ascii_uppercase();
+        Some(*byte)
+    }
+}
+
+impl ExactSizeIterator for MakeAsciiUppercase<'_> {}
+
+impl FusedIterator for MakeAsciiUppercase<'_> {}
+
 /// Returns a vector containing a copy of the given slice where each byte is
 /// mapped to its ASCII upper case equivalent.
 ///
@@ -71,6 +148,8 @@ pub fn to_ascii_uppercase<T: AsRef<[u8]>>(slice: T) -> Vec<u8> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     #[test]
     fn make_ascii_uppercase_empty() {
         let mut buf = *b"";
@@ -83,4 +162,32 @@ mod tests {
     fn to_ascii_uppercase_empty() {
         assert_eq!(super::to_ascii_uppercase(""), b"");
     }
+
+    #[test]
+    fn make_ascii_uppercase_iter_exhausted() {
+        let mut buf = *b"abc, XYZ \xFF\xC3\xA9 123";
+        let yielded = super::make_ascii_uppercase_iter(&mut buf).collect::<Vec<_>>();
+        assert_eq!(yielded, b"ABC, XYZ \xFF\xC3\xA9 123");
+        assert_eq!(buf, *b"ABC, XYZ \xFF\xC3\xA9 123");
+
+        let mut eager = *b"abc, XYZ \xFF\xC3\xA9 123";
+        super::make_ascii_uppercase(&mut eager);
+        assert_eq!(buf, eager);
+
+        let mut buf = *b"";
+        assert_eq!(super::make_ascii_uppercase_iter(&mut buf).next(), None);
+    }
+
+    #[test]
+    fn make_ascii_uppercase_iter_partially_consumed() {
+        let mut buf = *b"abcdef";
+        let mut iter = super::make_ascii_uppercase_iter(&mut buf);
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.next(), Some(b'A'));
+        assert_eq!(iter.next(), Some(b'B'));
+        assert_eq!(iter.next_back(), Some(b'F'));
+        assert_eq!(iter.len(), 3);
+        drop(iter);
+        assert_eq!(buf, *b"ABcdeF");
+    }
 }
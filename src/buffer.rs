@@ -0,0 +1,309 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+#[cfg(feature = "alloc")]
+use bstr::ByteSlice;
+
+/// The maximum length of the UTF-8 encoding of a `char`.
+const UTF_8_CHAR_MAX_BYTES: usize = 4;
+
+/// A buffer for the UTF-8 encoding of a single case mapped `char` or invalid
+/// UTF-8 byte sequence which is yielded one byte at a time.
+///
+/// Case mapping iterators yield bytes but map `char`s. Each mapped `char` is
+/// pushed into an `OutputBuffer` whole and its bytes are drained from either
+/// end before the next `char` is pushed.
+///
+/// The buffer is at most four bytes long, so the range of pending bytes is
+/// stored as `u8` indices to keep the case mapping iterators small.
+#[derive(Clone)]
+pub(crate) struct OutputBuffer {
+    bytes: [u8; UTF_8_CHAR_MAX_BYTES],
+    range: Range<u8>,
+}
+
+impl fmt::Debug for OutputBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only the bytes which are not yet yielded are meaningful.
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl OutputBuffer {
+    /// Create an empty buffer.
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0; UTF_8_CHAR_MAX_BYTES],
+            range: 0..0,
+        }
+    }
+
+    /// Replace the contents of the buffer with the UTF-8 encoding of `ch`.
+    pub(crate) fn push_char(&mut self, ch: char) {
+        debug_assert!(self.is_empty(), "buffered bytes were not yielded");
+
+        let len = ch.encode_utf8(&mut self.bytes).len();
+        self.fill(len);
+    }
+
+    /// Replace the contents of the buffer with the given bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is longer than the UTF-8 encoding of a `char`. Invalid
+    /// UTF-8 byte sequences are at most three bytes.
+    pub(crate) fn push_bytes(&mut self, bytes: &[u8]) {
+        debug_assert!(self.is_empty(), "buffered bytes were not yielded");
+
+        self.bytes[..bytes.len()].copy_from_slice(bytes);
+        self.fill(bytes.len());
+    }
+
+    // Mark the first `len` bytes of the buffer as pending.
+    #[allow(clippy::cast_possible_truncation)]
+    fn fill(&mut self, len: usize) {
+        debug_assert!(len <= UTF_8_CHAR_MAX_BYTES);
+        self.range = 0..len as u8;
+    }
+
+    /// Yield the next buffered byte from the front.
+    pub(crate) fn next_byte(&mut self) -> Option<u8> {
+        let idx = self.range.next()?;
+        Some(self.bytes[usize::from(idx)])
+    }
+
+    /// Yield the next buffered byte from the back.
+    pub(crate) fn next_back_byte(&mut self) -> Option<u8> {
+        let idx = self.range.next_back()?;
+        Some(self.bytes[usize::from(idx)])
+    }
+
+    /// The number of buffered bytes which are not yet yielded.
+    pub(crate) fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.bytes[usize::from(self.range.start)..usize::from(self.range.end)]
+    }
+}
+
+/// The case mapped bytes of an owned slice.
+///
+/// Case mapping iterators which take ownership of their slice cannot hold
+/// iterators which borrow from it, so the slice is case mapped in full when the
+/// iterator is created and the mapped bytes are yielded from this buffer.
+///
+/// The mapped bytes are kept after they are yielded so the iterator can be
+/// reset.
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub(crate) struct MappedBytes {
+    bytes: Vec<u8>,
+    range: Range<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for MappedBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MappedBytes")
+            .field(&self.as_slice().as_bstr())
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl MappedBytes {
+    /// Collect the bytes yielded by a case mapping iterator.
+    pub(crate) fn with_iter<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = u8> + Clone,
+    {
+        let bytes = crate::expansion::collect_exact(iter);
+        let range = 0..bytes.len();
+        Self { bytes, range }
+    }
+
+    /// The mapped bytes which are not yet yielded.
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.bytes[self.range.clone()]
+    }
+
+    /// Rewind to the first mapped byte.
+    pub(crate) fn reset(&mut self) {
+        self.range = 0..self.bytes.len();
+    }
+
+    /// Skip up to `n` bytes from the front in constant time.
+    pub(crate) fn advance(&mut self, n: usize) {
+        self.range.start = self.range.start.saturating_add(n).min(self.range.end);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Iterator for MappedBytes {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|idx| self.bytes[idx])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn count(self) -> usize {
+        self.range.len()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).map(|idx| self.bytes[idx])
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DoubleEndedIterator for MappedBytes {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|idx| self.bytes[idx])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ExactSizeIterator for MappedBytes {}
+
+#[cfg(feature = "alloc")]
+impl FusedIterator for MappedBytes {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::vec::Vec;
+
+    use super::OutputBuffer;
+
+    fn drain(buf: &mut OutputBuffer) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        while let Some(byte) = buf.next_byte() {
+            bytes.push(byte);
+        }
+        bytes
+    }
+
+    fn drain_back(buf: &mut OutputBuffer) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        while let Some(byte) = buf.next_back_byte() {
+            bytes.push(byte);
+        }
+        bytes.reverse();
+        bytes
+    }
+
+    #[test]
+    fn empty() {
+        let mut buf = OutputBuffer::new();
+        assert_eq!(buf.len(), 0);
+        assert!(buf.is_empty());
+        assert_eq!(buf.next_byte(), None);
+        assert_eq!(buf.next_back_byte(), None);
+    }
+
+    #[test]
+    fn encodings_of_every_length() {
+        // `a` U+0061, `é` U+00E9, `ﬃ` U+FB03, and `😀` U+1F600 encode to one,
+        // two, three, and four bytes.
+        for ch in ['a', 'é', 'ﬃ', '😀'] {
+            let mut expected = [0; 4];
+            let expected = ch.encode_utf8(&mut expected).as_bytes();
+
+            let mut buf = OutputBuffer::new();
+            buf.push_char(ch);
+            assert_eq!(buf.len(), expected.len());
+            assert_eq!(drain(&mut buf), expected, "{ch:?}");
+            assert!(buf.is_empty());
+
+            buf.push_char(ch);
+            assert_eq!(drain_back(&mut buf), expected, "{ch:?}");
+            assert!(buf.is_empty());
+        }
+    }
+
+    #[test]
+    fn drain_from_both_ends() {
+        let mut buf = OutputBuffer::new();
+        buf.push_char('😀');
+        assert_eq!(buf.next_byte(), Some(0xF0));
+        assert_eq!(buf.next_back_byte(), Some(0x80));
+        assert_eq!(buf.len(), 2);
+        assert_eq!(buf.next_byte(), Some(0x9F));
+        assert_eq!(buf.next_back_byte(), Some(0x98));
+        assert_eq!(buf.next_byte(), None);
+        assert_eq!(buf.next_back_byte(), None);
+    }
+
+    #[test]
+    fn push_bytes() {
+        let mut buf = OutputBuffer::new();
+        buf.push_bytes(b"\xF0\x9F\x87");
+        assert_eq!(drain(&mut buf), b"\xF0\x9F\x87");
+        buf.push_bytes(b"\xFF");
+        assert_eq!(drain_back(&mut buf), b"\xFF");
+        buf.push_bytes(b"");
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn push_replaces_drained_contents() {
+        let mut buf = OutputBuffer::new();
+        buf.push_char('😀');
+        drain(&mut buf);
+        buf.push_char('a');
+        assert_eq!(drain(&mut buf), b"a");
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn push_bytes_longer_than_a_char_panics() {
+        let mut buf = OutputBuffer::new();
+        buf.push_bytes(b"\xFF\xFF\xFF\xFF\xFF");
+    }
+
+    #[test]
+    fn debug_shows_pending_bytes() {
+        let mut buf = OutputBuffer::new();
+        assert_eq!(format!("{buf:?}"), "[]");
+        buf.push_char('é');
+        buf.next_byte();
+        assert_eq!(format!("{buf:?}"), "[169]");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn mapped_bytes_reset_and_advance() {
+        use super::MappedBytes;
+
+        let mut bytes = MappedBytes::with_iter(b"abcdef".iter().copied());
+        assert_eq!(bytes.next(), Some(b'a'));
+        assert_eq!(bytes.next_back(), Some(b'f'));
+        assert_eq!(bytes.as_slice(), b"bcde");
+        bytes.advance(3);
+        assert_eq!(bytes.as_slice(), b"e");
+        bytes.advance(usize::MAX);
+        assert_eq!(bytes.len(), 0);
+        assert_eq!(bytes.next(), None);
+        bytes.reset();
+        assert_eq!(bytes.as_slice(), b"abcdef");
+        assert_eq!(format!("{bytes:?}"), "MappedBytes(\"abcdef\")");
+    }
+}
@@ -0,0 +1,496 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::buffer::OutputBuffer;
+use crate::LowercaseMode;
+
+/// Compares the two given slices case-insensitively.
+///
+/// This function treats the given slices as [conventionally UTF-8 strings].
+/// Invalid UTF-8 byte sequences are compared as is.
+///
+/// Slices are compared lexicographically by the bytes of their [full Unicode
+/// case folding].
+///
+/// This is not the ordering of Ruby's `String#casecmp`, which only folds ASCII
+/// `A-Z`, so `"äöü".casecmp("ÄÖÜ")` is `1` in Ruby. Instead, `casecmp` is the
+/// ordering which is consistent with [`casecmp_eq`] and Ruby's
+/// `String#casecmp?`.
+///
+/// The comparison is lazy and does not allocate. It stops at the first
+/// differing byte.
+///
+/// # Examples
+///
+/// ```
+/// # use core::cmp::Ordering;
+/// # use roe::casecmp;
+/// assert_eq!(casecmp(b"aBcDeF", b"abcdef"), Ordering::Equal);
+/// assert_eq!(casecmp(b"abcdef", b"ABCDEFG"), Ordering::Less);
+/// assert_eq!(casecmp(b"abcdef", b"ABCDEE"), Ordering::Greater);
+/// assert_eq!(casecmp("STRASSE".as_bytes(), "straße".as_bytes()), Ordering::Equal);
+/// ```
+///
+/// [conventionally UTF-8 strings]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [full Unicode case folding]: LowercaseMode::Fold
+#[must_use]
+pub fn casecmp(a: &[u8], b: &[u8]) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+    crate::lowercase(a, LowercaseMode::Fold).cmp(crate::lowercase(b, LowercaseMode::Fold))
+}
+
+/// Returns whether the two given slices are equal when compared by their
+/// [full Unicode case folding].
+///
+/// This function treats the given slices as [conventionally UTF-8 strings].
+/// Invalid UTF-8 byte sequences are compared as is.
+///
+/// This is the equality of Ruby's `String#casecmp?` when both strings are
+/// UTF-8. It is equivalent to `casecmp(a, b) == Ordering::Equal`.
+///
+/// The comparison is lazy and does not allocate. It stops at the first
+/// differing byte.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::casecmp_eq;
+/// assert!(casecmp_eq(b"ABC", b"abc"));
+/// assert!(casecmp_eq("ß".as_bytes(), b"ss"));
+/// assert!(casecmp_eq("ΑΎΡΙΟ".as_bytes(), "αύριο".as_bytes()));
+/// assert!(!casecmp_eq(b"abc", b"abd"));
+/// ```
+///
+/// [conventionally UTF-8 strings]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [full Unicode case folding]: LowercaseMode::Fold
+#[must_use]
+pub fn casecmp_eq(a: &[u8], b: &[u8]) -> bool {
+    if a == b {
+        return true;
+    }
+    crate::lowercase(a, LowercaseMode::Fold).eq(crate::lowercase(b, LowercaseMode::Fold))
+}
+
+/// Compares the two given slices case-insensitively by their simple lowercase
+/// mappings.
+///
+/// This function treats the given slices as [conventionally UTF-8 strings].
+/// Invalid UTF-8 byte sequences are compared as is.
+///
+/// Each scalar in the slices is replaced with its simple (1:1) lowercase
+/// mapping from `UnicodeData.txt` and the slices are compared
+/// lexicographically by the resulting bytes. Unlike [`casecmp`] and
+/// [`casecmp_eq`], which compare by [full Unicode case folding], no scalar
+/// expands into several scalars:
+///
+/// - `ß` stays a single scalar, so it does not compare equal to `ss` or `SS`.
+/// - `İ` maps to `i`, so it compares equal to `i` and `I` instead of to `i`
+///   followed by U+0307 COMBINING DOT ABOVE.
+/// - `ς` final sigma is already lowercase, so it does not compare equal to `σ`
+///   or `Σ`.
+///
+/// The comparison is lazy and does not allocate. It stops at the first
+/// differing byte.
+///
+/// # Examples
+///
+/// ```
+/// # use core::cmp::Ordering;
+/// # use roe::{casecmp, casecmp_simple};
+/// assert_eq!(casecmp_simple(b"aBcDeF", b"abcdef"), Ordering::Equal);
+/// assert_eq!(casecmp_simple("ΑΎΡΙΟ".as_bytes(), "αύριο".as_bytes()), Ordering::Equal);
+///
+/// // `ß` does not expand.
+/// assert_eq!(casecmp_simple("ß".as_bytes(), b"SS"), Ordering::Greater);
+/// assert_eq!(casecmp("ß".as_bytes(), b"SS"), Ordering::Equal);
+/// ```
+///
+/// [conventionally UTF-8 strings]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [full Unicode case folding]: LowercaseMode::Fold
+#[must_use]
+pub fn casecmp_simple(a: &[u8], b: &[u8]) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+    SimpleLowercase::new(a).cmp(SimpleLowercase::new(b))
+}
+
+/// Returns the simple (1:1) lowercase mapping of the given `char`.
+fn simple_lowercase(ch: char) -> char {
+    // `İ` U+0130 LATIN CAPITAL LETTER I WITH DOT ABOVE is the only `char`
+    // whose full lowercase mapping is longer than one `char`. Its simple
+    // lowercase mapping is the first `char` of the full mapping, `i`.
+    ch.to_lowercase()
+        .next()
+        .expect("ToLowercase yields at least one char")
+}
+
+/// An iterator over the bytes of a conventionally UTF-8 string with each scalar
+/// replaced with its simple lowercase mapping.
+struct SimpleLowercase<'a> {
+    slice: &'a [u8],
+    next_bytes: OutputBuffer,
+}
+
+impl<'a> SimpleLowercase<'a> {
+    const fn new(slice: &'a [u8]) -> Self {
+        Self {
+            slice,
+            next_bytes: OutputBuffer::new(),
+        }
+    }
+}
+
+impl Iterator for SimpleLowercase<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_bytes.next_byte() {
+            return Some(byte);
+        }
+        let (&byte, remainder) = self.slice.split_first()?;
+        if byte.is_ascii() {
+            self.slice = remainder;
+            return Some(byte.to_ascii_lowercase());
+        }
+        match bstr::decode_utf8(self.slice) {
+            (Some(ch), size) => {
+                self.next_bytes.push_char(simple_lowercase(ch));
+                self.slice = &self.slice[size..];
+            }
+            (None, size) => {
+                self.next_bytes.push_bytes(&self.slice[..size]);
+                self.slice = &self.slice[size..];
+            }
+        }
+        self.next_bytes.next_byte()
+    }
+}
+
+/// Returns the canonical case-insensitive key of the given slice.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string].
+/// Invalid UTF-8 byte sequences are copied as is.
+///
+/// The key is the [full Unicode case folding] of the slice, the same bytes
+/// which [`casecmp`] and [`casecmp_eq`] compare. Keys are suitable for hashing
+/// and equality in case-insensitive collections: `fold_key(a) == fold_key(b)`
+/// if and only if `casecmp_eq(a, b)`.
+///
+/// This function is equivalent to [`to_lowercase`] with [`LowercaseMode::Fold`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::fold_key;
+/// assert_eq!(fold_key("Straße".as_bytes()), b"strasse");
+/// assert_eq!(fold_key("Straße".as_bytes()), fold_key(b"STRASSE"));
+/// assert_eq!(fold_key("ΣΊΣΥΦΟΣ".as_bytes()), "σίσυφοσ".as_bytes());
+/// ```
+///
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [full Unicode case folding]: LowercaseMode::Fold
+/// [`to_lowercase`]: crate::to_lowercase()
+#[must_use]
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn fold_key(slice: &[u8]) -> Vec<u8> {
+    crate::to_lowercase(slice, LowercaseMode::Fold)
+}
+
+/// Returns whether the two given slices are equal when compared
+/// case-insensitively.
+///
+/// This function treats the given slices as [conventionally UTF-8 strings].
+/// Invalid UTF-8 byte sequences are compared as is.
+///
+/// Slices are compared by their [full Unicode case folding]. This function is
+/// an alias of [`casecmp_eq`].
+///
+/// The comparison is lazy and does not allocate. It stops at the first
+/// differing byte.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::folds_same;
+/// assert!(folds_same(b"Artichoke", b"ARTICHOKE"));
+/// assert!(folds_same("ΑΎΡΙΟ".as_bytes(), "αύριο".as_bytes()));
+/// assert!(folds_same("Straße".as_bytes(), b"STRASSE"));
+/// assert!(!folds_same(b"Artichoke", b"Ruby"));
+///
+/// // Dotted and dotless I are distinct letters.
+/// assert!(!folds_same("I".as_bytes(), "ı".as_bytes()));
+/// ```
+///
+/// [conventionally UTF-8 strings]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [full Unicode case folding]: LowercaseMode::Fold
+#[must_use]
+pub fn folds_same(a: &[u8], b: &[u8]) -> bool {
+    casecmp_eq(a, b)
+}
+
+/// Returns the index of the first key in `existing` which compares
+/// case-insensitively equal to `new`.
+///
+/// This function packages the "case-insensitive uniqueness check" performed
+/// when inserting a key into a case-insensitive namespace. Keys are compared
+/// with [`folds_same`].
+///
+/// Returns [`None`] if `new` does not collide with any existing key.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::find_fold_collision;
+/// let existing: &[&[u8]] = &[b"Content-Type", b"Content-Length"];
+/// assert_eq!(find_fold_collision(b"content-length", existing), Some(1));
+/// assert_eq!(find_fold_collision(b"Accept", existing), None);
+/// ```
+#[must_use]
+pub fn find_fold_collision(new: &[u8], existing: &[&[u8]]) -> Option<usize> {
+    existing.iter().position(|key| folds_same(new, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    #[cfg(feature = "alloc")]
+    use super::fold_key;
+    use super::{
+        casecmp, casecmp_eq, casecmp_simple, find_fold_collision, folds_same, simple_lowercase,
+    };
+
+    #[test]
+    fn empty() {
+        assert!(folds_same(b"", b""));
+        assert!(!folds_same(b"", b"a"));
+        assert_eq!(find_fold_collision(b"", &[]), None);
+        assert_eq!(find_fold_collision(b"", &[b"a", b""]), Some(1));
+    }
+
+    #[test]
+    fn ascii() {
+        assert!(folds_same(b"abc", b"ABC"));
+        assert!(folds_same(b"aBc, 123", b"AbC, 123"));
+        assert!(!folds_same(b"abc", b"abcd"));
+        assert!(!folds_same(b"abc", b"abd"));
+    }
+
+    #[test]
+    fn unicode() {
+        assert!(folds_same("Αύριο".as_bytes(), "ΑΎΡΙΟ".as_bytes()));
+        assert!(folds_same("привет".as_bytes(), "ПРИВЕТ".as_bytes()));
+        assert!(folds_same("ǅ".as_bytes(), "Ǆ".as_bytes()));
+        // KELVIN SIGN lowercases to ASCII `k`.
+        assert!(folds_same("\u{212A}".as_bytes(), b"k"));
+    }
+
+    // The Turkish dotted and dotless I are the classic case-insensitive
+    // collision pitfall:
+    //
+    // - `I` and `ı` (U+0131 LATIN SMALL LETTER DOTLESS I) do not collide.
+    // - `İ` (U+0130 LATIN CAPITAL LETTER I WITH DOT ABOVE) and `i` do not
+    //   collide because `İ` maps to `i` followed by U+0307 COMBINING DOT ABOVE.
+    // - `İ` and `i\u{307}` do collide.
+    #[test]
+    fn turkish_i_collisions() {
+        assert!(!folds_same(b"I", "ı".as_bytes()));
+        assert!(!folds_same(b"i", "ı".as_bytes()));
+        assert!(!folds_same("İ".as_bytes(), b"i"));
+        assert!(!folds_same("İ".as_bytes(), b"I"));
+        assert!(folds_same("İ".as_bytes(), "i\u{307}".as_bytes()));
+        assert!(folds_same(
+            "İSTANBUL".as_bytes(),
+            "i\u{307}stanbul".as_bytes()
+        ));
+
+        let existing: &[&[u8]] = &["ıstanbul".as_bytes(), "i\u{307}stanbul".as_bytes()];
+        assert_eq!(find_fold_collision(b"ISTANBUL", existing), None);
+        assert_eq!(
+            find_fold_collision("İSTANBUL".as_bytes(), existing),
+            Some(1)
+        );
+        assert_eq!(
+            find_fold_collision("ıSTANBUL".as_bytes(), existing),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        assert!(folds_same(b"ABC\xFF\xFE", b"abc\xFF\xFE"));
+        assert!(!folds_same(b"abc\xFF", b"abc\xFE"));
+        assert!(!folds_same(b"\xFF", "\u{FFFD}".as_bytes()));
+    }
+
+    #[test]
+    fn sharp_s_collisions() {
+        assert!(folds_same("ß".as_bytes(), b"ss"));
+        assert!(folds_same("ß".as_bytes(), b"SS"));
+        assert!(folds_same("ẞ".as_bytes(), b"ss"));
+        assert_eq!(
+            folds_same("ß".as_bytes(), b"ss"),
+            casecmp_eq("ß".as_bytes(), b"ss")
+        );
+
+        let existing: &[&[u8]] = &[b"Strand", "Straße".as_bytes()];
+        assert_eq!(find_fold_collision(b"STRASSE", existing), Some(1));
+        assert_eq!(find_fold_collision(b"strasse", existing), Some(1));
+        assert_eq!(find_fold_collision("STRAẞE".as_bytes(), existing), Some(1));
+        assert_eq!(find_fold_collision(b"STRASE", existing), None);
+    }
+
+    #[test]
+    fn first_collision_is_returned() {
+        let existing: &[&[u8]] = &[b"Alpha", b"BETA", b"beta", b"Gamma"];
+        assert_eq!(find_fold_collision(b"Beta", existing), Some(1));
+        assert_eq!(find_fold_collision(b"gAMMA", existing), Some(3));
+        assert_eq!(find_fold_collision(b"Delta", existing), None);
+    }
+
+    #[test]
+    fn casecmp_ascii() {
+        assert!(casecmp_eq(b"ABC", b"abc"));
+        assert!(casecmp_eq(b"", b""));
+        assert!(!casecmp_eq(b"abc", b"abcd"));
+        assert_eq!(casecmp(b"ABC", b"abc"), Ordering::Equal);
+        assert_eq!(casecmp(b"abc", b"ABCD"), Ordering::Less);
+        assert_eq!(casecmp(b"ABCD", b"abc"), Ordering::Greater);
+        assert_eq!(casecmp(b"abc", b"ABD"), Ordering::Less);
+        assert_eq!(casecmp(b"", b"a"), Ordering::Less);
+        // Folding maps to lowercase, so `[` sorts after `A`, not before `a`.
+        assert_eq!(casecmp(b"[", b"a"), Ordering::Less);
+        assert_eq!(casecmp(b"[", b"A"), Ordering::Less);
+    }
+
+    #[test]
+    fn casecmp_full_folding() {
+        assert!(casecmp_eq("ß".as_bytes(), b"ss"));
+        assert!(casecmp_eq("ß".as_bytes(), b"SS"));
+        assert!(casecmp_eq("ẞ".as_bytes(), "ß".as_bytes()));
+        assert!(casecmp_eq("ﬃ".as_bytes(), b"FFI"));
+        assert!(casecmp_eq("\u{212A}".as_bytes(), b"K"));
+        assert!(casecmp_eq("Σίσυφος".as_bytes(), "ΣΊΣΥΦΟΣ".as_bytes()));
+        assert_eq!(casecmp("straße".as_bytes(), b"STRASSE"), Ordering::Equal);
+        assert_eq!(casecmp("straße".as_bytes(), b"STRAST"), Ordering::Less);
+        assert!(!casecmp_eq(b"I", "ı".as_bytes()));
+    }
+
+    #[test]
+    fn casecmp_invalid_utf8() {
+        assert!(casecmp_eq(b"ABC\xFF\xFE", b"abc\xFF\xFE"));
+        assert!(!casecmp_eq(b"abc\xFF", b"abc\xFE"));
+        assert!(!casecmp_eq(b"\xFF", "\u{FFFD}".as_bytes()));
+        assert_eq!(casecmp(b"abc\xFE", b"ABC\xFF"), Ordering::Less);
+        // A truncated KELVIN SIGN is compared as is, while KELVIN SIGN folds
+        // to ASCII `k`.
+        assert_eq!(
+            casecmp(b"\xE2\x84", "\u{212A}".as_bytes()),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn fold_key_is_casecmp_eq() {
+        let inputs: [&[u8]; 12] = [
+            b"",
+            "Straße".as_bytes(),
+            b"STRASSE",
+            b"strasse",
+            "STRAẞE".as_bytes(),
+            "ΣΊΣΥΦΟΣ".as_bytes(),
+            "σίσυφος".as_bytes(),
+            "Σίσυφοσ".as_bytes(),
+            "İ".as_bytes(),
+            b"i",
+            b"ABC\xFF\xFE",
+            b"abc\xFF\xFE",
+        ];
+        for a in inputs {
+            for b in inputs {
+                assert_eq!(fold_key(a) == fold_key(b), casecmp_eq(a, b));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn fold_key_sigma_variants() {
+        // Capital, medial, and final sigma all fold to medial sigma.
+        let key = "σ".as_bytes();
+        assert_eq!(fold_key("Σ".as_bytes()), key);
+        assert_eq!(fold_key("σ".as_bytes()), key);
+        assert_eq!(fold_key("ς".as_bytes()), key);
+        assert_eq!(fold_key("ΟΔΟΣ".as_bytes()), fold_key("οδος".as_bytes()));
+        assert_eq!(fold_key("Straße".as_bytes()), fold_key(b"STRASSE"));
+        assert_eq!(fold_key("Straße".as_bytes()), b"strasse");
+    }
+
+    #[test]
+    fn casecmp_simple_does_not_expand() {
+        assert_eq!(casecmp_simple("ß".as_bytes(), b"SS"), Ordering::Greater);
+        assert_eq!(casecmp_simple("ß".as_bytes(), b"ss"), Ordering::Greater);
+        assert_eq!(casecmp_simple(b"SS", "ß".as_bytes()), Ordering::Less);
+        assert_eq!(casecmp("ß".as_bytes(), b"SS"), Ordering::Equal);
+        assert_eq!(
+            casecmp_simple("ẞ".as_bytes(), "ß".as_bytes()),
+            Ordering::Equal
+        );
+        assert_eq!(casecmp_simple("ﬃ".as_bytes(), b"FFI"), Ordering::Greater);
+
+        // `İ` maps to `i` without a combining dot.
+        assert_eq!(casecmp_simple("İ".as_bytes(), b"i"), Ordering::Equal);
+        assert_eq!(casecmp_simple("İ".as_bytes(), b"I"), Ordering::Equal);
+        assert_eq!(casecmp("İ".as_bytes(), b"i"), Ordering::Greater);
+
+        // Final sigma is not folded to medial sigma.
+        assert_eq!(
+            casecmp_simple("ΟΔΟΣ".as_bytes(), "οδοσ".as_bytes()),
+            Ordering::Equal
+        );
+        assert_ne!(
+            casecmp_simple("ΟΔΟΣ".as_bytes(), "οδος".as_bytes()),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn casecmp_simple_ordering() {
+        assert_eq!(casecmp_simple(b"", b""), Ordering::Equal);
+        assert_eq!(casecmp_simple(b"ABC", b"abc"), Ordering::Equal);
+        assert_eq!(casecmp_simple(b"abc", b"ABCD"), Ordering::Less);
+        assert_eq!(casecmp_simple(b"abd", b"ABC"), Ordering::Greater);
+        assert_eq!(casecmp_simple(b"[", b"A"), Ordering::Less);
+        assert_eq!(casecmp_simple("\u{212A}".as_bytes(), b"k"), Ordering::Equal);
+        assert_eq!(
+            casecmp_simple(b"ABC\xFF\xFE", b"abc\xFF\xFE"),
+            Ordering::Equal
+        );
+        assert_eq!(casecmp_simple(b"abc\xFE", b"ABC\xFF"), Ordering::Less);
+        assert_ne!(
+            casecmp_simple(b"\xFF", "\u{FFFD}".as_bytes()),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn simple_lowercase_is_first_char_of_full_lowercase() {
+        // Only `İ` has a full lowercase mapping longer than one char, and its
+        // simple lowercase mapping is `i`.
+        for ch in (0..=0x10_FFFF).filter_map(char::from_u32) {
+            if ch.to_lowercase().len() > 1 {
+                assert_eq!(ch, 'İ');
+            }
+        }
+        assert_eq!(simple_lowercase('İ'), 'i');
+        assert_eq!(simple_lowercase('ẞ'), 'ß');
+        assert_eq!(simple_lowercase('ß'), 'ß');
+        assert_eq!(simple_lowercase('Σ'), 'σ');
+    }
+}
@@ -0,0 +1,265 @@
+use core::fmt::{self, Write};
+
+use crate::{LowercaseMode, TitlecaseMode, UppercaseMode};
+
+/// Write a copy of the given string with all uppercase letters replaced with
+/// their lowercase counterparts to the given formatter.
+///
+/// This function pushes the `char`s yielded by [`lowercase_chars`] into `f`
+/// without allocating, which makes it suitable for implementing
+/// [`Display`](fmt::Display) in `no_std` environments. `f` may be any
+/// [`fmt::Write`], including a [`fmt::Formatter`].
+///
+/// The case mapping mode is determined by the given [`LowercaseMode`]. See
+/// [`lowercase`] for details on how the input is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use core::fmt;
+/// # use roe::LowercaseMode;
+/// struct Lower<'a>(&'a str);
+///
+/// impl fmt::Display for Lower<'_> {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         roe::fmt_lowercase(f, self.0, LowercaseMode::Full)
+///     }
+/// }
+///
+/// assert_eq!(Lower("ΑΎΡΙΟ").to_string(), "αύριο");
+/// ```
+///
+/// # Errors
+///
+/// If the formatter returns an error, it is propagated and writing stops.
+/// Some case mapped `char`s may have already been written.
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [`lowercase_chars`]: crate::lowercase_chars()
+/// [`lowercase`]: crate::lowercase()
+pub fn fmt_lowercase<W>(f: &mut W, s: &str, options: LowercaseMode) -> fmt::Result
+where
+    W: Write + ?Sized,
+{
+    crate::lowercase_chars(s.as_bytes(), options).try_for_each(|ch| f.write_char(ch))
+}
+
+/// Write a copy of the given string with all lowercase letters replaced with
+/// their uppercase counterparts to the given formatter.
+///
+/// This function pushes the `char`s yielded by [`uppercase_chars`] into `f`
+/// without allocating, which makes it suitable for implementing
+/// [`Display`](fmt::Display) in `no_std` environments. `f` may be any
+/// [`fmt::Write`], including a [`fmt::Formatter`].
+///
+/// The case mapping mode is determined by the given [`UppercaseMode`]. See
+/// [`uppercase`] for details on how the input is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use core::fmt;
+/// # use roe::UppercaseMode;
+/// struct Upper<'a>(&'a str);
+///
+/// impl fmt::Display for Upper<'_> {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         roe::fmt_uppercase(f, self.0, UppercaseMode::Full)
+///     }
+/// }
+///
+/// assert_eq!(Upper("straße").to_string(), "STRASSE");
+/// ```
+///
+/// # Errors
+///
+/// If the formatter returns an error, it is propagated and writing stops.
+/// Some case mapped `char`s may have already been written.
+///
+/// [`uppercase_chars`]: crate::uppercase_chars()
+/// [`uppercase`]: crate::uppercase()
+pub fn fmt_uppercase<W>(f: &mut W, s: &str, options: UppercaseMode) -> fmt::Result
+where
+    W: Write + ?Sized,
+{
+    crate::uppercase_chars(s.as_bytes(), options).try_for_each(|ch| f.write_char(ch))
+}
+
+/// Write a copy of the given string with the first character replaced with its
+/// titlecase counterpart and all remaining uppercase letters replaced with
+/// their lowercase counterparts to the given formatter.
+///
+/// This function decodes the bytes yielded by [`titlecase`] and pushes each
+/// `char` into `f` without allocating, which makes it suitable for
+/// implementing [`Display`](fmt::Display) in `no_std` environments. `f` may be
+/// any [`fmt::Write`], including a [`fmt::Formatter`].
+///
+/// The case mapping mode is determined by the given [`TitlecaseMode`]. See
+/// [`titlecase`] for details on how the input is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use core::fmt;
+/// # use roe::TitlecaseMode;
+/// struct Title<'a>(&'a str);
+///
+/// impl fmt::Display for Title<'_> {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         roe::fmt_titlecase(f, self.0, TitlecaseMode::Full)
+///     }
+/// }
+///
+/// assert_eq!(Title("ǆUNGLA").to_string(), "ǅungla");
+/// ```
+///
+/// # Errors
+///
+/// If the formatter returns an error, it is propagated and writing stops.
+/// Some case mapped `char`s may have already been written.
+///
+/// # Panics
+///
+/// This function panics for the same [`TitlecaseMode`]s as [`titlecase`].
+///
+/// [`titlecase`]: crate::titlecase()
+pub fn fmt_titlecase<W>(f: &mut W, s: &str, options: TitlecaseMode) -> fmt::Result
+where
+    W: Write + ?Sized,
+{
+    // The input is valid UTF-8, so the titlecased bytes are too, and every
+    // char is complete after at most four bytes.
+    let mut buf = [0; 4];
+    let mut len = 0;
+    for byte in crate::titlecase(s.as_bytes(), options) {
+        buf[len] = byte;
+        len += 1;
+        if let (Some(ch), _) = bstr::decode_utf8(&buf[..len]) {
+            f.write_char(ch)?;
+            len = 0;
+        }
+    }
+    debug_assert_eq!(len, 0);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use core::fmt::{self, Write};
+
+    use super::{fmt_lowercase, fmt_titlecase, fmt_uppercase};
+    use crate::{LowercaseMode, TitlecaseMode, UppercaseMode};
+
+    enum Mapped<'a> {
+        Lowercase(&'a str, LowercaseMode),
+        Uppercase(&'a str, UppercaseMode),
+        Titlecase(&'a str, TitlecaseMode),
+    }
+
+    impl fmt::Display for Mapped<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match *self {
+                Self::Lowercase(s, mode) => fmt_lowercase(f, s, mode),
+                Self::Uppercase(s, mode) => fmt_uppercase(f, s, mode),
+                Self::Titlecase(s, mode) => fmt_titlecase(f, s, mode),
+            }
+        }
+    }
+
+    const INPUTS: [&str; 5] = ["", "abc, XYZ", "ΑΎΡΙΟ ΟΔΟΣ", "ß ﬃ İ ΐ", "ǆUNGLA 😀"];
+
+    fn mapped(iter: impl Iterator<Item = u8>) -> String {
+        String::from_utf8(iter.collect()).unwrap()
+    }
+
+    #[test]
+    fn display_matches_lowercase() {
+        for s in INPUTS {
+            for mode in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Lithuanian,
+                LowercaseMode::Fold,
+            ] {
+                let expected = mapped(crate::lowercase(s.as_bytes(), mode));
+                assert_eq!(Mapped::Lowercase(s, mode).to_string(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn display_matches_uppercase() {
+        for s in INPUTS {
+            for mode in [
+                UppercaseMode::Full,
+                UppercaseMode::Ascii,
+                UppercaseMode::Turkic,
+                UppercaseMode::CapitalSharpS,
+            ] {
+                let expected = mapped(crate::uppercase(s.as_bytes(), mode));
+                assert_eq!(Mapped::Uppercase(s, mode).to_string(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn display_matches_titlecase() {
+        for s in INPUTS {
+            for mode in [
+                TitlecaseMode::Full,
+                TitlecaseMode::Ascii,
+                TitlecaseMode::Lithuanian,
+            ] {
+                let expected = mapped(crate::titlecase(s.as_bytes(), mode));
+                assert_eq!(Mapped::Titlecase(s, mode).to_string(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn composes_with_other_writes() {
+        // The helpers write with `write_char`, so they compose with other
+        // writes in the same `Display` implementation.
+        struct Greeting<'a>(&'a str);
+
+        impl fmt::Display for Greeting<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("<")?;
+                fmt_titlecase(f, self.0, TitlecaseMode::Full)?;
+                f.write_str(">")
+            }
+        }
+
+        assert_eq!(Greeting("hELLO").to_string(), "<Hello>");
+    }
+
+    #[test]
+    fn errors_are_propagated() {
+        // A writer which fails after accepting `n` chars.
+        struct Limited(usize);
+
+        impl Write for Limited {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                for _ in s.chars() {
+                    self.0 = self.0.checked_sub(1).ok_or(fmt::Error)?;
+                }
+                Ok(())
+            }
+        }
+
+        assert!(fmt_lowercase(&mut Limited(3), "ABC", LowercaseMode::Full).is_ok());
+        assert!(fmt_lowercase(&mut Limited(2), "ABC", LowercaseMode::Full).is_err());
+        assert!(fmt_uppercase(&mut Limited(1), "ß", UppercaseMode::Full).is_err());
+        assert!(fmt_titlecase(&mut Limited(2), "ﬃ", TitlecaseMode::Full).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "not yet implemented")]
+    fn turkic_lowercase_panics() {
+        let _ = Mapped::Lowercase("I", LowercaseMode::Turkic).to_string();
+    }
+}
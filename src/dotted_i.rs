@@ -0,0 +1,151 @@
+//! Case mapping matrix for the dotted and dotless forms of the letter I.
+//!
+//! `I` U+0049, `i` U+0069, `İ` U+0130 LATIN CAPITAL LETTER I WITH DOT ABOVE,
+//! and `ı` U+0131 LATIN SMALL LETTER DOTLESS I are the trickiest scalars to
+//! case map: their mappings depend on the mode, `İ` expands when lowercased,
+//! and none of them round trip in every mode.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use bstr::ByteSlice;
+
+use crate::{LowercaseMode, UppercaseMode};
+
+const LETTERS: [&str; 4] = ["I", "i", "İ", "ı"];
+
+const DOTTED_I_LOWERCASE: &[u8] = &[0x69, 0xCC, 0x87];
+
+fn lowercase(s: &str, mode: LowercaseMode) -> Vec<u8> {
+    crate::lowercase(s.as_bytes(), mode).collect()
+}
+
+fn uppercase(s: &str, mode: UppercaseMode) -> Vec<u8> {
+    crate::uppercase(s.as_bytes(), mode).collect()
+}
+
+fn assert_lowercase(mode: LowercaseMode, expected: [&[u8]; 4]) {
+    for (&letter, expected) in LETTERS.iter().zip(expected) {
+        let mapped = lowercase(letter, mode);
+        assert_eq!(
+            mapped.as_bstr(),
+            expected.as_bstr(),
+            "lowercase {letter:?} in {mode:?} mode"
+        );
+        // The char iterator agrees with the byte iterator.
+        let chars = crate::lowercase_chars(letter.as_bytes(), mode).collect::<String>();
+        assert_eq!(chars.as_bytes().as_bstr(), expected.as_bstr());
+    }
+}
+
+fn assert_uppercase(mode: UppercaseMode, expected: [&[u8]; 4]) {
+    for (&letter, expected) in LETTERS.iter().zip(expected) {
+        let mapped = uppercase(letter, mode);
+        assert_eq!(
+            mapped.as_bstr(),
+            expected.as_bstr(),
+            "uppercase {letter:?} in {mode:?} mode"
+        );
+        let chars = crate::uppercase_chars(letter.as_bytes(), mode).collect::<String>();
+        assert_eq!(chars.as_bytes().as_bstr(), expected.as_bstr());
+    }
+}
+
+#[test]
+fn lowercase_matrix() {
+    // Each array lists the expected mappings of `I`, `i`, `İ`, and `ı`.
+    assert_lowercase(
+        LowercaseMode::Full,
+        [b"i", b"i", DOTTED_I_LOWERCASE, "ı".as_bytes()],
+    );
+    assert_lowercase(
+        LowercaseMode::Ascii,
+        [b"i", b"i", "İ".as_bytes(), "ı".as_bytes()],
+    );
+    assert_lowercase(
+        LowercaseMode::Lithuanian,
+        [b"i", b"i", DOTTED_I_LOWERCASE, "ı".as_bytes()],
+    );
+    assert_lowercase(
+        LowercaseMode::Fold,
+        [b"i", b"i", DOTTED_I_LOWERCASE, "ı".as_bytes()],
+    );
+    assert_lowercase(
+        LowercaseMode::TurkicFold,
+        ["ı".as_bytes(), b"i", b"i", "ı".as_bytes()],
+    );
+}
+
+#[test]
+fn lowercase_turkic_is_unsupported() {
+    for letter in LETTERS {
+        let err = crate::try_lowercase(letter.as_bytes(), LowercaseMode::Turkic).unwrap_err();
+        assert_eq!(err.mapping(), LowercaseMode::Turkic.into());
+    }
+}
+
+#[test]
+fn uppercase_matrix() {
+    let full: [&[u8]; 4] = [b"I", b"I", "İ".as_bytes(), b"I"];
+    assert_uppercase(UppercaseMode::Full, full);
+    assert_uppercase(UppercaseMode::Lithuanian, full);
+    assert_uppercase(UppercaseMode::CapitalSharpS, full);
+    assert_uppercase(
+        UppercaseMode::Ascii,
+        [b"I", b"I", "İ".as_bytes(), "ı".as_bytes()],
+    );
+    assert_uppercase(
+        UppercaseMode::Turkic,
+        [b"I", "İ".as_bytes(), "İ".as_bytes(), b"I"],
+    );
+    assert_uppercase(
+        UppercaseMode::Fold,
+        [b"i", b"i", DOTTED_I_LOWERCASE, "ı".as_bytes()],
+    );
+}
+
+#[test]
+fn round_trips() {
+    // `I` and `i` round trip in full mode, but `İ` and `ı` do not: `İ` loses
+    // its dot to a combining mark and `ı` gains one.
+    let round_trip = |s: &str| {
+        let upper = uppercase(s, UppercaseMode::Full);
+        crate::lowercase(&upper, LowercaseMode::Full).collect::<Vec<_>>()
+    };
+    assert_eq!(round_trip("i").as_bstr(), b"i".as_bstr());
+    assert_eq!(round_trip("ı").as_bstr(), b"i".as_bstr());
+    assert_eq!(round_trip("İ").as_bstr(), DOTTED_I_LOWERCASE.as_bstr());
+
+    // Turkic uppercasing followed by Turkic case folding round trips both
+    // lowercase letters.
+    let turkic_round_trip = |s: &str| {
+        let upper = uppercase(s, UppercaseMode::Turkic);
+        crate::lowercase(&upper, LowercaseMode::TurkicFold).collect::<Vec<_>>()
+    };
+    assert_eq!(turkic_round_trip("i").as_bstr(), b"i".as_bstr());
+    assert_eq!(turkic_round_trip("ı").as_bstr(), "ı".as_bytes().as_bstr());
+
+    // ASCII mode never touches the non-ASCII letters.
+    for s in ["İ", "ı"] {
+        let upper = uppercase(s, UppercaseMode::Ascii);
+        let lower = crate::lowercase(&upper, LowercaseMode::Ascii).collect::<Vec<_>>();
+        assert_eq!(lower.as_bstr(), s.as_bytes().as_bstr());
+    }
+}
+
+#[test]
+fn dotted_capital_i_expands_in_context() {
+    // The expansion of `İ` is the same wherever it appears and in either
+    // iteration direction.
+    let expected = [b"a".as_slice(), DOTTED_I_LOWERCASE, b"b"].concat();
+    let mapped = lowercase("AİB", LowercaseMode::Full);
+    assert_eq!(mapped.as_bstr(), expected.as_bstr());
+    let mut reversed = crate::lowercase("AİB".as_bytes(), LowercaseMode::Full)
+        .rev()
+        .collect::<Vec<_>>();
+    reversed.reverse();
+    assert_eq!(reversed.as_bstr(), expected.as_bstr());
+    let mapped = crate::lowercase_indices("AİB".as_bytes(), LowercaseMode::Full)
+        .map(|(_, byte)| byte)
+        .collect::<Vec<_>>();
+    assert_eq!(mapped.as_bstr(), expected.as_bstr());
+}
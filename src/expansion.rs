@@ -0,0 +1,376 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{LowercaseMode, TitlecaseMode, UppercaseMode};
+
+/// A case mapping operation and the mode it is performed in.
+///
+/// This enum is used to query static properties of case mapping operations,
+/// like their [maximum expansion ratio].
+///
+/// [maximum expansion ratio]: max_expansion_ratio
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CaseMapping {
+    /// [`lowercase`] with the given [`LowercaseMode`].
+    ///
+    /// [`lowercase`]: crate::lowercase()
+    Lowercase(LowercaseMode),
+    /// [`uppercase`] with the given [`UppercaseMode`].
+    ///
+    /// [`uppercase`]: crate::uppercase()
+    Uppercase(UppercaseMode),
+    /// [`titlecase`] with the given [`TitlecaseMode`].
+    ///
+    /// [`titlecase`]: crate::titlecase()
+    Titlecase(TitlecaseMode),
+}
+
+impl From<LowercaseMode> for CaseMapping {
+    fn from(mode: LowercaseMode) -> Self {
+        Self::Lowercase(mode)
+    }
+}
+
+impl From<UppercaseMode> for CaseMapping {
+    fn from(mode: UppercaseMode) -> Self {
+        Self::Uppercase(mode)
+    }
+}
+
+impl From<TitlecaseMode> for CaseMapping {
+    fn from(mode: TitlecaseMode) -> Self {
+        Self::Titlecase(mode)
+    }
+}
+
+/// Returns the maximum ratio of output bytes to input bytes the given case
+/// mapping can produce as a `(numerator, denominator)` pair.
+///
+/// For any input slice, the case mapped output is at most
+/// `input.len() * numerator / denominator` bytes long, rounded down. This bound
+/// is derived from the Unicode character database and is a static property of
+/// the operation and mode, not of any particular input. Use it to size buffers
+/// for worst-case inputs.
+///
+/// The ratios and the characters which achieve them are:
+///
+/// | Operation | Mode                     | Ratio | Worst case                                 |
+/// |-----------|--------------------------|-------|--------------------------------------------|
+/// | Lowercase | Full                     | 3 / 2 | `İ` U+0130, `Ⱥ` U+023A, `Ⱦ` U+023E         |
+/// | Lowercase | Turkic                   | 2 / 1 | `I` U+0049, which maps to `ı` U+0131       |
+/// | Lowercase | Lithuanian               | 5 / 2 | `Ì` U+00CC, `Í` U+00CD, `Ĩ` U+0128         |
+/// | Lowercase | Fold, Turkic fold        | 3 / 1 | `ΐ` U+0390, `ΰ` U+03B0                     |
+/// | Uppercase | Full, Turkic, Lithuanian | 3 / 1 | `ΐ` U+0390, `ΰ` U+03B0                     |
+/// | Uppercase | Fold                     | 3 / 1 | `ΐ` U+0390, `ΰ` U+03B0                     |
+/// | Uppercase | Capital sharp s          | 3 / 1 | `ΐ` U+0390, `ΰ` U+03B0                     |
+/// | Titlecase | Full, Turkic, Lithuanian | 3 / 1 | `ΐ` U+0390, `ΰ` U+03B0 as the first letter |
+/// | Any       | Ascii                    | 1 / 1 | None, ASCII case mapping preserves lengths |
+///
+/// `ΐ` and `ΰ` are two bytes in UTF-8 and expand to a capital letter followed
+/// by two combining marks, six bytes in total.
+///
+/// Invalid UTF-8 byte sequences are yielded as is and never expand.
+///
+/// The ratios for modes which are not yet implemented describe the mappings in
+/// `SpecialCasing.txt` and `CaseFolding.txt` for those modes.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{max_expansion_ratio, CaseMapping, LowercaseMode, UppercaseMode};
+/// let (num, den) = max_expansion_ratio(CaseMapping::Uppercase(UppercaseMode::Full));
+/// assert_eq!((num, den), (3, 1));
+///
+/// let input = "ΐ".as_bytes();
+/// let mut buf = Vec::with_capacity(input.len() * num / den);
+/// buf.extend(roe::uppercase(input, UppercaseMode::Full));
+/// assert_eq!(buf.len(), input.len() * num / den);
+///
+/// assert_eq!(max_expansion_ratio(LowercaseMode::Ascii.into()), (1, 1));
+/// ```
+#[must_use]
+pub const fn max_expansion_ratio(mapping: CaseMapping) -> (usize, usize) {
+    match mapping {
+        CaseMapping::Lowercase(LowercaseMode::Ascii)
+        | CaseMapping::Uppercase(UppercaseMode::Ascii)
+        | CaseMapping::Titlecase(TitlecaseMode::Ascii) => (1, 1),
+        CaseMapping::Lowercase(LowercaseMode::Full) => (3, 2),
+        CaseMapping::Lowercase(LowercaseMode::Turkic) => (2, 1),
+        CaseMapping::Lowercase(LowercaseMode::Lithuanian) => (5, 2),
+        CaseMapping::Lowercase(LowercaseMode::Fold | LowercaseMode::TurkicFold)
+        | CaseMapping::Uppercase(_)
+        | CaseMapping::Titlecase(_) => (3, 1),
+    }
+}
+
+/// Returns a good initial capacity for a buffer which will hold the given
+/// slice case mapped with the given [`CaseMapping`].
+///
+/// Unlike [`max_expansion_ratio`], this is an estimate and not a bound. The
+/// hint is exact for [ASCII modes] and for ASCII-only slices in every mode
+/// except the Turkic modes, where `I` and `i` map to two-byte letters. For
+/// other slices, the hint is the length of the slice plus an eighth so that a
+/// few expanding letters, like `ß` to `SS`, do not force a reallocation.
+///
+/// Functions which map into a buffer they do not own up front, like
+/// [`lowercase_in_place`], pre-size it with this hint. The allocating case
+/// mapping functions, like [`to_lowercase`] and [`to_uppercase`], count the
+/// exact length of their output instead.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{reserve_hint, CaseMapping, LowercaseMode, UppercaseMode};
+/// let hint = reserve_hint(b"Hello, World!", UppercaseMode::Full.into());
+/// assert_eq!(hint, roe::uppercase(b"Hello, World!", UppercaseMode::Full).count());
+///
+/// let input = "straße".as_bytes();
+/// let hint = reserve_hint(input, UppercaseMode::Full.into());
+/// assert!(hint >= roe::uppercase(input, UppercaseMode::Full).count());
+///
+/// assert_eq!(reserve_hint("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Ascii.into()), 10);
+/// ```
+///
+/// [ASCII modes]: LowercaseMode::Ascii
+#[cfg_attr(
+    feature = "alloc",
+    doc = "[`lowercase_in_place`]: crate::lowercase_in_place()"
+)]
+#[cfg_attr(
+    not(feature = "alloc"),
+    doc = "[`lowercase_in_place`]: https://docs.rs/roe/latest/roe/fn.lowercase_in_place.html"
+)]
+#[cfg_attr(feature = "alloc", doc = "[`to_lowercase`]: crate::to_lowercase()")]
+#[cfg_attr(
+    not(feature = "alloc"),
+    doc = "[`to_lowercase`]: https://docs.rs/roe/latest/roe/fn.to_lowercase.html"
+)]
+#[cfg_attr(feature = "alloc", doc = "[`to_uppercase`]: crate::to_uppercase()")]
+#[cfg_attr(
+    not(feature = "alloc"),
+    doc = "[`to_uppercase`]: https://docs.rs/roe/latest/roe/fn.to_uppercase.html"
+)]
+#[must_use]
+pub fn reserve_hint(slice: &[u8], mapping: CaseMapping) -> usize {
+    let is_exact = match mapping {
+        CaseMapping::Lowercase(LowercaseMode::Ascii)
+        | CaseMapping::Uppercase(UppercaseMode::Ascii)
+        | CaseMapping::Titlecase(TitlecaseMode::Ascii) => true,
+        CaseMapping::Lowercase(LowercaseMode::Turkic | LowercaseMode::TurkicFold)
+        | CaseMapping::Uppercase(UppercaseMode::Turkic)
+        | CaseMapping::Titlecase(TitlecaseMode::Turkic) => false,
+        _ => slice.is_ascii(),
+    };
+    if is_exact {
+        slice.len()
+    } else {
+        slice.len().saturating_add(slice.len() / 8)
+    }
+}
+
+/// Collect the bytes yielded by a case mapping iterator into a vector which is
+/// exactly as long as the output.
+///
+/// The upper bound of the size hint of a case mapping iterator is loose for
+/// non-ASCII input, so the output is measured with a first pass that counts the
+/// mapped bytes before a second pass fills a vector allocated with exactly
+/// that capacity. The case mapping iterators count without encoding mapped
+/// chars, and the count is free for ASCII-only input.
+#[cfg(feature = "alloc")]
+pub(crate) fn collect_exact<I>(iter: I) -> Vec<u8>
+where
+    I: Iterator<Item = u8> + Clone,
+{
+    let mut out = Vec::with_capacity(iter.clone().count());
+    out.extend(iter);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{max_expansion_ratio, reserve_hint, CaseMapping};
+    use crate::{LowercaseMode, TitlecaseMode, UppercaseMode};
+
+    fn assert_within_ratio(input: &[u8], output_len: usize, mapping: CaseMapping) {
+        let (num, den) = max_expansion_ratio(mapping);
+        assert!(
+            output_len * den <= input.len() * num,
+            "{:?} expanded {:?} to {} bytes",
+            mapping,
+            input,
+            output_len
+        );
+    }
+
+    #[test]
+    fn scalars_are_within_ratio() {
+        let mut buf = [0; 4];
+        for ch in (0..=0x10_FFFF).filter_map(char::from_u32) {
+            let input = ch.encode_utf8(&mut buf).as_bytes();
+            let lower = ch.to_lowercase().map(char::len_utf8).sum();
+            assert_within_ratio(input, lower, LowercaseMode::Full.into());
+            let upper = ch.to_uppercase().map(char::len_utf8).sum();
+            assert_within_ratio(input, upper, UppercaseMode::Full.into());
+            let fold = crate::lowercase(input, LowercaseMode::Fold).count();
+            assert_within_ratio(input, fold, LowercaseMode::Fold.into());
+            let fold = crate::lowercase(input, LowercaseMode::TurkicFold).count();
+            assert_within_ratio(input, fold, LowercaseMode::TurkicFold.into());
+            let title = crate::to_titlecase(ch)
+                .iter()
+                .filter(|&&ch| ch != '\0')
+                .map(|ch| ch.len_utf8())
+                .sum();
+            assert_within_ratio(input, title, TitlecaseMode::Full.into());
+        }
+    }
+
+    #[test]
+    fn worst_cases_achieve_ratio() {
+        let cases: [(&str, CaseMapping); 6] = [
+            ("İȺȾ", LowercaseMode::Full.into()),
+            ("ÌÍĨ", LowercaseMode::Lithuanian.into()),
+            ("ΐΰ", LowercaseMode::Fold.into()),
+            ("ΐΰ", UppercaseMode::Full.into()),
+            ("ΐ", TitlecaseMode::Full.into()),
+            ("xÌÍĨ", TitlecaseMode::Lithuanian.into()),
+        ];
+        for (input, mapping) in cases {
+            let input = input.as_bytes();
+            let output = match mapping {
+                CaseMapping::Lowercase(mode) => crate::lowercase(input, mode).count(),
+                CaseMapping::Uppercase(mode) => crate::uppercase(input, mode).count(),
+                CaseMapping::Titlecase(mode) => crate::titlecase(input, mode).count(),
+            };
+            assert_within_ratio(input, output, mapping);
+        }
+
+        let (num, den) = max_expansion_ratio(UppercaseMode::Full.into());
+        let input = "ΐΰ".as_bytes();
+        let output = crate::uppercase(input, UppercaseMode::Full).collect::<Vec<_>>();
+        assert_eq!(output.len(), input.len() * num / den);
+
+        let (num, den) = max_expansion_ratio(LowercaseMode::Full.into());
+        let input = "İȺȾ".as_bytes();
+        assert_eq!(
+            crate::lowercase(input, LowercaseMode::Full).count(),
+            input.len() * num / den
+        );
+    }
+
+    #[test]
+    fn ascii_modes_never_expand() {
+        let input = "ΐΰ İ ABC abc \u{1F600}".as_bytes();
+        let mappings: [CaseMapping; 3] = [
+            LowercaseMode::Ascii.into(),
+            UppercaseMode::Ascii.into(),
+            TitlecaseMode::Ascii.into(),
+        ];
+        for mapping in mappings {
+            assert_eq!(max_expansion_ratio(mapping), (1, 1));
+        }
+        assert_eq!(
+            crate::lowercase(input, LowercaseMode::Ascii).count(),
+            input.len()
+        );
+        assert_eq!(
+            crate::uppercase(input, UppercaseMode::Ascii).count(),
+            input.len()
+        );
+        assert_eq!(
+            crate::titlecase(input, TitlecaseMode::Ascii).count(),
+            input.len()
+        );
+    }
+
+    #[test]
+    fn ratios_are_reduced() {
+        let mappings: [CaseMapping; 15] = [
+            LowercaseMode::Full.into(),
+            LowercaseMode::Ascii.into(),
+            LowercaseMode::Turkic.into(),
+            LowercaseMode::Lithuanian.into(),
+            LowercaseMode::Fold.into(),
+            LowercaseMode::TurkicFold.into(),
+            UppercaseMode::Full.into(),
+            UppercaseMode::Ascii.into(),
+            UppercaseMode::Turkic.into(),
+            UppercaseMode::Lithuanian.into(),
+            UppercaseMode::Fold.into(),
+            TitlecaseMode::Full.into(),
+            TitlecaseMode::Ascii.into(),
+            TitlecaseMode::Turkic.into(),
+            TitlecaseMode::Lithuanian.into(),
+        ];
+        for mapping in mappings {
+            let (num, den) = max_expansion_ratio(mapping);
+            assert!(num >= den);
+            assert!((2..=den).all(|factor| num % factor != 0 || den % factor != 0));
+        }
+    }
+
+    #[test]
+    fn reserve_hint_is_exact_for_ascii() {
+        let input = b"The Quick Brown Fox; 0123456789 \x7F";
+        let mappings: [CaseMapping; 6] = [
+            LowercaseMode::Full.into(),
+            LowercaseMode::Fold.into(),
+            LowercaseMode::Ascii.into(),
+            UppercaseMode::Full.into(),
+            UppercaseMode::Lithuanian.into(),
+            TitlecaseMode::Full.into(),
+        ];
+        for mapping in mappings {
+            assert_eq!(reserve_hint(input, mapping), input.len());
+            assert_eq!(reserve_hint(b"", mapping), 0);
+        }
+
+        // `ix` uppercases to `İX` in Turkic mode.
+        let hint = reserve_hint(b"ix", UppercaseMode::Turkic.into());
+        assert!(hint >= 2);
+        assert_eq!(crate::uppercase(b"ix", UppercaseMode::Turkic).count(), 3);
+    }
+
+    #[test]
+    fn reserve_hint_leaves_headroom() {
+        let input = "Straße und Weg, αύριο ".repeat(8);
+        let input = input.as_bytes();
+        let hint = reserve_hint(input, UppercaseMode::Full.into());
+        assert_eq!(hint, input.len() + input.len() / 8);
+        assert!(hint >= crate::uppercase(input, UppercaseMode::Full).count());
+
+        // Invalid UTF-8 is not ASCII.
+        let hint = reserve_hint(b"abc\xFF", LowercaseMode::Full.into());
+        assert_eq!(hint, 4);
+        assert_eq!(reserve_hint(&[0xFF; 16], LowercaseMode::Full.into()), 18);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn mapping_throughput_sanity() {
+        use std::time::{Duration, Instant};
+
+        // A generous bound that only catches pathological regressions, like
+        // accidentally quadratic context scans, even in unoptimized builds.
+        const LIMIT: Duration = Duration::from_secs(30);
+
+        let corpora = [
+            "The Quick Brown Fox Jumps Over The Lazy Dog. ".repeat(4096),
+            "Η ΑΎΡΙΟ ΟΔΟΣ ΕΊΝΑΙ ΜΑΚΡΙΆ. ".repeat(4096),
+        ];
+        for corpus in &corpora {
+            let corpus = corpus.as_bytes();
+            let start = Instant::now();
+            let lower = crate::to_lowercase(corpus, LowercaseMode::Full);
+            let upper = crate::to_uppercase(corpus, UppercaseMode::Full);
+            let title = crate::to_titlecase_string(corpus, TitlecaseMode::Full).unwrap();
+            assert!(start.elapsed() < LIMIT);
+            assert_eq!(lower.len(), corpus.len());
+            assert_eq!(upper.len(), corpus.len());
+            assert_eq!(title.len(), corpus.len());
+            // The reserve hint avoids reallocating when lengths are preserved.
+            assert!(lower.capacity() <= reserve_hint(corpus, LowercaseMode::Full.into()));
+        }
+    }
+}
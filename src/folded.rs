@@ -0,0 +1,190 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use bstr::ByteSlice;
+
+use crate::LowercaseMode;
+
+/// A conventionally UTF-8 byte string which compares and hashes by its [full
+/// Unicode case folding].
+///
+/// `FoldedStr` is a key type for case-insensitive collections. Two
+/// `FoldedStr`s are equal if and only if [`casecmp_eq`] returns `true` for
+/// their slices, and equal `FoldedStr`s hash identically.
+///
+/// Neither comparison nor hashing allocates: both stream the case folded bytes
+/// of the wrapped slice.
+///
+/// # Examples
+///
+/// ```
+/// # use std::collections::HashSet;
+/// # use roe::FoldedStr;
+/// let mut set = HashSet::new();
+/// set.insert(FoldedStr::new("Straße".as_bytes()));
+///
+/// assert!(set.contains(&FoldedStr::new(b"STRASSE")));
+/// assert!(set.contains(&FoldedStr::new(b"strasse")));
+/// assert!(!set.contains(&FoldedStr::new(b"strase")));
+/// ```
+///
+/// [full Unicode case folding]: LowercaseMode::Fold
+/// [`casecmp_eq`]: crate::casecmp_eq()
+#[derive(Clone, Copy, Default)]
+pub struct FoldedStr<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> FoldedStr<'a> {
+    /// Wrap the given slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::FoldedStr;
+    /// assert_eq!(FoldedStr::new(b"ABC"), FoldedStr::new(b"abc"));
+    /// ```
+    #[must_use]
+    pub const fn new(slice: &'a [u8]) -> Self {
+        Self { slice }
+    }
+
+    /// Returns the wrapped slice, which is not case folded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::FoldedStr;
+    /// assert_eq!(FoldedStr::new(b"ABC").as_bytes(), b"ABC");
+    /// ```
+    #[must_use]
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.slice
+    }
+}
+
+impl fmt::Debug for FoldedStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FoldedStr")
+            .field(&self.slice.as_bstr())
+            .finish()
+    }
+}
+
+impl<'a> From<&'a [u8]> for FoldedStr<'a> {
+    #[inline]
+    fn from(slice: &'a [u8]) -> Self {
+        Self::new(slice)
+    }
+}
+
+impl<'a> From<&'a str> for FoldedStr<'a> {
+    #[inline]
+    fn from(s: &'a str) -> Self {
+        Self::new(s.as_bytes())
+    }
+}
+
+impl PartialEq for FoldedStr<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        crate::casecmp_eq(self.slice, other.slice)
+    }
+}
+
+impl Eq for FoldedStr<'_> {}
+
+impl Hash for FoldedStr<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut len = 0_usize;
+        for byte in crate::lowercase(self.slice, LowercaseMode::Fold) {
+            state.write_u8(byte);
+            len += 1;
+        }
+        // Terminate the stream so that adjacent fields of a compound key hash
+        // differently depending on where one ends and the next begins.
+        state.write_usize(len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::hash::{Hash, Hasher};
+
+    use super::FoldedStr;
+
+    // An FNV-1a hasher, which is deterministic across runs.
+    struct Fnv(u64);
+
+    impl Hasher for Fnv {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &byte in bytes {
+                self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+    }
+
+    fn hash(s: FoldedStr<'_>) -> u64 {
+        let mut hasher = Fnv(0xcbf2_9ce4_8422_2325);
+        s.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn eq_is_case_insensitive() {
+        assert_eq!(FoldedStr::from("ß"), FoldedStr::from("SS"));
+        assert_eq!(FoldedStr::from("ΑΎΡΙΟ"), FoldedStr::from("αύριο"));
+        assert_eq!(FoldedStr::from("\u{212A}"), FoldedStr::from("k"));
+        assert_eq!(
+            FoldedStr::from(&b"\xFFABC"[..]),
+            FoldedStr::from(&b"\xFFabc"[..])
+        );
+        assert_ne!(FoldedStr::from("ß"), FoldedStr::from("S"));
+        assert_ne!(FoldedStr::from("I"), FoldedStr::from("ı"));
+    }
+
+    #[test]
+    fn hash_is_consistent_with_eq() {
+        let pairs = [
+            ("ß", "SS"),
+            ("ß", "ss"),
+            ("Straße", "STRASSE"),
+            ("ΣΊΣΥΦΟΣ", "σίσυφος"),
+            ("ﬃ", "FFI"),
+            ("", ""),
+        ];
+        for (a, b) in pairs {
+            let (a, b) = (FoldedStr::from(a), FoldedStr::from(b));
+            assert_eq!(a, b);
+            assert_eq!(hash(a), hash(b));
+        }
+        assert_ne!(hash(FoldedStr::from("ß")), hash(FoldedStr::from("S")));
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            alloc::format!("{:?}", FoldedStr::from("Straße")),
+            "FoldedStr(\"Straße\")"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_set_membership() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        assert!(set.insert(FoldedStr::from("ß")));
+        assert!(set.contains(&FoldedStr::from("SS")));
+        assert!(set.contains(&FoldedStr::from("ss")));
+        assert!(set.contains(&FoldedStr::from("sS")));
+        assert!(!set.contains(&FoldedStr::from("s")));
+        // Inserting a case-insensitive duplicate does not grow the set.
+        assert!(!set.insert(FoldedStr::from("SS")));
+        assert_eq!(set.len(), 1);
+    }
+}
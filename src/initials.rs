@@ -0,0 +1,156 @@
+use alloc::vec::Vec;
+
+use bstr::ByteSlice;
+
+use crate::titlecase::split_word;
+use crate::UppercaseMode;
+
+/// Returns a vector containing the uppercased initials of the words in the
+/// given slice.
+///
+/// Words are found the same way as by [`titlecase_words`]: a word begins with
+/// its first [cased] character and ends at the next Unicode whitespace
+/// character or `_`. The initial of a word is that first cased character,
+/// which is mapped to its full Unicode uppercase equivalent. Runs of
+/// characters which contain no cased characters, like `"42"` or `"—"`, do not
+/// contribute an initial.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string].
+/// Invalid UTF-8 byte sequences are considered part of a word but are never
+/// cased, so they are never yielded as an initial.
+///
+/// To cap the number of initials that are returned, use
+/// [`initials_with_limit`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::initials;
+/// assert_eq!(initials(b"john ronald reuel tolkien"), b"JRRT");
+/// assert_eq!(initials(b"  leading and trailing  "), b"LAT");
+/// assert_eq!(initials(b"the 42nd street"), b"TNS");
+/// assert_eq!(initials("ύψιλον ëlan".as_bytes()), "ΎË".as_bytes());
+/// assert_eq!(initials(b"snake_case_name"), b"SCN");
+/// ```
+///
+/// [`titlecase_words`]: crate::titlecase_words()
+/// [cased]: https://www.unicode.org/versions/Unicode15.0.0/ch03.pdf#G33992
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+#[must_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn initials(slice: &[u8]) -> Vec<u8> {
+    initials_with_limit(slice, usize::MAX)
+}
+
+/// Returns a vector containing at most `limit` uppercased initials of the
+/// words in the given slice.
+///
+/// See [`initials`] for how words and their initials are determined.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::initials_with_limit;
+/// assert_eq!(initials_with_limit(b"john ronald reuel tolkien", 2), b"JR");
+/// assert_eq!(initials_with_limit(b"john ronald reuel tolkien", 10), b"JRRT");
+/// assert_eq!(initials_with_limit(b"john ronald reuel tolkien", 0), b"");
+/// ```
+#[must_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn initials_with_limit(slice: &[u8], limit: usize) -> Vec<u8> {
+    let mut initials = Vec::new();
+    let mut remainder = slice;
+    for _ in 0..limit {
+        let (_, word, rest) = split_word(remainder);
+        remainder = rest;
+        // Only the final word may be empty, when no cased characters remain.
+        let Some((_, end, _)) = word.char_indices().next() else {
+            break;
+        };
+        initials.extend(crate::uppercase(&word[..end], UppercaseMode::Full));
+    }
+    initials
+}
+
+#[cfg(test)]
+mod tests {
+    use bstr::ByteSlice;
+
+    use super::{initials, initials_with_limit};
+
+    #[test]
+    fn empty() {
+        assert_eq!(initials(b"").as_bstr(), b"".as_bstr());
+        assert_eq!(initials(b"   \t\n").as_bstr(), b"".as_bstr());
+    }
+
+    #[test]
+    fn ascii_words() {
+        assert_eq!(
+            initials(b"john ronald reuel tolkien").as_bstr(),
+            b"JRRT".as_bstr()
+        );
+        assert_eq!(
+            initials(b"Portable Network Graphics").as_bstr(),
+            b"PNG".as_bstr()
+        );
+        assert_eq!(
+            initials(b"\tleading\n\ntrailing  ").as_bstr(),
+            b"LT".as_bstr()
+        );
+    }
+
+    #[test]
+    fn words_without_cased_letters_are_skipped() {
+        assert_eq!(initials(b"route 66 east").as_bstr(), b"RE".as_bstr());
+        assert_eq!(initials("中文 text".as_bytes()).as_bstr(), b"T".as_bstr());
+        assert_eq!(initials(b"1st 2nd").as_bstr(), b"SN".as_bstr());
+    }
+
+    #[test]
+    fn multi_byte_initials() {
+        assert_eq!(
+            initials("αύριο έτος".as_bytes()).as_bstr(),
+            "ΑΈ".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            initials("ǆemal".as_bytes()).as_bstr(),
+            "Ǆ".as_bytes().as_bstr()
+        );
+        // Initials which uppercase to multiple characters are fully expanded.
+        assert_eq!(initials("ßtraße".as_bytes()).as_bstr(), b"SS".as_bstr());
+    }
+
+    #[test]
+    fn unicode_whitespace_separates_words() {
+        assert_eq!(
+            initials("no\u{A0}break\u{3000}space".as_bytes()).as_bstr(),
+            b"NBS".as_bstr()
+        );
+    }
+
+    #[test]
+    fn word_boundaries_match_titlecase_words() {
+        assert_eq!(initials(b"snake_case_name").as_bstr(), b"SCN".as_bstr());
+        assert_eq!(initials(b"__dunder__ x").as_bstr(), b"DX".as_bstr());
+        assert_eq!(initials(b"o'neil x-ray").as_bstr(), b"OX".as_bstr());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        assert_eq!(
+            initials(b"\xFF\xFE abc \xF0\x9Fxyz").as_bstr(),
+            b"AX".as_bstr()
+        );
+    }
+
+    #[test]
+    fn limit() {
+        let s = b"john ronald reuel tolkien";
+        assert_eq!(initials_with_limit(s, 0).as_bstr(), b"".as_bstr());
+        assert_eq!(initials_with_limit(s, 1).as_bstr(), b"J".as_bstr());
+        assert_eq!(initials_with_limit(s, 3).as_bstr(), b"JRR".as_bstr());
+        assert_eq!(initials_with_limit(s, 4).as_bstr(), b"JRRT".as_bstr());
+        assert_eq!(initials_with_limit(s, 100).as_bstr(), b"JRRT".as_bstr());
+    }
+}
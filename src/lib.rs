@@ -65,15 +65,27 @@
 //! Roe is `no_std` compatible with an optional dependency on the [`alloc`]
 //! crate.
 //!
-//! Roe has several Cargo features, all of which are enabled by default:
+//! Roe has several Cargo features. **std** and **alloc** are enabled by
+//! default:
 //!
 //! - **std** - Adds a dependency on [`std`], the Rust Standard Library. This
 //!   feature enables [`std::error::Error`] implementations on error types in
-//!   this crate. Enabling the **std** feature also enables the **alloc**
-//!   feature.
+//!   this crate and APIs that write case mapped bytes to a [`std::io::Write`].
+//!   Enabling the **std** feature also enables the **alloc** feature.
 //! - **alloc** - Adds a dependency on [`alloc`], the Rust allocation and
 //!   collections library. This feature enables APIs that allocate [`String`] or
 //!   [`Vec`].
+//! - **properties** - Adds a dependency on [`unicode-ident`] for Unicode
+//!   identifier properties. This feature enables APIs that check whether case
+//!   mapped strings are valid identifiers. Enabling the **properties** feature
+//!   also enables the **alloc** feature.
+//! - **serde** - Adds a dependency on [`serde`]. This feature enables
+//!   `Serialize` and `Deserialize` implementations on [`LowercaseMode`] and
+//!   [`UppercaseMode`], which are serialized as the same strings their
+//!   `TryFrom<&str>` implementations accept, plus `"full"` for the default mode.
+//! - **arbitrary** - Adds a dependency on [`arbitrary`]. This feature enables
+//!   `Arbitrary` implementations on [`LowercaseMode`] and [`UppercaseMode`]
+//!   for use in fuzz targets and property tests.
 //!
 #![cfg_attr(
     not(feature = "std"),
@@ -83,6 +95,10 @@
     not(feature = "std"),
     doc = "[`std::error::Error`]: https://doc.rust-lang.org/std/error/trait.Error.html"
 )]
+#![cfg_attr(
+    not(feature = "std"),
+    doc = "[`std::io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html"
+)]
 #![cfg_attr(
     not(feature = "alloc"),
     doc = "[`alloc`]: https://doc.rust-lang.org/alloc/index.html"
@@ -98,6 +114,9 @@
     doc = "[`Vec`]: https://doc.rust-lang.org/alloc/vec/struct.Vec.html"
 )]
 //! [Unicode case mapping]: https://unicode.org/faq/casemap_charprop.html#casemap
+//! [`unicode-ident`]: https://crates.io/crates/unicode-ident
+//! [`serde`]: https://crates.io/crates/serde
+//! [`arbitrary`]: https://crates.io/crates/arbitrary
 //! [conventionally UTF-8 binary strings]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
 
 #![no_std]
@@ -113,22 +132,80 @@ use core::convert::{TryFrom, TryInto};
 use core::fmt;
 use core::str::FromStr;
 
+use bstr::BStr;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod ascii;
+mod buffer;
+mod casecmp;
+mod display;
+#[cfg(test)]
+mod dotted_i;
+mod expansion;
+mod folded;
+#[cfg(feature = "alloc")]
+mod initials;
 mod lowercase;
+mod map_into;
+#[cfg(feature = "serde")]
+mod serde;
+mod stream;
+mod swapcase;
 mod titlecase;
 mod unicode;
 mod uppercase;
+mod utf8;
+#[cfg(feature = "std")]
+mod write;
 
-pub use ascii::{make_ascii_lowercase, make_ascii_titlecase, make_ascii_uppercase};
+pub use ascii::{
+    ascii_filter, make_ascii_lowercase, make_ascii_titlecase, make_ascii_titlecase_words,
+    make_ascii_uppercase, make_ascii_uppercase_iter, AsciiCasing, AsciiFilter, MakeAsciiUppercase,
+};
 #[cfg(feature = "alloc")]
 pub use ascii::{to_ascii_lowercase, to_ascii_titlecase, to_ascii_uppercase};
-pub use lowercase::Lowercase;
-pub use titlecase::Titlecase;
-pub use unicode::to_titlecase;
-pub use uppercase::Uppercase;
+#[cfg(feature = "alloc")]
+pub use casecmp::fold_key;
+pub use casecmp::{casecmp, casecmp_eq, casecmp_simple, find_fold_collision, folds_same};
+pub use display::{fmt_lowercase, fmt_titlecase, fmt_uppercase};
+pub use expansion::{max_expansion_ratio, reserve_hint, CaseMapping};
+pub use folded::FoldedStr;
+#[cfg(feature = "alloc")]
+pub use initials::{initials, initials_with_limit};
+#[cfg(feature = "alloc")]
+pub use lowercase::{
+    extend_lowercase, lowercase_cow, lowercase_in_place, to_lowercase, to_lowercase_string,
+};
+pub use lowercase::{
+    lowercase_chars, lowercase_chunks, lowercase_for_each_chunk, lowercase_indices,
+    lowercase_lossy, Lowercase, LowercaseCanonicalI, LowercaseChars, LowercaseChunk,
+    LowercaseChunks, LowercaseExcept, LowercaseIndices, LowercaseLossy, LowercaseStreamer,
+    MappedStr,
+};
+pub use map_into::{map_into, CapacityError};
+pub use swapcase::Swapcase;
+#[cfg(feature = "alloc")]
+pub use titlecase::{extend_titlecase, to_titlecase_string};
+pub use titlecase::{Titlecase, TitlecaseWords};
+pub use unicode::{
+    case_map_char, to_lowercase_char, to_lowercase_special, to_swapcase, to_titlecase,
+    to_uppercase_char, to_uppercase_special, CaseMapChar, CaseOp, CasingContext, CasingLanguage,
+    SpecialCaseMapping, SpecialCaseMappings, ToLowercase, ToUppercase,
+};
+#[cfg(feature = "properties")]
+pub use uppercase::uppercase_checked_ident;
+#[cfg(feature = "alloc")]
+pub use uppercase::{extend_uppercase, to_uppercase, to_uppercase_string, uppercase_with_offsets};
+pub use uppercase::{
+    uppercase_chars, UpcaseFirstWord, Uppercase, UppercaseChars, UppercaseStreamer,
+};
+pub use utf8::count_invalid_subparts;
+#[cfg(feature = "std")]
+pub use write::{write_lowercase, write_titlecase, write_uppercase};
 
 /// Error that indicates a failure to parse a [`LowercaseMode`],
-/// [`UppercaseMode`], or [`TitlecaseMode`].
+/// [`UppercaseMode`], [`TitlecaseMode`], or [`SwapcaseMode`].
 ///
 /// This error corresponds to the [Ruby `ArgumentError` Exception class].
 ///
@@ -178,6 +255,38 @@ impl InvalidCaseMappingMode {
     pub const fn message(self) -> &'static str {
         "invalid option"
     }
+
+    /// Construct a new error which records the rejected `input` for
+    /// diagnostics.
+    ///
+    /// `InvalidCaseMappingMode` is zero-sized and its [`Display`]
+    /// implementation matches Ruby's `ArgumentError` message exactly, so the
+    /// [`TryFrom`] and [`FromStr`] implementations for the mode types return
+    /// errors constructed with [`new`](Self::new). This constructor is an
+    /// opt-in for callers, like config loaders, which want the offending bytes
+    /// in their error messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::InvalidCaseMappingMode;
+    /// let err = InvalidCaseMappingMode::with_input(b"foo");
+    /// assert_eq!(err.input(), b"foo");
+    /// assert_eq!(err.to_string(), r#"invalid option: "foo""#);
+    ///
+    /// // The verbose error can be converted back to the terse one.
+    /// let err = InvalidCaseMappingMode::from(err);
+    /// assert_eq!(err.to_string(), "invalid option");
+    /// ```
+    ///
+    /// [`Display`]: fmt::Display
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn with_input(input: &[u8]) -> InvalidCaseMappingModeWithInput {
+        InvalidCaseMappingModeWithInput {
+            input: input.to_vec(),
+        }
+    }
 }
 
 impl fmt::Display for InvalidCaseMappingMode {
@@ -190,6 +299,152 @@ impl fmt::Display for InvalidCaseMappingMode {
 #[cfg(feature = "std")]
 impl std::error::Error for InvalidCaseMappingMode {}
 
+/// Error that indicates a failure to parse a case mapping mode and records the
+/// rejected input.
+///
+/// This error is created by [`InvalidCaseMappingMode::with_input`]. See its
+/// documentation for more.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::InvalidCaseMappingMode;
+/// let err = InvalidCaseMappingMode::with_input(b"ful\xFF");
+/// assert_eq!(err.message(), "invalid option");
+/// assert_eq!(err.to_string(), r#"invalid option: "ful\xff""#);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InvalidCaseMappingModeWithInput {
+    input: alloc::vec::Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl InvalidCaseMappingModeWithInput {
+    /// Retrieve the rejected input.
+    #[must_use]
+    pub fn input(&self) -> &[u8] {
+        &self.input
+    }
+
+    /// Retrieve the error message associated with this error, without the
+    /// rejected input.
+    ///
+    /// This is the same message as [`InvalidCaseMappingMode::message`].
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        InvalidCaseMappingMode::new().message()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<InvalidCaseMappingModeWithInput> for InvalidCaseMappingMode {
+    fn from(_err: InvalidCaseMappingModeWithInput) -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for InvalidCaseMappingModeWithInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use bstr::ByteSlice;
+
+        write!(f, "{}: {:?}", self.message(), self.input.as_bstr())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidCaseMappingModeWithInput {}
+
+/// Error that indicates a case mapping was requested in a mode which is not yet
+/// implemented.
+///
+/// This error is returned by the fallible case mapping functions, like
+/// [`try_lowercase`], [`try_uppercase`], and [`try_titlecase`], and describes
+/// the requested [`CaseMapping`]. Unlike [`InvalidCaseMappingMode`], this error
+/// is not about parsing: the mode is valid, but this crate cannot perform it
+/// yet.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{CaseMapping, LowercaseMode, UnsupportedCaseMappingMode};
+/// let err = roe::try_lowercase(b"ISTANBUL", LowercaseMode::Turkic).unwrap_err();
+/// assert_eq!(err.mapping(), CaseMapping::Lowercase(LowercaseMode::Turkic));
+/// assert_eq!(err.to_string(), "lowercase Turkic mode is not yet implemented");
+/// ```
+///
+/// [`try_lowercase`]: crate::try_lowercase()
+/// [`try_uppercase`]: crate::try_uppercase()
+/// [`try_titlecase`]: crate::try_titlecase()
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UnsupportedCaseMappingMode {
+    mapping: CaseMapping,
+}
+
+impl UnsupportedCaseMappingMode {
+    /// Construct a new `UnsupportedCaseMappingMode` error for the given case
+    /// mapping operation and mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{CaseMapping, LowercaseMode, UnsupportedCaseMappingMode};
+    /// const ERR: UnsupportedCaseMappingMode =
+    ///     UnsupportedCaseMappingMode::new(CaseMapping::Lowercase(LowercaseMode::Turkic));
+    /// assert_eq!(ERR.mapping(), CaseMapping::Lowercase(LowercaseMode::Turkic));
+    /// ```
+    #[must_use]
+    pub const fn new(mapping: CaseMapping) -> Self {
+        Self { mapping }
+    }
+
+    /// Retrieve the case mapping operation and mode which was requested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{CaseMapping, LowercaseMode, UnsupportedCaseMappingMode};
+    /// let err = UnsupportedCaseMappingMode::new(LowercaseMode::Turkic.into());
+    /// assert_eq!(err.mapping(), CaseMapping::Lowercase(LowercaseMode::Turkic));
+    /// ```
+    #[must_use]
+    pub const fn mapping(self) -> CaseMapping {
+        self.mapping
+    }
+}
+
+impl fmt::Display for UnsupportedCaseMappingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mapping {
+            CaseMapping::Lowercase(mode) => write!(f, "lowercase {mode:?}")?,
+            CaseMapping::Uppercase(mode) => write!(f, "uppercase {mode:?}")?,
+            CaseMapping::Titlecase(mode) => write!(f, "titlecase {mode:?}")?,
+        }
+        f.write_str(" mode is not yet implemented")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnsupportedCaseMappingMode {}
+
+// The length of the longest mode name, `lithuanian`.
+const MODE_NAME_MAX_LEN: usize = 10;
+
+// Parse a mode name in any ASCII case by lowercasing it into a stack buffer.
+fn parse_mode_ignore_ascii_case<T>(bytes: &[u8]) -> Result<T, InvalidCaseMappingMode>
+where
+    T: for<'a> TryFrom<&'a [u8], Error = InvalidCaseMappingMode>,
+{
+    if bytes.len() > MODE_NAME_MAX_LEN {
+        return Err(InvalidCaseMappingMode::new());
+    }
+    let mut buf = [0; MODE_NAME_MAX_LEN];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    make_ascii_lowercase(&mut buf);
+    T::try_from(&buf[..bytes.len()])
+}
+
 /// Options to configure the behavior of [`lowercase`].
 ///
 /// Which letters exactly are replaced, and by which other letters, depends on
@@ -201,22 +456,26 @@ impl std::error::Error for InvalidCaseMappingMode {}
 /// default.
 ///
 /// [`lowercase`]: crate::lowercase()
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum LowercaseMode {
     /// Full Unicode case mapping, suitable for most languages.
     ///
     /// See the [Turkic] and [Lithuanian] variants for exceptions.
     ///
-    /// Context-dependent case mapping as described in Table 3-14 of the Unicode
-    /// standard is currently not supported.
+    /// The context-dependent `Final_Sigma` mapping described in Table 3-14 of
+    /// the Unicode standard is supported: `Σ` is mapped to final sigma `ς` at
+    /// the end of a word and to `σ` otherwise.
     ///
     /// [Turkic]: Self::Turkic
     /// [Lithuanian]: Self::Lithuanian
+    #[default]
     Full,
     /// Only the ASCII region, i.e. the characters `'A'..='Z'` and `'a'..='z'`,
     /// are affected.
     ///
-    /// This option cannot be combined with any other option.
+    /// This option cannot be combined with any other option, except for case
+    /// folding, which is the same as ASCII lowercasing in the ASCII region.
+    /// See [`LowercaseOptions`].
     Ascii,
     /// Full Unicode case mapping, adapted for Turkic languages (Turkish,
     /// Azerbaijani, …).
@@ -224,25 +483,46 @@ pub enum LowercaseMode {
     /// This means that upper case I is mapped to lower case dotless i, and so
     /// on.
     Turkic,
-    /// Currently, just [full Unicode case mapping].
+    /// Full Unicode case mapping, adapted for Lithuanian (keeping the dot on
+    /// the lower case i even if there is an accent on top).
     ///
-    /// In the future, full Unicode case mapping adapted for Lithuanian (keeping
-    /// the dot on the lower case i even if there is an accent on top).
+    /// This means that `Ì`, `Í`, and `Ĩ` are mapped to lower case i, U+0307
+    /// COMBINING DOT ABOVE, and the accent, and that `I`, `J`, and `Į` gain an
+    /// explicit dot when they are followed by an accent above. The
+    /// `Final_Sigma` mapping applies the same as in [Full] mode.
     ///
-    /// [full Unicode case mapping]: Self::Full
+    /// [Full]: Self::Full
     Lithuanian,
     /// Unicode case **folding**, which is more far-reaching than Unicode case
     /// mapping.
     ///
-    /// This option currently cannot be combined with any other option (i.e.
-    /// there is currently no variant for turkic languages).
+    /// Case folding applies the common and full mappings in `CaseFolding.txt`,
+    /// so `ß` folds to `ss` and `ﬀ` folds to `ff`. It is suitable for
+    /// case-insensitive comparison.
+    ///
+    /// This option can be combined with [Turkic] case mapping, which selects
+    /// [`TurkicFold`], and with [ASCII] case mapping. See [`LowercaseOptions`].
+    ///
+    /// [Turkic]: Self::Turkic
+    /// [`TurkicFold`]: Self::TurkicFold
+    /// [ASCII]: Self::Ascii
     Fold,
-}
-
-impl Default for LowercaseMode {
-    fn default() -> Self {
-        Self::Full
-    }
+    /// Unicode case **folding**, adapted for Turkic languages (Turkish,
+    /// Azerbaijani, …).
+    ///
+    /// This applies the Turkic (T) mappings in `CaseFolding.txt` in addition
+    /// to the mappings applied by [`Fold`]: upper case `I` folds to lower case
+    /// dotless `ı` and upper case dotted `İ` folds to `i`. Common case folding
+    /// instead folds `I` to `i` and `İ` to `i` followed by U+0307 COMBINING DOT
+    /// ABOVE, so the two modes disagree on whether `"FILE"` and `"file"` are
+    /// equal ignoring case.
+    ///
+    /// This mode corresponds to passing both `:fold` and `:turkic` to
+    /// [`String#downcase`] in Ruby. See [`LowercaseOptions`].
+    ///
+    /// [`Fold`]: Self::Fold
+    /// [`String#downcase`]: https://ruby-doc.org/core-3.1.2/String.html#method-i-downcase
+    TurkicFold,
 }
 
 impl TryFrom<&str> for LowercaseMode {
@@ -303,180 +583,1429 @@ impl FromStr for LowercaseMode {
     }
 }
 
-/// Returns an iterator that yields a copy of the bytes in the given slice with
-/// all uppercase letters replaced with their lowercase counterparts.
-///
-/// This function treats the given slice as a [conventionally UTF-8 string].
-/// UTF-8 byte sequences are converted to their Unicode lowercase equivalents.
-/// Invalid UTF-8 byte sequences are yielded as is.
-///
-/// The case mapping mode is determined by the given [`LowercaseMode`]. See its
-/// documentation for details on the available case mapping modes.
-///
-/// # Panics
-///
-/// Not all [`LowercaseMode`]s are currently implemented. This function will
-/// panic if the caller supplies [Turkic] or [case folding] lowercasing mode.
-///
-/// [conventionally UTF-8 string]: https://docs.rs/bstr/0.2.*/bstr/#when-should-i-use-byte-strings
-/// [Turkic]: LowercaseMode::Turkic
-/// [case folding]: LowercaseMode::Fold
-// TODO: make this const once we're no longer panicking.
-pub fn lowercase(slice: &[u8], options: LowercaseMode) -> Lowercase<'_> {
-    match options {
-        LowercaseMode::Full | LowercaseMode::Lithuanian => Lowercase::with_slice(slice),
-        LowercaseMode::Ascii => Lowercase::with_ascii_slice(slice),
-        // TODO: implement `turkic` and `fold` modes.
-        LowercaseMode::Turkic => panic!("lowercase Turkic mode is not yet implemented"),
-        LowercaseMode::Fold => panic!("lowercase case folding mode is not yet implemented"),
-    }
-}
-
-/// Options to configure the behavior of [`uppercase`].
-///
-/// Which letters exactly are replaced, and by which other letters, depends on
-/// the given options.
-///
-/// See individual variants for a description of the available behaviors.
-///
-/// If you're not sure which mode to choose, [`UppercaseMode::Full`] is a a good
-/// default.
-///
-/// [`uppercase`]: crate::uppercase()
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub enum UppercaseMode {
-    /// Full Unicode case mapping, suitable for most languages.
+impl LowercaseMode {
+    /// Parse a mode from the bytes of a C string, as received across an FFI
+    /// boundary.
     ///
-    /// See the [Turkic] and [Lithuanian] variants for exceptions.
+    /// A single trailing NUL byte is stripped before the bytes are parsed the
+    /// same as the `TryFrom<&[u8]>` implementation.
     ///
-    /// Context-dependent case mapping as described in Table 3-14 of the Unicode
-    /// standard is currently not supported.
+    /// # Examples
     ///
-    /// [Turkic]: Self::Turkic
-    /// [Lithuanian]: Self::Lithuanian
-    Full,
-    /// Only the ASCII region, i.e. the characters `'A'..='Z'` and `'a'..='z'`,
-    /// are affected.
+    /// ```
+    /// # use roe::{InvalidCaseMappingMode, LowercaseMode};
+    /// assert_eq!(LowercaseMode::from_ffi_bytes(b"ascii\0"), Ok(LowercaseMode::Ascii));
+    /// assert_eq!(LowercaseMode::from_ffi_bytes(b"turkic"), Ok(LowercaseMode::Turkic));
+    /// assert_eq!(LowercaseMode::from_ffi_bytes(b"asc\0ii"), Err(InvalidCaseMappingMode::new()));
+    /// ```
     ///
-    /// This option cannot be combined with any other option.
-    Ascii,
-    /// Full Unicode case mapping, adapted for Turkic languages (Turkish,
-    /// Azerbaijani, …).
+    /// # Errors
     ///
-    /// This means that upper case I is mapped to lower case dotless i, and so
-    /// on.
-    Turkic,
-    /// Currently, just [full Unicode case mapping].
+    /// If the bytes contain a NUL byte other than the trailing one, or they do
+    /// not name a mode, an [`InvalidCaseMappingMode`] error is returned.
+    #[inline]
+    pub fn from_ffi_bytes(bytes: &[u8]) -> Result<Self, InvalidCaseMappingMode> {
+        let bytes = bytes.strip_suffix(b"\0").unwrap_or(bytes);
+        if bytes.contains(&b'\0') {
+            return Err(InvalidCaseMappingMode::new());
+        }
+        bytes.try_into()
+    }
+
+    /// Parse a mode from bytes, ignoring ASCII case.
     ///
-    /// In the future, full Unicode case mapping adapted for Lithuanian (keeping
-    /// the dot on the lower case i even if there is an accent on top).
+    /// The bytes are ASCII lowercased before they are parsed the same as the
+    /// `TryFrom<&[u8]>` implementation, which only accepts lowercase mode
+    /// names for parity with Ruby.
     ///
-    /// [full Unicode case mapping]: Self::Full
-    Lithuanian,
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::convert::TryFrom;
+    /// # use roe::{InvalidCaseMappingMode, LowercaseMode};
+    /// assert_eq!(LowercaseMode::from_bytes_ignore_ascii_case(b"ASCII"), Ok(LowercaseMode::Ascii));
+    /// assert_eq!(LowercaseMode::from_bytes_ignore_ascii_case(b"Fold"), Ok(LowercaseMode::Fold));
+    /// assert_eq!(LowercaseMode::try_from(&b"Fold"[..]), Err(InvalidCaseMappingMode::new()));
+    /// assert_eq!(LowercaseMode::from_bytes_ignore_ascii_case(b"Full"), Err(InvalidCaseMappingMode::new()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the bytes do not name a mode in any ASCII case, an
+    /// [`InvalidCaseMappingMode`] error is returned.
+    #[inline]
+    pub fn from_bytes_ignore_ascii_case(bytes: &[u8]) -> Result<Self, InvalidCaseMappingMode> {
+        parse_mode_ignore_ascii_case(bytes)
+    }
 }
 
-impl Default for UppercaseMode {
-    fn default() -> Self {
-        Self::Full
-    }
+/// A builder which combines lowercase options into a [`LowercaseMode`].
+///
+/// Each option corresponds to a Ruby `String#downcase` option symbol. Options
+/// are validated when the builder is [built], and combinations which do not
+/// correspond to a `LowercaseMode` are rejected.
+///
+/// | Options                 | Mode                          |
+/// | ----------------------- | ----------------------------- |
+/// | none                    | [`LowercaseMode::Full`]       |
+/// | `ascii`                 | [`LowercaseMode::Ascii`]      |
+/// | `ascii` and `fold`      | [`LowercaseMode::Ascii`]      |
+/// | `turkic`                | [`LowercaseMode::Turkic`]     |
+/// | `lithuanian`            | [`LowercaseMode::Lithuanian`] |
+/// | `fold`                  | [`LowercaseMode::Fold`]       |
+/// | `fold` and `turkic`     | [`LowercaseMode::TurkicFold`] |
+///
+/// ASCII-only case folding is the same as ASCII lowercasing, so combining
+/// `ascii` with `fold` builds [`LowercaseMode::Ascii`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{InvalidCaseMappingMode, LowercaseMode, LowercaseOptions};
+/// let mode = LowercaseOptions::new().ascii(true).fold(true).build();
+/// assert_eq!(mode, Ok(LowercaseMode::Ascii));
+///
+/// let mode = LowercaseOptions::new().fold(true).build().unwrap();
+/// let lowercase = roe::lowercase("ẞ".as_bytes(), mode);
+/// assert_eq!(lowercase.collect::<Vec<_>>(), b"ss");
+///
+/// let mode = LowercaseOptions::new().ascii(true).turkic(true).build();
+/// assert_eq!(mode, Err(InvalidCaseMappingMode::new()));
+/// ```
+///
+/// [built]: Self::build
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct LowercaseOptions {
+    ascii: bool,
+    fold: bool,
+    turkic: bool,
+    lithuanian: bool,
 }
 
-impl TryFrom<&str> for UppercaseMode {
-    type Error = InvalidCaseMappingMode;
+impl LowercaseOptions {
+    /// Construct a new `LowercaseOptions` with no options set.
+    ///
+    /// An empty set of options builds [`LowercaseMode::Full`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            ascii: false,
+            fold: false,
+            turkic: false,
+            lithuanian: false,
+        }
+    }
 
-    #[inline]
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        value.as_bytes().try_into()
+    /// Set whether only the ASCII region is affected, like Ruby's `:ascii`.
+    #[must_use]
+    pub const fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
     }
-}
 
-impl TryFrom<Option<&str>> for UppercaseMode {
-    type Error = InvalidCaseMappingMode;
+    /// Set whether to use Unicode case folding, like Ruby's `:fold`.
+    #[must_use]
+    pub const fn fold(mut self, fold: bool) -> Self {
+        self.fold = fold;
+        self
+    }
 
-    #[inline]
-    fn try_from(value: Option<&str>) -> Result<Self, Self::Error> {
-        value.map(str::as_bytes).try_into()
+    /// Set whether to adapt case mapping for Turkic languages, like Ruby's
+    /// `:turkic`.
+    #[must_use]
+    pub const fn turkic(mut self, turkic: bool) -> Self {
+        self.turkic = turkic;
+        self
     }
-}
 
-impl TryFrom<&[u8]> for UppercaseMode {
-    type Error = InvalidCaseMappingMode;
+    /// Set whether to adapt case mapping for Lithuanian, like Ruby's
+    /// `:lithuanian`.
+    #[must_use]
+    pub const fn lithuanian(mut self, lithuanian: bool) -> Self {
+        self.lithuanian = lithuanian;
+        self
+    }
 
-    #[inline]
-    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
-        match value {
-            b"ascii" => Ok(Self::Ascii),
-            b"turkic" => Ok(Self::Turkic),
-            b"lithuanian" => Ok(Self::Lithuanian),
+    /// Validate the options and build the [`LowercaseMode`] they describe.
+    ///
+    /// # Errors
+    ///
+    /// If the options are an illegal combination, such as `ascii` with
+    /// `turkic`, an [`InvalidCaseMappingMode`] error is returned.
+    pub const fn build(self) -> Result<LowercaseMode, InvalidCaseMappingMode> {
+        match self {
+            Self {
+                ascii: false,
+                fold: false,
+                turkic: false,
+                lithuanian: false,
+            } => Ok(LowercaseMode::Full),
+            Self {
+                ascii: true,
+                fold: _,
+                turkic: false,
+                lithuanian: false,
+            } => Ok(LowercaseMode::Ascii),
+            Self {
+                ascii: false,
+                fold: false,
+                turkic: true,
+                lithuanian: false,
+            } => Ok(LowercaseMode::Turkic),
+            Self {
+                ascii: false,
+                fold: false,
+                turkic: false,
+                lithuanian: true,
+            } => Ok(LowercaseMode::Lithuanian),
+            Self {
+                ascii: false,
+                fold: true,
+                turkic: false,
+                lithuanian: false,
+            } => Ok(LowercaseMode::Fold),
+            Self {
+                ascii: false,
+                fold: true,
+                turkic: true,
+                lithuanian: false,
+            } => Ok(LowercaseMode::TurkicFold),
             _ => Err(InvalidCaseMappingMode::new()),
         }
     }
 }
 
-impl TryFrom<Option<&[u8]>> for UppercaseMode {
+impl TryFrom<LowercaseOptions> for LowercaseMode {
     type Error = InvalidCaseMappingMode;
 
     #[inline]
-    fn try_from(value: Option<&[u8]>) -> Result<Self, Self::Error> {
-        match value {
-            None => Ok(Self::Full),
-            Some(b"ascii") => Ok(Self::Ascii),
-            Some(b"turkic") => Ok(Self::Turkic),
-            Some(b"lithuanian") => Ok(Self::Lithuanian),
-            Some(_) => Err(InvalidCaseMappingMode::new()),
-        }
-    }
-}
-
-impl FromStr for UppercaseMode {
-    type Err = InvalidCaseMappingMode;
-
-    #[inline]
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.try_into()
+    fn try_from(options: LowercaseOptions) -> Result<Self, Self::Error> {
+        options.build()
     }
 }
 
 /// Returns an iterator that yields a copy of the bytes in the given slice with
-/// all lowercase letters replaced with their uppercase counterparts.
+/// all uppercase letters replaced with their lowercase counterparts.
 ///
 /// This function treats the given slice as a [conventionally UTF-8 string].
-/// UTF-8 byte sequences are converted to their Unicode uppercase equivalents.
+/// UTF-8 byte sequences are converted to their Unicode lowercase equivalents.
 /// Invalid UTF-8 byte sequences are yielded as is.
 ///
-/// The case mapping mode is determined by the given [`UppercaseMode`]. See its
+/// The case mapping mode is determined by the given [`LowercaseMode`]. See its
 /// documentation for details on the available case mapping modes.
 ///
 /// # Panics
 ///
-/// Not all [`UppercaseMode`]s are currently implemented. This function will
-/// panic if the caller supplies [Turkic] uppercasing mode.
+/// Not all [`LowercaseMode`]s are currently implemented. This function will
+/// panic if the caller supplies [Turkic] lowercasing mode. Use
+/// [`try_lowercase`] to handle unimplemented modes without panicking.
 ///
 /// [conventionally UTF-8 string]: https://docs.rs/bstr/0.2.*/bstr/#when-should-i-use-byte-strings
 /// [Turkic]: LowercaseMode::Turkic
-// TODO: make this const once we're no longer panicking.
-pub fn uppercase(slice: &[u8], options: UppercaseMode) -> Uppercase<'_> {
-    match options {
-        UppercaseMode::Full | UppercaseMode::Lithuanian => Uppercase::with_slice(slice),
-        UppercaseMode::Ascii => Uppercase::with_ascii_slice(slice),
-        // TODO: implement `turkic` mode.
-        UppercaseMode::Turkic => panic!("uppercase Turkic mode is not yet implemented"),
+/// [`try_lowercase`]: crate::try_lowercase()
+pub fn lowercase(slice: &[u8], options: LowercaseMode) -> Lowercase<'_> {
+    match try_lowercase(slice, options) {
+        Ok(iter) => iter,
+        Err(err) => panic!("{}", err),
     }
 }
 
-/// Options to configure the behavior of [`titlecase`].
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// all uppercase letters replaced with their lowercase counterparts, or an
+/// error if the given [`LowercaseMode`] is not yet implemented.
 ///
-/// Which letters exactly are replaced, and by which other letters, depends on
-/// the given options.
+/// This function is the non-panicking counterpart to [`lowercase`]. It is
+/// suitable for callers which accept a case mapping mode from untrusted input.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// let s = roe::try_lowercase("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full).unwrap();
+/// assert_eq!(s.collect::<Vec<_>>(), "αύριο".as_bytes());
+/// ```
+///
+/// # Errors
+///
+/// If the given [`LowercaseMode`] is not yet implemented, an
+/// [`UnsupportedCaseMappingMode`] error is returned which carries the
+/// requested mode.
+///
+/// ```
+/// # use roe::{CaseMapping, LowercaseMode};
+/// let err = roe::try_lowercase(b"ISTANBUL", LowercaseMode::Turkic).unwrap_err();
+/// assert_eq!(err.mapping(), CaseMapping::Lowercase(LowercaseMode::Turkic));
+/// ```
+///
+/// [`lowercase`]: crate::lowercase()
+pub const fn try_lowercase(
+    slice: &[u8],
+    options: LowercaseMode,
+) -> Result<Lowercase<'_>, UnsupportedCaseMappingMode> {
+    match options {
+        // TODO: implement `turkic` mode.
+        LowercaseMode::Turkic => Err(UnsupportedCaseMappingMode::new(CaseMapping::Lowercase(
+            LowercaseMode::Turkic,
+        ))),
+        _ if slice.is_empty() => Ok(Lowercase::new()),
+        LowercaseMode::Full => Ok(Lowercase::with_slice(slice)),
+        LowercaseMode::Ascii => Ok(Lowercase::with_ascii_slice(slice)),
+        LowercaseMode::Lithuanian => Ok(Lowercase::with_lithuanian_slice(slice)),
+        LowercaseMode::Fold => Ok(Lowercase::with_fold_slice(slice)),
+        LowercaseMode::TurkicFold => Ok(Lowercase::with_turkic_fold_slice(slice)),
+    }
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given byte string
+/// with all uppercase letters replaced with their lowercase counterparts.
+///
+/// This function is the same as [`lowercase`] but accepts a [`BStr`], the
+/// conventionally UTF-8 byte string type of the [`bstr`] crate, so callers
+/// which work with `bstr` do not need to convert to a byte slice first. A
+/// `&BString` may be passed too, since it dereferences to a `&BStr`.
+///
+/// # Examples
+///
+/// ```
+/// # use bstr::BStr;
+/// # use roe::LowercaseMode;
+/// let s = roe::lowercase_bstr(BStr::new("ΑΎΡΙΟ"), LowercaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), "αύριο".as_bytes());
+///
+/// let s = roe::lowercase_bstr(BStr::new(b"ABC\xFF\xFEXYZ"), LowercaseMode::Ascii);
+/// assert_eq!(s.collect::<Vec<_>>(), b"abc\xFF\xFExyz");
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [`BStr`]: bstr::BStr
+/// [`bstr`]: https://docs.rs/bstr/1.*/bstr/
+/// [`lowercase`]: crate::lowercase()
+pub fn lowercase_bstr(s: &BStr, options: LowercaseMode) -> Lowercase<'_> {
+    lowercase(s, options)
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// all uppercase letters replaced with their lowercase counterparts using the
+/// [default lowercase mode].
+///
+/// This function is equivalent to calling [`lowercase`] with
+/// [`LowercaseMode::default()`] and corresponds to calling [`String#downcase`]
+/// in Ruby with no options.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// assert_eq!(
+///     roe::lowercase_default("ΑΎΡΙΟ".as_bytes()).collect::<Vec<_>>(),
+///     roe::lowercase("ΑΎΡΙΟ".as_bytes(), LowercaseMode::default()).collect::<Vec<_>>(),
+/// );
+/// ```
+///
+/// [default lowercase mode]: LowercaseMode::default
+/// [`lowercase`]: crate::lowercase()
+/// [`String#downcase`]: https://ruby-doc.org/core-3.1.2/String.html#method-i-downcase
+pub fn lowercase_default(slice: &[u8]) -> Lowercase<'_> {
+    lowercase(slice, LowercaseMode::default())
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// all uppercase letters replaced with their lowercase counterparts using
+/// [full] Unicode case mapping.
+///
+/// This function is equivalent to calling [`lowercase`] with
+/// [`LowercaseMode::Full`]. Unlike [`lowercase`], it does not dispatch on a
+/// mode and never panics, so it can be called in const contexts.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::Lowercase;
+/// const LOWERCASE: Lowercase<'static> = roe::lowercase_full("ΑΎΡΙΟ".as_bytes());
+/// assert_eq!(LOWERCASE.collect::<Vec<_>>(), "αύριο".as_bytes());
+/// ```
+///
+/// [full]: LowercaseMode::Full
+/// [`lowercase`]: crate::lowercase()
+pub const fn lowercase_full(slice: &[u8]) -> Lowercase<'_> {
+    Lowercase::with_slice(slice)
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// all ASCII uppercase letters replaced with their lowercase counterparts.
+///
+/// This function is equivalent to calling [`lowercase`] with
+/// [`LowercaseMode::Ascii`]. Unlike [`lowercase`], it does not dispatch on a
+/// mode and never panics, so it can be called in const contexts.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::Lowercase;
+/// const LOWERCASE: Lowercase<'static> = roe::lowercase_ascii("ABC ΑΎΡΙΟ".as_bytes());
+/// assert_eq!(LOWERCASE.collect::<Vec<_>>(), "abc ΑΎΡΙΟ".as_bytes());
+/// ```
+///
+/// [`lowercase`]: crate::lowercase()
+pub const fn lowercase_ascii(slice: &[u8]) -> Lowercase<'_> {
+    Lowercase::with_ascii_slice(slice)
+}
+
+/// Returns whether the given slice is already lowercase.
+///
+/// A slice is lowercase if mapping it with [`lowercase`] in the given
+/// [`LowercaseMode`] yields an identical byte sequence.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string].
+/// Invalid UTF-8 byte sequences are yielded as is by [`lowercase`], so they
+/// never make a slice not lowercase.
+///
+/// The comparison is lazy and does not allocate. It stops at the first byte
+/// which differs.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// assert!(roe::is_lowercase(b"abc, xyz", LowercaseMode::Full));
+/// assert!(roe::is_lowercase("αύριο".as_bytes(), LowercaseMode::Full));
+/// assert!(!roe::is_lowercase(b"abc, XYZ", LowercaseMode::Full));
+///
+/// // ASCII mode does not map non-ASCII letters.
+/// assert!(roe::is_lowercase("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Ascii));
+///
+/// // `ß` is lowercase, but it is not case folded.
+/// assert!(roe::is_lowercase("ß".as_bytes(), LowercaseMode::Full));
+/// assert!(!roe::is_lowercase("ß".as_bytes(), LowercaseMode::Fold));
+///
+/// assert!(roe::is_lowercase(b"abc\xFF\xFE", LowercaseMode::Full));
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [`lowercase`]: crate::lowercase()
+#[must_use]
+pub fn is_lowercase(slice: &[u8], options: LowercaseMode) -> bool {
+    lowercase(slice, options) == *slice
+}
+
+/// Returns whether lowercasing the given slice in [`LowercaseMode::Ascii`]
+/// would leave a character unchanged that [`LowercaseMode::Full`] would map.
+///
+/// This is the case if the slice contains a non-ASCII cased character which
+/// has a lowercase mapping, like `É` or `Σ`. Callers which chose ASCII mode
+/// can use this function to detect data which ASCII mode silently ignores.
+///
+/// Lowercase letters like `é` are not reported because full mode leaves them
+/// unchanged, too.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string].
+/// Invalid UTF-8 byte sequences are never mapped in either mode. The scan
+/// skips ASCII bytes and stops at the first character which would be missed.
+///
+/// # Examples
+///
+/// ```
+/// assert!(roe::ascii_mode_would_miss("CAFÉ".as_bytes()));
+/// assert!(!roe::ascii_mode_would_miss(b"CAFE"));
+///
+/// // Full mode does not map lowercase letters.
+/// assert!(!roe::ascii_mode_would_miss("café".as_bytes()));
+///
+/// assert!(!roe::ascii_mode_would_miss(b"CAFE\xFF\xFE"));
+/// ```
+///
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+#[must_use]
+pub fn ascii_mode_would_miss(slice: &[u8]) -> bool {
+    let mut rest = slice;
+    while let Some(start) = rest.iter().position(|&byte| !byte.is_ascii()) {
+        rest = &rest[start..];
+        let (ch, size) = bstr::decode_utf8(rest);
+        if let Some(ch) = ch {
+            let mut lowercase = ch.to_lowercase();
+            if !matches!((lowercase.next(), lowercase.next()), (Some(lower), None) if lower == ch) {
+                return true;
+            }
+        }
+        rest = &rest[size..];
+    }
+    false
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// all uppercase letters replaced with their lowercase counterparts, except for
+/// the characters in `exempt`, which are yielded unchanged.
+///
+/// This function behaves like [`lowercase`], but any character in the input
+/// which appears in `exempt` is passed through as is. This is useful for
+/// preserving case-significant markers in an otherwise normalized string.
+///
+/// `exempt` is matched against the decoded characters of the input, not its
+/// bytes. An exempt character matches its complete UTF-8 encoding in the input
+/// and never matches part of a multi-byte sequence. Invalid UTF-8 byte
+/// sequences never match an exempt character, including
+/// [`char::REPLACEMENT_CHARACTER`], and are yielded as is.
+///
+/// Context-dependent mappings, like the `Final_Sigma` rule, consider the whole
+/// input, including exempt characters.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// let s = roe::lowercase_except(b"HELLO {NAME}", LowercaseMode::Full, &['N', 'E']);
+/// assert_eq!(s.collect::<Vec<_>>(), b"hEllo {NamE}");
+///
+/// let s = roe::lowercase_except("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full, &['Ρ']);
+/// assert_eq!(s.collect::<Vec<_>>(), "αύΡιο".as_bytes());
+///
+/// // The exempt `N` follows the sigma, so it is not final.
+/// let s = roe::lowercase_except("ΑΣN".as_bytes(), LowercaseMode::Full, &['N']);
+/// assert_eq!(s.collect::<Vec<_>>(), "ασN".as_bytes());
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [`lowercase`]: crate::lowercase()
+pub fn lowercase_except<'a>(
+    slice: &'a [u8],
+    options: LowercaseMode,
+    exempt: &'a [char],
+) -> LowercaseExcept<'a> {
+    LowercaseExcept::with_slice(slice, options, exempt)
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// all uppercase letters replaced with their lowercase counterparts and the
+/// dotted and dotless I family collapsed to ASCII `i`.
+///
+/// This function uses [full Unicode case mapping] with the following
+/// additional, locale-independent mappings:
+///
+/// - `I` U+0049 LATIN CAPITAL LETTER I maps to `i`.
+/// - `İ` U+0130 LATIN CAPITAL LETTER I WITH DOT ABOVE maps to `i`.
+/// - `ı` U+0131 LATIN SMALL LETTER DOTLESS I maps to `i`.
+/// - `i` U+0069 LATIN SMALL LETTER I is yielded as is.
+///
+/// This transform is **lossy**. It drops the distinction between the dotted and
+/// dotless I, which is significant in Turkish and Azeri, so the result is not
+/// suitable for display. It is intended for case-insensitive identifier
+/// matching across text which mixes Turkic and non-Turkic conventions, where
+/// `ISTANBUL`, `İstanbul`, and `ıstanbul` should all be considered the same
+/// identifier.
+///
+/// Only the precomposed code points above are collapsed. A decomposed `I`
+/// followed by U+0307 COMBINING DOT ABOVE lowercases to `i` followed by the
+/// combining mark.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string].
+/// Invalid UTF-8 byte sequences are yielded as is.
+///
+/// # Examples
+///
+/// ```
+/// let s = roe::lowercase_canonical_i("İSTANBUL".as_bytes());
+/// assert_eq!(s.collect::<Vec<_>>(), b"istanbul");
+///
+/// let s = roe::lowercase_canonical_i("DİYARBAKIR".as_bytes());
+/// assert_eq!(s.collect::<Vec<_>>(), b"diyarbakir");
+///
+/// let s = roe::lowercase_canonical_i("ıstanbul".as_bytes());
+/// assert_eq!(s.collect::<Vec<_>>(), b"istanbul");
+/// ```
+///
+/// [full Unicode case mapping]: LowercaseMode::Full
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+pub fn lowercase_canonical_i(slice: &[u8]) -> LowercaseCanonicalI<'_> {
+    LowercaseCanonicalI::with_slice(slice)
+}
+
+/// Options to configure the behavior of [`uppercase`].
+///
+/// Which letters exactly are replaced, and by which other letters, depends on
+/// the given options.
+///
+/// See individual variants for a description of the available behaviors.
+///
+/// If you're not sure which mode to choose, [`UppercaseMode::Full`] is a a good
+/// default.
+///
+/// [`uppercase`]: crate::uppercase()
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UppercaseMode {
+    /// Full Unicode case mapping, suitable for most languages.
+    ///
+    /// See the [Turkic] and [Lithuanian] variants for exceptions.
+    ///
+    /// Context-dependent case mapping as described in Table 3-14 of the Unicode
+    /// standard is currently not supported.
+    ///
+    /// [Turkic]: Self::Turkic
+    /// [Lithuanian]: Self::Lithuanian
+    #[default]
+    Full,
+    /// Only the ASCII region, i.e. the characters `'A'..='Z'` and `'a'..='z'`,
+    /// are affected.
+    ///
+    /// This option cannot be combined with any other option.
+    Ascii,
+    /// Full Unicode case mapping, adapted for Turkic languages (Turkish,
+    /// Azerbaijani, …).
+    ///
+    /// This means that lower case i is mapped to upper case dotted I, and
+    /// lower case dotless i is mapped to upper case I.
+    Turkic,
+    /// Full Unicode case mapping, adapted for Lithuanian.
+    ///
+    /// Lithuanian lowercasing keeps the dot on the lower case i even if there
+    /// is an accent on top by inserting U+0307 COMBINING DOT ABOVE. This mode
+    /// removes that explicit dot when it follows a soft dotted letter like `i`
+    /// or `j`, so the accented letter uppercases without a dot:
+    ///
+    /// ```
+    /// # use roe::UppercaseMode;
+    /// let s = roe::uppercase("i\u{307}\u{301}".as_bytes(), UppercaseMode::Lithuanian);
+    /// assert_eq!(s.collect::<Vec<_>>(), "I\u{301}".as_bytes());
+    /// ```
+    ///
+    /// All other characters use [full Unicode case mapping].
+    ///
+    /// [full Unicode case mapping]: Self::Full
+    Lithuanian,
+    /// Unicode case **folding**, the same as [`LowercaseMode::Fold`].
+    ///
+    /// Case folding maps characters to a caseless form which is mostly
+    /// lowercase, not uppercase. This mode exists so that `"fold"` parses with
+    /// [`TryFrom`] as both a [`LowercaseMode`] and an `UppercaseMode`, and call
+    /// sites can pass one option to both [`lowercase`] and [`uppercase`].
+    ///
+    /// This deliberately diverges from Ruby, where `String#upcase` rejects
+    /// `:fold` with an `ArgumentError` because the option is only allowed for
+    /// downcasing.
+    ///
+    /// [`lowercase`]: crate::lowercase()
+    /// [`uppercase`]: crate::uppercase()
+    Fold,
+    /// Full Unicode case mapping, except that `ß` is mapped to the single
+    /// capital sharp s `ẞ` U+1E9E.
+    ///
+    /// Unicode and Ruby uppercase `ß` to `SS`. Some German orthographies
+    /// prefer `ẞ`, which this mode selects. There is no corresponding Ruby
+    /// option, so this mode is not parsed from option strings.
+    CapitalSharpS,
+}
+
+impl TryFrom<&str> for UppercaseMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.as_bytes().try_into()
+    }
+}
+
+impl TryFrom<Option<&str>> for UppercaseMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: Option<&str>) -> Result<Self, Self::Error> {
+        value.map(str::as_bytes).try_into()
+    }
+}
+
+impl TryFrom<&[u8]> for UppercaseMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        match value {
+            b"ascii" => Ok(Self::Ascii),
+            b"turkic" => Ok(Self::Turkic),
+            b"lithuanian" => Ok(Self::Lithuanian),
+            b"fold" => Ok(Self::Fold),
+            _ => Err(InvalidCaseMappingMode::new()),
+        }
+    }
+}
+
+impl TryFrom<Option<&[u8]>> for UppercaseMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: Option<&[u8]>) -> Result<Self, Self::Error> {
+        match value {
+            None => Ok(Self::Full),
+            Some(b"ascii") => Ok(Self::Ascii),
+            Some(b"turkic") => Ok(Self::Turkic),
+            Some(b"lithuanian") => Ok(Self::Lithuanian),
+            Some(b"fold") => Ok(Self::Fold),
+            Some(_) => Err(InvalidCaseMappingMode::new()),
+        }
+    }
+}
+
+impl FromStr for UppercaseMode {
+    type Err = InvalidCaseMappingMode;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl UppercaseMode {
+    /// Parse a mode from the bytes of a C string, as received across an FFI
+    /// boundary.
+    ///
+    /// A single trailing NUL byte is stripped before the bytes are parsed the
+    /// same as the `TryFrom<&[u8]>` implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{InvalidCaseMappingMode, UppercaseMode};
+    /// assert_eq!(UppercaseMode::from_ffi_bytes(b"ascii\0"), Ok(UppercaseMode::Ascii));
+    /// assert_eq!(UppercaseMode::from_ffi_bytes(b"turkic"), Ok(UppercaseMode::Turkic));
+    /// assert_eq!(UppercaseMode::from_ffi_bytes(b"asc\0ii"), Err(InvalidCaseMappingMode::new()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the bytes contain a NUL byte other than the trailing one, or they do
+    /// not name a mode, an [`InvalidCaseMappingMode`] error is returned.
+    #[inline]
+    pub fn from_ffi_bytes(bytes: &[u8]) -> Result<Self, InvalidCaseMappingMode> {
+        let bytes = bytes.strip_suffix(b"\0").unwrap_or(bytes);
+        if bytes.contains(&b'\0') {
+            return Err(InvalidCaseMappingMode::new());
+        }
+        bytes.try_into()
+    }
+
+    /// Parse a mode from bytes, ignoring ASCII case.
+    ///
+    /// The bytes are ASCII lowercased before they are parsed the same as the
+    /// `TryFrom<&[u8]>` implementation, which only accepts lowercase mode
+    /// names for parity with Ruby.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::convert::TryFrom;
+    /// # use roe::{InvalidCaseMappingMode, UppercaseMode};
+    /// assert_eq!(UppercaseMode::from_bytes_ignore_ascii_case(b"ASCII"), Ok(UppercaseMode::Ascii));
+    /// assert_eq!(UppercaseMode::from_bytes_ignore_ascii_case(b"Turkic"), Ok(UppercaseMode::Turkic));
+    /// assert_eq!(UppercaseMode::try_from(&b"Turkic"[..]), Err(InvalidCaseMappingMode::new()));
+    /// assert_eq!(UppercaseMode::from_bytes_ignore_ascii_case(b"Full"), Err(InvalidCaseMappingMode::new()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the bytes do not name a mode in any ASCII case, an
+    /// [`InvalidCaseMappingMode`] error is returned.
+    #[inline]
+    pub fn from_bytes_ignore_ascii_case(bytes: &[u8]) -> Result<Self, InvalidCaseMappingMode> {
+        parse_mode_ignore_ascii_case(bytes)
+    }
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// all lowercase letters replaced with their uppercase counterparts.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string].
+/// UTF-8 byte sequences are converted to their Unicode uppercase equivalents.
+/// Invalid UTF-8 byte sequences are yielded as is.
+///
+/// The case mapping mode is determined by the given [`UppercaseMode`]. See its
+/// documentation for details on the available case mapping modes.
+///
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/0.2.*/bstr/#when-should-i-use-byte-strings
+pub const fn uppercase(slice: &[u8], options: UppercaseMode) -> Uppercase<'_> {
+    match options {
+        _ if slice.is_empty() => Uppercase::new(),
+        UppercaseMode::Full => Uppercase::with_slice(slice),
+        UppercaseMode::Ascii => Uppercase::with_ascii_slice(slice),
+        UppercaseMode::Turkic => Uppercase::with_turkic_slice(slice),
+        UppercaseMode::Lithuanian => Uppercase::with_lithuanian_slice(slice),
+        UppercaseMode::Fold => Uppercase::with_fold_slice(slice),
+        UppercaseMode::CapitalSharpS => Uppercase::with_capital_sharp_s_slice(slice),
+    }
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// all lowercase letters replaced with their uppercase counterparts, or an
+/// error if the given [`UppercaseMode`] is not yet implemented.
+///
+/// This function is the non-panicking counterpart to [`uppercase`]. It is
+/// suitable for callers which accept a case mapping mode from untrusted input.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::UppercaseMode;
+/// let s = roe::try_uppercase("αύριο".as_bytes(), UppercaseMode::Full).unwrap();
+/// assert_eq!(s.collect::<Vec<_>>(), "ΑΎΡΙΟ".as_bytes());
+///
+/// let s = roe::try_uppercase(b"istanbul", UppercaseMode::Turkic).unwrap();
+/// assert_eq!(s.collect::<Vec<_>>(), "İSTANBUL".as_bytes());
+/// ```
+///
+/// # Errors
+///
+/// If the given [`UppercaseMode`] is not yet implemented, an
+/// [`UnsupportedCaseMappingMode`] error is returned which carries the
+/// requested mode. All [`UppercaseMode`]s are currently implemented.
+///
+/// [`uppercase`]: crate::uppercase()
+pub const fn try_uppercase(
+    slice: &[u8],
+    options: UppercaseMode,
+) -> Result<Uppercase<'_>, UnsupportedCaseMappingMode> {
+    // All uppercase modes are implemented, so `uppercase` never panics and
+    // remains usable in const contexts.
+    Ok(uppercase(slice, options))
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// all lowercase letters replaced with their uppercase counterparts using the
+/// [default uppercase mode].
+///
+/// This function is equivalent to calling [`uppercase`] with
+/// [`UppercaseMode::default()`] and corresponds to calling [`String#upcase`] in
+/// Ruby with no options.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::UppercaseMode;
+/// assert_eq!(
+///     roe::uppercase_default("αύριο".as_bytes()).collect::<Vec<_>>(),
+///     roe::uppercase("αύριο".as_bytes(), UppercaseMode::default()).collect::<Vec<_>>(),
+/// );
+/// ```
+///
+/// [default uppercase mode]: UppercaseMode::default
+/// [`uppercase`]: crate::uppercase()
+/// [`String#upcase`]: https://ruby-doc.org/core-3.1.2/String.html#method-i-upcase
+pub fn uppercase_default(slice: &[u8]) -> Uppercase<'_> {
+    uppercase(slice, UppercaseMode::default())
+}
+
+/// Returns whether the given slice is already uppercase.
+///
+/// A slice is uppercase if mapping it with [`uppercase`] in the given
+/// [`UppercaseMode`] yields an identical byte sequence.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string].
+/// Invalid UTF-8 byte sequences are yielded as is by [`uppercase`], so they
+/// never make a slice not uppercase.
+///
+/// The comparison is lazy and does not allocate. It stops at the first byte
+/// which differs.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::UppercaseMode;
+/// assert!(roe::is_uppercase(b"ABC, XYZ", UppercaseMode::Full));
+/// assert!(roe::is_uppercase("ΑΎΡΙΟ".as_bytes(), UppercaseMode::Full));
+/// assert!(!roe::is_uppercase(b"ABC, xyz", UppercaseMode::Full));
+///
+/// // ASCII mode does not map non-ASCII letters.
+/// assert!(roe::is_uppercase("αύριο".as_bytes(), UppercaseMode::Ascii));
+///
+/// // `ß` uppercases to `SS`.
+/// assert!(!roe::is_uppercase("ß".as_bytes(), UppercaseMode::Full));
+///
+/// // Titlecase digraphs are not uppercase.
+/// assert!(!roe::is_uppercase("ǅ".as_bytes(), UppercaseMode::Full));
+///
+/// assert!(roe::is_uppercase(b"ABC\xFF\xFE", UppercaseMode::Full));
+/// ```
+///
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [`uppercase`]: crate::uppercase()
+#[must_use]
+pub fn is_uppercase(slice: &[u8], options: UppercaseMode) -> bool {
+    uppercase(slice, options) == *slice
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// the letters of the first word replaced with their uppercase counterparts and
+/// all remaining bytes yielded as is.
+///
+/// The first word is the first run of non-whitespace characters in the slice.
+/// Whitespace is determined by the Unicode `White_Space` property. Leading
+/// whitespace is yielded as is, and everything from the first whitespace
+/// character after the first word onward is yielded verbatim. If the slice
+/// contains no whitespace after its first word, the whole slice is uppercased.
+///
+/// This differs from [`titlecase`], which lowercases all but the leading letter,
+/// and from [`uppercase`], which maps the entire slice.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string].
+/// Invalid UTF-8 byte sequences are considered part of a word and are yielded
+/// as is.
+///
+/// The case mapping mode for the first word is determined by the given
+/// [`UppercaseMode`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::UppercaseMode;
+/// let s = roe::upcase_first_word(b"note: Keep this As-Is", UppercaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"NOTE: Keep this As-Is");
+///
+/// let s = roe::upcase_first_word(b"  todo: fix me", UppercaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"  TODO: fix me");
+///
+/// let s = roe::upcase_first_word("straße und weg".as_bytes(), UppercaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"STRASSE und weg");
+/// ```
+///
+/// [`titlecase`]: crate::titlecase()
+/// [`uppercase`]: crate::uppercase()
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+pub fn upcase_first_word(slice: &[u8], options: UppercaseMode) -> UpcaseFirstWord<'_> {
+    UpcaseFirstWord::with_slice(slice, options)
+}
+
+/// Options to configure the behavior of [`titlecase`].
+///
+/// Which letters exactly are replaced, and by which other letters, depends on
+/// the given options.
+///
+/// See individual variants for a description of the available behaviors.
+///
+/// If you're not sure which mode to choose, [`TitlecaseMode::Full`] is a a good
+/// default.
+///
+/// [`titlecase`]: crate::titlecase()
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TitlecaseMode {
+    /// Full Unicode case mapping, suitable for most languages.
+    ///
+    /// See the [Turkic] and [Lithuanian] variants for exceptions.
+    ///
+    /// The context-dependent `Final_Sigma` mapping described in Table 3-14 of
+    /// the Unicode standard is supported for the letters after the first: `Σ`
+    /// is mapped to final sigma `ς` at the end of a word and to `σ` otherwise.
+    ///
+    /// [Turkic]: Self::Turkic
+    /// [Lithuanian]: Self::Lithuanian
+    #[default]
+    Full,
+    /// Only the ASCII region, i.e. the characters `'A'..='Z'` and `'a'..='z'`,
+    /// are affected.
+    ///
+    /// This option cannot be combined with any other option.
+    Ascii,
+    /// Full Unicode case mapping, adapted for Turkic languages (Turkish,
+    /// Azerbaijani, …).
+    ///
+    /// This means that a leading lower case i is mapped to title case dotted
+    /// I, and that upper case I is mapped to lower case dotless i in the
+    /// remaining letters.
+    Turkic,
+    /// Full Unicode case mapping, adapted for Lithuanian.
+    ///
+    /// The leading letter is mapped with [full Unicode case mapping]. All
+    /// remaining letters are lowercased with the Lithuanian rules, which keep
+    /// the dot on the lower case i even if there is an accent on top.
+    ///
+    /// [full Unicode case mapping]: Self::Full
+    Lithuanian,
+}
+
+impl TryFrom<&str> for TitlecaseMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.as_bytes().try_into()
+    }
+}
+
+impl TryFrom<Option<&str>> for TitlecaseMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: Option<&str>) -> Result<Self, Self::Error> {
+        value.map(str::as_bytes).try_into()
+    }
+}
+
+impl TryFrom<&[u8]> for TitlecaseMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        match value {
+            b"ascii" => Ok(Self::Ascii),
+            b"turkic" => Ok(Self::Turkic),
+            b"lithuanian" => Ok(Self::Lithuanian),
+            _ => Err(InvalidCaseMappingMode::new()),
+        }
+    }
+}
+
+impl TryFrom<Option<&[u8]>> for TitlecaseMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: Option<&[u8]>) -> Result<Self, Self::Error> {
+        match value {
+            None => Ok(Self::default()),
+            Some(value) => value.try_into(),
+        }
+    }
+}
+
+impl FromStr for TitlecaseMode {
+    type Err = InvalidCaseMappingMode;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl TitlecaseMode {
+    /// Parse a mode from bytes, ignoring ASCII case.
+    ///
+    /// The bytes are ASCII lowercased before they are parsed the same as the
+    /// `TryFrom<&[u8]>` implementation, which only accepts lowercase mode
+    /// names for parity with Ruby.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::convert::TryFrom;
+    /// # use roe::{InvalidCaseMappingMode, TitlecaseMode};
+    /// assert_eq!(TitlecaseMode::from_bytes_ignore_ascii_case(b"ASCII"), Ok(TitlecaseMode::Ascii));
+    /// assert_eq!(TitlecaseMode::from_bytes_ignore_ascii_case(b"Lithuanian"), Ok(TitlecaseMode::Lithuanian));
+    /// assert_eq!(TitlecaseMode::try_from(&b"Lithuanian"[..]), Err(InvalidCaseMappingMode::new()));
+    /// assert_eq!(TitlecaseMode::from_bytes_ignore_ascii_case(b"Fold"), Err(InvalidCaseMappingMode::new()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the bytes do not name a mode in any ASCII case, an
+    /// [`InvalidCaseMappingMode`] error is returned.
+    #[inline]
+    pub fn from_bytes_ignore_ascii_case(bytes: &[u8]) -> Result<Self, InvalidCaseMappingMode> {
+        parse_mode_ignore_ascii_case(bytes)
+    }
+}
+
+/// A case mapping mode which has been parsed without knowing which case mapping
+/// operation it will configure.
+///
+/// [`LowercaseMode`], [`UppercaseMode`], and [`TitlecaseMode`] share their
+/// string forms but not their variants: only lowercasing and uppercasing
+/// support case folding. Generic config code can parse a `CaseMappingMode`
+/// once and resolve it against whichever operation is chosen later with
+/// [`resolve_lowercase`], [`resolve_uppercase`], or [`resolve_titlecase`].
+///
+/// A `CaseMappingMode` is parsed from the same strings as the operation
+/// specific modes. Parsing [`None`] yields [`CaseMappingMode::Full`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{CaseMappingMode, InvalidCaseMappingMode, LowercaseMode, TitlecaseMode};
+/// let mode: CaseMappingMode = "fold".parse()?;
+/// assert_eq!(mode.resolve_lowercase(), Ok(LowercaseMode::Fold));
+/// // Titlecasing does not support case folding.
+/// assert_eq!(mode.resolve_titlecase(), Err(InvalidCaseMappingMode::new()));
+///
+/// let mode: CaseMappingMode = "turkic".parse()?;
+/// assert_eq!(mode.resolve_titlecase(), Ok(TitlecaseMode::Turkic));
+/// # Ok::<(), InvalidCaseMappingMode>(())
+/// ```
+///
+/// [`resolve_lowercase`]: Self::resolve_lowercase
+/// [`resolve_uppercase`]: Self::resolve_uppercase
+/// [`resolve_titlecase`]: Self::resolve_titlecase
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CaseMappingMode {
+    /// Full Unicode case mapping.
+    #[default]
+    Full,
+    /// ASCII case mapping.
+    Ascii,
+    /// Full Unicode case mapping, adapted for Turkic languages.
+    Turkic,
+    /// Full Unicode case mapping, adapted for Lithuanian.
+    Lithuanian,
+    /// Unicode case folding.
+    Fold,
+}
+
+impl CaseMappingMode {
+    /// Resolve this mode into a [`LowercaseMode`].
+    ///
+    /// Every `CaseMappingMode` is a valid lowercase mode.
+    ///
+    /// # Errors
+    ///
+    /// This function currently never returns an error. It returns a [`Result`]
+    /// so that modes which only apply to some operations can be added without
+    /// breaking callers.
+    pub const fn resolve_lowercase(self) -> Result<LowercaseMode, InvalidCaseMappingMode> {
+        match self {
+            Self::Full => Ok(LowercaseMode::Full),
+            Self::Ascii => Ok(LowercaseMode::Ascii),
+            Self::Turkic => Ok(LowercaseMode::Turkic),
+            Self::Lithuanian => Ok(LowercaseMode::Lithuanian),
+            Self::Fold => Ok(LowercaseMode::Fold),
+        }
+    }
+
+    /// Resolve this mode into an [`UppercaseMode`].
+    ///
+    /// Every `CaseMappingMode` is a valid uppercase mode. [`Fold`] resolves to
+    /// [`UppercaseMode::Fold`].
+    ///
+    /// # Errors
+    ///
+    /// This function currently never returns an error. It returns a [`Result`]
+    /// so that modes which only apply to some operations can be added without
+    /// breaking callers.
+    ///
+    /// [`Fold`]: Self::Fold
+    pub const fn resolve_uppercase(self) -> Result<UppercaseMode, InvalidCaseMappingMode> {
+        match self {
+            Self::Full => Ok(UppercaseMode::Full),
+            Self::Ascii => Ok(UppercaseMode::Ascii),
+            Self::Turkic => Ok(UppercaseMode::Turkic),
+            Self::Lithuanian => Ok(UppercaseMode::Lithuanian),
+            Self::Fold => Ok(UppercaseMode::Fold),
+        }
+    }
+
+    /// Resolve this mode into a [`TitlecaseMode`].
+    ///
+    /// # Errors
+    ///
+    /// Titlecasing does not support case folding. If this mode is [`Fold`], an
+    /// [`InvalidCaseMappingMode`] error is returned, the same error returned
+    /// when parsing `"fold"` as a `TitlecaseMode`.
+    ///
+    /// [`Fold`]: Self::Fold
+    pub const fn resolve_titlecase(self) -> Result<TitlecaseMode, InvalidCaseMappingMode> {
+        match self {
+            Self::Full => Ok(TitlecaseMode::Full),
+            Self::Ascii => Ok(TitlecaseMode::Ascii),
+            Self::Turkic => Ok(TitlecaseMode::Turkic),
+            Self::Lithuanian => Ok(TitlecaseMode::Lithuanian),
+            Self::Fold => Err(InvalidCaseMappingMode::new()),
+        }
+    }
+}
+
+impl TryFrom<&str> for CaseMappingMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.as_bytes().try_into()
+    }
+}
+
+impl TryFrom<Option<&str>> for CaseMappingMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: Option<&str>) -> Result<Self, Self::Error> {
+        value.map(str::as_bytes).try_into()
+    }
+}
+
+impl TryFrom<&[u8]> for CaseMappingMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        match value {
+            b"ascii" => Ok(Self::Ascii),
+            b"turkic" => Ok(Self::Turkic),
+            b"lithuanian" => Ok(Self::Lithuanian),
+            b"fold" => Ok(Self::Fold),
+            _ => Err(InvalidCaseMappingMode::new()),
+        }
+    }
+}
+
+impl TryFrom<Option<&[u8]>> for CaseMappingMode {
+    type Error = InvalidCaseMappingMode;
+
+    #[inline]
+    fn try_from(value: Option<&[u8]>) -> Result<Self, Self::Error> {
+        match value {
+            None => Ok(Self::Full),
+            Some(value) => value.try_into(),
+        }
+    }
+}
+
+impl FromStr for CaseMappingMode {
+    type Err = InvalidCaseMappingMode;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// the leading letter replaced with its titlecase counterpart and all remaining
+/// letters replaced with their lowercase counterparts.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string].
+/// UTF-8 byte sequences are converted to their Unicode titlecase equivalents.
+/// Invalid UTF-8 byte sequences are yielded as is.
+///
+/// The case mapping mode is determined by the given [`TitlecaseMode`]. See its
+/// documentation for details on the available case mapping modes.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::TitlecaseMode;
+/// let s = roe::titlecase(b"abc XYZ", TitlecaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"Abc xyz");
+///
+/// let s = roe::titlecase("αΎΡΙΟ".as_bytes(), TitlecaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), "Αύριο".as_bytes());
+///
+/// // Titlecase letters are used for the leading character.
+/// let s = roe::titlecase("ǆemal".as_bytes(), TitlecaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), "ǅemal".as_bytes());
+/// ```
+///
+/// ASCII case mapping leaves non-ASCII characters unchanged:
+///
+/// ```
+/// # use roe::TitlecaseMode;
+/// let s = roe::titlecase("éCOLE".as_bytes(), TitlecaseMode::Ascii);
+/// assert_eq!(s.collect::<Vec<_>>(), "école".as_bytes());
+/// ```
+///
+/// Invalid UTF-8 bytes are yielded as is:
+///
+/// ```
+/// # use roe::TitlecaseMode;
+/// let s = roe::titlecase(b"abc\xFF\xFEXYZ", TitlecaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"Abc\xFF\xFExyz");
+/// ```
+///
+/// Invalid UTF-8 byte sequences are not characters, so a leading invalid
+/// sequence does not take the place of the first character. Like Ruby, which
+/// titlecases the first character of a string, the first valid character is
+/// titlecased. [ASCII mode] operates on bytes, so there the invalid byte is the
+/// first character:
+///
+/// ```
+/// # use roe::TitlecaseMode;
+/// let s = roe::titlecase(b"\xFFabc", TitlecaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"\xFFAbc");
+///
+/// let s = roe::titlecase(b"\xFFabc", TitlecaseMode::Ascii);
+/// assert_eq!(s.collect::<Vec<_>>(), b"\xFFabc");
+/// ```
+///
+/// # Panics
+///
+/// Not all [`TitlecaseMode`]s are currently implemented. This function will
+/// panic if the caller supplies [Turkic] titlecasing mode. Use
+/// [`try_titlecase`] to handle unimplemented modes without panicking.
+///
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/0.2.*/bstr/#when-should-i-use-byte-strings
+/// [Turkic]: TitlecaseMode::Turkic
+/// [ASCII mode]: TitlecaseMode::Ascii
+/// [`try_titlecase`]: crate::try_titlecase()
+// TODO: make this const once we're no longer panicking.
+pub fn titlecase(slice: &[u8], options: TitlecaseMode) -> Titlecase<'_> {
+    match try_titlecase(slice, options) {
+        Ok(iter) => iter,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// the leading letter replaced with its titlecase counterpart and all remaining
+/// letters replaced with their lowercase counterparts, or an error if the given
+/// [`TitlecaseMode`] is not yet implemented.
+///
+/// This function is the non-panicking counterpart to [`titlecase`]. It is
+/// suitable for callers which accept a case mapping mode from untrusted input.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::TitlecaseMode;
+/// let s = roe::try_titlecase("αΎΡΙΟ".as_bytes(), TitlecaseMode::Full).unwrap();
+/// assert_eq!(s.collect::<Vec<_>>(), "Αύριο".as_bytes());
+/// ```
+///
+/// # Errors
+///
+/// If the given [`TitlecaseMode`] is not yet implemented, an
+/// [`UnsupportedCaseMappingMode`] error is returned which carries the
+/// requested mode.
+///
+/// ```
+/// # use roe::{CaseMapping, TitlecaseMode};
+/// let err = roe::try_titlecase(b"istanbul", TitlecaseMode::Turkic).unwrap_err();
+/// assert_eq!(err.mapping(), CaseMapping::Titlecase(TitlecaseMode::Turkic));
+/// ```
+///
+/// [`titlecase`]: crate::titlecase()
+// TODO: make this const once all modes have const constructors.
+pub fn try_titlecase(
+    slice: &[u8],
+    options: TitlecaseMode,
+) -> Result<Titlecase<'_>, UnsupportedCaseMappingMode> {
+    match options {
+        // TODO: implement `turkic` mode.
+        TitlecaseMode::Turkic => Err(UnsupportedCaseMappingMode::new(CaseMapping::Titlecase(
+            TitlecaseMode::Turkic,
+        ))),
+        _ if slice.is_empty() => Ok(Titlecase::new()),
+        TitlecaseMode::Full => Ok(Titlecase::with_slice(slice)),
+        TitlecaseMode::Ascii => Ok(Titlecase::with_ascii_slice(slice)),
+        TitlecaseMode::Lithuanian => Ok(Titlecase::with_lithuanian_slice(slice)),
+    }
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// the leading letter replaced with its titlecase counterpart and all remaining
+/// letters replaced with their lowercase counterparts using the [default
+/// titlecase mode].
+///
+/// This function is equivalent to calling [`titlecase`] with
+/// [`TitlecaseMode::default()`] and corresponds to calling
+/// [`String#capitalize`] in Ruby with no options.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::TitlecaseMode;
+/// assert_eq!(
+///     roe::titlecase_default("αύριο".as_bytes()).collect::<Vec<_>>(),
+///     roe::titlecase("αύριο".as_bytes(), TitlecaseMode::default()).collect::<Vec<_>>(),
+/// );
+/// ```
+///
+/// [default titlecase mode]: TitlecaseMode::default
+/// [`titlecase`]: crate::titlecase()
+/// [`String#capitalize`]: https://ruby-doc.org/core-3.1.2/String.html#method-i-capitalize
+pub fn titlecase_default(slice: &[u8]) -> Titlecase<'_> {
+    titlecase(slice, TitlecaseMode::default())
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// the leading letter replaced with its titlecase counterpart and all remaining
+/// letters replaced with their lowercase counterparts.
+///
+/// This function is an alias for [`titlecase`] named after Ruby's
+/// [`String#capitalize`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::TitlecaseMode;
+/// let s = roe::capitalize(b"hELLO wORLD", TitlecaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"Hello world");
+///
+/// let s = roe::capitalize("ǆUNGLA".as_bytes(), TitlecaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), "ǅungla".as_bytes());
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`TitlecaseMode`]s as [`titlecase`].
+///
+/// [`titlecase`]: crate::titlecase()
+/// [`String#capitalize`]: https://ruby-doc.org/core-3.1.2/String.html#method-i-capitalize
+pub fn capitalize(slice: &[u8], options: TitlecaseMode) -> Titlecase<'_> {
+    titlecase(slice, options)
+}
+
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// the first cased letter of each word replaced with its titlecase counterpart
+/// and all remaining letters of each word replaced with their lowercase
+/// counterparts.
+///
+/// Words are separated by whitespace and underscores (`_`). Whitespace is
+/// determined by the Unicode `White_Space` property, which includes all ASCII
+/// whitespace as well as characters like U+3000 IDEOGRAPHIC SPACE. Word
+/// boundaries and any uncased characters before the first cased letter of a
+/// word, like punctuation or digits, are yielded as is.
+///
+/// Unlike [`titlecase`], which titlecases the first character of the slice
+/// even when it is uncased, this function skips the uncased characters which
+/// begin a word and titlecases the first cased letter after them. `'hello`
+/// maps to `'Hello` and `1abc` maps to `1Abc`, where [`titlecase`] leaves both
+/// unchanged.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string].
+/// Invalid UTF-8 byte sequences are considered part of a word and are yielded
+/// as is.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::TitlecaseMode;
+/// let s = roe::titlecase_words(b"hello world", TitlecaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"Hello World");
+///
+/// let s = roe::titlecase_words(b"  hELLO_wORLD (again)", TitlecaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"  Hello_World (Again)");
+///
+/// let s = roe::titlecase_words("ǆungla straße".as_bytes(), TitlecaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), "ǅungla Straße".as_bytes());
+///
+/// // Uncased characters which begin a word are skipped.
+/// let s = roe::titlecase_words(b"'hello 1abc", TitlecaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"'Hello 1Abc");
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`TitlecaseMode`]s as [`titlecase`].
+///
+/// [`titlecase`]: crate::titlecase()
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+pub fn titlecase_words(slice: &[u8], options: TitlecaseMode) -> TitlecaseWords<'_> {
+    TitlecaseWords::with_slice(slice, options)
+}
+
+/// Options to configure the behavior of [`swapcase`].
+///
+/// Which letters exactly are replaced, and by which other letters, depends on
+/// the given options.
 ///
 /// See individual variants for a description of the available behaviors.
 ///
-/// If you're not sure which mode to choose, [`UppercaseMode::Full`] is a a good
+/// If you're not sure which mode to choose, [`SwapcaseMode::Full`] is a a good
 /// default.
 ///
-/// [`titlecase`]: crate::titlecase()
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub enum TitlecaseMode {
+/// [`swapcase`]: crate::swapcase()
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SwapcaseMode {
     /// Full Unicode case mapping, suitable for most languages.
     ///
     /// See the [Turkic] and [Lithuanian] variants for exceptions.
@@ -486,6 +2015,7 @@ pub enum TitlecaseMode {
     ///
     /// [Turkic]: Self::Turkic
     /// [Lithuanian]: Self::Lithuanian
+    #[default]
     Full,
     /// Only the ASCII region, i.e. the characters `'A'..='Z'` and `'a'..='z'`,
     /// are affected.
@@ -495,25 +2025,17 @@ pub enum TitlecaseMode {
     /// Full Unicode case mapping, adapted for Turkic languages (Turkish,
     /// Azerbaijani, …).
     ///
-    /// This means that upper case I is mapped to title case dotless i, and so
-    /// on.
+    /// This means that lower case i is mapped to upper case dotted I, and
+    /// upper case I is mapped to lower case dotless i.
     Turkic,
-    /// Currently, just [full Unicode case mapping].
-    ///
-    /// In the future, full Unicode case mapping adapted for Lithuanian (keeping
-    /// the dot on the title case i even if there is an accent on top).
+    /// Full Unicode case mapping, adapted for Lithuanian.
     ///
-    /// [full Unicode case mapping]: Self::Full
+    /// This means that the dot on the lower case i is kept even if there is an
+    /// accent on top of the upper case I.
     Lithuanian,
 }
 
-impl Default for TitlecaseMode {
-    fn default() -> Self {
-        Self::Full
-    }
-}
-
-impl TryFrom<&str> for TitlecaseMode {
+impl TryFrom<&str> for SwapcaseMode {
     type Error = InvalidCaseMappingMode;
 
     #[inline]
@@ -522,7 +2044,7 @@ impl TryFrom<&str> for TitlecaseMode {
     }
 }
 
-impl TryFrom<Option<&str>> for TitlecaseMode {
+impl TryFrom<Option<&str>> for SwapcaseMode {
     type Error = InvalidCaseMappingMode;
 
     #[inline]
@@ -531,7 +2053,7 @@ impl TryFrom<Option<&str>> for TitlecaseMode {
     }
 }
 
-impl TryFrom<&[u8]> for TitlecaseMode {
+impl TryFrom<&[u8]> for SwapcaseMode {
     type Error = InvalidCaseMappingMode;
 
     #[inline]
@@ -545,7 +2067,7 @@ impl TryFrom<&[u8]> for TitlecaseMode {
     }
 }
 
-impl TryFrom<Option<&[u8]>> for TitlecaseMode {
+impl TryFrom<Option<&[u8]>> for SwapcaseMode {
     type Error = InvalidCaseMappingMode;
 
     #[inline]
@@ -557,7 +2079,7 @@ impl TryFrom<Option<&[u8]>> for TitlecaseMode {
     }
 }
 
-impl FromStr for TitlecaseMode {
+impl FromStr for SwapcaseMode {
     type Err = InvalidCaseMappingMode;
 
     #[inline]
@@ -567,33 +2089,96 @@ impl FromStr for TitlecaseMode {
 }
 
 /// Returns an iterator that yields a copy of the bytes in the given slice with
-/// the leading letter replaced with its titlecase counterpart and all remaining
-/// letters replaced with their lowercase counterparts.
+/// all uppercase letters replaced with their lowercase counterparts and all
+/// lowercase letters replaced with their uppercase counterparts.
 ///
 /// This function treats the given slice as a [conventionally UTF-8 string].
-/// UTF-8 byte sequences are converted to their Unicode titlecase equivalents.
-/// Invalid UTF-8 byte sequences are yielded as is.
+/// UTF-8 byte sequences are converted to their Unicode swapcase equivalents.
+/// Titlecase letters, like `ǈ`, are replaced with their lowercase
+/// counterparts. Characters without case, like digits and punctuation, are
+/// yielded unchanged. Invalid UTF-8 byte sequences are yielded as is.
 ///
-/// The case mapping mode is determined by the given [`TitlecaseMode`]. See its
+/// The case mapping mode is determined by the given [`SwapcaseMode`]. See its
 /// documentation for details on the available case mapping modes.
 ///
+/// This function corresponds to calling [`String#swapcase`] in Ruby.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::SwapcaseMode;
+/// let s = roe::swapcase(b"Hello World 123", SwapcaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"hELLO wORLD 123");
+///
+/// let s = roe::swapcase("Αύριο ΚΑΙ école".as_bytes(), SwapcaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), "αΎΡΙΟ και ÉCOLE".as_bytes());
+///
+/// // Titlecase letters are swapped to lowercase.
+/// let s = roe::swapcase("ǈ".as_bytes(), SwapcaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), "ǉ".as_bytes());
+/// ```
+///
+/// ASCII case mapping leaves non-ASCII characters unchanged:
+///
+/// ```
+/// # use roe::SwapcaseMode;
+/// let s = roe::swapcase("Straße".as_bytes(), SwapcaseMode::Ascii);
+/// assert_eq!(s.collect::<Vec<_>>(), "sTRAßE".as_bytes());
+/// ```
+///
+/// Invalid UTF-8 bytes are yielded as is:
+///
+/// ```
+/// # use roe::SwapcaseMode;
+/// let s = roe::swapcase(b"abc\xFF\xFEXYZ", SwapcaseMode::Full);
+/// assert_eq!(s.collect::<Vec<_>>(), b"ABC\xFF\xFExyz");
+/// ```
+///
 /// # Panics
 ///
-/// Not all [`TitlecaseMode`]s are currently implemented. This function will
-/// panic if the caller supplies [Turkic] titlecasing mode.
+/// Not all [`SwapcaseMode`]s are currently implemented. This function will
+/// panic if the caller supplies [Turkic] or [Lithuanian] swapcasing mode.
 ///
-/// [conventionally UTF-8 string]: https://docs.rs/bstr/0.2.*/bstr/#when-should-i-use-byte-strings
-/// [Turkic]: TitlecaseMode::Turkic
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [`String#swapcase`]: https://ruby-doc.org/core-3.1.2/String.html#method-i-swapcase
+/// [Turkic]: SwapcaseMode::Turkic
+/// [Lithuanian]: SwapcaseMode::Lithuanian
 // TODO: make this const once we're no longer panicking.
-pub fn titlecase(slice: &[u8], options: TitlecaseMode) -> Titlecase<'_> {
+pub fn swapcase(slice: &[u8], options: SwapcaseMode) -> Swapcase<'_> {
     match options {
-        TitlecaseMode::Full | TitlecaseMode::Lithuanian => Titlecase::with_slice(slice),
-        TitlecaseMode::Ascii => Titlecase::with_ascii_slice(slice),
+        SwapcaseMode::Full => Swapcase::with_slice(slice),
+        SwapcaseMode::Ascii => Swapcase::with_ascii_slice(slice),
         // TODO: implement `turkic` mode.
-        TitlecaseMode::Turkic => panic!("titlecase Turkic mode is not yet implemented"),
+        SwapcaseMode::Turkic => panic!("swapcase Turkic mode is not yet implemented"),
+        // TODO: implement `lithuanian` mode.
+        SwapcaseMode::Lithuanian => panic!("swapcase Lithuanian mode is not yet implemented"),
     }
 }
 
+/// Returns an iterator that yields a copy of the bytes in the given slice with
+/// the case of all letters swapped using the [default swapcase mode].
+///
+/// This function is equivalent to calling [`swapcase`] with
+/// [`SwapcaseMode::default()`] and corresponds to calling [`String#swapcase`]
+/// in Ruby with no options.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::SwapcaseMode;
+/// assert_eq!(
+///     roe::swapcase_default("Αύριο".as_bytes()).collect::<Vec<_>>(),
+///     roe::swapcase("Αύριο".as_bytes(), SwapcaseMode::default()).collect::<Vec<_>>(),
+/// );
+/// ```
+///
+/// [default swapcase mode]: SwapcaseMode::default
+/// [`swapcase`]: crate::swapcase()
+/// [`String#swapcase`]: https://ruby-doc.org/core-3.1.2/String.html#method-i-swapcase
+pub fn swapcase_default(slice: &[u8]) -> Swapcase<'_> {
+    swapcase(slice, SwapcaseMode::default())
+}
+
 // Ensure code blocks in README.md compile
 //
 // This module and macro declaration should be kept at the end of the file, in
@@ -613,11 +2198,16 @@ readme!();
 
 #[cfg(test)]
 mod tests {
-    use core::{convert::TryInto, str::FromStr};
+    use core::convert::{TryFrom, TryInto};
+    use core::str::FromStr;
 
     use alloc::format;
+    use alloc::vec::Vec;
 
-    use crate::{InvalidCaseMappingMode, LowercaseMode, TitlecaseMode, UppercaseMode};
+    use crate::{
+        CaseMapping, CaseMappingMode, InvalidCaseMappingMode, LowercaseMode, LowercaseOptions,
+        SwapcaseMode, TitlecaseMode, UnsupportedCaseMappingMode, UppercaseMode,
+    };
 
     #[test]
     fn test_invalid_case_mapping_mode_fmt() {
@@ -625,6 +2215,26 @@ mod tests {
         assert_eq!(format!("{err}"), "invalid option");
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_invalid_case_mapping_mode_with_input_fmt() {
+        let err = InvalidCaseMappingMode::with_input(b"foo");
+        assert_eq!(err.input(), b"foo");
+        assert_eq!(format!("{err}"), r#"invalid option: "foo""#);
+
+        let err = InvalidCaseMappingMode::with_input(b"");
+        assert_eq!(format!("{err}"), r#"invalid option: """#);
+
+        let err = InvalidCaseMappingMode::with_input(b"as\"cii\0\xFF");
+        assert_eq!(format!("{err}"), r#"invalid option: "as\"cii\0\xff""#);
+
+        // The terse error remains zero-sized and keeps Ruby's exact message.
+        assert_eq!(size_of::<InvalidCaseMappingMode>(), 0);
+        let err = InvalidCaseMappingMode::from(err);
+        assert_eq!(err, InvalidCaseMappingMode::new());
+        assert_eq!(format!("{err}"), "invalid option");
+    }
+
     #[test]
     fn test_lowercase_mode_parsing() {
         assert_eq!(LowercaseMode::from_str("ascii"), Ok(LowercaseMode::Ascii));
@@ -640,6 +2250,244 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mode_from_ffi_bytes() {
+        assert_eq!(
+            LowercaseMode::from_ffi_bytes(b"ascii\0"),
+            Ok(LowercaseMode::Ascii)
+        );
+        assert_eq!(
+            LowercaseMode::from_ffi_bytes(b"turkic"),
+            Ok(LowercaseMode::Turkic)
+        );
+        assert_eq!(
+            UppercaseMode::from_ffi_bytes(b"ascii\0"),
+            Ok(UppercaseMode::Ascii)
+        );
+        assert_eq!(
+            UppercaseMode::from_ffi_bytes(b"turkic"),
+            Ok(UppercaseMode::Turkic)
+        );
+        for bytes in [
+            &b"asc\0ii"[..],
+            b"ascii\0\0",
+            b"\0ascii",
+            b"\0",
+            b"",
+            b"full\0",
+        ] {
+            assert_eq!(
+                LowercaseMode::from_ffi_bytes(bytes),
+                Err(InvalidCaseMappingMode::new())
+            );
+            assert_eq!(
+                UppercaseMode::from_ffi_bytes(bytes),
+                Err(InvalidCaseMappingMode::new())
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_iterator_sizes() {
+        use core::mem::size_of;
+
+        use crate::{Lowercase, Swapcase, Titlecase, Uppercase};
+
+        // The case mapping iterators buffer expansions as compact `char` and
+        // byte arrays rather than `core::char::ToLowercase` and `ToUppercase`,
+        // which track their position with `usize` indices. Before they did,
+        // `Lowercase` and `Uppercase` were 168 bytes and `Titlecase` was 136.
+        //
+        // The layout of these enums is not guaranteed, so only bound the sizes
+        // from above.
+        assert!(size_of::<Lowercase<'_>>() <= 88);
+        assert!(size_of::<Uppercase<'_>>() <= 88);
+        assert!(size_of::<Titlecase<'_>>() <= 96);
+        assert!(size_of::<Swapcase<'_>>() <= 72);
+    }
+
+    #[test]
+    fn test_lowercase_bstr_matches_lowercase() {
+        use bstr::{BStr, ByteSlice};
+
+        for s in [
+            &b"ABC, xyz"[..],
+            "ΑΎΡΙΟ ΟΔΟΣ".as_bytes(),
+            b"aB\xF0\x9F\x87Yz",
+        ] {
+            for mode in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Lithuanian,
+                LowercaseMode::Fold,
+            ] {
+                let mapped = crate::lowercase_bstr(BStr::new(s), mode).collect::<Vec<_>>();
+                let expected = crate::lowercase(s, mode).collect::<Vec<_>>();
+                assert_eq!(mapped.as_bstr(), expected.as_bstr());
+            }
+        }
+    }
+
+    #[test]
+    fn test_send_sync() {
+        use crate::{Lowercase, Swapcase, Titlecase, Uppercase};
+
+        // The iterators and modes only borrow byte slices and hold plain
+        // data, so they may be moved to and shared between threads. This
+        // fails to compile if a field which is not thread safe is added.
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<Lowercase<'_>>();
+        assert_send_sync::<Uppercase<'_>>();
+        assert_send_sync::<Titlecase<'_>>();
+        assert_send_sync::<Swapcase<'_>>();
+        assert_send_sync::<LowercaseMode>();
+        assert_send_sync::<UppercaseMode>();
+        assert_send_sync::<TitlecaseMode>();
+        assert_send_sync::<InvalidCaseMappingMode>();
+        assert_send_sync::<UnsupportedCaseMappingMode>();
+    }
+
+    #[test]
+    fn test_mode_from_bytes_ignore_ascii_case() {
+        for (bytes, mode) in [
+            (&b"ASCII"[..], LowercaseMode::Ascii),
+            (b"Turkic", LowercaseMode::Turkic),
+            (b"LiThUaNiAn", LowercaseMode::Lithuanian),
+            (b"Fold", LowercaseMode::Fold),
+            (b"fold", LowercaseMode::Fold),
+        ] {
+            assert_eq!(LowercaseMode::from_bytes_ignore_ascii_case(bytes), Ok(mode));
+            // The strict parser still rejects anything but lowercase names.
+            if bytes.iter().any(u8::is_ascii_uppercase) {
+                assert_eq!(
+                    LowercaseMode::try_from(bytes),
+                    Err(InvalidCaseMappingMode::new())
+                );
+            }
+        }
+        assert_eq!(
+            UppercaseMode::from_bytes_ignore_ascii_case(b"TURKIC"),
+            Ok(UppercaseMode::Turkic)
+        );
+        assert_eq!(
+            UppercaseMode::try_from(&b"TURKIC"[..]),
+            Err(InvalidCaseMappingMode::new())
+        );
+        assert_eq!(
+            TitlecaseMode::from_bytes_ignore_ascii_case(b"Lithuanian"),
+            Ok(TitlecaseMode::Lithuanian)
+        );
+        assert_eq!(
+            TitlecaseMode::try_from(&b"Lithuanian"[..]),
+            Err(InvalidCaseMappingMode::new())
+        );
+        for bytes in [
+            &b""[..],
+            b"FULL",
+            b"ASCII ",
+            b"LITHUANIANS",
+            "ASC\u{130}I".as_bytes(),
+            b"\xFF",
+        ] {
+            assert_eq!(
+                LowercaseMode::from_bytes_ignore_ascii_case(bytes),
+                Err(InvalidCaseMappingMode::new())
+            );
+            assert_eq!(
+                UppercaseMode::from_bytes_ignore_ascii_case(bytes),
+                Err(InvalidCaseMappingMode::new())
+            );
+            assert_eq!(
+                TitlecaseMode::from_bytes_ignore_ascii_case(bytes),
+                Err(InvalidCaseMappingMode::new())
+            );
+        }
+        // Only ASCII letters are case insensitive.
+        assert_eq!(
+            LowercaseMode::from_bytes_ignore_ascii_case("\u{212A}".as_bytes()),
+            Err(InvalidCaseMappingMode::new())
+        );
+    }
+
+    #[test]
+    fn test_lowercase_options() {
+        let options = LowercaseOptions::new();
+        assert_eq!(options, LowercaseOptions::default());
+        assert_eq!(options.build(), Ok(LowercaseMode::Full));
+        assert_eq!(options.ascii(true).build(), Ok(LowercaseMode::Ascii));
+        assert_eq!(
+            options.ascii(true).fold(true).build(),
+            Ok(LowercaseMode::Ascii)
+        );
+        assert_eq!(options.fold(true).build(), Ok(LowercaseMode::Fold));
+        assert_eq!(options.turkic(true).build(), Ok(LowercaseMode::Turkic));
+        assert_eq!(
+            options.fold(true).turkic(true).build(),
+            Ok(LowercaseMode::TurkicFold)
+        );
+        assert_eq!(
+            options.lithuanian(true).build(),
+            Ok(LowercaseMode::Lithuanian)
+        );
+        // Options can be unset.
+        assert_eq!(
+            options.ascii(true).ascii(false).build(),
+            Ok(LowercaseMode::Full)
+        );
+        assert_eq!(
+            LowercaseMode::try_from(options.fold(true)),
+            Ok(LowercaseMode::Fold)
+        );
+
+        for rejected in [
+            options.ascii(true).turkic(true),
+            options.ascii(true).lithuanian(true),
+            options.ascii(true).fold(true).turkic(true),
+            options.fold(true).lithuanian(true),
+            options.turkic(true).lithuanian(true),
+            options.ascii(true).fold(true).turkic(true).lithuanian(true),
+        ] {
+            assert_eq!(rejected.build(), Err(InvalidCaseMappingMode::new()));
+        }
+    }
+
+    #[test]
+    fn test_turkic_fold_diverges_from_fold() {
+        let fold = |s: &[u8]| crate::lowercase(s, LowercaseMode::Fold).collect::<Vec<_>>();
+        let turkic = |s: &[u8]| crate::lowercase(s, LowercaseMode::TurkicFold).collect::<Vec<_>>();
+
+        assert_eq!(fold(b"FILE"), b"file");
+        assert_eq!(turkic(b"FILE"), "fıle".as_bytes());
+        // `FILE` and `file` are only equal ignoring case with common folding.
+        assert_eq!(fold(b"FILE"), fold(b"file"));
+        assert_ne!(turkic(b"FILE"), turkic(b"file"));
+        // Turkish `FİLE` is `file` ignoring case.
+        assert_eq!(turkic("FİLE".as_bytes()), turkic(b"file"));
+        assert_ne!(fold("FİLE".as_bytes()), fold(b"file"));
+        assert_eq!(fold("FİLE".as_bytes()), "fi\u{307}le".as_bytes());
+
+        // All other characters fold the same in both modes.
+        let s = "ẞ ΣΑΣ ﬃ abc\u{212A}".as_bytes();
+        assert_eq!(turkic(s), fold(s));
+        assert_eq!(turkic(b"file"), b"file");
+    }
+
+    #[test]
+    fn test_lowercase_options_ascii_fold_is_ascii_only() {
+        let mode = LowercaseOptions::new()
+            .ascii(true)
+            .fold(true)
+            .build()
+            .unwrap();
+        let s = "ABC ẞ ΣΑΣ".as_bytes();
+        assert!(crate::lowercase(s, mode).eq("abc ẞ ΣΑΣ".bytes()));
+        // ASCII lowercasing folds the ASCII region.
+        let ascii = b"ABC xyz [_]";
+        assert!(crate::lowercase(ascii, mode).eq(crate::lowercase(ascii, LowercaseMode::Fold)));
+    }
+
     #[test]
     fn test_lowercase_mode_conversion() {
         let mut mode: LowercaseMode;
@@ -664,12 +2512,63 @@ mod tests {
             UppercaseMode::from_str("lithuanian"),
             Ok(UppercaseMode::Lithuanian)
         );
+        assert_eq!(UppercaseMode::from_str("fold"), Ok(UppercaseMode::Fold));
         assert_eq!(
             UppercaseMode::from_str("full"),
             Err(InvalidCaseMappingMode::new())
         );
     }
 
+    #[test]
+    fn test_lowercase_and_uppercase_mode_parsing_agree() {
+        let names = [
+            "ascii",
+            "turkic",
+            "lithuanian",
+            "fold",
+            "full",
+            "",
+            "FOLD",
+            "invalid",
+        ];
+        for name in names {
+            let lowercase = LowercaseMode::try_from(name).map(CaseMapping::from);
+            let uppercase = UppercaseMode::try_from(name).map(CaseMapping::from);
+            assert_eq!(lowercase.is_ok(), uppercase.is_ok(), "{name}");
+            assert_eq!(
+                LowercaseMode::try_from(Some(name.as_bytes())).is_ok(),
+                UppercaseMode::try_from(Some(name.as_bytes())).is_ok(),
+                "{name}"
+            );
+        }
+        assert_eq!(
+            LowercaseMode::try_from(None::<&str>).map(CaseMapping::from),
+            Ok(CaseMapping::Lowercase(LowercaseMode::Full))
+        );
+        assert_eq!(
+            UppercaseMode::try_from(None::<&str>).map(CaseMapping::from),
+            Ok(CaseMapping::Uppercase(UppercaseMode::Full))
+        );
+    }
+
+    #[test]
+    fn test_uppercase_fold_mode_folds() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"ABC, xyz",
+            "Straße ẞ".as_bytes(),
+            "ΣΊΣΥΦΟΣ ﬃ \u{212A}".as_bytes(),
+            b"ABC\xFF\xFExyz",
+        ];
+        for input in inputs {
+            let upper = crate::uppercase(input, UppercaseMode::Fold).collect::<Vec<_>>();
+            let fold = crate::lowercase(input, LowercaseMode::Fold).collect::<Vec<_>>();
+            assert_eq!(upper, fold);
+        }
+        assert!(crate::uppercase("Straße".as_bytes(), UppercaseMode::Fold)
+            .eq(b"strasse".iter().copied()));
+    }
+
     #[test]
     fn test_uppercase_mode_conversion() {
         let mut mode: UppercaseMode;
@@ -686,6 +2585,66 @@ mod tests {
         assert_eq!(mode, UppercaseMode::Turkic);
     }
 
+    #[test]
+    fn test_case_mapping_mode_parsing() {
+        assert_eq!(
+            CaseMappingMode::try_from(None::<&str>),
+            Ok(CaseMappingMode::Full)
+        );
+        assert_eq!(
+            CaseMappingMode::try_from("ascii"),
+            Ok(CaseMappingMode::Ascii)
+        );
+        assert_eq!(
+            CaseMappingMode::from_str("turkic"),
+            Ok(CaseMappingMode::Turkic)
+        );
+        assert_eq!(
+            CaseMappingMode::try_from(Some(&b"lithuanian"[..])),
+            Ok(CaseMappingMode::Lithuanian)
+        );
+        assert_eq!(
+            CaseMappingMode::try_from(&b"fold"[..]),
+            Ok(CaseMappingMode::Fold)
+        );
+        for invalid in ["", "full", "FOLD", "turkic_fold", "shouty"] {
+            assert_eq!(
+                CaseMappingMode::try_from(invalid),
+                Err(InvalidCaseMappingMode::new())
+            );
+        }
+    }
+
+    #[test]
+    fn test_case_mapping_mode_resolution() {
+        let names = [
+            None,
+            Some("ascii"),
+            Some("turkic"),
+            Some("lithuanian"),
+            Some("fold"),
+        ];
+        for name in names {
+            let mode = CaseMappingMode::try_from(name).unwrap();
+            // Resolving a mode agrees with parsing the operation specific mode.
+            assert_eq!(mode.resolve_lowercase(), LowercaseMode::try_from(name));
+            assert_eq!(mode.resolve_uppercase(), UppercaseMode::try_from(name));
+            assert_eq!(mode.resolve_titlecase(), TitlecaseMode::try_from(name));
+        }
+        assert_eq!(
+            CaseMappingMode::Fold.resolve_uppercase(),
+            Ok(UppercaseMode::Fold)
+        );
+        assert_eq!(
+            CaseMappingMode::Fold.resolve_titlecase(),
+            Err(InvalidCaseMappingMode::new())
+        );
+        assert_eq!(
+            CaseMappingMode::default().resolve_lowercase(),
+            Ok(LowercaseMode::default())
+        );
+    }
+
     #[test]
     fn test_titlecase_mode_parsing() {
         assert_eq!(TitlecaseMode::from_str("ascii"), Ok(TitlecaseMode::Ascii));
@@ -700,6 +2659,132 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_default_mode_functions() {
+        let s = "Αύριο, ABC xyz ß".as_bytes();
+        assert!(crate::lowercase_default(s).eq(crate::lowercase(s, LowercaseMode::Full)));
+        assert!(crate::uppercase_default(s).eq(crate::uppercase(s, UppercaseMode::Full)));
+        assert!(crate::titlecase_default(s).eq(crate::titlecase(s, TitlecaseMode::Full)));
+
+        // `nil` options in Ruby parse to the default mode.
+        let mode: LowercaseMode = None::<&[u8]>.try_into().unwrap();
+        assert_eq!(mode, LowercaseMode::default());
+        let mode: UppercaseMode = None::<&[u8]>.try_into().unwrap();
+        assert_eq!(mode, UppercaseMode::default());
+        let mode: TitlecaseMode = None::<&[u8]>.try_into().unwrap();
+        assert_eq!(mode, TitlecaseMode::default());
+    }
+
+    #[test]
+    fn test_capitalize_is_titlecase() {
+        let s = "hELLO wORLD, αΎΡΙΟ ß ﬃ".as_bytes();
+        for mode in [
+            TitlecaseMode::Full,
+            TitlecaseMode::Ascii,
+            TitlecaseMode::Lithuanian,
+        ] {
+            assert!(crate::capitalize(s, mode).eq(crate::titlecase(s, mode)));
+        }
+    }
+
+    #[test]
+    fn test_uppercase_turkic_mode() {
+        let s = "istanbul, diyarbakır".as_bytes();
+        assert!(crate::uppercase(s, UppercaseMode::Turkic).eq("İSTANBUL, DİYARBAKIR".bytes()));
+        let s = "Αύριο, ABC xyz ß".as_bytes();
+        assert!(
+            crate::uppercase(s, UppercaseMode::Turkic).eq(crate::uppercase(s, UppercaseMode::Full))
+        );
+    }
+
+    #[test]
+    fn test_uppercase_capital_sharp_s_mode() {
+        let s = "straße, ß".as_bytes();
+        // The default remains `SS` for Unicode and Ruby compatibility.
+        assert!(crate::uppercase(s, UppercaseMode::default()).eq("STRASSE, SS".bytes()));
+        assert!(crate::uppercase(s, UppercaseMode::Full).eq("STRASSE, SS".bytes()));
+        assert!(crate::uppercase(s, UppercaseMode::CapitalSharpS).eq("STRAẞE, ẞ".bytes()));
+        let s = "Αύριο, ABC xyz istanbul".as_bytes();
+        assert!(crate::uppercase(s, UppercaseMode::CapitalSharpS)
+            .eq(crate::uppercase(s, UppercaseMode::Full)));
+        // There is no Ruby option for the capital sharp s.
+        assert!(UppercaseMode::try_from("capital_sharp_s").is_err());
+    }
+
+    #[test]
+    fn test_is_lowercase() {
+        for mode in [
+            LowercaseMode::Full,
+            LowercaseMode::Ascii,
+            LowercaseMode::Lithuanian,
+            LowercaseMode::Fold,
+        ] {
+            assert!(crate::is_lowercase(b"", mode));
+            assert!(crate::is_lowercase(b"abc, 123 xyz", mode));
+            assert!(!crate::is_lowercase(b"abc, 123 xYz", mode));
+            assert!(!crate::is_lowercase(b"Abc", mode));
+            assert!(crate::is_lowercase(b"abc\xFF\xFE\xF0\x9F\x87xyz", mode));
+            assert!(!crate::is_lowercase(b"abc\xFF\xFEXYZ", mode));
+            assert_eq!(
+                crate::is_lowercase("ΑΎΡΙΟ".as_bytes(), mode),
+                mode == LowercaseMode::Ascii
+            );
+        }
+        let s = "αύριο ǆungla ß".as_bytes();
+        assert!(crate::is_lowercase(s, LowercaseMode::Full));
+        assert!(crate::is_lowercase(s, LowercaseMode::Lithuanian));
+        assert!(!crate::is_lowercase(s, LowercaseMode::Fold));
+        assert!(!crate::is_lowercase(
+            "αύριο ǅungla".as_bytes(),
+            LowercaseMode::Full
+        ));
+        // Final sigma is lowercase, but it case folds to `σ`.
+        assert!(crate::is_lowercase("οδος".as_bytes(), LowercaseMode::Full));
+        assert!(!crate::is_lowercase("οδος".as_bytes(), LowercaseMode::Fold));
+        assert!(crate::is_lowercase("οδοσ".as_bytes(), LowercaseMode::Fold));
+    }
+
+    #[test]
+    fn test_is_uppercase() {
+        for mode in [
+            UppercaseMode::Full,
+            UppercaseMode::Ascii,
+            UppercaseMode::Turkic,
+            UppercaseMode::Lithuanian,
+            UppercaseMode::CapitalSharpS,
+        ] {
+            assert!(crate::is_uppercase(b"", mode));
+            assert!(crate::is_uppercase(b"ABC, 123 XYZ", mode));
+            assert!(!crate::is_uppercase(b"ABC, 123 XyZ", mode));
+            assert!(crate::is_uppercase(b"ABC\xFF\xFE\xF0\x9F\x87XYZ", mode));
+            assert!(!crate::is_uppercase(b"ABC\xFF\xFExyz", mode));
+            assert_eq!(
+                crate::is_uppercase("αύριο".as_bytes(), mode),
+                mode == UppercaseMode::Ascii
+            );
+        }
+        let s = "ΑΎΡΙΟ ǄUNGLA İ".as_bytes();
+        assert!(crate::is_uppercase(s, UppercaseMode::Full));
+        assert!(crate::is_uppercase(s, UppercaseMode::Turkic));
+        assert!(!crate::is_uppercase(
+            "ΑΎΡΙΟ ǅUNGLA".as_bytes(),
+            UppercaseMode::Full
+        ));
+        assert!(!crate::is_uppercase(
+            "STRAẞE ß".as_bytes(),
+            UppercaseMode::Full
+        ));
+        // Case folding lowercases.
+        assert!(crate::is_uppercase(b"abc", UppercaseMode::Fold));
+        assert!(!crate::is_uppercase(b"ABC", UppercaseMode::Fold));
+    }
+
+    #[test]
+    #[should_panic(expected = "lowercase Turkic mode is not yet implemented")]
+    fn test_is_lowercase_turkic_mode_panics() {
+        let _ = crate::is_lowercase(b"istanbul", LowercaseMode::Turkic);
+    }
+
     #[test]
     fn test_titlecase_mode_conversion() {
         let mut mode: TitlecaseMode;
@@ -715,4 +2800,212 @@ mod tests {
         mode = Some(&b"turkic"[..]).try_into().unwrap();
         assert_eq!(mode, TitlecaseMode::Turkic);
     }
+
+    #[test]
+    fn test_swapcase_mode_parsing() {
+        assert_eq!(SwapcaseMode::from_str("ascii"), Ok(SwapcaseMode::Ascii));
+        assert_eq!(SwapcaseMode::from_str("turkic"), Ok(SwapcaseMode::Turkic));
+        assert_eq!(
+            SwapcaseMode::from_str("lithuanian"),
+            Ok(SwapcaseMode::Lithuanian)
+        );
+        assert_eq!(
+            SwapcaseMode::from_str("full"),
+            Err(InvalidCaseMappingMode::new())
+        );
+
+        let mode: SwapcaseMode = None::<&[u8]>.try_into().unwrap();
+        assert_eq!(mode, SwapcaseMode::default());
+        let mode: SwapcaseMode = Some("ascii").try_into().unwrap();
+        assert_eq!(mode, SwapcaseMode::Ascii);
+    }
+
+    #[test]
+    fn test_swapcase() {
+        let s = "Hello, Ωmega ǈ 123!".as_bytes();
+        assert!(crate::swapcase(s, SwapcaseMode::Full).eq("hELLO, ωMEGA ǉ 123!".bytes()));
+        assert!(crate::swapcase(s, SwapcaseMode::Ascii).eq("hELLO, ΩMEGA ǈ 123!".bytes()));
+        assert!(crate::swapcase_default(s).eq(crate::swapcase(s, SwapcaseMode::Full)));
+    }
+
+    #[test]
+    #[should_panic(expected = "swapcase Turkic mode is not yet implemented")]
+    fn test_swapcase_turkic_mode_panics() {
+        let _ = crate::swapcase(b"istanbul", SwapcaseMode::Turkic);
+    }
+
+    #[test]
+    fn test_try_lowercase() {
+        let s = "Αύριο, ABC xyz ß".as_bytes();
+        for mode in [
+            LowercaseMode::Full,
+            LowercaseMode::Ascii,
+            LowercaseMode::Lithuanian,
+            LowercaseMode::Fold,
+        ] {
+            let iter = crate::try_lowercase(s, mode).unwrap();
+            assert!(iter.eq(crate::lowercase(s, mode)));
+        }
+
+        let err = crate::try_lowercase(s, LowercaseMode::Turkic).unwrap_err();
+        assert_eq!(
+            err,
+            UnsupportedCaseMappingMode::new(CaseMapping::Lowercase(LowercaseMode::Turkic))
+        );
+        assert_eq!(err.mapping(), LowercaseMode::Turkic.into());
+    }
+
+    #[test]
+    fn test_empty_input_is_empty_iterator() {
+        for mode in [
+            LowercaseMode::Full,
+            LowercaseMode::Ascii,
+            LowercaseMode::Lithuanian,
+            LowercaseMode::Fold,
+            LowercaseMode::TurkicFold,
+        ] {
+            let iter = crate::lowercase(b"", mode);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+            assert_eq!(iter.exact_len(), Some(0));
+            assert_eq!(iter.mode(), None);
+            assert_eq!(iter.count(), 0);
+        }
+        for mode in [
+            UppercaseMode::Full,
+            UppercaseMode::Ascii,
+            UppercaseMode::Turkic,
+            UppercaseMode::Lithuanian,
+            UppercaseMode::Fold,
+            UppercaseMode::CapitalSharpS,
+        ] {
+            let iter = crate::uppercase(b"", mode);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+            assert_eq!(iter.mode(), None);
+            assert_eq!(iter.count(), 0);
+        }
+        for mode in [
+            TitlecaseMode::Full,
+            TitlecaseMode::Ascii,
+            TitlecaseMode::Lithuanian,
+        ] {
+            let iter = crate::titlecase(b"", mode);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+            assert_eq!(iter.mode(), None);
+            assert_eq!(iter.count(), 0);
+        }
+
+        // Unsupported modes are rejected even for empty input.
+        assert!(crate::try_lowercase(b"", LowercaseMode::Turkic).is_err());
+        assert!(crate::try_titlecase(b"", TitlecaseMode::Turkic).is_err());
+    }
+
+    #[test]
+    fn test_ascii_mode_would_miss() {
+        assert!(crate::ascii_mode_would_miss("CAFÉ".as_bytes()));
+        assert!(!crate::ascii_mode_would_miss(b"CAFE"));
+        assert!(!crate::ascii_mode_would_miss(b""));
+
+        // Only characters that full mode lowercases are reported.
+        assert!(crate::ascii_mode_would_miss("ΑΎΡΙΟ".as_bytes()));
+        assert!(crate::ascii_mode_would_miss("ǅ".as_bytes()));
+        assert!(crate::ascii_mode_would_miss("İ".as_bytes()));
+        assert!(!crate::ascii_mode_would_miss("café αύριο ß".as_bytes()));
+        assert!(!crate::ascii_mode_would_miss("ª 中 😀".as_bytes()));
+
+        // Invalid UTF-8 is skipped, including truncated sequences of cased
+        // characters.
+        assert!(!crate::ascii_mode_would_miss(b"ABC\xFF\xFE\xC3"));
+        assert!(crate::ascii_mode_would_miss(b"\xFF\xC3\x89"));
+
+        // The result agrees with comparing the two modes' output.
+        let inputs: [&[u8]; 6] = [
+            "CAFÉ".as_bytes(),
+            b"CAFE",
+            "café".as_bytes(),
+            "ΟΔΟΣ".as_bytes(),
+            b"abc\xE2\x82",
+            "Ⱥ\u{FFFD}".as_bytes(),
+        ];
+        for input in inputs {
+            let ascii = crate::lowercase(input, LowercaseMode::Ascii);
+            let full = crate::lowercase(input, LowercaseMode::Full);
+            assert_eq!(crate::ascii_mode_would_miss(input), !ascii.eq(full));
+        }
+    }
+
+    #[test]
+    fn test_const_lowercase_constructors() {
+        const FULL: crate::Lowercase<'static> = crate::lowercase_full("ΑΎΡΙΟ ABC".as_bytes());
+        const ASCII: crate::Lowercase<'static> = crate::lowercase_ascii("ΑΎΡΙΟ ABC".as_bytes());
+        const TRY_FULL: Result<crate::Lowercase<'static>, UnsupportedCaseMappingMode> =
+            crate::try_lowercase(b"ABC", LowercaseMode::Full);
+
+        let s = "ΑΎΡΙΟ ABC".as_bytes();
+        assert!(FULL.eq(crate::lowercase(s, LowercaseMode::Full)));
+        assert!(ASCII.eq(crate::lowercase(s, LowercaseMode::Ascii)));
+        assert_eq!(FULL.mode(), Some(LowercaseMode::Full));
+        assert_eq!(ASCII.mode(), Some(LowercaseMode::Ascii));
+        assert_eq!(TRY_FULL.unwrap().collect::<Vec<_>>(), b"abc");
+    }
+
+    #[test]
+    fn test_try_uppercase() {
+        let s = "Αύριο, ABC xyz ß istanbul".as_bytes();
+        for mode in [
+            UppercaseMode::Full,
+            UppercaseMode::Ascii,
+            UppercaseMode::Turkic,
+            UppercaseMode::Lithuanian,
+            UppercaseMode::CapitalSharpS,
+        ] {
+            let iter = crate::try_uppercase(s, mode).unwrap();
+            assert!(iter.eq(crate::uppercase(s, mode)));
+        }
+    }
+
+    #[test]
+    fn test_try_titlecase() {
+        let s = "αύριο, ABC xyz ǆ".as_bytes();
+        for mode in [
+            TitlecaseMode::Full,
+            TitlecaseMode::Ascii,
+            TitlecaseMode::Lithuanian,
+        ] {
+            let iter = crate::try_titlecase(s, mode).unwrap();
+            assert!(iter.eq(crate::titlecase(s, mode)));
+        }
+
+        let err = crate::try_titlecase(s, TitlecaseMode::Turkic).unwrap_err();
+        assert_eq!(
+            err,
+            UnsupportedCaseMappingMode::new(CaseMapping::Titlecase(TitlecaseMode::Turkic))
+        );
+        assert_eq!(err.mapping(), TitlecaseMode::Turkic.into());
+    }
+
+    #[test]
+    #[should_panic(expected = "titlecase Turkic mode is not yet implemented")]
+    fn test_titlecase_turkic_mode_panics() {
+        let _ = crate::titlecase(b"istanbul", TitlecaseMode::Turkic);
+    }
+
+    #[test]
+    fn test_unsupported_case_mapping_mode_fmt() {
+        let err = UnsupportedCaseMappingMode::new(LowercaseMode::Turkic.into());
+        assert_eq!(
+            format!("{err}"),
+            "lowercase Turkic mode is not yet implemented"
+        );
+        let err = UnsupportedCaseMappingMode::new(TitlecaseMode::Turkic.into());
+        assert_eq!(
+            format!("{err}"),
+            "titlecase Turkic mode is not yet implemented"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lowercase Turkic mode is not yet implemented")]
+    fn test_lowercase_turkic_mode_panics() {
+        let _ = crate::lowercase(b"ISTANBUL", LowercaseMode::Turkic);
+    }
 }
@@ -6,10 +6,11 @@ use bstr::ByteSlice;
 #[derive(Clone)]
 #[must_use = "Lowercase is a Iterator and must be used"]
 pub struct Lowercase<'a> {
+    source: &'a [u8],
     slice: &'a [u8],
 }
 
-impl<'a> fmt::Debug for Lowercase<'a> {
+impl fmt::Debug for Lowercase<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Lowercase")
             .field("slice", &self.slice.as_bstr())
@@ -25,11 +26,30 @@ impl<'a> From<&'a [u8]> for Lowercase<'a> {
 
 impl<'a> Lowercase<'a> {
     pub const fn with_slice(slice: &'a [u8]) -> Self {
-        Self { slice }
+        Self {
+            source: slice,
+            slice,
+        }
+    }
+
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    pub fn reset(&mut self) {
+        self.slice = self.source;
+    }
+
+    /// Skip up to `n` bytes without mapping them and return the number of
+    /// bytes skipped.
+    pub(crate) fn advance(&mut self, n: usize) -> usize {
+        let n = n.min(self.slice.len());
+        self.slice = &self.slice[n..];
+        n
     }
 }
 
-impl<'a> Iterator for Lowercase<'a> {
+impl Iterator for Lowercase<'_> {
     type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -46,9 +66,15 @@ impl<'a> Iterator for Lowercase<'a> {
     fn count(self) -> usize {
         self.slice.len()
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // Skip the first `n` bytes without mapping them.
+        self.advance(n);
+        self.next()
+    }
 }
 
-impl<'a> DoubleEndedIterator for Lowercase<'a> {
+impl DoubleEndedIterator for Lowercase<'_> {
     fn next_back(&mut self) -> Option<Self::Item> {
         let (&byte, remainder) = self.slice.split_last()?;
         self.slice = remainder;
@@ -56,9 +82,9 @@ impl<'a> DoubleEndedIterator for Lowercase<'a> {
     }
 }
 
-impl<'a> ExactSizeIterator for Lowercase<'a> {}
+impl ExactSizeIterator for Lowercase<'_> {}
 
-impl<'a> FusedIterator for Lowercase<'a> {}
+impl FusedIterator for Lowercase<'_> {}
 
 #[cfg(test)]
 mod tests {
@@ -236,6 +262,43 @@ mod tests {
         assert_eq!(Lowercase::with_slice(&utf8_with_invalid_bytes).count(), 10);
     }
 
+    #[test]
+    fn nth() {
+        let mut iter = Lowercase::with_slice(b"ABC, XYZ");
+        assert_eq!(iter.nth(1), Some(b'b'));
+        assert_eq!(iter.nth(1), Some(b','));
+        assert_eq!(iter.nth(2), Some(b'y'));
+        assert_eq!(iter.as_slice().as_bstr(), b"Z".as_bstr());
+        assert_eq!(iter.nth(2), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(Lowercase::with_slice(b"\xFF\xFEa").nth(1), Some(0xFE));
+    }
+
+    #[test]
+    fn advance() {
+        let mut iter = Lowercase::with_slice(b"ABC, XYZ");
+        assert_eq!(iter.advance(5), 5);
+        assert_eq!(iter.as_slice().as_bstr(), b"XYZ".as_bstr());
+        assert_eq!(iter.advance(0), 0);
+        assert_eq!(iter.advance(5), 3);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn nth_matches_repeated_next() {
+        let input = b"ABC, XYZ\xFF\xFE";
+        for n in 0..=input.len() + 1 {
+            let mut nth = Lowercase::with_slice(input);
+            let mut next = Lowercase::with_slice(input);
+            for _ in 0..n {
+                next.next();
+            }
+            assert_eq!(nth.nth(n), next.next());
+            assert_eq!(nth.size_hint(), next.size_hint());
+            assert!(nth.eq(next));
+        }
+    }
+
     #[test]
     fn size_hint_covers_count() {
         let iter = Lowercase::with_slice(b"");
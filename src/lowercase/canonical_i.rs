@@ -0,0 +1,185 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use super::Lowercase;
+
+const LATIN_CAPITAL_LETTER_I_WITH_DOT_ABOVE: char = '\u{130}';
+const LATIN_SMALL_LETTER_DOTLESS_I: char = '\u{131}';
+
+/// An iterator that yields the lowercase equivalent of a conventionally UTF-8
+/// byte string with the dotted and dotless I family collapsed to ASCII `i`.
+///
+/// This iterator yields [bytes].
+///
+/// This struct is created by the [`lowercase_canonical_i`] function. See its
+/// documentation for more.
+///
+/// [bytes]: u8
+/// [`lowercase_canonical_i`]: crate::lowercase_canonical_i()
+#[derive(Clone)]
+#[must_use = "LowercaseCanonicalI is a Iterator and must be used"]
+pub struct LowercaseCanonicalI<'a> {
+    slice: &'a [u8],
+    run: Lowercase<'a>,
+    next_i: bool,
+}
+
+impl fmt::Debug for LowercaseCanonicalI<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LowercaseCanonicalI")
+            .field("slice", &self.slice.as_bstr())
+            .field("run", &self.run)
+            .field("next_i", &self.next_i)
+            .finish()
+    }
+}
+
+impl<'a> LowercaseCanonicalI<'a> {
+    pub(crate) fn with_slice(slice: &'a [u8]) -> Self {
+        Self {
+            slice,
+            run: Lowercase::new(),
+            next_i: false,
+        }
+    }
+
+    /// Split the remaining slice at the next non-ASCII member of the I family.
+    ///
+    /// `I` lowercases to `i` under full Unicode case mapping, so only `İ` and
+    /// `ı` need special handling.
+    fn next_run(&mut self) {
+        let mut run_len = 0;
+        let mut i_len = 0;
+        let mut remaining = self.slice;
+        loop {
+            match bstr::decode_utf8(remaining) {
+                (_, 0) => break,
+                (
+                    Some(LATIN_CAPITAL_LETTER_I_WITH_DOT_ABOVE | LATIN_SMALL_LETTER_DOTLESS_I),
+                    size,
+                ) => {
+                    i_len = size;
+                    break;
+                }
+                (_, size) => {
+                    run_len += size;
+                    remaining = &remaining[size..];
+                }
+            }
+        }
+        let (run, remainder) = self.slice.split_at(run_len);
+        self.run = Lowercase::with_slice(run);
+        self.next_i = i_len > 0;
+        self.slice = &remainder[i_len..];
+    }
+}
+
+impl Iterator for LowercaseCanonicalI<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(byte) = self.run.next() {
+                return Some(byte);
+            }
+            if self.next_i {
+                self.next_i = false;
+                return Some(b'i');
+            }
+            if self.slice.is_empty() {
+                return None;
+            }
+            self.next_run();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (run_min, run_max) = self.run.size_hint();
+        let (_, remaining_max) = Lowercase::with_slice(self.slice).size_hint();
+        let next_i = usize::from(self.next_i);
+        let min = run_min.saturating_add(next_i);
+        let max = run_max
+            .zip(remaining_max)
+            .and_then(|(run_max, remaining_max)| run_max.checked_add(remaining_max))
+            .and_then(|max| max.checked_add(next_i));
+        (min, max)
+    }
+}
+
+impl FusedIterator for LowercaseCanonicalI<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::LowercaseCanonicalI;
+
+    #[test]
+    fn empty() {
+        let iter = LowercaseCanonicalI::with_slice(b"");
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+    }
+
+    #[test]
+    fn i_family_collapses_to_ascii_i() {
+        for s in ["I", "i", "İ", "ı"] {
+            let iter = LowercaseCanonicalI::with_slice(s.as_bytes());
+            assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"i".as_bstr());
+        }
+    }
+
+    #[test]
+    fn cross_locale_identifiers_match() {
+        let expected = b"istanbul".as_bstr();
+        for s in ["ISTANBUL", "İSTANBUL", "istanbul", "ıstanbul", "İstanbul"] {
+            let iter = LowercaseCanonicalI::with_slice(s.as_bytes());
+            assert_eq!(iter.collect::<Vec<_>>().as_bstr(), expected);
+        }
+
+        let iter = LowercaseCanonicalI::with_slice("DİYARBAKIR".as_bytes());
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"diyarbakir".as_bstr());
+    }
+
+    #[test]
+    fn other_characters_use_full_lowercase() {
+        let iter = LowercaseCanonicalI::with_slice("ΑΎΡΙΟ Straße".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "αύριο straße".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn combining_dot_above_is_preserved() {
+        // Only the precomposed code points are collapsed. A decomposed
+        // `I` + U+0307 COMBINING DOT ABOVE keeps its combining mark.
+        let iter = LowercaseCanonicalI::with_slice("I\u{307}".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "i\u{307}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let iter = LowercaseCanonicalI::with_slice(b"\xFFI\xC4\xB0\xC4\xFE");
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"\xFFii\xC4\xFE".as_bstr()
+        );
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        for s in ["", "ISTANBUL", "İSTANBUL", "ıİıİ", "ΑΎΡΙΟ İ"] {
+            let iter = LowercaseCanonicalI::with_slice(s.as_bytes());
+            let (min, max) = iter.size_hint();
+            let count = iter.count();
+            assert!(min <= count);
+            assert!(count <= max.unwrap());
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use core::char::REPLACEMENT_CHARACTER;
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use crate::unicode::fold::{to_casefold, to_turkic_casefold};
+use crate::unicode::lithuanian::to_lithuanian_lowercase;
+use crate::unicode::sigma::{lowercase_sigma, GREEK_CAPITAL_LETTER_SIGMA};
+use crate::unicode::std_case_mapping_iter::CaseMappingIter;
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, LowercaseMode, UnsupportedCaseMappingMode};
+
+/// Returns an iterator that yields the [`char`]s of the given slice with all
+/// uppercase letters replaced with their lowercase counterparts.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string] and
+/// maps the same as [`lowercase`], but yields `char`s instead of bytes. Case
+/// mappings are yielded directly without being encoded as UTF-8. Each invalid
+/// UTF-8 byte sequence is replaced with a single U+FFFD REPLACEMENT CHARACTER,
+/// following the "substitution of maximal subparts" strategy.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// let lowercase = roe::lowercase_chars("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full);
+/// assert_eq!(lowercase.collect::<String>(), "αύριο");
+///
+/// let lowercase = roe::lowercase_chars(b"ABC\xF0\x9F\x87XYZ", LowercaseMode::Full);
+/// assert_eq!(lowercase.collect::<String>(), "abc\u{FFFD}xyz");
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/0.2.*/bstr/#when-should-i-use-byte-strings
+/// [`lowercase`]: crate::lowercase()
+pub fn lowercase_chars(slice: &[u8], options: LowercaseMode) -> LowercaseChars<'_> {
+    if options == LowercaseMode::Turkic {
+        let err = UnsupportedCaseMappingMode::new(CaseMapping::Lowercase(options));
+        panic!("{}", err);
+    }
+    LowercaseChars {
+        source: slice,
+        offset: 0,
+        mode: options,
+        lowercase: CaseMappingIter::Zero,
+    }
+}
+
+/// An iterator that yields the lowercase equivalent of a conventionally UTF-8
+/// byte string as [`char`]s.
+///
+/// This struct is created by the [`lowercase_chars`] function. See its
+/// documentation for more.
+///
+/// [`lowercase_chars`]: crate::lowercase_chars()
+#[derive(Clone)]
+#[must_use = "LowercaseChars is a Iterator and must be used"]
+pub struct LowercaseChars<'a> {
+    source: &'a [u8],
+    offset: usize,
+    mode: LowercaseMode,
+    lowercase: CaseMappingIter,
+}
+
+impl fmt::Debug for LowercaseChars<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LowercaseChars")
+            .field("source", &self.source.as_bstr())
+            .field("offset", &self.offset)
+            .field("mode", &self.mode)
+            .field("lowercase", &self.lowercase)
+            .finish()
+    }
+}
+
+impl<'a> LowercaseChars<'a> {
+    /// Views the not yet case mapped portion of the source slice.
+    ///
+    /// Like [`Lowercase::as_slice`], the returned slice may begin in the middle
+    /// of an expansion.
+    ///
+    /// [`Lowercase::as_slice`]: crate::Lowercase::as_slice
+    #[must_use]
+    pub fn as_slice(&self) -> &'a [u8] {
+        &self.source[self.offset..]
+    }
+}
+
+impl Iterator for LowercaseChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(ch) = self.lowercase.next() {
+            return Some(ch);
+        }
+        let offset = self.offset;
+        let (ch, size) = match bstr::decode_utf8(&self.source[offset..]) {
+            (_, 0) => return None,
+            (Some(ch), size) => (ch, size),
+            (None, size) => {
+                self.offset += size;
+                return Some(REPLACEMENT_CHARACTER);
+            }
+        };
+        self.offset += size;
+        let (preceding, following) = (&self.source[..offset], &self.source[offset + size..]);
+        let chars = lowercase_char(preceding, following, ch, self.mode);
+        self.lowercase = CaseMappingIter::new(chars);
+        self.lowercase.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every char and invalid UTF-8 byte sequence in the remaining slice
+        // yields at least one char. Every yielded char corresponds to at least
+        // one byte yielded by `lowercase`, whose output is bounded by the
+        // maximum expansion ratio.
+        let remaining = self.as_slice();
+        let (pending, _) = self.lowercase.size_hint();
+        let (num, den) = max_expansion_ratio(CaseMapping::Lowercase(self.mode));
+        let max = remaining.len().checked_mul(num).map(|max| max / den);
+        (
+            lead_byte_count(remaining) + pending,
+            max.and_then(|max| max.checked_add(pending)),
+        )
+    }
+}
+
+impl FusedIterator for LowercaseChars<'_> {}
+
+/// Map the char `ch`, which is surrounded by the bytes `preceding` and
+/// `following`, to lowercase with the given mode.
+///
+/// `preceding` and `following` are used as context for the final sigma and
+/// Lithuanian rules.
+pub(crate) fn lowercase_char(
+    preceding: &[u8],
+    following: &[u8],
+    ch: char,
+    mode: LowercaseMode,
+) -> [char; 3] {
+    match mode {
+        LowercaseMode::Ascii => [ch.to_ascii_lowercase(), '\0', '\0'],
+        LowercaseMode::Full | LowercaseMode::Lithuanian if ch == GREEK_CAPITAL_LETTER_SIGMA => {
+            [lowercase_sigma(preceding, following), '\0', '\0']
+        }
+        LowercaseMode::Lithuanian => to_lithuanian_lowercase(ch, following),
+        LowercaseMode::Fold => to_casefold(ch),
+        LowercaseMode::TurkicFold => to_turkic_casefold(ch),
+        LowercaseMode::Full | LowercaseMode::Turkic => {
+            let mut chars = ['\0'; 3];
+            for (slot, ch) in chars.iter_mut().zip(ch.to_lowercase()) {
+                *slot = ch;
+            }
+            chars
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::lowercase_chars;
+    use crate::LowercaseMode;
+
+    const INPUTS: [&[u8]; 9] = [
+        b"",
+        b"ABC, xyz",
+        "ΑΎΡΙΟ Αύριο".as_bytes(),
+        "ΟΔΟΣ ΣΑΣ Σ".as_bytes(),
+        "İ ẞ ΐ ﬃ \u{212A}".as_bytes(),
+        "ǄUNGLA ǅ".as_bytes(),
+        "I\u{300} Ì Į\u{301}".as_bytes(),
+        b"\xFF\xFEABC",
+        b"aB\xF0\x9F\x87Yz\xFF\xFE\xCE",
+    ];
+
+    #[test]
+    fn matches_lowercase_decoded_lossily() {
+        for input in INPUTS {
+            for mode in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Lithuanian,
+                LowercaseMode::Fold,
+            ] {
+                let chars = lowercase_chars(input, mode).collect::<String>();
+                let bytes = crate::lowercase(input, mode).collect::<Vec<_>>();
+                assert_eq!(chars, String::from_utf8_lossy(&bytes));
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_utf8_maximal_subparts() {
+        let lowercase = lowercase_chars(b"A\xF0\x9F\x87Z\xFF\xFE", LowercaseMode::Full);
+        assert_eq!(lowercase.collect::<String>(), "a\u{FFFD}z\u{FFFD}\u{FFFD}");
+    }
+
+    #[test]
+    fn as_slice() {
+        let mut lowercase = lowercase_chars("ßİX".as_bytes(), LowercaseMode::Full);
+        assert_eq!(lowercase.next(), Some('ß'));
+        assert_eq!(lowercase.as_slice(), "İX".as_bytes());
+        assert_eq!(lowercase.next(), Some('i'));
+        // The combining dot of the expansion has not been yielded yet.
+        assert_eq!(lowercase.as_slice(), b"X");
+        assert_eq!(lowercase.next(), Some('\u{307}'));
+        assert_eq!(lowercase.next(), Some('x'));
+        assert_eq!(lowercase.as_slice(), b"");
+        assert_eq!(lowercase.next(), None);
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        for input in INPUTS {
+            let mut lowercase = lowercase_chars(input, LowercaseMode::Lithuanian);
+            loop {
+                let (min, max) = lowercase.size_hint();
+                let count = lowercase.clone().count();
+                assert!(min <= count);
+                assert!(count <= max.unwrap());
+                if lowercase.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not yet implemented")]
+    fn turkic_mode_panics() {
+        let _ = lowercase_chars(b"I", LowercaseMode::Turkic);
+    }
+}
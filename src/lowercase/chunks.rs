@@ -0,0 +1,673 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use crate::unicode::lithuanian::to_lithuanian_lowercase;
+use crate::unicode::sigma::{lowercase_sigma, GREEK_CAPITAL_LETTER_SIGMA};
+use crate::LowercaseMode;
+
+const TO_LOWER_EXPAND: usize = 3;
+const UTF_8_CHAR_MAX_BYTES: usize = 4;
+const MAX_SCALAR_LOWERCASE_BYTES: usize = TO_LOWER_EXPAND * UTF_8_CHAR_MAX_BYTES;
+
+// Size of the stack buffer used to accumulate mapped bytes before they are
+// passed to the callback.
+const CHUNK_BUFFER_LEN: usize = 64;
+
+// Size of the inline buffer of a `MappedStr`, which is kept small so mapped
+// chunks are cheap to move.
+const MAPPED_STR_LEN: usize = 32;
+
+/// Lowercase the given slice and invoke `f` with each chunk of the output.
+///
+/// Concatenating the chunks passed to `f`, in order, produces the same bytes as
+/// collecting the iterator returned by [`lowercase`] with the same mode. This
+/// function is a push-based alternative to the [`Lowercase`] iterator which
+/// lets callers stream output into arbitrary destinations without collecting
+/// or allocating.
+///
+/// Runs of input which are unchanged by the case mapping, such as lowercase
+/// ASCII, punctuation, and invalid UTF-8 byte sequences, are passed to `f`
+/// borrowed directly from `slice`. Mapped characters are accumulated in a
+/// small stack buffer which is passed to `f` when it fills up or when the next
+/// unchanged run begins.
+///
+/// `f` is never called with an empty chunk. No guarantees are made about chunk
+/// boundaries beyond that they never split the UTF-8 encoding of a mapped
+/// character.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// let mut chunks = Vec::new();
+/// roe::lowercase_for_each_chunk(b"abc XYZ abc", LowercaseMode::Full, |chunk| {
+///     chunks.push(chunk.to_vec());
+/// });
+/// assert_eq!(chunks, [&b"abc "[..], b"xyz", b" abc"]);
+/// ```
+///
+/// The chunks can be written directly to a sink:
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// let mut out = Vec::new();
+/// roe::lowercase_for_each_chunk("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full, |chunk| {
+///     out.extend_from_slice(chunk);
+/// });
+/// assert_eq!(out, "αύριο".as_bytes());
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [`lowercase`]: crate::lowercase()
+/// [`Lowercase`]: crate::Lowercase
+pub fn lowercase_for_each_chunk<F>(slice: &[u8], options: LowercaseMode, mut f: F)
+where
+    F: FnMut(&[u8]),
+{
+    // Reject unsupported modes up front, even for inputs which are entirely
+    // unchanged by the case mapping.
+    let _ = crate::lowercase(&[], options);
+
+    let mut buf = [0; CHUNK_BUFFER_LEN];
+    let mut buf_len = 0;
+    let mut borrowed_start = 0;
+    let mut offset = 0;
+
+    while offset < slice.len() {
+        let mut mapped = [0; MAX_SCALAR_LOWERCASE_BYTES];
+        let (size, mapped_len) = lowercase_step(slice, offset, options, &mut mapped);
+
+        if mapped_len == 0 {
+            // The character is unchanged and extends the borrowed run.
+            if buf_len > 0 {
+                f(&buf[..buf_len]);
+                buf_len = 0;
+            }
+        } else {
+            if borrowed_start < offset {
+                f(&slice[borrowed_start..offset]);
+            }
+            if buf_len + mapped_len > buf.len() {
+                f(&buf[..buf_len]);
+                buf_len = 0;
+            }
+            buf[buf_len..buf_len + mapped_len].copy_from_slice(&mapped[..mapped_len]);
+            buf_len += mapped_len;
+            borrowed_start = offset + size;
+        }
+        offset += size;
+    }
+
+    if buf_len > 0 {
+        f(&buf[..buf_len]);
+    }
+    if borrowed_start < slice.len() {
+        f(&slice[borrowed_start..]);
+    }
+}
+
+/// Returns an iterator that yields the lowercase equivalent of the given slice
+/// in chunks.
+///
+/// This function is a pull-based alternative to [`lowercase_for_each_chunk`].
+/// Each [`LowercaseChunk`] is either a run of valid UTF-8 which is unchanged by
+/// the case mapping and borrowed from `slice`, a run of mapped characters
+/// buffered in the chunk itself, or an invalid UTF-8 byte sequence borrowed
+/// from `slice`. Valid chunks can be written whole with [`write_str`].
+///
+/// Concatenating the bytes of the yielded chunks, in order, produces the same
+/// bytes as collecting the iterator returned by [`lowercase`] with the same
+/// mode. Chunks are never empty and never split the UTF-8 encoding of a
+/// character.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{LowercaseChunk, LowercaseMode};
+/// let mut out = String::new();
+/// let mut invalid = Vec::new();
+/// for chunk in roe::lowercase_chunks(b"abc XYZ \xFF", LowercaseMode::Full) {
+///     match chunk {
+///         LowercaseChunk::Unchanged(s) => out.push_str(s),
+///         LowercaseChunk::Mapped(s) => out.push_str(s.as_str()),
+///         LowercaseChunk::Invalid(bytes) => invalid.extend_from_slice(bytes),
+///     }
+/// }
+/// assert_eq!(out, "abc xyz ");
+/// assert_eq!(invalid, b"\xFF");
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [`write_str`]: core::fmt::Write::write_str
+/// [`lowercase`]: crate::lowercase()
+pub fn lowercase_chunks(slice: &[u8], options: LowercaseMode) -> LowercaseChunks<'_> {
+    // Reject unsupported modes up front, even for inputs which are entirely
+    // unchanged by the case mapping.
+    let _ = crate::lowercase(&[], options);
+
+    LowercaseChunks {
+        slice,
+        offset: 0,
+        unchanged: &[],
+        options,
+    }
+}
+
+/// A chunk of the lowercase equivalent of a conventionally UTF-8 byte string.
+///
+/// This enum is yielded by the [`LowercaseChunks`] iterator. See
+/// [`lowercase_chunks`] for more.
+///
+/// [`lowercase_chunks`]: crate::lowercase_chunks()
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LowercaseChunk<'a> {
+    /// A run of valid UTF-8 which is unchanged by the case mapping, borrowed
+    /// from the input.
+    Unchanged(&'a str),
+    /// A run of mapped characters.
+    Mapped(MappedStr),
+    /// An invalid UTF-8 byte sequence, borrowed from the input.
+    Invalid(&'a [u8]),
+}
+
+impl LowercaseChunk<'_> {
+    /// Returns the bytes of this chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::LowercaseMode;
+    /// let mut chunks = roe::lowercase_chunks(b"ABC\xFF", LowercaseMode::Full);
+    /// assert_eq!(chunks.next().unwrap().as_bytes(), b"abc");
+    /// assert_eq!(chunks.next().unwrap().as_bytes(), b"\xFF");
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Unchanged(s) => s.as_bytes(),
+            Self::Mapped(s) => s.as_bytes(),
+            Self::Invalid(bytes) => bytes,
+        }
+    }
+
+    /// Returns this chunk as a string slice, or `None` if it is an invalid
+    /// UTF-8 byte sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::LowercaseMode;
+    /// let mut chunks = roe::lowercase_chunks(b"ABC\xFF", LowercaseMode::Full);
+    /// assert_eq!(chunks.next().unwrap().as_str(), Some("abc"));
+    /// assert_eq!(chunks.next().unwrap().as_str(), None);
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Unchanged(s) => Some(s),
+            Self::Mapped(s) => Some(s.as_str()),
+            Self::Invalid(_) => None,
+        }
+    }
+}
+
+/// A run of case mapped characters buffered inline.
+///
+/// Mapped characters cannot be borrowed from the input, so each
+/// [`LowercaseChunk::Mapped`] chunk owns a small fixed size buffer of their
+/// UTF-8 encodings.
+#[derive(Clone, Copy)]
+pub struct MappedStr {
+    bytes: [u8; MAPPED_STR_LEN],
+    len: u8,
+}
+
+impl MappedStr {
+    const fn new() -> Self {
+        Self {
+            bytes: [0; MAPPED_STR_LEN],
+            len: 0,
+        }
+    }
+
+    // Append the UTF-8 encoding of a mapped character if it fits.
+    fn try_push(&mut self, mapped: &[u8]) -> bool {
+        let start = usize::from(self.len);
+        let end = start + mapped.len();
+        match (self.bytes.get_mut(start..end), u8::try_from(end)) {
+            (Some(dst), Ok(len)) => {
+                dst.copy_from_slice(mapped);
+                self.len = len;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the buffered characters as a string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{LowercaseChunk, LowercaseMode};
+    /// let mut chunks = roe::lowercase_chunks("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full);
+    /// if let Some(LowercaseChunk::Mapped(s)) = chunks.next() {
+    ///     assert_eq!(s.as_str(), "αύριο");
+    /// }
+    /// ```
+    #[must_use]
+    // The buffer only ever holds whole UTF-8 encoded characters.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(self.as_bytes()).expect("mapped chars are valid UTF-8")
+    }
+
+    /// Returns the UTF-8 encoding of the buffered characters.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.len)]
+    }
+}
+
+impl fmt::Debug for MappedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MappedStr").field(&self.as_str()).finish()
+    }
+}
+
+impl PartialEq for MappedStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for MappedStr {}
+
+/// An iterator that yields the lowercase equivalent of a conventionally UTF-8
+/// byte string in chunks.
+///
+/// This struct is created by the [`lowercase_chunks`] function. See its
+/// documentation for more.
+///
+/// [`lowercase_chunks`]: crate::lowercase_chunks()
+#[derive(Clone)]
+#[must_use = "LowercaseChunks is a Iterator and must be used"]
+pub struct LowercaseChunks<'a> {
+    // The whole input is kept because context-dependent mappings like final
+    // sigma look at the bytes preceding `offset`.
+    slice: &'a [u8],
+    offset: usize,
+    // A run of unchanged input which is yet to be split into valid and invalid
+    // UTF-8 chunks.
+    unchanged: &'a [u8],
+    options: LowercaseMode,
+}
+
+impl fmt::Debug for LowercaseChunks<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LowercaseChunks")
+            .field("slice", &self.slice.as_bstr())
+            .field("offset", &self.offset)
+            .field("unchanged", &self.unchanged.as_bstr())
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+impl<'a> LowercaseChunks<'a> {
+    // Split the next valid or invalid UTF-8 chunk off of the pending unchanged
+    // run.
+    fn next_unchanged(&mut self) -> Option<LowercaseChunk<'a>> {
+        let chunk = self.unchanged.utf8_chunks().next()?;
+        if chunk.valid().is_empty() {
+            self.unchanged = &self.unchanged[chunk.invalid().len()..];
+            Some(LowercaseChunk::Invalid(chunk.invalid()))
+        } else {
+            self.unchanged = &self.unchanged[chunk.valid().len()..];
+            Some(LowercaseChunk::Unchanged(chunk.valid()))
+        }
+    }
+}
+
+impl<'a> Iterator for LowercaseChunks<'a> {
+    type Item = LowercaseChunk<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(chunk) = self.next_unchanged() {
+            return Some(chunk);
+        }
+        let start = self.offset;
+        let mut buf = MappedStr::new();
+        while self.offset < self.slice.len() {
+            let mut mapped = [0; MAX_SCALAR_LOWERCASE_BYTES];
+            let (size, mapped_len) =
+                lowercase_step(self.slice, self.offset, self.options, &mut mapped);
+            if mapped_len == 0 {
+                // An unchanged character ends a run of mapped characters.
+                if buf.len != 0 {
+                    break;
+                }
+            } else if (buf.len == 0 && self.offset > start) || !buf.try_push(&mapped[..mapped_len])
+            {
+                // A mapped character ends a run of unchanged characters and is
+                // mapped again by the next call.
+                break;
+            }
+            self.offset += size;
+        }
+        if buf.len != 0 {
+            return Some(LowercaseChunk::Mapped(buf));
+        }
+        self.unchanged = &self.slice[start..self.offset];
+        self.next_unchanged()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every chunk consumes at least one byte of input.
+        let remaining = self.unchanged.len() + (self.slice.len() - self.offset);
+        (usize::from(remaining > 0), Some(remaining))
+    }
+}
+
+impl FusedIterator for LowercaseChunks<'_> {}
+
+// Lowercase the character at `offset` into `mapped` and return its size in
+// `slice` and the number of mapped bytes, taking the ASCII fast path where the
+// mode allows it.
+fn lowercase_step(
+    slice: &[u8],
+    offset: usize,
+    options: LowercaseMode,
+    mapped: &mut [u8; MAX_SCALAR_LOWERCASE_BYTES],
+) -> (usize, usize) {
+    match slice[offset] {
+        // The Lithuanian mappings of `I` and `J` depend on the combining
+        // marks which follow them.
+        b'I' | b'J' if options == LowercaseMode::Lithuanian => {
+            lowercase_scalar(slice, offset, options, mapped)
+        }
+        // Turkic case folding maps `I` to dotless `ı`.
+        b'I' if options == LowercaseMode::TurkicFold => {
+            lowercase_scalar(slice, offset, options, mapped)
+        }
+        byte @ b'A'..=b'Z' => {
+            mapped[0] = byte.to_ascii_lowercase();
+            (1, 1)
+        }
+        byte if byte.is_ascii() || options == LowercaseMode::Ascii => (1, 0),
+        _ => lowercase_scalar(slice, offset, options, mapped),
+    }
+}
+
+// Lowercase the UTF-8 byte sequence at `offset` into `mapped` and return its
+// size in `slice` and the number of mapped bytes. Sequences which are unchanged
+// by the case mapping, including invalid UTF-8, have zero mapped bytes.
+fn lowercase_scalar(
+    slice: &[u8],
+    offset: usize,
+    options: LowercaseMode,
+    mapped: &mut [u8; MAX_SCALAR_LOWERCASE_BYTES],
+) -> (usize, usize) {
+    let (ch, size) = match bstr::decode_utf8(&slice[offset..]) {
+        (Some(ch), size) => (ch, size),
+        (None, size) => return (size, 0),
+    };
+    let scalar = &slice[offset..offset + size];
+    let mut mapped_len = 0;
+    let full_or_lithuanian = matches!(options, LowercaseMode::Full | LowercaseMode::Lithuanian);
+    if full_or_lithuanian && ch == GREEK_CAPITAL_LETTER_SIGMA {
+        let (preceding, following) = (&slice[..offset], &slice[offset + size..]);
+        mapped_len = lowercase_sigma(preceding, following)
+            .encode_utf8(&mut mapped[..])
+            .len();
+    } else if options == LowercaseMode::Lithuanian {
+        let following = &slice[offset + size..];
+        for ch in to_lithuanian_lowercase(ch, following) {
+            if ch != '\0' {
+                mapped_len += ch.encode_utf8(&mut mapped[mapped_len..]).len();
+            }
+        }
+    } else {
+        for (dst, byte) in mapped.iter_mut().zip(crate::lowercase(scalar, options)) {
+            *dst = byte;
+            mapped_len += 1;
+        }
+    }
+    if mapped[..mapped_len] == *scalar {
+        (size, 0)
+    } else {
+        (size, mapped_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::{
+        lowercase_chunks, lowercase_for_each_chunk, LowercaseChunk, CHUNK_BUFFER_LEN,
+        MAPPED_STR_LEN,
+    };
+    use crate::LowercaseMode;
+
+    fn collect_chunks(slice: &[u8], options: LowercaseMode) -> Vec<Vec<u8>> {
+        let mut chunks = Vec::new();
+        lowercase_for_each_chunk(slice, options, |chunk| chunks.push(chunk.to_vec()));
+        chunks
+    }
+
+    #[test]
+    fn empty() {
+        assert!(collect_chunks(b"", LowercaseMode::Full).is_empty());
+        assert!(collect_chunks(b"", LowercaseMode::Ascii).is_empty());
+    }
+
+    #[test]
+    fn chunks_reconstruct_full_output() {
+        let inputs: [&[u8]; 11] = [
+            b"abc, xyz",
+            b"ABC, XYZ",
+            b"FILE",
+            "I\u{301}".as_bytes(),
+            "ΑΎΡΙΟ Αύριο".as_bytes(),
+            "ΟΔΟΣ ΣΑΣ Σ".as_bytes(),
+            "İSTANBUL".as_bytes(),
+            "ȺȾ Ǆǅǆ \u{1F600} ẞ".as_bytes(),
+            "ÌÍĨ I\u{300} J\u{301} Į\u{303}".as_bytes(),
+            b"ABC\xFF\xFEXYZ\xF0\x9F\x87",
+            &[b'A'; 1000],
+        ];
+        for input in inputs {
+            for options in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Lithuanian,
+                LowercaseMode::Fold,
+                LowercaseMode::TurkicFold,
+            ] {
+                let chunks = collect_chunks(input, options);
+                assert!(chunks.iter().all(|chunk| !chunk.is_empty()));
+                let out = chunks.concat();
+                let expected = crate::lowercase(input, options).collect::<Vec<_>>();
+                assert_eq!(out.as_bstr(), expected.as_bstr());
+            }
+        }
+    }
+
+    #[test]
+    fn turkic_fold_maps_ascii_capital_i_to_dotless_i() {
+        let out = collect_chunks(b"FILE", LowercaseMode::TurkicFold).concat();
+        assert_eq!(out.as_bstr(), "fıle".as_bytes().as_bstr());
+
+        let out = collect_chunks("I\u{301}".as_bytes(), LowercaseMode::TurkicFold).concat();
+        assert_eq!(out.as_bstr(), "ı\u{301}".as_bytes().as_bstr());
+    }
+
+    #[test]
+    fn unchanged_input_is_borrowed_whole() {
+        let mut s = "abc αύριο ".as_bytes().to_vec();
+        s.extend_from_slice(b"\xFF\xFE");
+        let mut calls = 0;
+        lowercase_for_each_chunk(&s, LowercaseMode::Full, |chunk| {
+            calls += 1;
+            assert_eq!(chunk.as_ptr(), s.as_ptr());
+            assert_eq!(chunk.len(), s.len());
+        });
+        assert_eq!(calls, 1);
+
+        let s = "ΑΎΡΙΟ abc".as_bytes();
+        let mut calls = 0;
+        lowercase_for_each_chunk(s, LowercaseMode::Ascii, |chunk| {
+            calls += 1;
+            assert_eq!(chunk.as_ptr(), s.as_ptr());
+        });
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn alternating_runs() {
+        assert_eq!(
+            collect_chunks(b"abcDEFghi", LowercaseMode::Full),
+            [&b"abc"[..], b"def", b"ghi"]
+        );
+        assert_eq!(
+            collect_chunks("Αύριο".as_bytes(), LowercaseMode::Full),
+            ["α".as_bytes(), "ύριο".as_bytes()]
+        );
+    }
+
+    #[test]
+    fn large_mapped_runs_are_split() {
+        let s = [b'A'; CHUNK_BUFFER_LEN * 3 + 1];
+        let chunks = collect_chunks(&s, LowercaseMode::Full);
+        assert_eq!(chunks.len(), 4);
+        assert!(chunks.iter().all(|chunk| chunk.len() <= CHUNK_BUFFER_LEN));
+
+        // Multi-byte mappings are never split across chunks.
+        let s = "İ".repeat(CHUNK_BUFFER_LEN);
+        let chunks = collect_chunks(s.as_bytes(), LowercaseMode::Full);
+        assert!(chunks.iter().all(|chunk| chunk.to_str().is_ok()));
+    }
+
+    #[test]
+    fn iterator_chunks_reconstruct_full_output() {
+        let inputs: [&[u8]; 10] = [
+            b"",
+            b"abc, XYZ",
+            b"FILE",
+            "I\u{301}".as_bytes(),
+            "ΑΎΡΙΟ Αύριο ΟΔΟΣ Σ".as_bytes(),
+            "İSTANBUL ẞ \u{1F600}".as_bytes(),
+            "ÌÍĨ I\u{300} J\u{301} Į\u{303}".as_bytes(),
+            b"ABC\xFF\xFEXYZ\xF0\x9F\x87",
+            b"\xCE\x91\xCE\xA3\xFF\xCE\xA3",
+            &[b'A'; 1000],
+        ];
+        for input in inputs {
+            for options in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Lithuanian,
+                LowercaseMode::Fold,
+                LowercaseMode::TurkicFold,
+            ] {
+                let mut out = Vec::new();
+                for chunk in lowercase_chunks(input, options) {
+                    assert!(!chunk.as_bytes().is_empty());
+                    if let LowercaseChunk::Invalid(bytes) = chunk {
+                        assert!(bytes.to_str().is_err());
+                    } else {
+                        assert!(chunk.as_str().is_some());
+                    }
+                    out.extend_from_slice(chunk.as_bytes());
+                }
+                let expected = crate::lowercase(input, options).collect::<Vec<_>>();
+                assert_eq!(out.as_bstr(), expected.as_bstr());
+            }
+        }
+    }
+
+    #[test]
+    fn iterator_chunk_kinds() {
+        let chunks = lowercase_chunks(b"abc DEF\xFF\xFEghi", LowercaseMode::Full)
+            .map(|chunk| match chunk {
+                LowercaseChunk::Unchanged(s) => ("unchanged", s.as_bytes().to_vec()),
+                LowercaseChunk::Mapped(s) => ("mapped", s.as_bytes().to_vec()),
+                LowercaseChunk::Invalid(bytes) => ("invalid", bytes.to_vec()),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            chunks,
+            [
+                ("unchanged", b"abc ".to_vec()),
+                ("mapped", b"def".to_vec()),
+                ("invalid", b"\xFF".to_vec()),
+                ("invalid", b"\xFE".to_vec()),
+                ("unchanged", b"ghi".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn iterator_borrows_unchanged_input() {
+        let s = "abc αύριο".as_bytes();
+        let mut chunks = lowercase_chunks(s, LowercaseMode::Full);
+        let chunk = chunks.next().unwrap();
+        assert!(matches!(chunk, LowercaseChunk::Unchanged(_)));
+        assert_eq!(chunk.as_bytes().as_ptr(), s.as_ptr());
+        assert_eq!(chunk.as_bytes().len(), s.len());
+        assert_eq!(chunks.next(), None);
+    }
+
+    #[test]
+    fn iterator_splits_large_mapped_runs() {
+        let s = "İ".repeat(CHUNK_BUFFER_LEN);
+        let mut count = 0;
+        for chunk in lowercase_chunks(s.as_bytes(), LowercaseMode::Full) {
+            assert!(matches!(chunk, LowercaseChunk::Mapped(_)));
+            assert!(chunk.as_bytes().len() <= MAPPED_STR_LEN);
+            count += 1;
+        }
+        assert!(count > 1);
+    }
+
+    #[test]
+    fn iterator_size_hint_covers_count() {
+        let inputs: [&[u8]; 3] = [b"", b"abc DEF\xFF\xFEghi", "ΑΎΡΙΟ Σ".as_bytes()];
+        for input in inputs {
+            let mut iter = lowercase_chunks(input, LowercaseMode::Full);
+            loop {
+                let (min, max) = iter.size_hint();
+                let count = iter.clone().count();
+                assert!(min <= count);
+                assert!(count <= max.unwrap());
+                if iter.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "lowercase Turkic mode is not yet implemented")]
+    fn iterator_unsupported_mode_panics_eagerly() {
+        let _ = lowercase_chunks(b"abc", LowercaseMode::Turkic);
+    }
+
+    #[test]
+    #[should_panic(expected = "lowercase Turkic mode is not yet implemented")]
+    fn unsupported_mode_panics_eagerly() {
+        lowercase_for_each_chunk(b"abc", LowercaseMode::Turkic, |_| {});
+    }
+}
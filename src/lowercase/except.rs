@@ -0,0 +1,278 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use super::chars::lowercase_char;
+use crate::buffer::OutputBuffer;
+use crate::unicode::std_case_mapping_iter::CaseMappingIter;
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, LowercaseMode, UnsupportedCaseMappingMode};
+
+/// An iterator that yields the lowercase equivalent of a conventionally UTF-8
+/// byte string, passing through a set of exempt characters unchanged.
+///
+/// This iterator yields [bytes].
+///
+/// This struct is created by the [`lowercase_except`] function. See its
+/// documentation for more.
+///
+/// [bytes]: u8
+/// [`lowercase_except`]: crate::lowercase_except()
+#[derive(Clone)]
+#[must_use = "LowercaseExcept is a Iterator and must be used"]
+pub struct LowercaseExcept<'a> {
+    source: &'a [u8],
+    offset: usize,
+    exempt: &'a [char],
+    mode: LowercaseMode,
+    next_bytes: OutputBuffer,
+    lowercase: CaseMappingIter,
+}
+
+impl fmt::Debug for LowercaseExcept<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LowercaseExcept")
+            .field("source", &self.source.as_bstr())
+            .field("offset", &self.offset)
+            .field("exempt", &self.exempt)
+            .field("mode", &self.mode)
+            .field("next_bytes", &self.next_bytes)
+            .field("lowercase", &self.lowercase)
+            .finish()
+    }
+}
+
+impl<'a> LowercaseExcept<'a> {
+    pub(crate) fn with_slice(slice: &'a [u8], mode: LowercaseMode, exempt: &'a [char]) -> Self {
+        if mode == LowercaseMode::Turkic {
+            let err = UnsupportedCaseMappingMode::new(CaseMapping::Lowercase(mode));
+            panic!("{}", err);
+        }
+        Self {
+            source: slice,
+            offset: 0,
+            exempt,
+            mode,
+            next_bytes: OutputBuffer::new(),
+            lowercase: CaseMappingIter::Zero,
+        }
+    }
+
+    fn encode_next_char(&mut self) -> Option<u8> {
+        let ch = self.lowercase.next()?;
+        self.next_bytes.push_char(ch);
+        self.next_bytes.next_byte()
+    }
+}
+
+impl Iterator for LowercaseExcept<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_bytes.next_byte() {
+            return Some(byte);
+        }
+        if let Some(byte) = self.encode_next_char() {
+            return Some(byte);
+        }
+        let offset = self.offset;
+        match bstr::decode_utf8(&self.source[offset..]) {
+            (_, 0) => None,
+            (Some(ch), size) => {
+                self.offset += size;
+                if self.exempt.contains(&ch) {
+                    self.next_bytes
+                        .push_bytes(&self.source[offset..offset + size]);
+                    return self.next_bytes.next_byte();
+                }
+                // Context-dependent mappings see the whole input, including
+                // exempt characters, as if nothing were exempt.
+                let (preceding, following) =
+                    (&self.source[..offset], &self.source[offset + size..]);
+                let chars = lowercase_char(preceding, following, ch, self.mode);
+                self.lowercase = CaseMappingIter::new(chars);
+                self.encode_next_char()
+            }
+            (None, _) => {
+                self.offset += 1;
+                Some(self.source[offset])
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        const UTF_8_CHAR_MAX_BYTES: usize = 4;
+        // Bytes of the current char which have not been yielded yet plus the
+        // chars of the current case mapping which have not been encoded.
+        let (pending_chars, _) = self.lowercase.size_hint();
+        let pending_min = self.next_bytes.len() + pending_chars;
+        let pending_max = self.next_bytes.len() + pending_chars * UTF_8_CHAR_MAX_BYTES;
+        let remaining = &self.source[self.offset..];
+        let (num, den) = max_expansion_ratio(CaseMapping::Lowercase(self.mode));
+        let max = remaining.len().checked_mul(num).map(|max| max / den);
+        (
+            lead_byte_count(remaining) + pending_min,
+            max.and_then(|max| max.checked_add(pending_max)),
+        )
+    }
+}
+
+impl FusedIterator for LowercaseExcept<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::LowercaseExcept;
+    use crate::LowercaseMode;
+
+    #[test]
+    fn empty() {
+        let iter = LowercaseExcept::with_slice(b"", LowercaseMode::Full, &['A']);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+
+        let iter = LowercaseExcept::with_slice(b"ABC", LowercaseMode::Full, &[]);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"abc".as_bstr());
+    }
+
+    #[test]
+    fn ascii_exemptions() {
+        let iter = LowercaseExcept::with_slice(b"HELLO {NAME}", LowercaseMode::Full, &['N', 'E']);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"hEllo {NamE}".as_bstr()
+        );
+
+        let iter = LowercaseExcept::with_slice(b"XXX", LowercaseMode::Ascii, &['X']);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"XXX".as_bstr());
+    }
+
+    #[test]
+    fn unicode_exemptions() {
+        let iter = LowercaseExcept::with_slice("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full, &['Ρ']);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "αύΡιο".as_bytes().as_bstr()
+        );
+
+        let iter = LowercaseExcept::with_slice("İİ".as_bytes(), LowercaseMode::Full, &['İ']);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "İİ".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn final_sigma_sees_exempt_characters() {
+        // `N` is cased, so the sigma is not at the end of a word.
+        let iter = LowercaseExcept::with_slice("ΑΣN".as_bytes(), LowercaseMode::Full, &['N']);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "ασN".as_bytes().as_bstr()
+        );
+
+        let iter = LowercaseExcept::with_slice("ΑΣ N".as_bytes(), LowercaseMode::Full, &['N']);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "ας N".as_bytes().as_bstr()
+        );
+
+        let iter = LowercaseExcept::with_slice("ΑΣΑ".as_bytes(), LowercaseMode::Full, &['Α']);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "ΑσΑ".as_bytes().as_bstr()
+        );
+
+        let iter =
+            LowercaseExcept::with_slice("ΟΔΟΣ Σ".as_bytes(), LowercaseMode::Lithuanian, &['Δ']);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "οΔος σ".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn matches_lowercase_without_exemptions() {
+        let inputs: [&[u8]; 5] = [
+            "ΑΎΡΙΟ ΟΔΟΣ Σ".as_bytes(),
+            "İ ẞ ΐ ﬃ".as_bytes(),
+            "I\u{300} Į\u{301} J\u{303}".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF\xFE\xCE",
+            b"",
+        ];
+        let modes = [
+            LowercaseMode::Full,
+            LowercaseMode::Ascii,
+            LowercaseMode::Lithuanian,
+            LowercaseMode::Fold,
+            LowercaseMode::TurkicFold,
+        ];
+        for input in inputs {
+            for mode in modes {
+                let iter = LowercaseExcept::with_slice(input, mode, &['\u{1F600}']);
+                let expected = crate::lowercase(input, mode).collect::<Vec<_>>();
+                assert_eq!(
+                    iter.collect::<Vec<_>>().as_bstr(),
+                    expected.as_bstr(),
+                    "{mode:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ascii_mode_ignores_unicode() {
+        let iter =
+            LowercaseExcept::with_slice("ΑΎΡΙΟ ABC".as_bytes(), LowercaseMode::Ascii, &['B']);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "ΑΎΡΙΟ aBc".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn exemptions_match_chars_not_bytes() {
+        // `Ā` is encoded as `\xC4\x80`. Exempting `\u{C4}` does not exempt the
+        // leading byte of `Ā`.
+        let iter = LowercaseExcept::with_slice("Ā".as_bytes(), LowercaseMode::Full, &['\u{C4}']);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), "ā".as_bytes().as_bstr());
+
+        // Invalid UTF-8 never matches the replacement character.
+        let iter = LowercaseExcept::with_slice(b"A\xFFB", LowercaseMode::Full, &['\u{FFFD}']);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"a\xFFb".as_bstr());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let iter = LowercaseExcept::with_slice(b"ABC\xFF\xFEXYZ", LowercaseMode::Full, &['X']);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"abc\xFF\xFEXyz".as_bstr()
+        );
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        let inputs: [&[u8]; 4] = [
+            b"HELLO {NAME}",
+            "ΑΎΡΙΟ".as_bytes(),
+            b"ABC\xFF\xFEXYZ",
+            "İSTANBUL".as_bytes(),
+        ];
+        for input in inputs {
+            let iter = LowercaseExcept::with_slice(input, LowercaseMode::Full, &['N', 'Ρ', 'X']);
+            let (min, max) = iter.size_hint();
+            let count = iter.count();
+            assert!(min <= count);
+            assert!(count <= max.unwrap());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "lowercase Turkic mode is not yet implemented")]
+    fn unsupported_mode_panics_eagerly() {
+        let _ = LowercaseExcept::with_slice(b"", LowercaseMode::Turkic, &[]);
+    }
+}
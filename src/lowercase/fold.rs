@@ -0,0 +1,474 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use crate::buffer::OutputBuffer;
+use crate::unicode::fold::ToCasefold;
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, LowercaseMode};
+
+// Folded output is buffered separately for each end of the iterator. Once
+// `slice` is exhausted, each end drains the buffers of the other end.
+#[derive(Clone)]
+#[must_use = "Fold is a Iterator and must be used"]
+pub struct Fold<'a> {
+    source: &'a [u8],
+    slice: &'a [u8],
+    next_bytes: OutputBuffer,
+    casefold: Option<ToCasefold>,
+    next_back_bytes: OutputBuffer,
+    casefold_back: Option<ToCasefold>,
+    turkic: bool,
+}
+
+impl fmt::Debug for Fold<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fold")
+            .field("slice", &self.slice.as_bstr())
+            .field("next_bytes", &self.next_bytes)
+            .field("casefold", &self.casefold)
+            .field("next_back_bytes", &self.next_back_bytes)
+            .field("casefold_back", &self.casefold_back)
+            .field("turkic", &self.turkic)
+            .finish()
+    }
+}
+
+impl<'a> From<&'a [u8]> for Fold<'a> {
+    fn from(slice: &'a [u8]) -> Self {
+        Self::with_slice(slice)
+    }
+}
+
+impl<'a> Fold<'a> {
+    pub const fn with_slice(slice: &'a [u8]) -> Self {
+        Self {
+            source: slice,
+            slice,
+            next_bytes: OutputBuffer::new(),
+            casefold: None,
+            next_back_bytes: OutputBuffer::new(),
+            casefold_back: None,
+            turkic: false,
+        }
+    }
+
+    pub const fn with_turkic_slice(slice: &'a [u8]) -> Self {
+        Self {
+            source: slice,
+            slice,
+            next_bytes: OutputBuffer::new(),
+            casefold: None,
+            next_back_bytes: OutputBuffer::new(),
+            casefold_back: None,
+            turkic: true,
+        }
+    }
+
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    pub fn reset(&mut self) {
+        *self = if self.turkic {
+            Self::with_turkic_slice(self.source)
+        } else {
+            Self::with_slice(self.source)
+        };
+    }
+
+    pub const fn is_turkic(&self) -> bool {
+        self.turkic
+    }
+
+    fn to_casefold(&self, ch: char) -> ToCasefold {
+        if self.turkic {
+            ToCasefold::new_turkic(ch)
+        } else {
+            ToCasefold::new(ch)
+        }
+    }
+
+    fn pending_chars(&self) -> usize {
+        let front = self.casefold.as_ref().map_or(0, ExactSizeIterator::len);
+        let back = self
+            .casefold_back
+            .as_ref()
+            .map_or(0, ExactSizeIterator::len);
+        front + back
+    }
+}
+
+impl Iterator for Fold<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_bytes.next_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self.casefold.as_mut().and_then(Iterator::next) {
+            self.next_bytes.push_char(ch);
+            return self.next_bytes.next_byte();
+        }
+
+        self.casefold = None;
+
+        match bstr::decode_utf8(self.slice) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // reverse iteration.
+                if let Some(ch) = self.casefold_back.as_mut().and_then(Iterator::next) {
+                    self.next_bytes.push_char(ch);
+                    return self.next_bytes.next_byte();
+                }
+                self.next_back_bytes.next_byte()
+            }
+            (Some(ch), size) => {
+                self.slice = &self.slice[size..];
+                let mut casefold = self.to_casefold(ch);
+                let ch = casefold
+                    .next()
+                    .expect("ToCasefold yields at least one char");
+                self.next_bytes.push_char(ch);
+
+                self.casefold = Some(casefold);
+                self.next_bytes.next_byte()
+            }
+            (None, size) => {
+                let (bytes, remainder) = self.slice.split_at(size);
+                self.slice = remainder;
+
+                self.next_bytes.push_bytes(bytes);
+                self.next_bytes.next_byte()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        const UTF_8_CHAR_MAX_BYTES: usize = 4;
+        const FOLD_RATIO: (usize, usize) =
+            max_expansion_ratio(CaseMapping::Lowercase(LowercaseMode::Fold));
+        // Bytes of the current chars which have not been yielded yet plus the
+        // chars of the current case foldings which have not been encoded.
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        let pending_chars = self.pending_chars();
+        let pending_min = pending_bytes + pending_chars;
+        let pending_max = pending_bytes + pending_chars * UTF_8_CHAR_MAX_BYTES;
+        let len = self.slice.len();
+        // Turkic case folding maps ASCII `I` to a two-byte dotless `ı`.
+        if self.slice.is_ascii() && !self.turkic {
+            (len + pending_min, Some(len + pending_max))
+        } else {
+            // Every char and invalid UTF-8 byte sequence in the remaining
+            // slice yields at least one byte, and no mapping expands by more
+            // than the maximum expansion ratio.
+            let (num, den) = FOLD_RATIO;
+            let max = len.checked_mul(num).map(|max| max / den);
+            (
+                lead_byte_count(self.slice) + pending_min,
+                max.and_then(|max| max.checked_add(pending_max)),
+            )
+        }
+    }
+
+    fn count(self) -> usize {
+        if self.slice.is_ascii() && self.pending_chars() == 0 && !self.turkic {
+            self.next_bytes.len() + self.next_back_bytes.len() + self.slice.len()
+        } else {
+            self.fold(0, |acc, _| acc + 1)
+        }
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        // The last byte is the last byte of the final scalar's folding, which
+        // reverse iteration maps without visiting the rest of the slice.
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Fold<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_back_bytes.next_back_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self
+            .casefold_back
+            .as_mut()
+            .and_then(DoubleEndedIterator::next_back)
+        {
+            self.next_back_bytes.push_char(ch);
+            return self.next_back_bytes.next_back_byte();
+        }
+
+        self.casefold_back = None;
+
+        match bstr::decode_last_utf8(self.slice) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // forward iteration.
+                if let Some(ch) = self
+                    .casefold
+                    .as_mut()
+                    .and_then(DoubleEndedIterator::next_back)
+                {
+                    self.next_back_bytes.push_char(ch);
+                    return self.next_back_bytes.next_back_byte();
+                }
+                self.next_bytes.next_back_byte()
+            }
+            (Some(ch), size) => {
+                self.slice = &self.slice[..self.slice.len() - size];
+                // Multi-char foldings are yielded back to front, starting with
+                // the last char of the folding.
+                let mut casefold = self.to_casefold(ch);
+                let ch = casefold
+                    .next_back()
+                    .expect("ToCasefold yields at least one char");
+                self.next_back_bytes.push_char(ch);
+
+                self.casefold_back = Some(casefold);
+                self.next_back_bytes.next_back_byte()
+            }
+            (None, size) => {
+                let (remainder, bytes) = self.slice.split_at(self.slice.len() - size);
+                self.slice = remainder;
+
+                self.next_back_bytes.push_bytes(bytes);
+                self.next_back_bytes.next_back_byte()
+            }
+        }
+    }
+}
+
+impl FusedIterator for Fold<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::Fold;
+
+    fn casefold(s: &[u8]) -> Vec<u8> {
+        Fold::from(s).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(casefold(b"").as_bstr(), b"".as_bstr());
+    }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(
+            casefold(b"abc, XYZ 123").as_bstr(),
+            b"abc, xyz 123".as_bstr()
+        );
+    }
+
+    #[test]
+    fn full_mappings_expand() {
+        assert_eq!(casefold("ß".as_bytes()).as_bstr(), b"ss".as_bstr());
+        assert_eq!(casefold("ẞ".as_bytes()).as_bstr(), b"ss".as_bstr());
+        assert_eq!(casefold("ﬀ".as_bytes()).as_bstr(), b"ff".as_bstr());
+        assert_eq!(
+            casefold("Straße".as_bytes()).as_bstr(),
+            b"strasse".as_bstr()
+        );
+        assert_eq!(
+            casefold("İ".as_bytes()).as_bstr(),
+            "i\u{307}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn round_trip_latin() {
+        assert_eq!(
+            casefold("STRASSE".as_bytes()),
+            casefold("straße".as_bytes())
+        );
+        assert_eq!(casefold("ÉCOLE".as_bytes()), casefold("école".as_bytes()));
+        assert_eq!(casefold("ǄǅǆǊ".as_bytes()), casefold("ǆǆǆǌ".as_bytes()));
+        assert_eq!(casefold("\u{212A}elvin".as_bytes()), casefold(b"KELVIN"));
+        assert_eq!(casefold("ﬀ".as_bytes()), casefold(b"FF"));
+    }
+
+    #[test]
+    fn round_trip_greek() {
+        assert_eq!(casefold("ΑΎΡΙΟ".as_bytes()), casefold("αύριο".as_bytes()));
+        assert_eq!(
+            casefold("ΣΊΣΥΦΟΣ".as_bytes()),
+            casefold("σίσυφος".as_bytes())
+        );
+        assert_eq!(
+            casefold("ΐ".as_bytes()),
+            casefold("Ι\u{308}\u{301}".as_bytes())
+        );
+        assert_eq!(casefold("µ".as_bytes()), casefold("Μ".as_bytes()));
+    }
+
+    #[test]
+    fn round_trip_cyrillic() {
+        assert_eq!(casefold("ПРИВЕТ".as_bytes()), casefold("привет".as_bytes()));
+        assert_eq!(casefold("ЁЖИК".as_bytes()), casefold("ёжик".as_bytes()));
+    }
+
+    #[test]
+    fn round_trip_armenian() {
+        assert_eq!(
+            casefold("ՀԱՅԱՍՏԱՆ".as_bytes()),
+            casefold("հայաստան".as_bytes())
+        );
+        assert_eq!(casefold("և".as_bytes()), casefold("ԵՒ".as_bytes()));
+        assert_eq!(casefold("ﬓ".as_bytes()), casefold("ՄՆ".as_bytes()));
+    }
+
+    #[test]
+    fn different_strings_do_not_fold_same() {
+        assert_ne!(casefold(b"abc"), casefold(b"abd"));
+        assert_ne!(casefold("I".as_bytes()), casefold("ı".as_bytes()));
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        assert_eq!(
+            casefold(b"AB\xFF\xFEcD").as_bstr(),
+            b"ab\xFF\xFEcd".as_bstr()
+        );
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(Fold::with_slice(b"").count(), 0);
+        assert_eq!(Fold::with_slice(b"abc, XYZ").count(), 8);
+        assert_eq!(Fold::with_slice("ß".as_bytes()).count(), 2);
+        assert_eq!(Fold::with_slice("ﬃ".as_bytes()).count(), 3);
+
+        let mut iter = Fold::with_slice("ﬃ".as_bytes());
+        assert_eq!(iter.next(), Some(b'f'));
+        assert_eq!(iter.count(), 2);
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        let inputs: [&[u8]; 6] = [
+            b"",
+            b"abc, XYZ",
+            "ß ﬃ".as_bytes(),
+            "\u{212A}\u{212A}".as_bytes(),
+            "ΐΰ".as_bytes(),
+            b"A\xFF\xFEB",
+        ];
+        for input in inputs {
+            let mut iter = Fold::with_slice(input);
+            loop {
+                let (min, max) = iter.size_hint();
+                let count = iter.clone().count();
+                assert!(min <= count);
+                assert!(count <= max.unwrap());
+                if iter.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn turkic() {
+        let turkic = |s: &[u8]| Fold::with_turkic_slice(s).collect::<Vec<_>>();
+        assert_eq!(turkic(b"FILE").as_bstr(), "fıle".as_bytes().as_bstr());
+        assert_eq!(casefold(b"FILE").as_bstr(), b"file".as_bstr());
+        assert_eq!(
+            turkic("İSTANBUL".as_bytes()).as_bstr(),
+            b"istanbul".as_bstr()
+        );
+        assert_eq!(
+            casefold("İSTANBUL".as_bytes()).as_bstr(),
+            "i\u{307}stanbul".as_bytes().as_bstr()
+        );
+        assert_eq!(turkic(b"I"), turkic("ı".as_bytes()));
+        assert_eq!(turkic("İ".as_bytes()), turkic(b"i"));
+        assert_ne!(turkic(b"I"), turkic(b"i"));
+        assert_eq!(
+            turkic(b"Stra\xC3\x9Fe\xFF").as_bstr(),
+            casefold(b"Stra\xC3\x9Fe\xFF").as_bstr()
+        );
+    }
+
+    #[test]
+    fn rev() {
+        let inputs: [&[u8]; 6] = [
+            b"",
+            b"abc, XYZ",
+            "Straße ﬃ".as_bytes(),
+            "ẞ İ ΐΰ".as_bytes(),
+            "ΣΊΣΥΦΟΣ \u{212A}".as_bytes(),
+            b"A\xF0\x9F\x87\xFFz",
+        ];
+        for input in inputs {
+            let mut rev = Fold::with_slice(input).rev().collect::<Vec<_>>();
+            rev.reverse();
+            assert_eq!(rev.as_bstr(), casefold(input).as_bstr());
+
+            let mut rev = Fold::with_turkic_slice(input).rev().collect::<Vec<_>>();
+            rev.reverse();
+            let forward = Fold::with_turkic_slice(input).collect::<Vec<_>>();
+            assert_eq!(rev.as_bstr(), forward.as_bstr());
+        }
+    }
+
+    #[test]
+    fn next_and_next_back_interleaved() {
+        // `ﬃ` folds to `ffi`.
+        let mut iter = Fold::with_slice("ẞﬃ".as_bytes());
+        assert_eq!(iter.next_back(), Some(b'i'));
+        assert_eq!(iter.next(), Some(b's'));
+        assert_eq!(iter.next_back(), Some(b'f'));
+        assert_eq!(iter.next_back(), Some(b'f'));
+        assert_eq!(iter.next_back(), Some(b's'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        // `İ` folds to `i` followed by U+0307 COMBINING DOT ABOVE.
+        let mut iter = Fold::with_slice("İ".as_bytes());
+        assert_eq!(iter.next(), Some(b'i'));
+        assert_eq!(iter.next_back(), Some(0x87));
+        assert_eq!(iter.next_back(), Some(0xCC));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn size_hint_covers_count_when_interleaved() {
+        let mut iter = Fold::with_slice("ﬃ ẞ".as_bytes());
+        loop {
+            let (min, max) = iter.size_hint();
+            let count = iter.clone().count();
+            assert!(min <= count);
+            assert!(count <= max.unwrap());
+            if iter.next_back().is_none() {
+                break;
+            }
+            let _ = iter.next();
+        }
+    }
+
+    #[test]
+    fn turkic_size_hint_covers_count() {
+        for input in ["", "FILE", "İI", "abc, XYZ"] {
+            let mut iter = Fold::with_turkic_slice(input.as_bytes());
+            loop {
+                let (min, max) = iter.size_hint();
+                let count = iter.clone().count();
+                assert!(min <= count);
+                assert!(count <= max.unwrap());
+                if iter.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -1,26 +1,49 @@
-use core::char::ToLowercase;
 use core::fmt;
 use core::iter::FusedIterator;
-use core::ops::Range;
 
 use bstr::ByteSlice;
 
+use crate::buffer::OutputBuffer;
+use crate::unicode::sigma::{lowercase_sigma, GREEK_CAPITAL_LETTER_SIGMA};
+use crate::unicode::{case_map_char, CaseMapChar, CaseOp};
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, CaseMappingMode, LowercaseMode};
+
+// Mapped output is buffered separately for each end of the iterator. Bytes
+// which are not yet yielded are laid out, in order, as:
+//
+// 1. `next_bytes`, the rest of the front char.
+// 2. The chars remaining in `lowercase`.
+// 3. The lowercase mapping of `slice`.
+// 4. The chars remaining in `lowercase_back`.
+// 5. `next_back_bytes`, the rest of the back char.
+//
+// Once `slice` is exhausted, each end drains the buffers of the other end.
+//
+// `slice` is `source[offset..offset + slice.len()]`. The rest of `source` is
+// kept around as context for the final sigma rule.
 #[derive(Clone)]
 #[must_use = "Lowercase is a Iterator and must be used"]
 pub struct Lowercase<'a> {
+    source: &'a [u8],
+    offset: usize,
     slice: &'a [u8],
-    next_bytes: [u8; 4],
-    next_range: Range<usize>,
-    lowercase: Option<ToLowercase>,
+    next_bytes: OutputBuffer,
+    lowercase: Option<CaseMapChar>,
+    next_back_bytes: OutputBuffer,
+    lowercase_back: Option<CaseMapChar>,
 }
 
-impl<'a> fmt::Debug for Lowercase<'a> {
+impl fmt::Debug for Lowercase<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Lowercase")
+            .field("source", &self.source.as_bstr())
+            .field("offset", &self.offset)
             .field("slice", &self.slice.as_bstr())
             .field("next_bytes", &self.next_bytes)
-            .field("next_range", &self.next_range)
             .field("lowercase", &self.lowercase)
+            .field("next_back_bytes", &self.next_back_bytes)
+            .field("lowercase_back", &self.lowercase_back)
             .finish()
     }
 }
@@ -34,91 +57,243 @@ impl<'a> From<&'a [u8]> for Lowercase<'a> {
 impl<'a> Lowercase<'a> {
     pub const fn with_slice(slice: &'a [u8]) -> Self {
         Self {
+            source: slice,
+            offset: 0,
             slice,
-            next_bytes: [0; 4],
-            next_range: 0..0,
+            next_bytes: OutputBuffer::new(),
             lowercase: None,
+            next_back_bytes: OutputBuffer::new(),
+            lowercase_back: None,
         }
     }
+
+    pub fn reset(&mut self) {
+        *self = Self::with_slice(self.source);
+    }
+
+    fn pending_chars(&self) -> usize {
+        let front = self.lowercase.as_ref().map_or(0, ExactSizeIterator::len);
+        let back = self
+            .lowercase_back
+            .as_ref()
+            .map_or(0, ExactSizeIterator::len);
+        front + back
+    }
+
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.slice
+    }
 }
 
-impl<'a> Iterator for Lowercase<'a> {
+impl Iterator for Lowercase<'_> {
     type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(idx) = self.next_range.next() {
-            debug_assert!(self.next_bytes.get(idx).is_some());
-
-            return Some(self.next_bytes[idx]);
+        if let Some(byte) = self.next_bytes.next_byte() {
+            return Some(byte);
         }
 
         if let Some(ch) = self.lowercase.as_mut().and_then(Iterator::next) {
-            let enc = ch.encode_utf8(&mut self.next_bytes);
-
-            self.next_range = 1..enc.len();
-            debug_assert!(self.next_bytes.get(self.next_range.clone()).is_some());
-
-            return Some(self.next_bytes[0]);
+            self.next_bytes.push_char(ch);
+            return self.next_bytes.next_byte();
         }
 
         self.lowercase = None;
 
+        // ASCII bytes are mapped directly, without decoding and re-encoding a
+        // UTF-8 scalar. Only non-ASCII bytes engage the Unicode case mapping.
+        if let Some((&byte, remainder)) = self.slice.split_first() {
+            if byte.is_ascii() {
+                self.slice = remainder;
+                self.offset += 1;
+                return Some(byte.to_ascii_lowercase());
+            }
+        }
+
         match bstr::decode_utf8(self.slice) {
-            (_, 0) => None,
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // reverse iteration.
+                if let Some(ch) = self.lowercase_back.as_mut().and_then(Iterator::next) {
+                    self.next_bytes.push_char(ch);
+                    return self.next_bytes.next_byte();
+                }
+                self.next_back_bytes.next_byte()
+            }
+            (Some(GREEK_CAPITAL_LETTER_SIGMA), size) => {
+                let preceding = &self.source[..self.offset];
+                let following = &self.source[self.offset + size..];
+                self.slice = &self.slice[size..];
+                self.offset += size;
+                self.next_bytes
+                    .push_char(lowercase_sigma(preceding, following));
+                self.next_bytes.next_byte()
+            }
             (Some(ch), size) => {
                 self.slice = &self.slice[size..];
-                let mut lowercase = ch.to_lowercase();
+                self.offset += size;
+                let mut lowercase = case_map_char(ch, CaseOp::Lower, CaseMappingMode::Full)
+                    .expect("Full mode supports lowercasing");
                 let ch = lowercase
                     .next()
-                    .expect("ToLowercase yields at least one char");
-                let enc = ch.encode_utf8(&mut self.next_bytes);
-
-                self.next_range = 1..enc.len();
-                debug_assert!(self.next_bytes.get(self.next_range.clone()).is_some());
+                    .expect("CaseMapChar yields at least one char");
+                self.next_bytes.push_char(ch);
 
                 self.lowercase = Some(lowercase);
-                Some(self.next_bytes[0])
+                self.next_bytes.next_byte()
             }
             (None, size) => {
                 let (bytes, remainder) = self.slice.split_at(size);
                 self.slice = remainder;
+                self.offset += size;
 
-                // Invalid byte sequences are at most three bytes.
-                debug_assert!(self.next_bytes.get(..bytes.len()).is_some());
-
-                self.next_bytes[..bytes.len()].copy_from_slice(bytes);
-                self.next_range = 1..bytes.len();
-                Some(self.next_bytes[0])
+                self.next_bytes.push_bytes(bytes);
+                self.next_bytes.next_byte()
             }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        const TO_LOWER_EXPAND: usize = 3;
         const UTF_8_CHAR_MAX_BYTES: usize = 4;
-        if self.slice.is_empty() {
-            (0, Some(0))
-        } else if self.slice.is_ascii() {
+        const TO_LOWER_RATIO: (usize, usize) =
+            max_expansion_ratio(CaseMapping::Lowercase(LowercaseMode::Full));
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        let pending_chars = self.pending_chars();
+        let pending_min = pending_bytes + pending_chars;
+        let pending_max = pending_bytes + pending_chars * UTF_8_CHAR_MAX_BYTES;
+        if self.slice.is_ascii() {
             let len = self.slice.len();
-            (len, Some(len))
+            (len + pending_min, Some(len + pending_max))
         } else {
-            let len = self.slice.len();
-            (len, Some(len * TO_LOWER_EXPAND * UTF_8_CHAR_MAX_BYTES))
+            // Every char and invalid UTF-8 byte sequence in the remaining
+            // slice yields at least one byte, and no mapping expands by more
+            // than the maximum expansion ratio.
+            let (num, den) = TO_LOWER_RATIO;
+            let max = self.slice.len().checked_mul(num).map(|max| max / den);
+            (
+                lead_byte_count(self.slice) + pending_min,
+                max.and_then(|max| max.checked_add(pending_max)),
+            )
         }
     }
 
     fn count(self) -> usize {
-        if self.slice.is_empty() {
-            0
-        } else if self.slice.is_ascii() {
-            self.slice.len()
-        } else {
-            self.fold(0, |acc, _| acc + 1)
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        if self.slice.is_ascii() && self.pending_chars() == 0 {
+            return pending_bytes + self.slice.len();
         }
+        // Counting only needs the encoded length of each mapped char, so the
+        // mapped chars are never encoded. Both lowercase forms of `Σ` are two
+        // bytes, so the final sigma context does not need to be examined.
+        let pending = self
+            .lowercase
+            .into_iter()
+            .flatten()
+            .chain(self.lowercase_back.into_iter().flatten())
+            .map(char::len_utf8)
+            .sum::<usize>();
+        let mapped = self
+            .slice
+            .utf8_chunks()
+            .map(|chunk| {
+                let valid = chunk
+                    .valid()
+                    .chars()
+                    .map(|ch| {
+                        if ch.is_ascii() {
+                            1
+                        } else {
+                            ch.to_lowercase().map(char::len_utf8).sum()
+                        }
+                    })
+                    .sum::<usize>();
+                // Invalid UTF-8 byte sequences are yielded as is.
+                valid + chunk.invalid().len()
+            })
+            .sum::<usize>();
+        pending_bytes + pending + mapped
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        // The last byte is the last byte of the final scalar's mapping, which
+        // reverse iteration maps without visiting the rest of the slice.
+        self.next_back()
     }
 }
 
-impl<'a> FusedIterator for Lowercase<'a> {}
+impl DoubleEndedIterator for Lowercase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_back_bytes.next_back_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self
+            .lowercase_back
+            .as_mut()
+            .and_then(DoubleEndedIterator::next_back)
+        {
+            self.next_back_bytes.push_char(ch);
+            return self.next_back_bytes.next_back_byte();
+        }
+
+        self.lowercase_back = None;
+
+        if let Some((&byte, remainder)) = self.slice.split_last() {
+            if byte.is_ascii() {
+                self.slice = remainder;
+                return Some(byte.to_ascii_lowercase());
+            }
+        }
+
+        match bstr::decode_last_utf8(self.slice) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // forward iteration.
+                if let Some(ch) = self
+                    .lowercase
+                    .as_mut()
+                    .and_then(DoubleEndedIterator::next_back)
+                {
+                    self.next_back_bytes.push_char(ch);
+                    return self.next_back_bytes.next_back_byte();
+                }
+                self.next_bytes.next_back_byte()
+            }
+            (Some(GREEK_CAPITAL_LETTER_SIGMA), size) => {
+                self.slice = &self.slice[..self.slice.len() - size];
+                let start = self.offset + self.slice.len();
+                let preceding = &self.source[..start];
+                let following = &self.source[start + size..];
+                self.next_back_bytes
+                    .push_char(lowercase_sigma(preceding, following));
+                self.next_back_bytes.next_back_byte()
+            }
+            (Some(ch), size) => {
+                self.slice = &self.slice[..self.slice.len() - size];
+                // Multi-char mappings are yielded back to front, starting with
+                // the last char of the mapping.
+                let mut lowercase = case_map_char(ch, CaseOp::Lower, CaseMappingMode::Full)
+                    .expect("Full mode supports lowercasing");
+                let ch = lowercase
+                    .next_back()
+                    .expect("CaseMapChar yields at least one char");
+                self.next_back_bytes.push_char(ch);
+
+                self.lowercase_back = Some(lowercase);
+                self.next_back_bytes.next_back_byte()
+            }
+            (None, size) => {
+                let (remainder, bytes) = self.slice.split_at(self.slice.len() - size);
+                self.slice = remainder;
+
+                self.next_back_bytes.push_bytes(bytes);
+                self.next_back_bytes.next_back_byte()
+            }
+        }
+    }
+}
+
+impl FusedIterator for Lowercase<'_> {}
 
 #[cfg(test)]
 mod tests {
@@ -253,6 +428,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn four_byte_scalar_to_four_byte_scalar() {
+        // Every byte of the mapped scalar is yielded, in order, one `next` call
+        // at a time, and the iterator is exhausted after the fourth byte.
+        //
+        // DESERET CAPITAL LETTER LONG I lowercases to U+10428.
+        let mut iter = Lowercase::from("\u{10400}".as_bytes());
+        assert_eq!(iter.next(), Some(0xF0));
+        assert_eq!(iter.next(), Some(0x90));
+        assert_eq!(iter.next(), Some(0x90));
+        assert_eq!(iter.next(), Some(0xA8));
+        assert_eq!(iter.next(), None);
+
+        // Reverse iteration yields the same bytes back to front.
+        let mut iter = Lowercase::from("\u{10400}".as_bytes());
+        assert_eq!(iter.next_back(), Some(0xA8));
+        assert_eq!(iter.next_back(), Some(0x90));
+        assert_eq!(iter.next_back(), Some(0x90));
+        assert_eq!(iter.next_back(), Some(0xF0));
+        assert_eq!(iter.next_back(), None);
+
+        // OSAGE CAPITAL LETTER A lowercases to U+104D8, which differs from
+        // it in the last two bytes.
+        let mut iter = Lowercase::from("\u{104B0}".as_bytes());
+        assert_eq!(iter.next(), Some(0xF0));
+        assert_eq!(iter.next(), Some(0x90));
+        assert_eq!(iter.next(), Some(0x93));
+        assert_eq!(iter.next(), Some(0x98));
+        assert_eq!(iter.next(), None);
+
+        // ADLAM CAPITAL LETTER ALIF lowercases to U+1E922.
+        let mut iter = Lowercase::from("\u{1E900}".as_bytes());
+        assert_eq!(iter.next(), Some(0xF0));
+        assert_eq!(iter.next(), Some(0x9E));
+        assert_eq!(iter.next(), Some(0xA4));
+        assert_eq!(iter.next(), Some(0xA2));
+        assert_eq!(iter.next(), None);
+
+        // Consecutive four byte mappings do not bleed into each other.
+        let mut iter = Lowercase::from("a\u{10400}\u{10400}z".as_bytes());
+        assert_eq!(iter.next(), Some(b'a'));
+        for _ in 0..2 {
+            assert_eq!(iter.next(), Some(0xF0));
+            assert_eq!(iter.next(), Some(0x90));
+            assert_eq!(iter.next(), Some(0x90));
+            assert_eq!(iter.next(), Some(0xA8));
+        }
+        assert_eq!(iter.next(), Some(b'z'));
+        assert_eq!(iter.next(), None);
+    }
+
     #[test]
     fn case_map_to_two_chars() {
         let s = "İ".as_bytes();
@@ -276,32 +502,96 @@ mod tests {
         }
     }
 
+    #[test]
+    fn final_sigma() {
+        // Word-final `Σ` maps to `ς`.
+        let iter = Lowercase::from("ΟΔΟΣ".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "οδος".as_bytes().as_bstr()
+        );
+
+        let iter = Lowercase::from("ΟΔΟΣ ΟΔΟΣ.".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "οδος οδος.".as_bytes().as_bstr()
+        );
+
+        // Word-internal and word-initial `Σ` map to `σ`.
+        let iter = Lowercase::from("ΣΙΣΥΦΟΣ".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "σισυφος".as_bytes().as_bstr()
+        );
+
+        // Isolated `Σ` is not preceded by a cased letter and maps to `σ`.
+        let iter = Lowercase::from("Σ".as_bytes());
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), "σ".as_bytes().as_bstr());
+
+        let iter = Lowercase::from("1 Σ 2".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "1 σ 2".as_bytes().as_bstr()
+        );
+
+        // Case-ignorable characters are skipped when finding the context.
+        let iter = Lowercase::from("ΑΣ'Α ΑΣ' ΑΣ\u{301}".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "ασ'α ας' ας\u{301}".as_bytes().as_bstr()
+        );
+
+        // Lowercase `σ` and `ς` are not changed.
+        let iter = Lowercase::from("σς".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "σς".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn final_sigma_rev() {
+        let mut rev = Lowercase::from("ΟΔΟΣ ΣΑΣ Σ".as_bytes())
+            .rev()
+            .collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev.as_bstr(), "οδος σας σ".as_bytes().as_bstr());
+
+        // The context includes bytes already consumed from either end.
+        let mut iter = Lowercase::from("ΑΣΑ".as_bytes());
+        assert_eq!(iter.next_back(), Some(0xB1));
+        assert_eq!(iter.next_back(), Some(0xCE));
+        assert_eq!(iter.next(), Some(0xCE));
+        assert_eq!(iter.next(), Some(0xB1));
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), "σ".as_bytes().as_bstr());
+    }
+
     #[test]
     fn size_hint() {
         assert_eq!(Lowercase::with_slice(b"").size_hint(), (0, Some(0)));
         assert_eq!(Lowercase::with_slice(b"abc, xyz").size_hint(), (8, Some(8)));
         assert_eq!(
             Lowercase::with_slice(b"abc, \xFF\xFE, xyz").size_hint(),
-            (12, Some(144))
+            (12, Some(18))
         );
         assert_eq!(
             Lowercase::with_slice("�".as_bytes()).size_hint(),
-            (3, Some(36))
+            (1, Some(4))
         );
         assert_eq!(
             Lowercase::with_slice("Έτος".as_bytes()).size_hint(),
-            (8, Some(96))
+            (4, Some(12))
         );
         assert_eq!(
             Lowercase::with_slice("ZȺȾ".as_bytes()).size_hint(),
-            (5, Some(60))
+            (3, Some(7))
         );
 
         let mut utf8_with_invalid_bytes = b"\xFF\xFE".to_vec();
         utf8_with_invalid_bytes.extend_from_slice("Έτος".as_bytes());
         assert_eq!(
             Lowercase::with_slice(&utf8_with_invalid_bytes).size_hint(),
-            (10, Some(120))
+            (6, Some(15))
         );
     }
 
@@ -319,6 +609,31 @@ mod tests {
         assert_eq!(Lowercase::with_slice(&utf8_with_invalid_bytes).count(), 10);
     }
 
+    #[test]
+    fn count_matches_collected_len() {
+        let inputs: [&[u8]; 7] = [
+            "ZȺȾ".as_bytes(),
+            "ΑΎΡΙΟ ΟΔΟΣ Σ".as_bytes(),
+            b"abc, \xFF\xFE, xyz",
+            b"\xFF\xFE\xCE",
+            b"aB\xF0\x9F\x87Yz",
+            // `İ`, an invalid byte, `ẞ`, and a truncated 4-byte sequence.
+            b"\xC4\xB0\xFF\xE1\xBA\x9E\xF0\x9F\x87",
+            "\u{FFFD}Ⱥ\u{1F600}".as_bytes(),
+        ];
+        for input in inputs {
+            let iter = Lowercase::with_slice(input);
+            assert_eq!(iter.clone().count(), iter.collect::<Vec<_>>().len());
+
+            // Partially consume both ends so the count includes buffered
+            // bytes and chars.
+            let mut iter = Lowercase::with_slice(input);
+            iter.next();
+            iter.next_back();
+            assert_eq!(iter.clone().count(), iter.collect::<Vec<_>>().len());
+        }
+    }
+
     #[test]
     fn size_hint_covers_count() {
         let iter = Lowercase::with_slice(b"");
@@ -372,7 +687,245 @@ mod tests {
         let iter = Lowercase::from(s);
         assert_eq!(
             format!("{iter:?}"),
-            "Lowercase { slice: \"Αύριο\", next_bytes: [0, 0, 0, 0], next_range: 0..0, lowercase: None }"
+            "Lowercase { source: \"Αύριο\", offset: 0, slice: \"Αύριο\", next_bytes: [], lowercase: None, next_back_bytes: [], lowercase_back: None }"
+        );
+    }
+
+    #[test]
+    fn rev() {
+        let inputs: [&[u8]; 8] = [
+            b"",
+            b"abc, xyz",
+            "ßﬃ".as_bytes(),
+            "Αύριο Έτος".as_bytes(),
+            "ΟΔΟΣ ΣΑΣ Σ".as_bytes(),
+            "zⱥⱦ ǅ ὖ ῷ".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF\xFE",
+            "\u{1F600} ẞ İ ΐ".as_bytes(),
+        ];
+        for input in inputs {
+            let mut rev = Lowercase::from(input).rev().collect::<Vec<_>>();
+            rev.reverse();
+            let forward = Lowercase::from(input).collect::<Vec<_>>();
+            assert_eq!(rev.as_bstr(), forward.as_bstr());
+        }
+    }
+
+    #[test]
+    fn next_and_next_back_interleaved() {
+        // `ß` and `ﬃ` are lowercase and map to themselves.
+        let mut iter = Lowercase::from("ßﬃ".as_bytes());
+        assert_eq!(iter.next(), Some(0xC3));
+        assert_eq!(iter.next_back(), Some(0x83));
+        assert_eq!(iter.next(), Some(0x9F));
+        assert_eq!(iter.next_back(), Some(0xAC));
+        assert_eq!(iter.next_back(), Some(0xEF));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        // `İ` lowercases to `i` followed by U+0307 COMBINING DOT ABOVE.
+        let mut iter = Lowercase::from("İ".as_bytes());
+        assert_eq!(iter.next_back(), Some(0x87));
+        assert_eq!(iter.next(), Some(b'i'));
+        assert_eq!(iter.next_back(), Some(0xCC));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = Lowercase::from("ẞİ".as_bytes());
+        assert_eq!(iter.next_back(), Some(0x87));
+        assert_eq!(iter.next_back(), Some(0xCC));
+        assert_eq!(iter.next_back(), Some(b'i'));
+        assert_eq!(iter.next(), Some(0xC3));
+        assert_eq!(iter.next_back(), Some(0x9F));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn next_and_next_back_interleaved_multi_byte() {
+        // Every split of interleaved `next` and `next_back` calls yields the
+        // same bytes as forward iteration.
+        let inputs: [&[u8]; 6] = [
+            "Σİ".as_bytes(),
+            "ΑΣ Σ".as_bytes(),
+            "İȺȾ".as_bytes(),
+            "ΑΎΡΙΟ".as_bytes(),
+            b"a\xF0\x9F\x87\xFFz",
+            // Both chars shrink when lowercased.
+            "ẞ\u{212A}".as_bytes(),
+        ];
+        for input in inputs {
+            let expected = Lowercase::from(input).collect::<Vec<_>>();
+            for pattern in 0_u32..(1 << expected.len()) {
+                let mut iter = Lowercase::from(input);
+                let mut front = Vec::new();
+                let mut back = Vec::new();
+                for bit in 0..expected.len() {
+                    if pattern & (1 << bit) == 0 {
+                        front.push(iter.next().unwrap());
+                    } else {
+                        back.push(iter.next_back().unwrap());
+                    }
+                    let (min, max) = iter.size_hint();
+                    let count = iter.clone().count();
+                    assert!(min <= count);
+                    assert!(count <= max.unwrap());
+                }
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+                back.reverse();
+                front.extend(back);
+                assert_eq!(front.as_bstr(), expected.as_bstr());
+            }
+        }
+    }
+
+    fn per_scalar_lowercase(slice: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut buf = [0; 4];
+        let mut slice = slice;
+        while !slice.is_empty() {
+            let (ch, size) = bstr::decode_utf8(slice);
+            match ch {
+                Some(ch) => {
+                    for ch in ch.to_lowercase() {
+                        out.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+                    }
+                }
+                None => out.extend_from_slice(&slice[..size]),
+            }
+            slice = &slice[size..];
+        }
+        out
+    }
+
+    #[test]
+    fn mostly_ascii_matches_per_scalar_mapping() {
+        let mut input = Vec::new();
+        for i in 0..512 {
+            input.extend_from_slice(b"The Quick Brown Fox Jumps Over The Lazy Dog. ");
+            match i % 4 {
+                0 => input.extend_from_slice("École ΑΎΡΙΟ ß İ ".as_bytes()),
+                1 => input.extend_from_slice(b"\xFF\xF0\x9F\x87 "),
+                _ => {}
+            }
+        }
+        let expected = per_scalar_lowercase(&input);
+
+        let forward = Lowercase::from(input.as_slice()).collect::<Vec<_>>();
+        assert_eq!(forward.as_bstr(), expected.as_bstr());
+
+        let mut rev = Lowercase::from(input.as_slice()).rev().collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev.as_bstr(), expected.as_bstr());
+
+        assert_eq!(Lowercase::from(input.as_slice()).count(), expected.len());
+    }
+
+    #[test]
+    fn ascii_run_then_non_ascii() {
+        let inputs: [&[u8]; 4] = [
+            "abcXYZ ǅ".as_bytes(),
+            "ǅ abcXYZ".as_bytes(),
+            b"abc\xFFXYZ",
+            "ßaBcß".as_bytes(),
+        ];
+        for input in inputs {
+            let expected = per_scalar_lowercase(input);
+            let iter = Lowercase::from(input);
+            assert_eq!(iter.collect::<Vec<_>>().as_bstr(), expected.as_bstr());
+        }
+    }
+
+    #[test]
+    fn size_hint_covers_count_for_shrinking_chars() {
+        // `ẞ` U+1E9E and `K` U+212A KELVIN SIGN are three bytes and lowercase to
+        // the two byte `ß` and the one byte `k`.
+        let inputs: [&[u8]; 3] = [
+            "ẞẞẞ".as_bytes(),
+            "\u{212A}\u{212A}\u{212A}".as_bytes(),
+            "ẞ \u{212A}elvin İ".as_bytes(),
+        ];
+        for input in inputs {
+            let mut iter = Lowercase::from(input);
+            loop {
+                let (min, max) = iter.size_hint();
+                let count = iter.clone().count();
+                assert!(min <= count);
+                assert!(count <= max.unwrap());
+                if iter.next().is_none() {
+                    break;
+                }
+            }
+        }
+        assert_eq!(
+            Lowercase::from("\u{212A}\u{212A}".as_bytes()).size_hint(),
+            (2, Some(9))
         );
     }
+
+    #[test]
+    fn fused_after_invalid_utf8_tail() {
+        let inputs: [&[u8]; 5] = [
+            b"abc\xFF",
+            b"ABC\xFF",
+            "ΑΎΡΙΟΣ".as_bytes(),
+            b"\xCE\xA3\xF0\x9F\x87",
+            b"\xFF\xFE",
+        ];
+        for input in inputs {
+            let mut iter = Lowercase::from(input);
+            while iter.next().is_some() {}
+            for _ in 0..4 {
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+                assert_eq!(iter.size_hint(), (0, Some(0)));
+            }
+
+            // Partially buffered output from reverse iteration is drained
+            // once, not resurrected after exhaustion.
+            let mut iter = Lowercase::from(input);
+            let back = iter.next_back();
+            assert!(back.is_some());
+            let forward = iter.by_ref().collect::<Vec<_>>();
+            assert_eq!(forward.len() + 1, Lowercase::from(input).count());
+            for _ in 0..4 {
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn last_matches_collected_last() {
+        let inputs: [&[u8]; 8] = [
+            b"",
+            b"abc XYZ",
+            "ΑΎΡΙΟΣ".as_bytes(),
+            "xİ".as_bytes(),
+            "İ".as_bytes(),
+            b"ABC\xFF",
+            b"ABC\xF0\x9F\x87",
+            b"\xFF\xFE",
+        ];
+        for input in inputs {
+            let expected = Lowercase::from(input).collect::<Vec<_>>();
+            assert_eq!(Lowercase::from(input).last(), expected.last().copied());
+
+            // Partially consumed iterators, including those in the middle of
+            // an expansion, yield the same last byte.
+            for skip in 0..expected.len() {
+                let mut iter = Lowercase::from(input);
+                for _ in 0..skip {
+                    iter.next();
+                }
+                assert_eq!(iter.last(), expected.last().copied());
+            }
+
+            let mut iter = Lowercase::from(input);
+            if iter.next_back().is_some() {
+                let len = expected.len();
+                assert_eq!(iter.last(), expected[..len - 1].last().copied());
+            }
+        }
+    }
 }
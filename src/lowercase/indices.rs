@@ -0,0 +1,257 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use super::chars::lowercase_char;
+use crate::buffer::OutputBuffer;
+use crate::unicode::std_case_mapping_iter::CaseMappingIter;
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, LowercaseMode, UnsupportedCaseMappingMode};
+
+/// Returns an iterator that yields the bytes of the given slice with all
+/// uppercase letters replaced with their lowercase counterparts, along with the
+/// byte offset in the input which produced each byte.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string] and
+/// yields the same bytes as [`lowercase`]. All bytes produced by mapping a
+/// UTF-8 scalar value share the offset of the start of that scalar in the
+/// input, including scalars which expand to several characters when
+/// lowercased. Invalid UTF-8 bytes are yielded as is and each is paired with
+/// its own offset in the input.
+///
+/// This is the case mapping equivalent of [`str::char_indices`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// let lowercase = roe::lowercase_indices(b"aBc", LowercaseMode::Full);
+/// assert_eq!(lowercase.collect::<Vec<_>>(), [(0, b'a'), (1, b'b'), (2, b'c')]);
+///
+/// // `İ` is two bytes in the input and expands to `i` and a combining dot.
+/// let lowercase = roe::lowercase_indices("xİ".as_bytes(), LowercaseMode::Full);
+/// let offsets = lowercase.map(|(offset, _)| offset).collect::<Vec<_>>();
+/// assert_eq!(offsets, [0, 1, 1, 1]);
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [`lowercase`]: crate::lowercase()
+pub fn lowercase_indices(slice: &[u8], options: LowercaseMode) -> LowercaseIndices<'_> {
+    if options == LowercaseMode::Turkic {
+        let err = UnsupportedCaseMappingMode::new(CaseMapping::Lowercase(options));
+        panic!("{}", err);
+    }
+    LowercaseIndices {
+        source: slice,
+        offset: 0,
+        start: 0,
+        mode: options,
+        next_bytes: OutputBuffer::new(),
+        lowercase: CaseMappingIter::Zero,
+    }
+}
+
+/// An iterator that yields the lowercase equivalent of a conventionally UTF-8
+/// byte string along with the input byte offset of each yielded byte.
+///
+/// This struct is created by the [`lowercase_indices`] function. See its
+/// documentation for more.
+///
+/// [`lowercase_indices`]: crate::lowercase_indices()
+#[derive(Clone)]
+#[must_use = "LowercaseIndices is a Iterator and must be used"]
+pub struct LowercaseIndices<'a> {
+    source: &'a [u8],
+    offset: usize,
+    start: usize,
+    mode: LowercaseMode,
+    next_bytes: OutputBuffer,
+    lowercase: CaseMappingIter,
+}
+
+impl fmt::Debug for LowercaseIndices<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LowercaseIndices")
+            .field("source", &self.source.as_bstr())
+            .field("offset", &self.offset)
+            .field("start", &self.start)
+            .field("mode", &self.mode)
+            .field("next_bytes", &self.next_bytes)
+            .field("lowercase", &self.lowercase)
+            .finish()
+    }
+}
+
+impl<'a> LowercaseIndices<'a> {
+    /// Views the not yet case mapped portion of the source slice.
+    ///
+    /// Like [`Lowercase::as_slice`], the returned slice may begin in the middle
+    /// of an expansion.
+    ///
+    /// [`Lowercase::as_slice`]: crate::Lowercase::as_slice
+    #[must_use]
+    pub fn as_slice(&self) -> &'a [u8] {
+        &self.source[self.offset..]
+    }
+
+    fn encode_next_char(&mut self) -> Option<(usize, u8)> {
+        let ch = self.lowercase.next()?;
+        self.next_bytes.push_char(ch);
+        let byte = self.next_bytes.next_byte()?;
+        Some((self.start, byte))
+    }
+}
+
+impl Iterator for LowercaseIndices<'_> {
+    type Item = (usize, u8);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_bytes.next_byte() {
+            return Some((self.start, byte));
+        }
+        if let Some(item) = self.encode_next_char() {
+            return Some(item);
+        }
+        let offset = self.offset;
+        match bstr::decode_utf8(&self.source[offset..]) {
+            (_, 0) => None,
+            (Some(ch), size) => {
+                self.offset += size;
+                self.start = offset;
+                let (preceding, following) =
+                    (&self.source[..offset], &self.source[offset + size..]);
+                let chars = lowercase_char(preceding, following, ch, self.mode);
+                self.lowercase = CaseMappingIter::new(chars);
+                self.encode_next_char()
+            }
+            // Each byte of an invalid UTF-8 byte sequence is its own source.
+            (None, _) => {
+                self.offset += 1;
+                Some((offset, self.source[offset]))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        const UTF_8_CHAR_MAX_BYTES: usize = 4;
+        // Bytes of the current char which have not been yielded yet plus the
+        // chars of the current case mapping which have not been encoded.
+        let (pending_chars, _) = self.lowercase.size_hint();
+        let pending_min = self.next_bytes.len() + pending_chars;
+        let pending_max = self.next_bytes.len() + pending_chars * UTF_8_CHAR_MAX_BYTES;
+        let remaining = self.as_slice();
+        let (num, den) = max_expansion_ratio(CaseMapping::Lowercase(self.mode));
+        let max = remaining.len().checked_mul(num).map(|max| max / den);
+        (
+            lead_byte_count(remaining) + pending_min,
+            max.and_then(|max| max.checked_add(pending_max)),
+        )
+    }
+}
+
+impl FusedIterator for LowercaseIndices<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::lowercase_indices;
+    use crate::LowercaseMode;
+
+    const INPUTS: [&[u8]; 8] = [
+        b"",
+        b"ABC, xyz",
+        "ΑΎΡΙΟ ΟΔΟΣ Σ".as_bytes(),
+        "İ ẞ ΐ ﬃ \u{212A}".as_bytes(),
+        "ǄUNGLA ǅ".as_bytes(),
+        "I\u{300} Ì Į\u{301}".as_bytes(),
+        b"\xFF\xFEABC",
+        b"aB\xF0\x9F\x87Yz\xFF\xFE\xCE",
+    ];
+
+    const MODES: [LowercaseMode; 4] = [
+        LowercaseMode::Full,
+        LowercaseMode::Ascii,
+        LowercaseMode::Lithuanian,
+        LowercaseMode::Fold,
+    ];
+
+    #[test]
+    fn bytes_match_lowercase() {
+        for input in INPUTS {
+            for mode in MODES {
+                let bytes = lowercase_indices(input, mode)
+                    .map(|(_, byte)| byte)
+                    .collect::<Vec<_>>();
+                let expected = crate::lowercase(input, mode).collect::<Vec<_>>();
+                assert_eq!(bytes.as_bstr(), expected.as_bstr());
+            }
+        }
+    }
+
+    #[test]
+    fn expansions_share_offsets() {
+        let indices = lowercase_indices("xﬃy".as_bytes(), LowercaseMode::Fold).collect::<Vec<_>>();
+        assert_eq!(
+            indices,
+            [(0, b'x'), (1, b'f'), (1, b'f'), (1, b'i'), (4, b'y')]
+        );
+
+        // `ﬃ` is lowercase, so it is only expanded by case folding.
+        let indices = lowercase_indices("ﬃ".as_bytes(), LowercaseMode::Full).collect::<Vec<_>>();
+        assert_eq!(indices, [(0, 0xEF), (0, 0xAC), (0, 0x83)]);
+
+        let indices = lowercase_indices("aİ".as_bytes(), LowercaseMode::Full).collect::<Vec<_>>();
+        assert_eq!(indices, [(0, b'a'), (1, b'i'), (1, 0xCC), (1, 0x87)]);
+    }
+
+    #[test]
+    fn invalid_utf8_bytes_have_own_offsets() {
+        let indices = lowercase_indices(b"A\xF0\x9F\x87Z", LowercaseMode::Full).collect::<Vec<_>>();
+        assert_eq!(
+            indices,
+            [(0, b'a'), (1, 0xF0), (2, 0x9F), (3, 0x87), (4, b'z')]
+        );
+    }
+
+    #[test]
+    fn final_sigma_uses_context() {
+        let indices =
+            lowercase_indices("ΟΣ ΣΑ".as_bytes(), LowercaseMode::Full).collect::<Vec<_>>();
+        let bytes = indices.iter().map(|&(_, byte)| byte).collect::<Vec<_>>();
+        assert_eq!(bytes.as_bstr(), "ος σα".as_bytes().as_bstr());
+        let offsets = indices
+            .iter()
+            .map(|&(offset, _)| offset)
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, [0, 0, 2, 2, 4, 5, 5, 7, 7]);
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        for input in INPUTS {
+            let mut indices = lowercase_indices(input, LowercaseMode::Fold);
+            loop {
+                let (min, max) = indices.size_hint();
+                let count = indices.clone().count();
+                assert!(min <= count);
+                assert!(count <= max.unwrap());
+                if indices.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not yet implemented")]
+    fn turkic_mode_panics() {
+        let _ = lowercase_indices(b"I", LowercaseMode::Turkic);
+    }
+}
@@ -0,0 +1,476 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use crate::buffer::OutputBuffer;
+use crate::unicode::lithuanian::ToLithuanianLowercase;
+use crate::unicode::sigma::{lowercase_sigma, GREEK_CAPITAL_LETTER_SIGMA};
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, LowercaseMode};
+
+// Mapped output is buffered separately for each end of the iterator. Bytes
+// which are not yet yielded are laid out, in order, as:
+//
+// 1. `next_bytes`, the rest of the front char.
+// 2. The chars remaining in `lowercase`.
+// 3. The lowercase mapping of `source[start..end]`.
+// 4. The chars remaining in `lowercase_back`.
+// 5. `next_back_bytes`, the rest of the back char.
+//
+// The rest of `source` is kept around as context for the `More_Above` and
+// final sigma rules.
+// Once the slice is exhausted, each end drains the buffers of the other end.
+#[derive(Clone)]
+#[must_use = "Lowercase is a Iterator and must be used"]
+pub struct Lowercase<'a> {
+    source: &'a [u8],
+    start: usize,
+    end: usize,
+    next_bytes: OutputBuffer,
+    lowercase: Option<ToLithuanianLowercase>,
+    next_back_bytes: OutputBuffer,
+    lowercase_back: Option<ToLithuanianLowercase>,
+}
+
+impl fmt::Debug for Lowercase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lowercase")
+            .field("slice", &self.as_slice().as_bstr())
+            .field("next_bytes", &self.next_bytes)
+            .field("lowercase", &self.lowercase)
+            .field("next_back_bytes", &self.next_back_bytes)
+            .field("lowercase_back", &self.lowercase_back)
+            .finish()
+    }
+}
+
+impl<'a> From<&'a [u8]> for Lowercase<'a> {
+    fn from(slice: &'a [u8]) -> Self {
+        Self::with_slice(slice)
+    }
+}
+
+impl<'a> Lowercase<'a> {
+    pub const fn with_slice(slice: &'a [u8]) -> Self {
+        Self {
+            source: slice,
+            start: 0,
+            end: slice.len(),
+            next_bytes: OutputBuffer::new(),
+            lowercase: None,
+            next_back_bytes: OutputBuffer::new(),
+            lowercase_back: None,
+        }
+    }
+
+    fn pending_chars(&self) -> usize {
+        let front = self.lowercase.as_ref().map_or(0, ExactSizeIterator::len);
+        let back = self
+            .lowercase_back
+            .as_ref()
+            .map_or(0, ExactSizeIterator::len);
+        front + back
+    }
+
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.source.split_at(self.end).0.split_at(self.start).1
+    }
+
+    /// Whether the remaining slice ends with an `I` or `J` whose `More_Above`
+    /// context has been consumed by reverse iteration.
+    ///
+    /// Forward iteration looks for the context in the rest of the source, so
+    /// the letter still keeps its dot and expands to three bytes.
+    fn ends_with_detached_soft_dotted(&self) -> bool {
+        self.end < self.source.len() && matches!(self.as_slice().last(), Some(b'I' | b'J'))
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::with_slice(self.source);
+    }
+}
+
+impl Iterator for Lowercase<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_bytes.next_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self.lowercase.as_mut().and_then(Iterator::next) {
+            self.next_bytes.push_char(ch);
+            return self.next_bytes.next_byte();
+        }
+
+        self.lowercase = None;
+
+        let slice = self.as_slice();
+        match bstr::decode_utf8(slice) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // reverse iteration.
+                if let Some(ch) = self.lowercase_back.as_mut().and_then(Iterator::next) {
+                    self.next_bytes.push_char(ch);
+                    return self.next_bytes.next_byte();
+                }
+                self.next_back_bytes.next_byte()
+            }
+            (Some(GREEK_CAPITAL_LETTER_SIGMA), size) => {
+                let preceding = &self.source[..self.start];
+                self.start += size;
+                let following = &self.source[self.start..];
+                self.next_bytes
+                    .push_char(lowercase_sigma(preceding, following));
+                self.next_bytes.next_byte()
+            }
+            (Some(ch), size) => {
+                self.start += size;
+                // The Lithuanian rules for `I`, `J`, and `Į` depend on the
+                // combining marks which follow the letter.
+                let following = &self.source[self.start..];
+                let mut lowercase = ToLithuanianLowercase::new(ch, following);
+                let ch = lowercase
+                    .next()
+                    .expect("ToLithuanianLowercase yields at least one char");
+                self.next_bytes.push_char(ch);
+
+                self.lowercase = Some(lowercase);
+                self.next_bytes.next_byte()
+            }
+            (None, size) => {
+                self.next_bytes.push_bytes(&slice[..size]);
+                self.start += size;
+                self.next_bytes.next_byte()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        const UTF_8_CHAR_MAX_BYTES: usize = 4;
+        const TO_LOWER_RATIO: (usize, usize) =
+            max_expansion_ratio(CaseMapping::Lowercase(LowercaseMode::Lithuanian));
+        // Bytes of the current chars which have not been yielded yet plus the
+        // chars of the current lowercase expansions which have not been
+        // encoded.
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        let pending_chars = self.pending_chars();
+        let pending_min = pending_bytes + pending_chars;
+        let pending_max = pending_bytes + pending_chars * UTF_8_CHAR_MAX_BYTES;
+        let slice = self.as_slice();
+        let len = slice.len();
+        let detached = self.ends_with_detached_soft_dotted();
+        if slice.is_ascii() && !detached {
+            // The Lithuanian rules only apply to letters followed by combining
+            // marks, none of which remain for an ASCII slice, so each byte
+            // maps to exactly one byte.
+            (len + pending_min, Some(len + pending_max))
+        } else {
+            // Every char and invalid UTF-8 byte sequence in the remaining
+            // slice yields at least one byte, and no mapping expands by more
+            // than the maximum expansion ratio. The exception is a trailing
+            // `I` or `J` whose accent has already been yielded from the back,
+            // which maps to up to three bytes.
+            let (num, den) = TO_LOWER_RATIO;
+            let (len, trailing) = if detached { (len - 1, 3) } else { (len, 0) };
+            let max = len
+                .checked_mul(num)
+                .and_then(|max| (max / den).checked_add(trailing));
+            (
+                lead_byte_count(slice) + pending_min,
+                max.and_then(|max| max.checked_add(pending_max)),
+            )
+        }
+    }
+
+    fn count(self) -> usize {
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        if self.as_slice().is_ascii()
+            && !self.ends_with_detached_soft_dotted()
+            && self.pending_chars() == 0
+        {
+            pending_bytes + self.as_slice().len()
+        } else {
+            self.fold(0, |acc, _| acc + 1)
+        }
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        // The last byte is the last byte of the final scalar's mapping, which
+        // reverse iteration maps without visiting the rest of the slice.
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Lowercase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_back_bytes.next_back_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self
+            .lowercase_back
+            .as_mut()
+            .and_then(DoubleEndedIterator::next_back)
+        {
+            self.next_back_bytes.push_char(ch);
+            return self.next_back_bytes.next_back_byte();
+        }
+
+        self.lowercase_back = None;
+
+        let slice = self.as_slice();
+        match bstr::decode_last_utf8(slice) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // forward iteration.
+                if let Some(ch) = self
+                    .lowercase
+                    .as_mut()
+                    .and_then(DoubleEndedIterator::next_back)
+                {
+                    self.next_back_bytes.push_char(ch);
+                    return self.next_back_bytes.next_back_byte();
+                }
+                self.next_bytes.next_back_byte()
+            }
+            (Some(GREEK_CAPITAL_LETTER_SIGMA), size) => {
+                let following = &self.source[self.end..];
+                self.end -= size;
+                let preceding = &self.source[..self.end];
+                self.next_back_bytes
+                    .push_char(lowercase_sigma(preceding, following));
+                self.next_back_bytes.next_back_byte()
+            }
+            (Some(ch), size) => {
+                let following = &self.source[self.end..];
+                self.end -= size;
+                // Multi-char mappings are yielded back to front, starting with
+                // the last char of the mapping.
+                let mut lowercase = ToLithuanianLowercase::new(ch, following);
+                let ch = lowercase
+                    .next_back()
+                    .expect("ToLithuanianLowercase yields at least one char");
+                self.next_back_bytes.push_char(ch);
+
+                self.lowercase_back = Some(lowercase);
+                self.next_back_bytes.next_back_byte()
+            }
+            (None, size) => {
+                self.next_back_bytes
+                    .push_bytes(&slice[slice.len() - size..]);
+                self.end -= size;
+                self.next_back_bytes.next_back_byte()
+            }
+        }
+    }
+}
+
+impl FusedIterator for Lowercase<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::Lowercase;
+
+    fn lowercase(s: &str) -> Vec<u8> {
+        Lowercase::from(s.as_bytes()).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(lowercase("").as_bstr(), b"".as_bstr());
+    }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(lowercase("ABC, xyz IJ").as_bstr(), b"abc, xyz ij".as_bstr());
+    }
+
+    // Examples from the Lithuanian section of `SpecialCasing.txt`.
+    #[test]
+    fn special_casing_unconditional() {
+        assert_eq!(
+            lowercase("Ì").as_bstr(),
+            "i\u{307}\u{300}".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            lowercase("Í").as_bstr(),
+            "i\u{307}\u{301}".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            lowercase("Ĩ").as_bstr(),
+            "i\u{307}\u{303}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn special_casing_more_above() {
+        assert_eq!(
+            lowercase("I\u{300}").as_bstr(),
+            "i\u{307}\u{300}".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            lowercase("J\u{301}").as_bstr(),
+            "j\u{307}\u{301}".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            lowercase("Į\u{303}").as_bstr(),
+            "į\u{307}\u{303}".as_bytes().as_bstr()
+        );
+        // Marks which attach below do not end the `More_Above` context.
+        assert_eq!(
+            lowercase("Į\u{323}\u{301}").as_bstr(),
+            "į\u{307}\u{323}\u{301}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn not_more_above() {
+        assert_eq!(lowercase("Į").as_bstr(), "į".as_bytes().as_bstr());
+        assert_eq!(lowercase("ĮI").as_bstr(), "įi".as_bytes().as_bstr());
+        assert_eq!(
+            lowercase("Įa\u{300}").as_bstr(),
+            "įa\u{300}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn other_characters_match_full_mode() {
+        let inputs: [&[u8]; 6] = [
+            b"ABC, xyz",
+            "ΑΎΡΙΟ".as_bytes(),
+            "ΟΔΟΣ ΣΑΣ Σ".as_bytes(),
+            "ǄǅǆǊ".as_bytes(),
+            "İȺȾ".as_bytes(),
+            b"AB\xF0\x9F\x87Yz\xFF\xFE",
+        ];
+        for input in inputs {
+            let lithuanian = Lowercase::from(input).collect::<Vec<_>>();
+            let full = crate::lowercase::full::Lowercase::from(input).collect::<Vec<_>>();
+            assert_eq!(lithuanian.as_bstr(), full.as_bstr());
+        }
+    }
+
+    #[test]
+    fn rev() {
+        let inputs: [&[u8]; 6] = [
+            b"",
+            b"ABC, xyz IJ",
+            "ÌÍĨ".as_bytes(),
+            "I\u{300}J\u{301}Į\u{323}\u{301} Įa\u{300}".as_bytes(),
+            "ΑΎΡΙΟ İȺȾ".as_bytes(),
+            b"I\xFF\xCC\x80 A\xF0\x9F\x87z",
+        ];
+        for input in inputs {
+            let mut rev = Lowercase::from(input).rev().collect::<Vec<_>>();
+            rev.reverse();
+            let forward = Lowercase::from(input).collect::<Vec<_>>();
+            assert_eq!(rev.as_bstr(), forward.as_bstr());
+        }
+    }
+
+    #[test]
+    fn next_and_next_back_interleaved() {
+        // `I` keeps its dot when the accent which follows it has already been
+        // yielded by reverse iteration.
+        let mut iter = Lowercase::from("I\u{300}".as_bytes());
+        assert_eq!(iter.next_back(), Some(0x80));
+        assert_eq!(iter.next_back(), Some(0xCC));
+        assert_eq!(iter.next(), Some(b'i'));
+        assert_eq!(iter.next_back(), Some(0x87));
+        assert_eq!(iter.next_back(), Some(0xCC));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = Lowercase::from("Ì".as_bytes());
+        assert_eq!(iter.next(), Some(b'i'));
+        assert_eq!(iter.next_back(), Some(0x80));
+        assert_eq!(iter.next(), Some(0xCC));
+        assert_eq!(iter.next(), Some(0x87));
+        assert_eq!(iter.next_back(), Some(0xCC));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn final_sigma() {
+        assert_eq!(lowercase("ΟΔΟΣ").as_bstr(), "οδος".as_bytes().as_bstr());
+        assert_eq!(
+            lowercase("ΣΑΣ Σ ΑΣ\u{301}Ì").as_bstr(),
+            "σας σ ασ\u{301}i\u{307}\u{300}".as_bytes().as_bstr()
+        );
+
+        let mut rev = Lowercase::from("ΟΔΟΣ ΣΑΣ".as_bytes())
+            .rev()
+            .collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev.as_bstr(), "οδος σας".as_bytes().as_bstr());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let iter = Lowercase::from(&b"I\xFF\xCC\x80"[..]);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"i\xFF\xCC\x80".as_bstr()
+        );
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(Lowercase::with_slice(b"").count(), 0);
+        assert_eq!(Lowercase::with_slice(b"ABC, xyz").count(), 8);
+        assert_eq!(Lowercase::with_slice("Ì".as_bytes()).count(), 5);
+        assert_eq!(Lowercase::with_slice("I\u{300}".as_bytes()).count(), 5);
+
+        let mut iter = Lowercase::with_slice("Ì".as_bytes());
+        assert_eq!(iter.next(), Some(b'i'));
+        assert_eq!(iter.count(), 4);
+
+        // `I` and `J` keep their dots when the accents which follow them have
+        // been yielded by reverse iteration.
+        let mut iter = Lowercase::with_slice("I\u{300}J".as_bytes());
+        assert_eq!(iter.next_back(), Some(b'j'));
+        assert_eq!(iter.next_back(), Some(0x80));
+        assert_eq!(iter.clone().count(), 4);
+        assert_eq!(iter.count(), 4);
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        let inputs: [&[u8]; 8] = [
+            b"",
+            b"ABC, xyz",
+            "ÌÍĨ".as_bytes(),
+            "I\u{300}J\u{301}".as_bytes(),
+            "ÌI\u{301}".as_bytes(),
+            "AJ\u{323}\u{301}".as_bytes(),
+            "\u{212A}\u{212A}".as_bytes(),
+            b"I\xFF\xCC\x80",
+        ];
+        for input in inputs {
+            // Every combination of front and back steps, so that the context
+            // of a trailing `I` or `J` may already have been consumed.
+            for back_steps in 0..=input.len() * 3 {
+                let mut iter = Lowercase::with_slice(input);
+                for _ in 0..back_steps {
+                    iter.next_back();
+                }
+                loop {
+                    let (min, max) = iter.size_hint();
+                    let count = iter.clone().count();
+                    assert_eq!(count, iter.clone().fold(0, |acc, _| acc + 1));
+                    assert!(min <= count);
+                    assert!(count <= max.unwrap());
+                    if iter.next().is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use super::Lowercase;
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, LowercaseMode, UnsupportedCaseMappingMode};
+
+/// The UTF-8 encoding of U+FFFD REPLACEMENT CHARACTER.
+const REPLACEMENT_CHARACTER: &[u8] = "\u{FFFD}".as_bytes();
+
+/// Returns an iterator that yields the bytes of the given slice with all
+/// uppercase letters replaced with their lowercase counterparts and all invalid
+/// UTF-8 byte sequences replaced with U+FFFD REPLACEMENT CHARACTER.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string]
+/// and maps its valid UTF-8 the same as [`lowercase`]. Unlike [`lowercase`],
+/// which yields invalid UTF-8 byte sequences as is, each maximal invalid
+/// subpart of the slice is replaced with the three byte encoding of `�`, so
+/// the output is always valid UTF-8. This is the same substitution strategy
+/// used by [`String::from_utf8_lossy`].
+///
+/// Invalid UTF-8 byte sequences end the context of context-dependent mappings
+/// like final sigma.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// let lowercase = roe::lowercase_lossy(b"ABC\xFF\xFEXYZ", LowercaseMode::Full);
+/// assert_eq!(lowercase.collect::<Vec<_>>(), "abc��xyz".as_bytes());
+///
+/// // A truncated multi-byte sequence is one maximal invalid subpart.
+/// let lowercase = roe::lowercase_lossy(b"\xF0\x9F\x87XYZ", LowercaseMode::Full);
+/// assert_eq!(lowercase.collect::<Vec<_>>(), "�xyz".as_bytes());
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [`lowercase`]: crate::lowercase()
+/// [`String::from_utf8_lossy`]: https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_lossy
+pub fn lowercase_lossy(slice: &[u8], options: LowercaseMode) -> LowercaseLossy<'_> {
+    if options == LowercaseMode::Turkic {
+        let err = UnsupportedCaseMappingMode::new(CaseMapping::Lowercase(options));
+        panic!("{}", err);
+    }
+    LowercaseLossy {
+        lowercase: Lowercase::new(),
+        replacement: b"",
+        remainder: slice,
+        mode: options,
+    }
+}
+
+/// An iterator that yields the lowercase equivalent of a conventionally UTF-8
+/// byte string with invalid UTF-8 byte sequences replaced with U+FFFD
+/// REPLACEMENT CHARACTER.
+///
+/// This struct is created by the [`lowercase_lossy`] function. See its
+/// documentation for more.
+///
+/// [`lowercase_lossy`]: crate::lowercase_lossy()
+#[derive(Clone)]
+#[must_use = "LowercaseLossy is a Iterator and must be used"]
+pub struct LowercaseLossy<'a> {
+    // The lowercase mapping of a run of valid UTF-8, followed by the
+    // replacement for the invalid UTF-8 byte sequence which ends the run.
+    lowercase: Lowercase<'a>,
+    replacement: &'static [u8],
+    remainder: &'a [u8],
+    mode: LowercaseMode,
+}
+
+impl fmt::Debug for LowercaseLossy<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LowercaseLossy")
+            .field("lowercase", &self.lowercase)
+            .field("replacement", &self.replacement.as_bstr())
+            .field("remainder", &self.remainder.as_bstr())
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl LowercaseLossy<'_> {
+    /// Split the next run of valid UTF-8 and the invalid UTF-8 byte sequence
+    /// which follows it off of the remainder.
+    fn next_chunk(&mut self) {
+        let chunk = self.remainder.utf8_chunks().next();
+        let (valid, invalid) =
+            chunk.map_or(("", &b""[..]), |chunk| (chunk.valid(), chunk.invalid()));
+        self.remainder = &self.remainder[valid.len() + invalid.len()..];
+        self.lowercase = crate::lowercase(valid.as_bytes(), self.mode);
+        self.replacement = if invalid.is_empty() {
+            b""
+        } else {
+            REPLACEMENT_CHARACTER
+        };
+    }
+}
+
+impl Iterator for LowercaseLossy<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(byte) = self.lowercase.next() {
+                return Some(byte);
+            }
+            if let Some((&byte, replacement)) = self.replacement.split_first() {
+                self.replacement = replacement;
+                return Some(byte);
+            }
+            if self.remainder.is_empty() {
+                return None;
+            }
+            self.next_chunk();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (min, max) = self.lowercase.size_hint();
+        let pending = self.replacement.len();
+        // Each invalid byte of the remainder is replaced with at most the
+        // three bytes of U+FFFD.
+        let (num, den) = max_expansion_ratio(CaseMapping::Lowercase(self.mode));
+        let num = num.max(REPLACEMENT_CHARACTER.len() * den);
+        let remainder_max = self.remainder.len().checked_mul(num).map(|max| max / den);
+        let min = min + pending + lead_byte_count(self.remainder);
+        let max = max
+            .and_then(|max| max.checked_add(pending))
+            .and_then(|max| max.checked_add(remainder_max?));
+        (min, max)
+    }
+}
+
+impl FusedIterator for LowercaseLossy<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::lowercase_lossy;
+    use crate::LowercaseMode;
+
+    const MODES: [LowercaseMode; 5] = [
+        LowercaseMode::Full,
+        LowercaseMode::Ascii,
+        LowercaseMode::Lithuanian,
+        LowercaseMode::Fold,
+        LowercaseMode::TurkicFold,
+    ];
+
+    fn lossy(slice: &[u8], mode: LowercaseMode) -> Vec<u8> {
+        lowercase_lossy(slice, mode).collect()
+    }
+
+    #[test]
+    fn valid_utf8_matches_lowercase() {
+        let inputs = ["", "ABC, xyz", "ΑΎΡΙΟ ΟΔΟΣ Σ", "İ ẞ ΐ ﬃ"];
+        for input in inputs {
+            for mode in MODES {
+                let expected = crate::lowercase(input.as_bytes(), mode).collect::<Vec<_>>();
+                assert_eq!(lossy(input.as_bytes(), mode).as_bstr(), expected.as_bstr());
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_bytes_are_replaced() {
+        for mode in MODES {
+            assert_eq!(
+                lossy(b"abc\xFF\xFExyz", mode).as_bstr(),
+                "abc\u{FFFD}\u{FFFD}xyz".as_bytes().as_bstr()
+            );
+            assert_eq!(
+                lossy(b"ABC\xFF\xFEXYZ", mode).as_bstr(),
+                "abc\u{FFFD}\u{FFFD}xyz".as_bytes().as_bstr()
+            );
+            assert_eq!(
+                lossy(b"\xFF", mode).as_bstr(),
+                "\u{FFFD}".as_bytes().as_bstr()
+            );
+        }
+    }
+
+    #[test]
+    fn maximal_subparts_are_replaced_once() {
+        // A truncated four byte sequence is a single maximal subpart.
+        assert_eq!(
+            lossy(b"A\xF0\x9F\x87Z", LowercaseMode::Full).as_bstr(),
+            "a\u{FFFD}z".as_bytes().as_bstr()
+        );
+        // A lead byte followed by a byte which cannot continue it ends the
+        // subpart, and the non-continuation byte starts a new one.
+        assert_eq!(
+            lossy(b"\xE2\x82\xFF", LowercaseMode::Full).as_bstr(),
+            "\u{FFFD}\u{FFFD}".as_bytes().as_bstr()
+        );
+        // Lone continuation bytes are each their own subpart.
+        assert_eq!(
+            lossy(b"\x80\x80", LowercaseMode::Full).as_bstr(),
+            "\u{FFFD}\u{FFFD}".as_bytes().as_bstr()
+        );
+        // The substitution matches `String::from_utf8_lossy`.
+        let input = b"aB\xF0\x9F\x87Yz\xFF\xFE\xCE";
+        let expected = alloc::string::String::from_utf8_lossy(input).to_lowercase();
+        assert_eq!(
+            lossy(input, LowercaseMode::Full).as_bstr(),
+            expected.as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn output_is_valid_utf8() {
+        let inputs: [&[u8]; 4] = [
+            b"\xFF\xFEABC",
+            b"aB\xF0\x9F\x87Yz\xFF\xFE\xCE",
+            b"\xCE\x9F\xCE\xA3\xFF",
+            b"\xCE\xA3\xED\xA0\x80",
+        ];
+        for input in inputs {
+            for mode in MODES {
+                assert!(lossy(input, mode).to_str().is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_utf8_ends_final_sigma_context() {
+        assert_eq!(
+            lossy(b"\xCE\x91\xCE\xA3\xFF", LowercaseMode::Full).as_bstr(),
+            "ας\u{FFFD}".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            lossy(b"\xFF\xCE\xA3", LowercaseMode::Full).as_bstr(),
+            "\u{FFFD}σ".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        let inputs: [&[u8]; 4] = [
+            b"",
+            b"\xFF\xFE\x80",
+            b"aB\xF0\x9F\x87Yz\xFF\xFE\xCE",
+            b"\xCE\x90\xFF\xC4\xB0",
+        ];
+        for input in inputs {
+            for mode in MODES {
+                let mut iter = lowercase_lossy(input, mode);
+                loop {
+                    let (min, max) = iter.size_hint();
+                    let count = iter.clone().count();
+                    assert!(min <= count);
+                    assert!(count <= max.unwrap());
+                    if iter.next().is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not yet implemented")]
+    fn turkic_mode_panics() {
+        let _ = lowercase_lossy(b"I", LowercaseMode::Turkic);
+    }
+}
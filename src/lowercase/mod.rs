@@ -1,14 +1,57 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
 use core::iter::FusedIterator;
 
+#[cfg(feature = "alloc")]
+use crate::buffer::MappedBytes;
+use crate::LowercaseMode;
+
 mod ascii;
+mod canonical_i;
+mod chars;
+mod chunks;
+mod except;
+mod fold;
 mod full;
+mod indices;
+mod lithuanian;
+mod lossy;
+#[cfg(feature = "alloc")]
+mod owned;
+mod stream;
+
+pub use canonical_i::LowercaseCanonicalI;
+pub use chars::{lowercase_chars, LowercaseChars};
+pub use chunks::{
+    lowercase_chunks, lowercase_for_each_chunk, LowercaseChunk, LowercaseChunks, MappedStr,
+};
+pub use except::LowercaseExcept;
+pub use indices::{lowercase_indices, LowercaseIndices};
+pub use lossy::{lowercase_lossy, LowercaseLossy};
+#[cfg(feature = "alloc")]
+pub use owned::{
+    extend_lowercase, lowercase_cow, lowercase_in_place, to_lowercase, to_lowercase_string,
+};
+pub use stream::LowercaseStreamer;
 
 #[derive(Debug, Clone)]
 #[allow(variant_size_differences)]
 enum Inner<'a> {
     Empty,
     Full(full::Lowercase<'a>),
+    // Full Unicode case mapping over a slice which is entirely ASCII produces
+    // the same output as ASCII case mapping. Slices which are detected to be
+    // ASCII-only at construction time use the cheaper ASCII iterator. The
+    // same holds for Unicode case folding, so the mode is kept alongside.
+    FullAscii(ascii::Lowercase<'a>, LowercaseMode),
     Ascii(ascii::Lowercase<'a>),
+    Fold(fold::Fold<'a>),
+    Lithuanian(lithuanian::Lowercase<'a>),
+    // A slice owned by the iterator is case mapped up front with the
+    // borrowing iterators above.
+    #[cfg(feature = "alloc")]
+    Owned(MappedBytes, LowercaseMode),
 }
 
 /// An iterator that yields the lowercase equivalent of a conventionally UTF-8
@@ -19,6 +62,20 @@ enum Inner<'a> {
 /// This struct is created by the [`lowercase`] function. See its documentation
 /// for more.
 ///
+/// # Reverse Iteration
+///
+/// `Lowercase` implements [`DoubleEndedIterator`] so iterators created with
+/// any case mapping mode can be reversed:
+///
+/// ```
+/// # use roe::Lowercase;
+/// let lowercase = Lowercase::with_ascii_slice(b"ABC");
+/// assert_eq!(lowercase.rev().collect::<Vec<_>>(), b"cba");
+///
+/// let lowercase = Lowercase::with_slice("ÀB".as_bytes());
+/// assert_eq!(lowercase.rev().collect::<Vec<_>>(), b"b\xA0\xC3");
+/// ```
+///
 /// [bytes]: u8
 /// [`lowercase`]: crate::lowercase()
 #[derive(Debug, Clone)]
@@ -37,6 +94,7 @@ impl<'a> Lowercase<'a> {
     /// let mut lowercase = Lowercase::new();
     /// assert_eq!(lowercase.next(), None);
     /// ```
+    #[allow(clippy::new_without_default)]
     pub const fn new() -> Self {
         Self { iter: Inner::Empty }
     }
@@ -80,8 +138,14 @@ impl<'a> Lowercase<'a> {
     /// assert_eq!(lowercase.collect::<Vec<_>>(), expected);
     /// ```
     pub const fn with_slice(slice: &'a [u8]) -> Self {
-        Self {
-            iter: Inner::Full(full::Lowercase::with_slice(slice)),
+        if slice.is_ascii() {
+            Self {
+                iter: Inner::FullAscii(ascii::Lowercase::with_slice(slice), LowercaseMode::Full),
+            }
+        } else {
+            Self {
+                iter: Inner::Full(full::Lowercase::with_slice(slice)),
+            }
         }
     }
 
@@ -122,24 +186,437 @@ impl<'a> Lowercase<'a> {
             iter: Inner::Ascii(ascii::Lowercase::with_slice(slice)),
         }
     }
+
+    /// Create a new lowercase iterator with the given byte slice using full
+    /// Unicode case mapping adapted for Lithuanian.
+    ///
+    /// Lithuanian retains the dot on a lowercase `i` or `j` which is followed
+    /// by an accent by inserting U+0307 COMBINING DOT ABOVE.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let lowercase = Lowercase::with_lithuanian_slice("Ì".as_bytes());
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), "i\u{307}\u{300}".as_bytes());
+    ///
+    /// let lowercase = Lowercase::with_lithuanian_slice("J\u{301}".as_bytes());
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), "j\u{307}\u{301}".as_bytes());
+    /// ```
+    ///
+    /// Characters not covered by a Lithuanian rule use their full Unicode
+    /// lowercase mapping:
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let lowercase = Lowercase::with_lithuanian_slice("ΑΎΡΙΟ".as_bytes());
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), "αύριο".as_bytes());
+    /// ```
+    pub const fn with_lithuanian_slice(slice: &'a [u8]) -> Self {
+        // The Lithuanian rules only apply to letters followed by combining
+        // marks, so ASCII-only slices map the same as for full case mapping.
+        if slice.is_ascii() {
+            Self {
+                iter: Inner::FullAscii(
+                    ascii::Lowercase::with_slice(slice),
+                    LowercaseMode::Lithuanian,
+                ),
+            }
+        } else {
+            Self {
+                iter: Inner::Lithuanian(lithuanian::Lowercase::with_slice(slice)),
+            }
+        }
+    }
+
+    /// Create a new lowercase iterator with the given byte slice using full
+    /// Unicode case folding.
+    ///
+    /// Case folding applies the common and full mappings in `CaseFolding.txt`
+    /// and is suitable for case-insensitive comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let lowercase = Lowercase::with_fold_slice("Straße".as_bytes());
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), b"strasse");
+    ///
+    /// let lowercase = Lowercase::with_fold_slice("ﬀ".as_bytes());
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), b"ff");
+    /// ```
+    ///
+    /// Invalid UTF-8 bytes are yielded as is without impacting Unicode
+    /// characters:
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let lowercase = Lowercase::with_fold_slice(b"ABC\xFF\xFEXYZ");
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), b"abc\xFF\xFExyz");
+    /// ```
+    pub const fn with_fold_slice(slice: &'a [u8]) -> Self {
+        if slice.is_ascii() {
+            Self {
+                iter: Inner::FullAscii(ascii::Lowercase::with_slice(slice), LowercaseMode::Fold),
+            }
+        } else {
+            Self {
+                iter: Inner::Fold(fold::Fold::with_slice(slice)),
+            }
+        }
+    }
+
+    /// Create a new lowercase iterator with the given byte slice using Turkic
+    /// Unicode case folding.
+    ///
+    /// Turkic case folding folds `I` to dotless `ı` and `İ` to `i`, and folds
+    /// all other characters like [`with_fold_slice`](Self::with_fold_slice).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let lowercase = Lowercase::with_turkic_fold_slice(b"FILE");
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), "fıle".as_bytes());
+    ///
+    /// let lowercase = Lowercase::with_turkic_fold_slice("İSTANBUL".as_bytes());
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), b"istanbul");
+    /// ```
+    pub const fn with_turkic_fold_slice(slice: &'a [u8]) -> Self {
+        // ASCII `I` is the only ASCII character which Turkic case folding
+        // maps outside of the ASCII range.
+        let mut idx = 0;
+        let mut is_ascii = true;
+        while idx < slice.len() {
+            if !slice[idx].is_ascii() || slice[idx] == b'I' {
+                is_ascii = false;
+                break;
+            }
+            idx += 1;
+        }
+        if is_ascii {
+            Self {
+                iter: Inner::FullAscii(
+                    ascii::Lowercase::with_slice(slice),
+                    LowercaseMode::TurkicFold,
+                ),
+            }
+        } else {
+            Self {
+                iter: Inner::Fold(fold::Fold::with_turkic_slice(slice)),
+            }
+        }
+    }
+
+    /// Views the not yet case mapped portion of the source slice.
+    ///
+    /// This has the same lifetime as the original slice, so the iterator can
+    /// be dropped and the remaining bytes recovered, like
+    /// [`Chars::as_str`].
+    ///
+    /// Bytes of a character whose case mapping is only partially yielded are
+    /// not part of the returned slice: the character has already been
+    /// consumed from the source slice and the rest of its mapping is buffered
+    /// in the iterator. The returned slice may therefore begin in the middle
+    /// of an expansion.
+    ///
+    /// An iterator created with `with_owned` case maps its slice up front, so
+    /// the returned slice is always empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let mut lowercase = Lowercase::with_slice("İSTANBUL".as_bytes());
+    /// assert_eq!(lowercase.as_slice(), "İSTANBUL".as_bytes());
+    ///
+    /// assert_eq!(lowercase.next(), Some(b'i'));
+    /// // The expansion of the first character is in progress.
+    /// assert_eq!(lowercase.as_slice(), "STANBUL".as_bytes());
+    /// ```
+    ///
+    /// [`Chars::as_str`]: core::str::Chars::as_str
+    #[must_use]
+    pub const fn as_slice(&self) -> &'a [u8] {
+        match self.iter {
+            Inner::Empty => &[],
+            Inner::Full(ref iter) => iter.as_slice(),
+            Inner::FullAscii(ref iter, _) | Inner::Ascii(ref iter) => iter.as_slice(),
+            Inner::Fold(ref iter) => iter.as_slice(),
+            Inner::Lithuanian(ref iter) => iter.as_slice(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(..) => &[],
+        }
+    }
+
+    /// Rewind the iterator to the start of the slice it was created with.
+    ///
+    /// Case mapped bytes which are buffered but not yet yielded are discarded,
+    /// so after a reset the iterator yields the same bytes as a freshly
+    /// constructed one. This lets a tight loop map the same slice repeatedly
+    /// without cloning the iterator up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let mut lowercase = Lowercase::with_slice("İSTANBUL".as_bytes());
+    /// assert_eq!(lowercase.next(), Some(b'i'));
+    /// lowercase.reset();
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), "i\u{307}stanbul".as_bytes());
+    /// ```
+    pub fn reset(&mut self) {
+        match self.iter {
+            Inner::Empty => {}
+            Inner::Full(ref mut iter) => iter.reset(),
+            Inner::FullAscii(ref mut iter, _) | Inner::Ascii(ref mut iter) => iter.reset(),
+            Inner::Fold(ref mut iter) => iter.reset(),
+            Inner::Lithuanian(ref mut iter) => iter.reset(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.reset(),
+        }
+    }
+
+    /// Skip the next `n` bytes of output and return the iterator.
+    ///
+    /// Iterators which map with ASCII case mapping, including full case
+    /// mapping of an ASCII-only slice, skip the bytes in constant time by
+    /// slicing the remaining input forward, as do iterators over an owned
+    /// slice. Other iterators map and discard the skipped bytes. If fewer than
+    /// `n` bytes remain, the returned iterator is exhausted.
+    ///
+    /// Unlike [`Iterator::skip`], the returned iterator is a `Lowercase`, so its
+    /// inherent methods like [`as_slice`](Self::as_slice) are still available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let lowercase = Lowercase::with_slice(b"ABC, XYZ").skip_bytes(5);
+    /// assert_eq!(lowercase.as_slice(), b"XYZ");
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), b"xyz");
+    /// ```
+    pub fn skip_bytes(mut self, n: usize) -> Self {
+        match self.iter {
+            Inner::FullAscii(ref mut iter, _) | Inner::Ascii(ref mut iter) => {
+                iter.advance(n);
+            }
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.advance(n),
+            _ => {
+                if let Some(n) = n.checked_sub(1) {
+                    self.nth(n);
+                }
+            }
+        }
+        self
+    }
+
+    /// Returns the exact number of bytes remaining in the iterator, if it is
+    /// known without case mapping the remaining slice.
+    ///
+    /// ASCII case mapping, and full case mapping of an ASCII-only slice, never
+    /// changes the length of the slice, so this method returns [`Some`] for
+    /// iterators which map with ASCII case mapping. It returns [`None`] for
+    /// iterators which map non-ASCII slices with Unicode case mapping, since
+    /// the output may be longer or shorter than the input. Iterators created
+    /// with `with_owned` have already case mapped their slice and always
+    /// return `Some`.
+    ///
+    /// When this method returns `Some`, the value is equal to the lower and
+    /// upper bounds of the iterator's [size hint].
+    ///
+    /// Unlike [`count`], this method does not consume the iterator, so it can
+    /// be used to size an output buffer before mapping without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let mut lowercase = Lowercase::with_ascii_slice(b"abc XYZ");
+    /// assert_eq!(lowercase.exact_len(), Some(7));
+    /// lowercase.next();
+    /// assert_eq!(lowercase.exact_len(), Some(6));
+    ///
+    /// let lowercase = Lowercase::with_slice("ΑΎΡΙΟ".as_bytes());
+    /// assert_eq!(lowercase.exact_len(), None);
+    ///
+    /// assert_eq!(Lowercase::new().exact_len(), Some(0));
+    /// ```
+    ///
+    /// Sizing a stack buffer:
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let lowercase = Lowercase::with_ascii_slice(b"ABC xyz");
+    /// let mut buf = [0; 16];
+    /// let len = lowercase.exact_len().unwrap();
+    /// for (dst, byte) in buf[..len].iter_mut().zip(lowercase) {
+    ///     *dst = byte;
+    /// }
+    /// assert_eq!(&buf[..len], b"abc xyz");
+    /// ```
+    ///
+    /// [`count`]: Iterator::count
+    /// [size hint]: Iterator::size_hint
+    #[must_use]
+    #[doc(alias = "byte_len")]
+    pub fn exact_len(&self) -> Option<usize> {
+        match self.iter {
+            Inner::Empty => Some(0),
+            Inner::FullAscii(ref iter, _) | Inner::Ascii(ref iter) => Some(iter.len()),
+            Inner::Full(_) | Inner::Fold(_) | Inner::Lithuanian(_) => None,
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref iter, _) => Some(iter.len()),
+        }
+    }
+
+    /// Returns the [`LowercaseMode`] this iterator was created with, or
+    /// [`None`] if it was created with [`Lowercase::new`].
+    ///
+    /// [`lowercase`] returns an empty iterator for an empty slice, whose mode is
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{Lowercase, LowercaseMode};
+    /// assert_eq!(Lowercase::with_slice(b"ABC").mode(), Some(LowercaseMode::Full));
+    /// assert_eq!(Lowercase::with_ascii_slice(b"ABC").mode(), Some(LowercaseMode::Ascii));
+    /// assert_eq!(Lowercase::with_fold_slice("ẞ".as_bytes()).mode(), Some(LowercaseMode::Fold));
+    /// assert_eq!(Lowercase::new().mode(), None);
+    /// ```
+    ///
+    /// [`lowercase`]: crate::lowercase()
+    #[must_use]
+    pub const fn mode(&self) -> Option<LowercaseMode> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(_) => Some(LowercaseMode::Full),
+            Inner::FullAscii(_, mode) => Some(mode),
+            Inner::Ascii(_) => Some(LowercaseMode::Ascii),
+            Inner::Fold(ref iter) if iter.is_turkic() => Some(LowercaseMode::TurkicFold),
+            Inner::Fold(_) => Some(LowercaseMode::Fold),
+            Inner::Lithuanian(_) => Some(LowercaseMode::Lithuanian),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(_, mode) => Some(mode),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Lowercase<'static> {
+    /// Create a new lowercase iterator which takes ownership of the given byte
+    /// vector and maps it with the given [`LowercaseMode`].
+    ///
+    /// The returned iterator does not borrow, so it can be built from a
+    /// temporary buffer and returned or stored as a `'static` value.
+    ///
+    /// The vector is case mapped in full by [`lowercase`] when the iterator is
+    /// created and its mapping is yielded from an exactly sized buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{Lowercase, LowercaseMode};
+    /// fn lowercase_greeting() -> Lowercase<'static> {
+    ///     let greeting = String::from("ΑΎΡΙΟ");
+    ///     Lowercase::with_owned(greeting.into_bytes(), LowercaseMode::Full)
+    /// }
+    ///
+    /// assert_eq!(lowercase_greeting().collect::<Vec<_>>(), "αύριο".as_bytes());
+    ///
+    /// let lowercase = Lowercase::with_owned("Straße".into(), LowercaseMode::Fold);
+    /// assert_eq!(lowercase.rev().collect::<Vec<_>>(), b"essarts");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics for the same unimplemented modes as [`lowercase`].
+    ///
+    /// [`lowercase`]: crate::lowercase()
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    // The vector is taken by value so callers can hand over a temporary
+    // buffer. It is dropped once its mapping is collected.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn with_owned(slice: Vec<u8>, mode: LowercaseMode) -> Self {
+        Self {
+            iter: Inner::Owned(MappedBytes::with_iter(crate::lowercase(&slice, mode)), mode),
+        }
+    }
+}
+
+impl<'a> From<&'a [u8]> for Lowercase<'a> {
+    /// Create a new lowercase iterator with the given byte slice using full
+    /// Unicode case mapping.
+    ///
+    /// This is the same as [`Lowercase::with_slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let lowercase = Lowercase::from(&b"ABC"[..]);
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), b"abc");
+    /// ```
+    #[inline]
+    fn from(slice: &'a [u8]) -> Self {
+        Self::with_slice(slice)
+    }
+}
+
+impl<'a> From<&'a str> for Lowercase<'a> {
+    /// Create a new lowercase iterator with the bytes of the given string using
+    /// full Unicode case mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Lowercase;
+    /// let lowercase = Lowercase::from("ΑΎΡΙΟ");
+    /// assert_eq!(lowercase.collect::<Vec<_>>(), "αύριο".as_bytes());
+    /// ```
+    #[inline]
+    fn from(s: &'a str) -> Self {
+        Self::with_slice(s.as_bytes())
+    }
 }
 
-impl<'a> Iterator for Lowercase<'a> {
+impl Iterator for Lowercase<'_> {
     type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.iter {
             Inner::Empty => None,
             Inner::Full(ref mut iter) => iter.next(),
-            Inner::Ascii(ref mut iter) => iter.next(),
+            Inner::FullAscii(ref mut iter, _) | Inner::Ascii(ref mut iter) => iter.next(),
+            Inner::Fold(ref mut iter) => iter.next(),
+            Inner::Lithuanian(ref mut iter) => iter.next(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.next(),
         }
     }
 
+    /// Returns the bounds on the remaining length of the iterator.
+    ///
+    /// The lower bound is the number of chars and invalid UTF-8 byte sequences
+    /// in the remaining slice, each of which yields at least one byte. The
+    /// upper bound is the remaining slice length scaled by the
+    /// [maximum expansion ratio] of the case mapping. Both bounds include the
+    /// bytes of a partially yielded case mapping.
+    ///
+    /// ASCII-only slices and owned slices have an exact size hint.
+    ///
+    /// [maximum expansion ratio]: crate::max_expansion_ratio()
     fn size_hint(&self) -> (usize, Option<usize>) {
         match self.iter {
             Inner::Empty => (0, Some(0)),
             Inner::Full(ref iter) => iter.size_hint(),
-            Inner::Ascii(ref iter) => iter.size_hint(),
+            Inner::FullAscii(ref iter, _) | Inner::Ascii(ref iter) => iter.size_hint(),
+            Inner::Fold(ref iter) => iter.size_hint(),
+            Inner::Lithuanian(ref iter) => iter.size_hint(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref iter, _) => iter.size_hint(),
         }
     }
 
@@ -147,19 +624,121 @@ impl<'a> Iterator for Lowercase<'a> {
         match self.iter {
             Inner::Empty => 0,
             Inner::Full(iter) => iter.count(),
-            Inner::Ascii(iter) => iter.count(),
+            Inner::FullAscii(iter, _) | Inner::Ascii(iter) => iter.count(),
+            Inner::Fold(iter) => iter.count(),
+            Inner::Lithuanian(iter) => iter.count(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(iter, _) => iter.count(),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(ref mut iter) => iter.nth(n),
+            Inner::FullAscii(ref mut iter, _) | Inner::Ascii(ref mut iter) => iter.nth(n),
+            Inner::Fold(ref mut iter) => iter.nth(n),
+            Inner::Lithuanian(ref mut iter) => iter.nth(n),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.nth(n),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(iter) => iter.last(),
+            Inner::FullAscii(mut iter, _) | Inner::Ascii(mut iter) => iter.next_back(),
+            Inner::Fold(mut iter) => iter.next_back(),
+            Inner::Lithuanian(mut iter) => iter.next_back(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(iter, _) => iter.last(),
         }
     }
 }
 
-impl<'a> FusedIterator for Lowercase<'a> {}
+impl DoubleEndedIterator for Lowercase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(ref mut iter) => iter.next_back(),
+            Inner::Lithuanian(ref mut iter) => iter.next_back(),
+            Inner::Fold(ref mut iter) => iter.next_back(),
+            Inner::FullAscii(ref mut iter, _) | Inner::Ascii(ref mut iter) => iter.next_back(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.next_back(),
+        }
+    }
+}
+
+impl FusedIterator for Lowercase<'_> {}
+
+/// Formats the remaining case mapped bytes as a conventionally UTF-8 string.
+///
+/// Invalid UTF-8 byte sequences are rendered as U+FFFD REPLACEMENT CHARACTER,
+/// the same as the [`Display`] implementation of [`BStr`]. Formatting maps a
+/// clone of the iterator and does not allocate or advance `self`.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{LowercaseMode, Lowercase};
+/// let lowercase = roe::lowercase("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full);
+/// assert_eq!(lowercase.to_string(), "αύριο");
+///
+/// let lowercase = Lowercase::with_ascii_slice(b"abc\xFF");
+/// assert_eq!(format!("{lowercase}"), "abc\u{FFFD}");
+/// ```
+///
+/// [`Display`]: fmt::Display
+/// [`BStr`]: bstr::BStr
+impl fmt::Display for Lowercase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::utf8::fmt_lossy(self.clone(), f)
+    }
+}
+
+/// Compares the remaining case mapped bytes with a byte slice.
+///
+/// Comparison maps a clone of the iterator and stops at the first byte which
+/// differs, so it does not allocate or advance `self`. Slices with a length
+/// outside of the iterator's [size hint] compare unequal without mapping any
+/// bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// assert_eq!(roe::lowercase(b"abc XYZ", LowercaseMode::Ascii), b"abc xyz"[..]);
+/// assert_eq!(roe::lowercase("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full), "αύριο".as_bytes());
+/// assert_ne!(roe::lowercase(b"abc", LowercaseMode::Ascii), b"ab"[..]);
+/// ```
+///
+/// [size hint]: Iterator::size_hint
+impl PartialEq<[u8]> for Lowercase<'_> {
+    fn eq(&self, other: &[u8]) -> bool {
+        let (min, max) = self.size_hint();
+        if other.len() < min || max.is_some_and(|max| other.len() > max) {
+            return false;
+        }
+        Iterator::eq(self.clone(), other.iter().copied())
+    }
+}
+
+impl PartialEq<&[u8]> for Lowercase<'_> {
+    fn eq(&self, other: &&[u8]) -> bool {
+        *self == **other
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use alloc::format;
     use alloc::vec::Vec;
     use bstr::ByteSlice;
 
-    use super::Lowercase;
+    use super::{Inner, Lowercase};
+    use crate::LowercaseMode;
 
     #[test]
     fn empty() {
@@ -173,6 +752,19 @@ mod tests {
         assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
     }
 
+    #[test]
+    fn full_mode_ascii_slice_uses_ascii_iterator() {
+        let iter = Lowercase::with_slice(b"ABC, xyz");
+        assert!(matches!(iter.iter, Inner::FullAscii(..)));
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"abc, xyz".as_bstr());
+
+        let iter = Lowercase::with_slice("ΑΎΡΙΟ abc".as_bytes());
+        assert!(matches!(iter.iter, Inner::Full(_)));
+
+        let iter = Lowercase::with_slice(b"abc\xFF");
+        assert!(matches!(iter.iter, Inner::Full(_)));
+    }
+
     #[test]
     fn size_hint() {
         assert_eq!(Lowercase::new().size_hint(), (0, Some(0)));
@@ -180,26 +772,26 @@ mod tests {
         assert_eq!(Lowercase::with_slice(b"abc, xyz").size_hint(), (8, Some(8)));
         assert_eq!(
             Lowercase::with_slice(b"abc, \xFF\xFE, xyz").size_hint(),
-            (12, Some(144))
+            (12, Some(18))
         );
         assert_eq!(
             Lowercase::with_slice("�".as_bytes()).size_hint(),
-            (3, Some(36))
+            (1, Some(4))
         );
         assert_eq!(
             Lowercase::with_slice("Έτος".as_bytes()).size_hint(),
-            (8, Some(96))
+            (4, Some(12))
         );
         assert_eq!(
             Lowercase::with_slice("ZȺȾ".as_bytes()).size_hint(),
-            (5, Some(60))
+            (3, Some(7))
         );
 
         let mut utf8_with_invalid_bytes = b"\xFF\xFE".to_vec();
         utf8_with_invalid_bytes.extend_from_slice("Έτος".as_bytes());
         assert_eq!(
             Lowercase::with_slice(&utf8_with_invalid_bytes).size_hint(),
-            (10, Some(120))
+            (6, Some(15))
         );
 
         assert_eq!(
@@ -231,6 +823,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn nth_matches_repeated_next() {
+        let inputs: [&[u8]; 3] = [b"ABC, XYZ", b"ABC, XYZ\xFF\xFE", "Έτος ΑΎΡΙΟ".as_bytes()];
+        for input in inputs {
+            for n in 0..=input.len() + 1 {
+                for (mut nth, mut next) in [
+                    (Lowercase::with_slice(input), Lowercase::with_slice(input)),
+                    (
+                        Lowercase::with_ascii_slice(input),
+                        Lowercase::with_ascii_slice(input),
+                    ),
+                ] {
+                    for _ in 0..n {
+                        next.next();
+                    }
+                    assert_eq!(nth.nth(n), next.next());
+                    assert_eq!(nth.as_slice().as_bstr(), next.as_slice().as_bstr());
+                    assert!(nth.eq(next));
+                }
+            }
+        }
+
+        let mut iter = Lowercase::with_ascii_slice(b"ABC, XYZ");
+        assert_eq!(iter.nth(2), Some(b'c'));
+        assert_eq!(iter.nth(3), Some(b'y'));
+        assert_eq!(iter.next(), Some(b'z'));
+        assert_eq!(iter.nth(1), None);
+        assert_eq!(Lowercase::new().nth(1), None);
+    }
+
+    #[test]
+    fn mode() {
+        assert_eq!(Lowercase::new().mode(), None);
+        for input in [&b"ABC"[..], "ΑΎΡΙΟ".as_bytes()] {
+            assert_eq!(
+                Lowercase::with_slice(input).mode(),
+                Some(LowercaseMode::Full)
+            );
+            assert_eq!(
+                Lowercase::with_ascii_slice(input).mode(),
+                Some(LowercaseMode::Ascii)
+            );
+            assert_eq!(
+                Lowercase::with_lithuanian_slice(input).mode(),
+                Some(LowercaseMode::Lithuanian)
+            );
+            assert_eq!(
+                Lowercase::with_fold_slice(input).mode(),
+                Some(LowercaseMode::Fold)
+            );
+            assert_eq!(
+                Lowercase::with_turkic_fold_slice(input).mode(),
+                Some(LowercaseMode::TurkicFold)
+            );
+        }
+        // The mode survives iteration to exhaustion.
+        let mut iter = Lowercase::with_fold_slice(b"ABC");
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.mode(), Some(LowercaseMode::Fold));
+
+        for mode in [
+            LowercaseMode::Full,
+            LowercaseMode::Ascii,
+            LowercaseMode::Lithuanian,
+            LowercaseMode::Fold,
+            LowercaseMode::TurkicFold,
+        ] {
+            assert_eq!(crate::lowercase(b"xyz", mode).mode(), Some(mode));
+        }
+    }
+
     #[test]
     fn count() {
         assert_eq!(Lowercase::new().count(), 0);
@@ -270,4 +933,404 @@ mod tests {
         assert!(min <= count);
         assert!(count <= max.unwrap());
     }
+
+    #[test]
+    fn rev() {
+        let iter = Lowercase::new();
+        assert_eq!(iter.rev().collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+
+        let iter = Lowercase::with_ascii_slice(b"abc");
+        assert_eq!(iter.rev().collect::<Vec<_>>().as_bstr(), b"cba".as_bstr());
+
+        let iter = Lowercase::with_ascii_slice(b"ABC, XYZ");
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>().as_bstr(),
+            b"zyx ,cba".as_bstr()
+        );
+
+        // Full mode over ASCII-only slices is reversible.
+        let iter = Lowercase::with_slice(b"ABC");
+        assert_eq!(iter.rev().collect::<Vec<_>>().as_bstr(), b"cba".as_bstr());
+    }
+
+    #[test]
+    fn next_and_next_back_meet_in_the_middle() {
+        let mut iter = Lowercase::with_ascii_slice(b"ABCD");
+        assert_eq!(iter.next(), Some(b'a'));
+        assert_eq!(iter.next_back(), Some(b'd'));
+        assert_eq!(iter.next(), Some(b'b'));
+        assert_eq!(iter.next_back(), Some(b'c'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn rev_full_mode_non_ascii() {
+        let iter = Lowercase::with_slice("ΑΎΡΙΟ".as_bytes());
+        let mut rev = iter.rev().collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev.as_bstr(), "αύριο".as_bytes().as_bstr());
+
+        let mut iter = Lowercase::with_slice("İ".as_bytes());
+        assert_eq!(iter.next_back(), Some(0x87));
+        assert_eq!(iter.next(), Some(b'i'));
+        assert_eq!(iter.next_back(), Some(0xCC));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn fold_mode_ascii_slice_uses_ascii_iterator() {
+        let iter = Lowercase::with_fold_slice(b"ABC, xyz");
+        assert!(matches!(iter.iter, Inner::FullAscii(..)));
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"abc, xyz".as_bstr());
+
+        let iter = Lowercase::with_fold_slice("STRASSE straße".as_bytes());
+        assert!(matches!(iter.iter, Inner::Fold(_)));
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"strasse strasse".as_bstr()
+        );
+    }
+
+    #[test]
+    fn rev_fold_mode_non_ascii() {
+        let iter = Lowercase::with_fold_slice("ẞ".as_bytes());
+        assert_eq!(iter.rev().collect::<Vec<_>>().as_bstr(), b"ss".as_bstr());
+
+        let input = "STRASSE ẞ ﬃ İ".as_bytes();
+        let mut rev = Lowercase::with_fold_slice(input).rev().collect::<Vec<_>>();
+        rev.reverse();
+        let forward = Lowercase::with_fold_slice(input).collect::<Vec<_>>();
+        assert_eq!(rev.as_bstr(), forward.as_bstr());
+    }
+
+    #[test]
+    fn rev_lithuanian_mode_non_ascii() {
+        let iter = crate::lowercase("Ì".as_bytes(), LowercaseMode::Lithuanian);
+        assert_eq!(iter.rev().count(), 5);
+
+        let input = "ÌJ\u{301} ΑΎΡΙΟ".as_bytes();
+        let mut rev = Lowercase::with_lithuanian_slice(input)
+            .rev()
+            .collect::<Vec<_>>();
+        rev.reverse();
+        let forward = Lowercase::with_lithuanian_slice(input).collect::<Vec<_>>();
+        assert_eq!(rev.as_bstr(), forward.as_bstr());
+    }
+
+    #[test]
+    fn lithuanian_mode_next_back_consumes_more_above_context() {
+        // The `I` keeps its dot even though its accent has already been
+        // yielded from the back.
+        let mut iter = crate::lowercase("I\u{301}".as_bytes(), LowercaseMode::Lithuanian);
+        assert_eq!(iter.next_back(), Some(0x81));
+        let (min, max) = iter.size_hint();
+        assert!(min <= 4 && max.is_some_and(|max| max >= 4));
+        assert_eq!(iter.clone().count(), 4);
+        let remaining = iter.clone().collect::<Vec<_>>();
+        assert_eq!(remaining.as_bstr(), b"i\xCC\x87\xCC".as_bstr());
+        assert!(iter == remaining[..]);
+
+        let mut iter = crate::lowercase("I\u{300}J".as_bytes(), LowercaseMode::Lithuanian);
+        assert_eq!(iter.next_back(), Some(b'j'));
+        assert_eq!(iter.next_back(), Some(0x80));
+        assert_eq!(iter.count(), 4);
+    }
+
+    #[test]
+    fn lithuanian_mode_ascii_slice_uses_ascii_iterator() {
+        let iter = Lowercase::with_lithuanian_slice(b"IJ, xyz");
+        assert!(matches!(iter.iter, Inner::FullAscii(..)));
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"ij, xyz".as_bstr());
+
+        let iter = Lowercase::with_lithuanian_slice("ÌJ\u{301}".as_bytes());
+        assert!(matches!(iter.iter, Inner::Lithuanian(_)));
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "i\u{307}\u{300}j\u{307}\u{301}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn as_slice_after_partial_consumption() {
+        let mut iter = Lowercase::with_slice("ABC İX".as_bytes());
+        assert_eq!(iter.as_slice().as_bstr(), "ABC İX".as_bytes().as_bstr());
+        assert_eq!(iter.next(), Some(b'a'));
+        assert_eq!(iter.as_slice().as_bstr(), "BC İX".as_bytes().as_bstr());
+
+        let mut iter = Lowercase::with_slice("İXİ".as_bytes());
+        assert_eq!(iter.next(), Some(b'i'));
+        // The combining dot of the first `İ` has not been yielded yet.
+        assert_eq!(iter.as_slice().as_bstr(), "Xİ".as_bytes().as_bstr());
+        assert_eq!(iter.next_back(), Some(0x87));
+        assert_eq!(iter.as_slice().as_bstr(), b"X".as_bstr());
+        // The last byte of the trailing combining dot was yielded from the back.
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"\xCC\x87xi\xCC".as_bstr()
+        );
+
+        let mut iter = Lowercase::with_ascii_slice(b"ABC");
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.as_slice().as_bstr(), b"B".as_bstr());
+
+        for mut iter in [
+            Lowercase::with_fold_slice("ßX".as_bytes()),
+            Lowercase::with_lithuanian_slice("ÌX".as_bytes()),
+        ] {
+            iter.next();
+            assert_eq!(iter.as_slice().as_bstr(), b"X".as_bstr());
+        }
+
+        let mut iter = Lowercase::new();
+        assert_eq!(iter.as_slice(), b"");
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.as_slice(), b"");
+    }
+
+    #[test]
+    fn exact_len() {
+        assert_eq!(Lowercase::new().exact_len(), Some(0));
+
+        let mut iter = Lowercase::with_ascii_slice("ΑΎΡΙΟ abc".as_bytes());
+        let len = iter.clone().count();
+        for remaining in (0..=len).rev() {
+            assert_eq!(iter.exact_len(), Some(remaining));
+            assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+            iter.next();
+        }
+
+        let iter = Lowercase::with_slice("ΑΎΡΙΟ".as_bytes());
+        assert_eq!(iter.exact_len(), None);
+        assert_eq!(Lowercase::with_slice(b"ABC xyz").exact_len(), Some(7));
+        assert_eq!(Lowercase::with_fold_slice(b"ABC").exact_len(), Some(3));
+        assert_eq!(Lowercase::with_lithuanian_slice(b"IJ").exact_len(), Some(2));
+        assert_eq!(Lowercase::with_slice("ΑΒΓ".as_bytes()).exact_len(), None);
+        assert_eq!(Lowercase::with_fold_slice("ß".as_bytes()).exact_len(), None);
+        assert_eq!(
+            Lowercase::with_lithuanian_slice("Ì".as_bytes()).exact_len(),
+            None
+        );
+    }
+
+    #[test]
+    fn display_matches_bstr() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"abc, XYZ",
+            "ΑΎΡΙΟ ß İ ǅ".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF\xFE",
+            b"\xF0\x9F\x98\x80 \xE1\xBA\x9E \xCE",
+        ];
+        for input in inputs {
+            for mode in [LowercaseMode::Full, LowercaseMode::Ascii] {
+                let iter = crate::lowercase(input, mode);
+                let expected = iter.clone().collect::<Vec<_>>();
+                assert_eq!(format!("{iter}"), format!("{}", expected.as_bstr()));
+                // Formatting does not advance the iterator.
+                assert_eq!(iter.collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn partial_eq_slice() {
+        let iter = crate::lowercase("ΑΎΡΙΟ ẞ".as_bytes(), LowercaseMode::Full);
+        assert_eq!(iter, "αύριο ß".as_bytes());
+        assert_eq!(iter, *"αύριο ß".as_bytes());
+        // Comparison does not advance the iterator.
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "αύριο ß".as_bytes().as_bstr()
+        );
+
+        let iter = crate::lowercase("ẞ".as_bytes(), LowercaseMode::Fold);
+        assert_eq!(iter, b"ss"[..]);
+
+        // Unequal lengths.
+        let iter = crate::lowercase("ΑΎΡΙΟ ẞ".as_bytes(), LowercaseMode::Full);
+        let expected = "αύριο ß".as_bytes();
+        assert_ne!(iter, expected[..expected.len() - 1]);
+        assert_ne!(iter, [expected, b"!"].concat()[..]);
+        assert_ne!(iter, b""[..]);
+        assert_eq!(crate::lowercase(b"", LowercaseMode::Full), b""[..]);
+
+        // Unequal contents.
+        assert_ne!(iter, "ΑΎΡΙΟ ẞ".as_bytes());
+
+        // Invalid UTF-8 byte sequences are compared as is.
+        assert_eq!(
+            crate::lowercase(b"ABC\xFF\xFE", LowercaseMode::Full),
+            b"abc\xFF\xFE"[..]
+        );
+        assert_ne!(
+            crate::lowercase(b"ABC\xFF\xFE", LowercaseMode::Full),
+            "Abc\u{FFFD}".as_bytes()
+        );
+    }
+
+    #[test]
+    fn last_matches_collected_last() {
+        let inputs: [&[u8]; 4] = [
+            b"ABC, XYZ",
+            "ΑΎΡΙΟΣ".as_bytes(),
+            "xİ".as_bytes(),
+            b"ABC\xF0\x9F\x87",
+        ];
+        for input in inputs {
+            for mode in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Lithuanian,
+                LowercaseMode::Fold,
+                LowercaseMode::TurkicFold,
+            ] {
+                let expected = crate::lowercase(input, mode).collect::<Vec<_>>();
+                assert_eq!(
+                    crate::lowercase(input, mode).last(),
+                    expected.last().copied()
+                );
+            }
+        }
+        assert_eq!(Lowercase::new().last(), None);
+    }
+
+    #[test]
+    fn reset_matches_fresh_iterator() {
+        let inputs: [&[u8]; 4] = [
+            b"abc, XYZ",
+            "ΑΎΡΙΟ İ ß ﬃ".as_bytes(),
+            b"ABC\xFF\xFE\xCE\xA3XYZ\xF0\x9F\x87",
+            b"",
+        ];
+        for input in inputs {
+            for mode in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Lithuanian,
+                LowercaseMode::Fold,
+                LowercaseMode::TurkicFold,
+            ] {
+                let expected = crate::lowercase(input, mode).collect::<Vec<_>>();
+                // Reset after consuming any prefix of the output, including
+                // from the middle of an expansion and after exhaustion.
+                for consumed in 0..=expected.len() {
+                    let mut iter = crate::lowercase(input, mode);
+                    iter.by_ref().take(consumed).for_each(drop);
+                    iter.reset();
+                    assert_eq!(
+                        iter.collect::<Vec<_>>().as_bstr(),
+                        expected.as_bstr(),
+                        "{mode:?} after {consumed} bytes"
+                    );
+                }
+            }
+            // Buffered output from both ends is discarded.
+            let expected = crate::lowercase(input, LowercaseMode::Full).collect::<Vec<_>>();
+            let mut iter = crate::lowercase(input, LowercaseMode::Full);
+            iter.next();
+            iter.next_back();
+            iter.reset();
+            assert_eq!(iter.collect::<Vec<_>>().as_bstr(), expected.as_bstr());
+        }
+
+        let mut iter = Lowercase::new();
+        iter.reset();
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn skip_bytes_matches_nth() {
+        let inputs: [&[u8]; 3] = [b"abc, XYZ", b"ABC\xFF\xFExyz", "ΑΎΡΙΟ ß".as_bytes()];
+        for input in inputs {
+            for mode in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Fold,
+            ] {
+                let len = crate::lowercase(input, mode).count();
+                for n in 0..=len + 1 {
+                    let mut nth = crate::lowercase(input, mode);
+                    let expected = nth.nth(n);
+                    let mut skipped = crate::lowercase(input, mode).skip_bytes(n);
+                    assert_eq!(skipped.next(), expected, "{mode:?} skipping {n} bytes");
+                    assert_eq!(skipped.as_slice().as_bstr(), nth.as_slice().as_bstr());
+                    assert!(skipped.eq(nth));
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn owned_lowercase_from_temporary() -> Lowercase<'static> {
+        let mut source = Vec::new();
+        source.extend_from_slice("ΑΎΡΙΟ ".as_bytes());
+        source.extend_from_slice(b"\xFF abc XYZ");
+        Lowercase::with_owned(source, LowercaseMode::Full)
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn with_owned_outlives_source_scope() {
+        use alloc::boxed::Box;
+
+        let mut source = Vec::new();
+        source.extend_from_slice("ΑΎΡΙΟ ".as_bytes());
+        source.extend_from_slice(b"\xFF abc XYZ");
+        let expected = crate::lowercase(&source, LowercaseMode::Full).collect::<Vec<_>>();
+        drop(source);
+        let iter = owned_lowercase_from_temporary();
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), expected.as_bstr());
+        assert!(expected.starts_with("αύριο ".as_bytes()));
+
+        // The iterator is `'static` and can be boxed as a trait object.
+        let boxed: Box<dyn Iterator<Item = u8>> = Box::new(owned_lowercase_from_temporary());
+        assert_eq!(boxed.count(), expected.len());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn with_owned_matches_borrowed() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"abc, XYZ",
+            "ΑΎΡΙΟ ΟΔΟΣ ß İ ΐ ǆ".as_bytes(),
+            "Ì I\u{301} i\u{307}".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF",
+        ];
+        for input in inputs {
+            for mode in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Lithuanian,
+                LowercaseMode::Fold,
+                LowercaseMode::TurkicFold,
+            ] {
+                let expected = crate::lowercase(input, mode).collect::<Vec<_>>();
+                let mut owned = Lowercase::with_owned(input.to_vec(), mode);
+                assert_eq!(owned.mode(), Some(mode));
+                assert_eq!(owned.exact_len(), Some(expected.len()));
+                assert_eq!(owned.size_hint(), (expected.len(), Some(expected.len())));
+                assert!(owned.as_slice().is_empty());
+                assert_eq!(
+                    owned.clone().collect::<Vec<_>>().as_bstr(),
+                    expected.as_bstr()
+                );
+
+                let mut rev = owned.clone().rev().collect::<Vec<_>>();
+                rev.reverse();
+                assert_eq!(rev.as_bstr(), expected.as_bstr());
+
+                owned.next();
+                owned.reset();
+                assert_eq!(owned.clone().count(), expected.len());
+                let skipped = owned.skip_bytes(2).collect::<Vec<_>>();
+                assert_eq!(
+                    skipped.as_bstr(),
+                    expected.get(2..).unwrap_or_default().as_bstr()
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,511 @@
+use alloc::borrow::Cow;
+use alloc::string::{FromUtf8Error, String};
+use alloc::vec::Vec;
+
+use super::chars::lowercase_char;
+use crate::expansion::collect_exact;
+use crate::unicode::std_case_mapping_iter::CaseMappingIter;
+use crate::{CaseMapping, LowercaseMode, UnsupportedCaseMappingMode};
+
+/// Returns a vector containing a copy of the given slice with all uppercase
+/// letters replaced with their lowercase counterparts.
+///
+/// This function is equivalent to collecting the iterator returned by
+/// [`lowercase`] into a [`Vec`]. The mapped length is counted with a first pass
+/// over the slice, so the vector is allocated once, with exactly the capacity
+/// it needs.
+///
+/// The case mapping mode is determined by the given [`LowercaseMode`]. See
+/// [`lowercase`] for details on how the input slice is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{to_lowercase, LowercaseMode};
+/// assert_eq!(to_lowercase(b"ABCxyz", LowercaseMode::Full), b"abcxyz");
+/// assert_eq!(to_lowercase("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full), "αύριο".as_bytes());
+/// assert_eq!(to_lowercase("ẞ".as_bytes(), LowercaseMode::Fold), b"ss");
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [`lowercase`]: crate::lowercase()
+#[must_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn to_lowercase(slice: &[u8], options: LowercaseMode) -> Vec<u8> {
+    collect_exact(crate::lowercase(slice, options))
+}
+
+/// Returns a [`String`] containing a copy of the given slice with all uppercase
+/// letters replaced with their lowercase counterparts.
+///
+/// This function collects the iterator returned by [`lowercase`] into an exactly
+/// sized [`Vec`] and converts it to a `String` with [`String::from_utf8`].
+///
+/// The case mapping mode is determined by the given [`LowercaseMode`]. See
+/// [`lowercase`] for details on how the input slice is mapped.
+///
+/// # Errors
+///
+/// Case mapping valid UTF-8 always produces valid UTF-8, so this function only
+/// returns an error if the given slice is not valid UTF-8. Invalid UTF-8 byte
+/// sequences are passed through the case mapping as is. The mapped bytes can
+/// be recovered with [`FromUtf8Error::into_bytes`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{to_lowercase_string, LowercaseMode};
+/// assert_eq!(
+///     to_lowercase_string("ΑΎΡΙΟ ΟΔΟΣ".as_bytes(), LowercaseMode::Full).unwrap(),
+///     "αύριο οδος"
+/// );
+///
+/// let err = to_lowercase_string(b"abc\xFFxyz", LowercaseMode::Full).unwrap_err();
+/// assert_eq!(err.utf8_error().valid_up_to(), 3);
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [`lowercase`]: crate::lowercase()
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn to_lowercase_string(slice: &[u8], options: LowercaseMode) -> Result<String, FromUtf8Error> {
+    String::from_utf8(collect_exact(crate::lowercase(slice, options)))
+}
+
+/// Appends a copy of the given slice with all uppercase letters replaced with
+/// their lowercase counterparts to `out`, returning the number of bytes
+/// appended.
+///
+/// The existing contents of `out` are preserved. Before any bytes are appended,
+/// capacity is reserved for the upper bound of the [size hint] of the iterator
+/// returned by [`lowercase`], so `out` grows at most once per call. Reusing the
+/// same `out` for many slices amortizes the allocation across calls.
+///
+/// The case mapping mode is determined by the given [`LowercaseMode`]. See
+/// [`lowercase`] for details on how the input slice is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{extend_lowercase, LowercaseMode};
+/// let mut out = b"> ".to_vec();
+/// let appended = extend_lowercase(&mut out, "ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full);
+/// assert_eq!(out, "> αύριο".as_bytes());
+/// assert_eq!(appended, "αύριο".len());
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [`lowercase`]: crate::lowercase()
+/// [size hint]: Iterator::size_hint
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn extend_lowercase(out: &mut Vec<u8>, slice: &[u8], options: LowercaseMode) -> usize {
+    let iter = crate::lowercase(slice, options);
+    let (min, max) = iter.size_hint();
+    let len = out.len();
+    out.reserve(max.unwrap_or(min));
+    out.extend(iter);
+    out.len() - len
+}
+
+/// Returns the given slice with all uppercase letters replaced with their
+/// lowercase counterparts, borrowing the slice if it is already lowercase.
+///
+/// Before allocating, this function lazily compares the slice with its
+/// lowercase mapping, the same as [`is_lowercase`]. If mapping the slice would
+/// not change it, the slice is returned as [`Cow::Borrowed`]. Otherwise, the
+/// mapped bytes are returned as [`Cow::Owned`], the same as [`to_lowercase`].
+///
+/// Invalid UTF-8 byte sequences are yielded as is by [`lowercase`], so they
+/// never require an owned copy.
+///
+/// The case mapping mode is determined by the given [`LowercaseMode`]. See
+/// [`lowercase`] for details on how the input slice is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use roe::{lowercase_cow, LowercaseMode};
+/// let lowercase = lowercase_cow(b"abc xyz", LowercaseMode::Full);
+/// assert!(matches!(lowercase, Cow::Borrowed(b"abc xyz")));
+///
+/// let lowercase = lowercase_cow("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full);
+/// assert!(matches!(lowercase, Cow::Owned(_)));
+/// assert_eq!(lowercase, "αύριο".as_bytes());
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [`is_lowercase`]: crate::is_lowercase()
+/// [`lowercase`]: crate::lowercase()
+#[must_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn lowercase_cow(slice: &[u8], options: LowercaseMode) -> Cow<'_, [u8]> {
+    if crate::is_lowercase(slice, options) {
+        Cow::Borrowed(slice)
+    } else {
+        Cow::Owned(to_lowercase(slice, options))
+    }
+}
+
+/// Replaces all uppercase letters in the given buffer with their lowercase
+/// counterparts in place.
+///
+/// This function maps `buf` the same as [`lowercase`] without allocating a
+/// separate output buffer. Most characters, including those in the Latin-1
+/// Supplement, Greek, and Cyrillic blocks, lowercase to the same number of
+/// bytes, and the buffer is mapped in place as long as lengths are preserved.
+/// Once a character lowercases to a different number of bytes, like `İ` which
+/// expands to `i` and U+0307 COMBINING DOT ABOVE, or `\u{212A}` KELVIN SIGN
+/// which contracts to ASCII `k`, the unmapped tail of the buffer is moved out
+/// and the rest of the mapping is appended to the buffer.
+///
+/// Invalid UTF-8 byte sequences are left as is.
+///
+/// The case mapping mode is determined by the given [`LowercaseMode`]. See
+/// [`lowercase`] for details on how the buffer is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{lowercase_in_place, LowercaseMode};
+/// let mut buf = "ΑΎΡΙΟ ΟΔΟΣ".as_bytes().to_vec();
+/// lowercase_in_place(&mut buf, LowercaseMode::Full);
+/// assert_eq!(buf, "αύριο οδος".as_bytes());
+///
+/// let mut buf = "İSTANBUL".as_bytes().to_vec();
+/// lowercase_in_place(&mut buf, LowercaseMode::Full);
+/// assert_eq!(buf, "i\u{307}stanbul".as_bytes());
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [`lowercase`]: crate::lowercase()
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn lowercase_in_place(buf: &mut Vec<u8>, options: LowercaseMode) {
+    if options == LowercaseMode::Turkic {
+        let err = UnsupportedCaseMappingMode::new(CaseMapping::Lowercase(options));
+        panic!("{}", err);
+    }
+    if options == LowercaseMode::Ascii {
+        buf.make_ascii_lowercase();
+        return;
+    }
+    let mut bytes = [0; 4];
+    let mut offset = 0;
+    // Map in place until a char changes length.
+    while let (ch, size @ 1..) = bstr::decode_utf8(&buf[offset..]) {
+        if let Some(ch) = ch {
+            let (preceding, rest) = buf.split_at(offset);
+            let chars = lowercase_char(preceding, &rest[size..], ch, options);
+            let mut chars = CaseMappingIter::new(chars);
+            match (chars.next(), chars.next()) {
+                (Some(lower), None) if lower.len_utf8() == size => {
+                    lower.encode_utf8(&mut buf[offset..offset + size]);
+                }
+                _ => break,
+            }
+        }
+        offset += size;
+    }
+    if offset == buf.len() {
+        return;
+    }
+    // The already mapped prefix stays in `buf`, which remains the preceding
+    // context while the rest of the mapping is appended from the old tail.
+    let tail = buf.split_off(offset);
+    buf.reserve(crate::reserve_hint(&tail, CaseMapping::Lowercase(options)));
+    let mut offset = 0;
+    while let (ch, size @ 1..) = bstr::decode_utf8(&tail[offset..]) {
+        if let Some(ch) = ch {
+            let chars = lowercase_char(buf, &tail[offset + size..], ch, options);
+            for lower in CaseMappingIter::new(chars) {
+                buf.extend_from_slice(lower.encode_utf8(&mut bytes).as_bytes());
+            }
+        } else {
+            buf.extend_from_slice(&tail[offset..offset + size]);
+        }
+        offset += size;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use alloc::borrow::Cow;
+
+    use super::{
+        extend_lowercase, lowercase_cow, lowercase_in_place, to_lowercase, to_lowercase_string,
+    };
+    use crate::LowercaseMode;
+
+    #[test]
+    fn empty() {
+        assert!(to_lowercase(b"", LowercaseMode::Full).is_empty());
+        assert!(to_lowercase(b"", LowercaseMode::Ascii).is_empty());
+    }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(
+            to_lowercase(b"ABC, xyz", LowercaseMode::Full).as_bstr(),
+            b"abc, xyz".as_bstr()
+        );
+        assert_eq!(
+            to_lowercase("ABC, ΑΎΡΙΟ".as_bytes(), LowercaseMode::Ascii).as_bstr(),
+            "abc, ΑΎΡΙΟ".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn multi_char_expansion() {
+        assert_eq!(
+            to_lowercase("İ".as_bytes(), LowercaseMode::Full).as_bstr(),
+            "i\u{307}".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            to_lowercase("ß".as_bytes(), LowercaseMode::Fold).as_bstr(),
+            b"ss".as_bstr()
+        );
+    }
+
+    #[test]
+    fn matches_iterator() {
+        let s = b"AB\xFFC \xCE\x91\xCE\x8E\xCE\xA1\xCE\x99\xCE\x9F \xE1\xBA\x9E";
+        for mode in [
+            LowercaseMode::Full,
+            LowercaseMode::Ascii,
+            LowercaseMode::Fold,
+        ] {
+            assert!(to_lowercase(s, mode)
+                .into_iter()
+                .eq(crate::lowercase(s, mode)));
+        }
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let inputs = ["", "abc, XYZ", "Αύριο ΑΎΡΙΟ", "İ ß ẞ ﬃ", "\u{1F600} ǅ"];
+        for input in inputs {
+            for mode in [LowercaseMode::Full, LowercaseMode::Ascii] {
+                let string = to_lowercase_string(input.as_bytes(), mode).unwrap();
+                assert_eq!(string.as_bytes(), to_lowercase(input.as_bytes(), mode));
+            }
+        }
+    }
+
+    #[test]
+    fn string_invalid_utf8() {
+        for mode in [LowercaseMode::Full, LowercaseMode::Ascii] {
+            let err = to_lowercase_string(b"abc\xFFxyz\xF0\x9F\x87", mode).unwrap_err();
+            assert_eq!(err.utf8_error().valid_up_to(), 3);
+            assert_eq!(
+                err.into_bytes(),
+                to_lowercase(b"abc\xFFxyz\xF0\x9F\x87", mode)
+            );
+        }
+    }
+
+    #[test]
+    fn extend_preserves_existing_content() {
+        let input = "ΑΎΡΙΟ ß İ ẞ ﬃ ΟΔΟΣ".as_bytes();
+        for mode in [
+            LowercaseMode::Full,
+            LowercaseMode::Ascii,
+            LowercaseMode::Fold,
+        ] {
+            let mut out = b"prefix ".to_vec();
+            let appended = extend_lowercase(&mut out, input, mode);
+            let expected = crate::lowercase(input, mode).collect::<Vec<_>>();
+            assert_eq!(appended, expected.len());
+            assert_eq!(out[..7].as_bstr(), b"prefix ".as_bstr());
+            assert_eq!(out[7..].as_bstr(), expected.as_bstr());
+
+            assert_eq!(extend_lowercase(&mut out, b"", mode), 0);
+            assert_eq!(out.len(), 7 + expected.len());
+        }
+    }
+
+    #[test]
+    fn extend_reserves_capacity_once() {
+        let input = "ΑΎΡΙΟ ß İ ẞ ﬃ ΟΔΟΣ".as_bytes();
+        let mut out = b"prefix ".to_vec();
+        let (_, max) = crate::lowercase(input, LowercaseMode::Full).size_hint();
+        // `out` is expected to grow exactly as if the whole upper bound were
+        // reserved up front.
+        let mut reserved = out.clone();
+        reserved.reserve(max.unwrap());
+        extend_lowercase(&mut out, input, LowercaseMode::Full);
+        assert_eq!(out.capacity(), reserved.capacity());
+
+        // Spare capacity is reused without growing.
+        let mut out = Vec::with_capacity(64);
+        let ptr = out.as_ptr();
+        extend_lowercase(&mut out, b"abc", LowercaseMode::Full);
+        extend_lowercase(&mut out, b"xyz", LowercaseMode::Full);
+        assert_eq!(out.capacity(), 64);
+        assert_eq!(out.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn cow_borrows_lowercase_input() {
+        let inputs: [&[u8]; 6] = [
+            b"",
+            b"abc, 123 xyz",
+            "αύριο οδος".as_bytes(),
+            "ß ǆungla".as_bytes(),
+            b"abc\xFF\xFE\xF0\x9F\x87xyz",
+            b"\xFF",
+        ];
+        for input in inputs {
+            for mode in [LowercaseMode::Full, LowercaseMode::Lithuanian] {
+                let lowercase = lowercase_cow(input, mode);
+                assert!(matches!(lowercase, Cow::Borrowed(slice) if slice == input));
+            }
+        }
+        let lowercase = lowercase_cow("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Ascii);
+        assert!(matches!(lowercase, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn cow_owns_mapped_input() {
+        let inputs: [&[u8]; 5] = [
+            b"abc, 123 xYz",
+            b"Abc",
+            "αύριο ΟΔΟΣ".as_bytes(),
+            "ǅungla".as_bytes(),
+            b"abc\xFF\xFEXYZ",
+        ];
+        for input in inputs {
+            for mode in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Fold,
+            ] {
+                let lowercase = lowercase_cow(input, mode);
+                let expected = to_lowercase(input, mode);
+                if expected == input {
+                    assert!(matches!(lowercase, Cow::Borrowed(_)));
+                } else {
+                    assert!(matches!(lowercase, Cow::Owned(ref owned) if *owned == expected));
+                }
+            }
+        }
+        // `ß` is lowercase, but it is not case folded.
+        let lowercase = lowercase_cow("ß".as_bytes(), LowercaseMode::Fold);
+        assert!(matches!(lowercase, Cow::Owned(ref owned) if owned == b"ss"));
+    }
+
+    #[test]
+    fn in_place_equal_length() {
+        let mut buf = "ÀÉÎÕÜ ΑΎΡΙΟ ПРИВЕТ abc".as_bytes().to_vec();
+        let capacity = buf.capacity();
+        lowercase_in_place(&mut buf, LowercaseMode::Full);
+        assert_eq!(buf.as_bstr(), "àéîõü αύριο привет abc".as_bytes().as_bstr());
+        // Mapping which preserves lengths does not reallocate.
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[test]
+    fn in_place_expanding_and_contracting() {
+        let mut buf = "xİy".as_bytes().to_vec();
+        lowercase_in_place(&mut buf, LowercaseMode::Full);
+        assert_eq!(buf.as_bstr(), "xi\u{307}y".as_bytes().as_bstr());
+
+        let mut buf = "\u{212A}ELVIN ΟΔΟΣ".as_bytes().to_vec();
+        lowercase_in_place(&mut buf, LowercaseMode::Full);
+        assert_eq!(buf.as_bstr(), "kelvin οδος".as_bytes().as_bstr());
+
+        let mut buf = "STRAẞE".as_bytes().to_vec();
+        lowercase_in_place(&mut buf, LowercaseMode::Fold);
+        assert_eq!(buf.as_bstr(), b"strasse".as_bstr());
+    }
+
+    #[test]
+    fn in_place_invalid_utf8() {
+        let mut buf = b"AB\xFF\xFE\xF0\x9F\x87C\xCE".to_vec();
+        lowercase_in_place(&mut buf, LowercaseMode::Full);
+        assert_eq!(buf.as_bstr(), b"ab\xFF\xFE\xF0\x9F\x87c\xCE".as_bstr());
+
+        let mut buf = b"\xFF\xE2\x84\xAA\xFFA".to_vec();
+        lowercase_in_place(&mut buf, LowercaseMode::Full);
+        assert_eq!(buf.as_bstr(), b"\xFFk\xFFa".as_bstr());
+    }
+
+    #[test]
+    fn in_place_matches_to_lowercase() {
+        let inputs: [&[u8]; 10] = [
+            b"",
+            b"ABC, xyz",
+            "ΑΣ ΣΑ Σ'Σ ΟΔΟΣ.".as_bytes(),
+            "İΣ ΑİΣ \u{212A}Σ".as_bytes(),
+            "ǄUNGLA Ǆ ẞ ΐ ﬃ".as_bytes(),
+            "I\u{300} Ì Į\u{301} J\u{301}".as_bytes(),
+            b"\xFF\xFEABC",
+            b"aB\xF0\x9F\x87Yz\xFF\xFE\xCE",
+            "ΑΣ\u{212A}".as_bytes(),
+            b"FILE",
+        ];
+        let modes = [
+            LowercaseMode::Full,
+            LowercaseMode::Ascii,
+            LowercaseMode::Lithuanian,
+            LowercaseMode::Fold,
+            LowercaseMode::TurkicFold,
+        ];
+        for input in inputs {
+            for mode in modes {
+                let mut buf = input.to_vec();
+                lowercase_in_place(&mut buf, mode);
+                assert_eq!(buf.as_bstr(), to_lowercase(input, mode).as_bstr());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not yet implemented")]
+    fn in_place_turkic_mode_panics() {
+        lowercase_in_place(&mut b"I".to_vec(), LowercaseMode::Turkic);
+    }
+
+    #[test]
+    fn exactly_sized() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"ABC, xyz",
+            "ΑΎΡΙΟ ΟΔΟΣ İ".as_bytes(),
+            "ẞ ﬃ ΐ".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF",
+        ];
+        for input in inputs {
+            for mode in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Lithuanian,
+                LowercaseMode::Fold,
+                LowercaseMode::TurkicFold,
+            ] {
+                let lowercase = to_lowercase(input, mode);
+                assert_eq!(lowercase.len(), lowercase.capacity());
+                assert_eq!(lowercase, crate::lowercase(input, mode).collect::<Vec<_>>());
+            }
+            if let Ok(lowercase) = to_lowercase_string(input, LowercaseMode::Full) {
+                assert_eq!(lowercase.len(), lowercase.capacity());
+            }
+        }
+    }
+}
@@ -0,0 +1,540 @@
+use core::iter;
+
+use crate::stream::{collect_mapped, Carry, Mapped};
+use crate::unicode::properties::{
+    canonical_combining_class, is_case_ignorable, is_cased, COMBINING_CLASS_ABOVE,
+};
+use crate::unicode::sigma::{lowercase_sigma_in_context, GREEK_CAPITAL_LETTER_SIGMA};
+use crate::{max_expansion_ratio, CaseMapping, LowercaseMode, UnsupportedCaseMappingMode};
+
+/// The UTF-8 encoding of U+0307 COMBINING DOT ABOVE.
+const COMBINING_DOT_ABOVE: &[u8] = "\u{307}".as_bytes();
+
+/// The most input bytes which are held back while waiting for the context of
+/// a `Σ` or a Lithuanian `I`, `J`, or `Į`.
+const HELD_MAX_BYTES: usize = 32;
+
+/// Held back input followed by a UTF-8 sequence which is completed by the
+/// next chunk.
+const PENDING_MAX_BYTES: usize = HELD_MAX_BYTES + 4;
+
+/// A lowercase case mapper for conventionally UTF-8 input which arrives in
+/// chunks.
+///
+/// Chunks may split UTF-8 byte sequences at any byte. An incomplete UTF-8
+/// sequence at the end of a chunk is buffered by the streamer and is case
+/// mapped once the chunks which follow complete it. Call [`finish`] after the
+/// last chunk to flush a sequence which is never completed.
+///
+/// Context-dependent mappings see the input on both sides of a chunk
+/// boundary. A `Σ` at the end of a chunk in [`LowercaseMode::Full`] and
+/// [`LowercaseMode::Lithuanian`], and an `I`, `J`, or `Į` at the end of a chunk
+/// in [`LowercaseMode::Lithuanian`], is held back along with any combining
+/// marks which follow it until a later chunk or [`finish`] resolves its
+/// context. A character whose context is not resolved within 32 bytes is
+/// mapped as if the input ended there.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{LowercaseMode, LowercaseStreamer};
+/// let input = "ΑΎΡΙΟ".as_bytes();
+/// let mut streamer = LowercaseStreamer::new(LowercaseMode::Full);
+/// let mut lowercase = streamer.push(&input[..3]).collect::<Vec<_>>();
+/// lowercase.extend(streamer.push(&input[3..]));
+/// lowercase.extend(streamer.finish());
+/// assert_eq!(lowercase, "αύριο".as_bytes());
+/// ```
+///
+/// A `Σ` which ends a chunk is not mapped until the following input is known:
+///
+/// ```
+/// # use roe::{LowercaseMode, LowercaseStreamer};
+/// let mut streamer = LowercaseStreamer::new(LowercaseMode::Full);
+/// assert_eq!(streamer.push("ΑΣ".as_bytes()).collect::<Vec<_>>(), "α".as_bytes());
+/// assert_eq!(streamer.push("Α".as_bytes()).collect::<Vec<_>>(), "σα".as_bytes());
+/// assert_eq!(streamer.finish().count(), 0);
+/// ```
+///
+/// [`finish`]: Self::finish
+#[derive(Debug, Clone, Copy)]
+pub struct LowercaseStreamer {
+    mode: LowercaseMode,
+    carry: Carry,
+    // Input which has been pushed but is not yet mapped because its context
+    // depends on input which has not been pushed yet.
+    held: Pending,
+    // Whether the input before `held` ends with a cased char followed by zero
+    // or more case-ignorable chars.
+    preceding_cased: bool,
+}
+
+impl LowercaseStreamer {
+    /// Create a new streamer which lowercases its input with the given
+    /// [`LowercaseMode`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+    ///
+    /// [`lowercase`]: crate::lowercase()
+    #[must_use]
+    pub fn new(mode: LowercaseMode) -> Self {
+        if mode == LowercaseMode::Turkic {
+            let err = UnsupportedCaseMappingMode::new(CaseMapping::Lowercase(mode));
+            panic!("{}", err);
+        }
+        Self {
+            mode,
+            carry: Carry::new(),
+            held: Pending::new(),
+            preceding_cased: false,
+        }
+    }
+
+    /// Returns an iterator that yields the lowercase bytes of the given chunk.
+    ///
+    /// A UTF-8 sequence which begins in an earlier chunk and is completed by
+    /// this one is yielded first, after any held back input. An incomplete
+    /// UTF-8 sequence at the end of this chunk is not yielded until a later
+    /// chunk completes it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{LowercaseMode, LowercaseStreamer};
+    /// let mut streamer = LowercaseStreamer::new(LowercaseMode::Full);
+    /// // The first byte of `Ύ`.
+    /// assert_eq!(streamer.push(b"A\xCE").collect::<Vec<_>>(), b"a");
+    /// assert_eq!(streamer.push(b"\x8E").collect::<Vec<_>>(), "ύ".as_bytes());
+    /// ```
+    pub fn push<'a>(&mut self, chunk: &'a [u8]) -> impl Iterator<Item = u8> + 'a {
+        let (head, body) = self.carry.split(chunk);
+        let mut front = self.held;
+        front.extend_from_slice(head.as_slice());
+        self.map(front, body, false)
+    }
+
+    /// Consume the streamer and return an iterator that yields the held back
+    /// input and the buffered incomplete UTF-8 sequence, if any.
+    ///
+    /// Held back input is mapped as if the input ends after it. The incomplete
+    /// sequence is never completed, so it is yielded as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{LowercaseMode, LowercaseStreamer};
+    /// let mut streamer = LowercaseStreamer::new(LowercaseMode::Full);
+    /// assert_eq!(streamer.push(b"ABC\xF0\x9F").collect::<Vec<_>>(), b"abc");
+    /// assert_eq!(streamer.finish().collect::<Vec<_>>(), b"\xF0\x9F");
+    ///
+    /// let mut streamer = LowercaseStreamer::new(LowercaseMode::Full);
+    /// assert_eq!(streamer.push("ΟΔΟΣ".as_bytes()).collect::<Vec<_>>(), "οδο".as_bytes());
+    /// assert_eq!(streamer.finish().collect::<Vec<_>>(), "ς".as_bytes());
+    /// ```
+    pub fn finish(mut self) -> impl Iterator<Item = u8> {
+        let mut front = self.held;
+        front.extend_from_slice(self.carry.take().as_slice());
+        self.map(front, &[], true)
+    }
+
+    // Map `front` followed by `body`. Unless this is the last input, a char at
+    // the end whose context depends on input which has not been pushed yet is
+    // held back for the next call.
+    fn map<'a>(&mut self, front: Pending, body: &'a [u8], is_last: bool) -> Pushed<'a> {
+        let mut iter = Pushed {
+            mode: self.mode,
+            front,
+            body,
+            offset: 0,
+            end: front.len + body.len(),
+            preceding_cased: self.preceding_cased,
+            mapped: collect_mapped(iter::empty()),
+        };
+        if !is_last {
+            let unresolved = iter.unresolved_len();
+            if unresolved <= HELD_MAX_BYTES {
+                iter.end -= unresolved;
+            }
+        }
+        let (preceding, held) = iter.split_at(iter.end);
+        self.preceding_cased = has_preceding_cased(preceding, self.preceding_cased);
+        self.held = Pending::new();
+        for slice in held {
+            self.held.extend_from_slice(slice);
+        }
+        iter
+    }
+}
+
+/// Input bytes which are mapped ahead of the body of a chunk.
+#[derive(Debug, Clone, Copy)]
+struct Pending {
+    bytes: [u8; PENDING_MAX_BYTES],
+    len: usize,
+}
+
+impl Pending {
+    const fn new() -> Self {
+        Self {
+            bytes: [0; PENDING_MAX_BYTES],
+            len: 0,
+        }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        let end = self.len + bytes.len();
+        self.bytes[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+    }
+}
+
+/// An iterator that yields the lowercase bytes of a pushed chunk.
+///
+/// The input is `front` followed by `body`, of which the first `end` bytes are
+/// mapped. The bytes which follow `end` are context only.
+struct Pushed<'a> {
+    mode: LowercaseMode,
+    front: Pending,
+    body: &'a [u8],
+    offset: usize,
+    end: usize,
+    // Whether the input before `front` ends with a cased char followed by zero
+    // or more case-ignorable chars.
+    preceding_cased: bool,
+    mapped: Mapped,
+}
+
+impl Pushed<'_> {
+    /// Split the input at the given offset into the bytes which precede it and
+    /// the bytes which follow it.
+    fn split_at(&self, offset: usize) -> ([&[u8]; 2], [&[u8]; 2]) {
+        let front = self.front.as_slice();
+        if offset <= front.len() {
+            let (before, after) = front.split_at(offset);
+            ([before, &[]], [after, self.body])
+        } else {
+            let (before, after) = self.body.split_at(offset - front.len());
+            ([front, before], [&[], after])
+        }
+    }
+
+    /// Returns the length of the suffix of the input which begins with a char
+    /// whose context is not resolved by the input.
+    fn unresolved_len(&self) -> usize {
+        let mut final_sigma = matches!(self.mode, LowercaseMode::Full | LowercaseMode::Lithuanian);
+        let mut more_above = self.mode == LowercaseMode::Lithuanian;
+        let mut len = 0;
+        for (ch, size) in decode_rev(self.split_at(self.end).0) {
+            let ch = match ch {
+                Some(ch) if final_sigma || more_above => ch,
+                // Invalid UTF-8 byte sequences end the context.
+                _ => break,
+            };
+            len += size;
+            if (final_sigma && ch == GREEK_CAPITAL_LETTER_SIGMA)
+                || (more_above && matches!(ch, 'I' | 'J' | 'Į'))
+            {
+                return len;
+            }
+            final_sigma &= is_case_ignorable(ch);
+            more_above &= !matches!(canonical_combining_class(ch), 0 | COMBINING_CLASS_ABOVE);
+        }
+        0
+    }
+}
+
+impl Iterator for Pushed<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.mapped.next() {
+            return Some(byte);
+        }
+        if self.offset >= self.end {
+            return None;
+        }
+        let (preceding, following) = self.split_at(self.offset);
+        let rest = if following[0].is_empty() {
+            following[1]
+        } else {
+            following[0]
+        };
+        // Only `I` and `J` have context-dependent mappings in the ASCII range.
+        if let Some(&byte) = rest.first() {
+            if byte.is_ascii() && !matches!(byte, b'I' | b'J') {
+                self.offset += 1;
+                return Some(byte.to_ascii_lowercase());
+            }
+        }
+        let (ch, size) = bstr::decode_utf8(rest);
+        let bytes = &rest[..size];
+        let (_, following) = self.split_at(self.offset + size);
+        let mapped = match ch {
+            Some(GREEK_CAPITAL_LETTER_SIGMA)
+                if matches!(self.mode, LowercaseMode::Full | LowercaseMode::Lithuanian) =>
+            {
+                let sigma = lowercase_sigma_in_context(
+                    has_preceding_cased(preceding, self.preceding_cased),
+                    is_followed_by_cased(following),
+                );
+                let mut buf = [0; 4];
+                collect_mapped(sigma.encode_utf8(&mut buf).bytes())
+            }
+            // `I`, `J`, and `Į` gain an explicit dot when they are followed by
+            // `More_Above` combining marks.
+            Some('I' | 'J' | 'Į')
+                if self.mode == LowercaseMode::Lithuanian && is_more_above(following) =>
+            {
+                let dot = COMBINING_DOT_ABOVE.iter().copied();
+                collect_mapped(crate::lowercase(bytes, self.mode).chain(dot))
+            }
+            _ => collect_mapped(crate::lowercase(bytes, self.mode)),
+        };
+        self.offset += size;
+        self.mapped = mapped;
+        self.mapped.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (num, den) = max_expansion_ratio(CaseMapping::Lowercase(self.mode));
+        let pending = self.mapped.len();
+        let max = (self.end - self.offset)
+            .checked_mul(num)
+            .map(|max| max / den);
+        (pending, max.and_then(|max| max.checked_add(pending)))
+    }
+}
+
+/// Decode the chars of the given slices from front to back. Invalid UTF-8 byte
+/// sequences are decoded as `None`.
+fn decode(slices: [&[u8]; 2]) -> impl Iterator<Item = Option<char>> + '_ {
+    IntoIterator::into_iter(slices).flat_map(|mut slice| {
+        iter::from_fn(move || match bstr::decode_utf8(slice) {
+            (_, 0) => None,
+            (ch, size) => {
+                slice = &slice[size..];
+                Some(ch)
+            }
+        })
+    })
+}
+
+/// Decode the chars of the given slices from back to front along with their
+/// encoded size. Invalid UTF-8 byte sequences are decoded as `None`.
+fn decode_rev(slices: [&[u8]; 2]) -> impl Iterator<Item = (Option<char>, usize)> + '_ {
+    let [front, back] = slices;
+    IntoIterator::into_iter([back, front]).flat_map(|mut slice| {
+        iter::from_fn(move || match bstr::decode_last_utf8(slice) {
+            (_, 0) => None,
+            (ch, size) => {
+                slice = &slice[..slice.len() - size];
+                Some((ch, size))
+            }
+        })
+    })
+}
+
+/// Returns whether `preceding` ends with a cased char followed by zero or more
+/// case-ignorable chars, or `default` if it is empty or only contains
+/// case-ignorable chars.
+fn has_preceding_cased(preceding: [&[u8]; 2], default: bool) -> bool {
+    for (ch, _) in decode_rev(preceding) {
+        match ch {
+            Some(ch) if is_case_ignorable(ch) => {}
+            Some(ch) => return is_cased(ch),
+            None => return false,
+        }
+    }
+    default
+}
+
+/// Returns whether `following` begins with zero or more case-ignorable chars
+/// followed by a cased char.
+fn is_followed_by_cased(following: [&[u8]; 2]) -> bool {
+    for ch in decode(following) {
+        match ch {
+            Some(ch) if is_case_ignorable(ch) => {}
+            Some(ch) => return is_cased(ch),
+            None => return false,
+        }
+    }
+    false
+}
+
+/// Returns whether `following` begins with a combining mark of class Above
+/// with no intervening char of combining class 0 or Above.
+fn is_more_above(following: [&[u8]; 2]) -> bool {
+    for ch in decode(following) {
+        match ch.map(canonical_combining_class) {
+            Some(COMBINING_CLASS_ABOVE) => return true,
+            Some(0) | None => return false,
+            Some(_) => {}
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::{LowercaseStreamer, HELD_MAX_BYTES};
+    use crate::LowercaseMode;
+
+    fn stream(chunks: &[&[u8]], mode: LowercaseMode) -> Vec<u8> {
+        let mut streamer = LowercaseStreamer::new(mode);
+        let mut out = Vec::new();
+        for chunk in chunks {
+            out.extend(streamer.push(chunk));
+        }
+        out.extend(streamer.finish());
+        out
+    }
+
+    #[test]
+    fn split_between_every_byte() {
+        let input = "ΑΎΡΙΟ".as_bytes();
+        for mid in 0..=input.len() {
+            let chunks = [&input[..mid], &input[mid..]];
+            let lowercase = stream(&chunks, LowercaseMode::Full);
+            assert_eq!(lowercase.as_bstr(), "αύριο".as_bytes().as_bstr());
+        }
+    }
+
+    #[test]
+    fn matches_lowercase() {
+        let input = ["İ ẞ \u{1F600} ABC ΑΣ".as_bytes(), b"\xFF\xF0\x9F\x87"].concat();
+        for mode in [
+            LowercaseMode::Full,
+            LowercaseMode::Ascii,
+            LowercaseMode::Lithuanian,
+            LowercaseMode::Fold,
+            LowercaseMode::TurkicFold,
+        ] {
+            let expected = crate::lowercase(&input, mode).collect::<Vec<_>>();
+            for mid in 0..=input.len() {
+                for end in mid..=input.len() {
+                    let chunks = [&input[..mid], &input[mid..end], &input[end..]];
+                    assert_eq!(stream(&chunks, mode).as_bstr(), expected.as_bstr());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn final_sigma_split_points() {
+        assert_eq!(
+            stream(&["ΑΣ".as_bytes(), "Α".as_bytes()], LowercaseMode::Full).as_bstr(),
+            "ασα".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            stream(&["ΑΣ".as_bytes(), b" "], LowercaseMode::Full).as_bstr(),
+            "ας ".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            stream(&["Α".as_bytes(), "ΣΑ".as_bytes()], LowercaseMode::Full).as_bstr(),
+            "ασα".as_bytes().as_bstr()
+        );
+
+        let input = "ΟΔΟΣ ΣΑΣ Σ ΑΣ\u{301}Α ΑΣ\u{301}. ΑΣ'\u{301}".as_bytes();
+        for mode in [LowercaseMode::Full, LowercaseMode::Lithuanian] {
+            let expected = crate::lowercase(input, mode).collect::<Vec<_>>();
+            for mid in 0..=input.len() {
+                for end in mid..=input.len() {
+                    let chunks = [&input[..mid], &input[mid..end], &input[end..]];
+                    assert_eq!(stream(&chunks, mode).as_bstr(), expected.as_bstr());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lithuanian_split_points() {
+        let lithuanian = |chunks: &[&[u8]]| stream(chunks, LowercaseMode::Lithuanian);
+        assert_eq!(
+            lithuanian(&[b"I", "\u{301}".as_bytes()]).as_bstr(),
+            "i\u{307}\u{301}".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            lithuanian(&["Į\u{323}".as_bytes(), "\u{301}".as_bytes()]).as_bstr(),
+            "į\u{307}\u{323}\u{301}".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            lithuanian(&[b"J", b"a\xCC\x80"]).as_bstr(),
+            "ja\u{300}".as_bytes().as_bstr()
+        );
+
+        let input = "I\u{300}J\u{323}\u{301} Į Į\u{323}a ÌI".as_bytes();
+        let expected = crate::lowercase(input, LowercaseMode::Lithuanian).collect::<Vec<_>>();
+        for mid in 0..=input.len() {
+            for end in mid..=input.len() {
+                let chunks = [&input[..mid], &input[mid..end], &input[end..]];
+                assert_eq!(lithuanian(&chunks).as_bstr(), expected.as_bstr());
+            }
+        }
+    }
+
+    #[test]
+    fn held_input_is_bounded() {
+        // COMBINING ACUTE ACCENT is case-ignorable and two bytes long. The
+        // sigma and the accents fill the held back input.
+        let accents = "\u{301}".repeat((HELD_MAX_BYTES - "Σ".len()) / 2);
+        let mut streamer = LowercaseStreamer::new(LowercaseMode::Full);
+        assert_eq!(
+            streamer.push("ΑΣ".as_bytes()).collect::<Vec<_>>().as_bstr(),
+            "α".as_bytes().as_bstr()
+        );
+        assert_eq!(streamer.push(accents.as_bytes()).count(), 0);
+        // The sigma is mapped as if the input ended once too much input is
+        // held back.
+        let mut expected = "ς".as_bytes().to_vec();
+        expected.extend(accents.as_bytes());
+        expected.extend("\u{301}".as_bytes());
+        assert_eq!(
+            streamer
+                .push("\u{301}".as_bytes())
+                .collect::<Vec<_>>()
+                .as_bstr(),
+            expected.as_bstr()
+        );
+        assert_eq!(
+            streamer.push("Α".as_bytes()).collect::<Vec<_>>().as_bstr(),
+            "α".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn one_byte_at_a_time() {
+        let input = "ΑΎΡΙΟ ΟΔΟΣ \u{1F600}".as_bytes();
+        let chunks = input.chunks(1).collect::<Vec<_>>();
+        let lowercase = stream(&chunks, LowercaseMode::Full);
+        assert_eq!(
+            lowercase.as_bstr(),
+            "αύριο οδος \u{1F600}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn finish_flushes_incomplete_sequence() {
+        let mut streamer = LowercaseStreamer::new(LowercaseMode::Full);
+        assert_eq!(streamer.push(b"\xE2").count(), 0);
+        assert_eq!(streamer.push(b"\x82").count(), 0);
+        assert_eq!(streamer.finish().collect::<Vec<_>>(), b"\xE2\x82");
+
+        let streamer = LowercaseStreamer::new(LowercaseMode::Full);
+        assert_eq!(streamer.finish().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "not yet implemented")]
+    fn unsupported_mode_panics() {
+        let _ = LowercaseStreamer::new(LowercaseMode::Turkic);
+    }
+}
@@ -0,0 +1,254 @@
+use core::fmt;
+
+use crate::CaseMapping;
+
+/// Error that indicates the case mapped output of [`map_into`] does not fit in
+/// the given buffer.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{CapacityError, UppercaseMode};
+/// let mut out = [0; 4];
+/// let result = roe::map_into("ß".as_bytes(), UppercaseMode::Full.into(), &mut out);
+/// assert_eq!(result, Ok(2));
+///
+/// let result = roe::map_into(b"abcde", UppercaseMode::Full.into(), &mut out);
+/// assert_eq!(result, Err(CapacityError::new()));
+/// ```
+///
+/// [`map_into`]: crate::map_into()
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CapacityError {
+    _private: (),
+}
+
+impl CapacityError {
+    /// Construct a new `CapacityError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::CapacityError;
+    /// const ERR: CapacityError = CapacityError::new();
+    /// assert_eq!(ERR.message(), "case mapped output exceeds buffer capacity");
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+
+    /// Retrieve the error message associated with this `CapacityError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::CapacityError;
+    /// const MESSAGE: &str = CapacityError::new().message();
+    /// assert_eq!(MESSAGE, "case mapped output exceeds buffer capacity");
+    /// ```
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub const fn message(self) -> &'static str {
+        "case mapped output exceeds buffer capacity"
+    }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const MESSAGE: &str = CapacityError::new().message();
+        f.write_str(MESSAGE)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
+
+/// Case map the given slice into a fixed capacity buffer and return the number
+/// of bytes written.
+///
+/// This function materializes case mapped output without an allocator. The
+/// bytes written to the front of `out` are the same bytes yielded by
+/// [`lowercase`], [`uppercase`], or [`titlecase`] for the given
+/// [`CaseMapping`]. Invalid UTF-8 byte sequences are copied to `out` as is.
+///
+/// Bytes of `out` past the returned length are left untouched.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{CaseMapping, LowercaseMode, TitlecaseMode};
+/// let mut out = [0; 16];
+/// let len = roe::map_into("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full.into(), &mut out)?;
+/// assert_eq!(&out[..len], "αύριο".as_bytes());
+///
+/// let len = roe::map_into(b"abc\xFF", CaseMapping::Titlecase(TitlecaseMode::Ascii), &mut out)?;
+/// assert_eq!(&out[..len], b"Abc\xFF");
+/// # Ok::<(), roe::CapacityError>(())
+/// ```
+///
+/// # Errors
+///
+/// If the case mapped output is longer than `N` bytes, a [`CapacityError`] is
+/// returned. Output is written as it is mapped, so when this function returns
+/// an error `out` has been overwritten with the first `N` bytes of the output,
+/// which may end in the middle of a character.
+///
+/// # Panics
+///
+/// This function panics for the same unimplemented modes as [`lowercase`],
+/// [`uppercase`], and [`titlecase`].
+///
+/// [`lowercase`]: crate::lowercase()
+/// [`uppercase`]: crate::uppercase()
+/// [`titlecase`]: crate::titlecase()
+pub fn map_into<const N: usize>(
+    slice: &[u8],
+    mapping: CaseMapping,
+    out: &mut [u8; N],
+) -> Result<usize, CapacityError> {
+    match mapping {
+        CaseMapping::Lowercase(mode) => write_into(crate::lowercase(slice, mode), out),
+        CaseMapping::Uppercase(mode) => write_into(crate::uppercase(slice, mode), out),
+        CaseMapping::Titlecase(mode) => write_into(crate::titlecase(slice, mode), out),
+    }
+}
+
+fn write_into<I>(iter: I, out: &mut [u8]) -> Result<usize, CapacityError>
+where
+    I: Iterator<Item = u8>,
+{
+    let mut written = 0;
+    for byte in iter {
+        let dst = out.get_mut(written).ok_or_else(CapacityError::new)?;
+        *dst = byte;
+        written += 1;
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::{map_into, CapacityError};
+    use crate::{CaseMapping, LowercaseMode, TitlecaseMode, UppercaseMode};
+
+    #[test]
+    fn exact_fit() {
+        let mut out = [0; 7];
+        let len = map_into("straße".as_bytes(), UppercaseMode::Full.into(), &mut out);
+        assert_eq!(len, Ok(7));
+        assert_eq!(out.as_bstr(), b"STRASSE".as_bstr());
+
+        let mut out = [0; 3];
+        let len = map_into("İ".as_bytes(), LowercaseMode::Full.into(), &mut out);
+        assert_eq!(len, Ok(3));
+        assert_eq!(out.as_bstr(), "i\u{307}".as_bytes().as_bstr());
+
+        let mut out = [0; 0];
+        let len = map_into(b"", TitlecaseMode::Full.into(), &mut out);
+        assert_eq!(len, Ok(0));
+    }
+
+    #[test]
+    fn matches_iterators() {
+        let inputs: [&[u8]; 5] = [
+            b"abc, XYZ",
+            "ΑΎΡΙΟ ΟΔΟΣ".as_bytes(),
+            "ǆUNGLA ﬃ straße".as_bytes(),
+            "IJ\u{301} İı".as_bytes(),
+            b"",
+        ];
+        let lowercase_modes = [
+            LowercaseMode::Full,
+            LowercaseMode::Ascii,
+            LowercaseMode::Lithuanian,
+            LowercaseMode::Fold,
+            LowercaseMode::TurkicFold,
+        ];
+        let uppercase_modes = [
+            UppercaseMode::Full,
+            UppercaseMode::Ascii,
+            UppercaseMode::Turkic,
+            UppercaseMode::Lithuanian,
+            UppercaseMode::Fold,
+            UppercaseMode::CapitalSharpS,
+        ];
+        let titlecase_modes = [
+            TitlecaseMode::Full,
+            TitlecaseMode::Ascii,
+            TitlecaseMode::Lithuanian,
+        ];
+        for input in inputs {
+            let mut out = [0; 64];
+            for mode in lowercase_modes {
+                let len = map_into(input, CaseMapping::Lowercase(mode), &mut out).unwrap();
+                let expected = crate::lowercase(input, mode);
+                assert_eq!(out[..len].as_bstr(), expected.collect::<Vec<_>>().as_bstr());
+            }
+            for mode in uppercase_modes {
+                let len = map_into(input, CaseMapping::Uppercase(mode), &mut out).unwrap();
+                let expected = crate::uppercase(input, mode);
+                assert_eq!(out[..len].as_bstr(), expected.collect::<Vec<_>>().as_bstr());
+            }
+            for mode in titlecase_modes {
+                let len = map_into(input, CaseMapping::Titlecase(mode), &mut out).unwrap();
+                let expected = crate::titlecase(input, mode);
+                assert_eq!(out[..len].as_bstr(), expected.collect::<Vec<_>>().as_bstr());
+            }
+        }
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let mut out = [0; 6];
+        let result = map_into("straße".as_bytes(), UppercaseMode::Full.into(), &mut out);
+        assert_eq!(result, Err(CapacityError::new()));
+        // The output which fits is written before the error is returned.
+        assert_eq!(out.as_bstr(), b"STRASS".as_bstr());
+
+        // The buffer may end in the middle of an expansion.
+        let mut out = [0; 2];
+        let result = map_into("İ".as_bytes(), LowercaseMode::Full.into(), &mut out);
+        assert_eq!(result, Err(CapacityError::new()));
+        assert_eq!(out.as_bstr(), b"i\xCC".as_bstr());
+
+        let mut out = [0; 0];
+        let result = map_into(b"a", LowercaseMode::Ascii.into(), &mut out);
+        assert_eq!(result, Err(CapacityError::new()));
+    }
+
+    #[test]
+    fn invalid_utf8_is_copied() {
+        let mut out = [0; 16];
+        let len = map_into(
+            b"ABC\xFF\xFEXYZ\xF0\x9F\x87",
+            LowercaseMode::Full.into(),
+            &mut out,
+        );
+        assert_eq!(len, Ok(11));
+        assert_eq!(out[..11].as_bstr(), b"abc\xFF\xFExyz\xF0\x9F\x87".as_bstr());
+        // Bytes past the output are untouched.
+        assert!(out[11..].iter().all(|&byte| byte == 0));
+
+        let mut out = [0; 4];
+        let result = map_into(
+            b"\xFF\xFE\xFD\xFC\xFB",
+            UppercaseMode::Full.into(),
+            &mut out,
+        );
+        assert_eq!(result, Err(CapacityError::new()));
+        assert_eq!(out.as_bstr(), b"\xFF\xFE\xFD\xFC".as_bstr());
+    }
+
+    #[test]
+    fn error_message() {
+        assert_eq!(
+            CapacityError::new().to_string(),
+            "case mapped output exceeds buffer capacity"
+        );
+    }
+}
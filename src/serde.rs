@@ -0,0 +1,210 @@
+//! [`Serialize`] and [`Deserialize`] implementations for the case mapping mode
+//! enums.
+//!
+//! Modes are serialized as the same lowercase strings their `TryFrom<&[u8]>`
+//! implementations accept, plus `"full"` for the default full Unicode mode,
+//! `"turkic_fold"` for Turkic case folding, which Ruby selects with two
+//! options, and `"capital_sharp_s"`, which has no Ruby option.
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::{LowercaseMode, UppercaseMode};
+
+const fn lowercase_mode_name(mode: LowercaseMode) -> &'static str {
+    match mode {
+        LowercaseMode::Full => "full",
+        LowercaseMode::Ascii => "ascii",
+        LowercaseMode::Turkic => "turkic",
+        LowercaseMode::Lithuanian => "lithuanian",
+        LowercaseMode::Fold => "fold",
+        LowercaseMode::TurkicFold => "turkic_fold",
+    }
+}
+
+const fn uppercase_mode_name(mode: UppercaseMode) -> &'static str {
+    match mode {
+        UppercaseMode::Full => "full",
+        UppercaseMode::Ascii => "ascii",
+        UppercaseMode::Turkic => "turkic",
+        UppercaseMode::Lithuanian => "lithuanian",
+        UppercaseMode::Fold => "fold",
+        UppercaseMode::CapitalSharpS => "capital_sharp_s",
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for LowercaseMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(lowercase_mode_name(*self))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for UppercaseMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(uppercase_mode_name(*self))
+    }
+}
+
+struct LowercaseModeVisitor;
+
+impl Visitor<'_> for LowercaseModeVisitor {
+    type Value = LowercaseMode;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a lowercase mode string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_bytes(v.as_bytes())
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            b"full" => Ok(LowercaseMode::Full),
+            b"turkic_fold" => Ok(LowercaseMode::TurkicFold),
+            v => LowercaseMode::try_from(v).map_err(E::custom),
+        }
+    }
+}
+
+struct UppercaseModeVisitor;
+
+impl Visitor<'_> for UppercaseModeVisitor {
+    type Value = UppercaseMode;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an uppercase mode string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_bytes(v.as_bytes())
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            b"full" => Ok(UppercaseMode::Full),
+            b"capital_sharp_s" => Ok(UppercaseMode::CapitalSharpS),
+            v => UppercaseMode::try_from(v).map_err(E::custom),
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for LowercaseMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(LowercaseModeVisitor)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for UppercaseMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(UppercaseModeVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use core::convert::TryFrom;
+
+    use crate::{InvalidCaseMappingMode, LowercaseMode, UppercaseMode};
+
+    #[test]
+    fn lowercase_mode_round_trip() {
+        for (mode, json) in [
+            (LowercaseMode::Full, r#""full""#),
+            (LowercaseMode::Ascii, r#""ascii""#),
+            (LowercaseMode::Turkic, r#""turkic""#),
+            (LowercaseMode::Lithuanian, r#""lithuanian""#),
+            (LowercaseMode::Fold, r#""fold""#),
+            (LowercaseMode::TurkicFold, r#""turkic_fold""#),
+        ] {
+            assert_eq!(serde_json::to_string(&mode).unwrap(), json);
+            assert_eq!(serde_json::from_str::<LowercaseMode>(json).unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn uppercase_mode_round_trip() {
+        for (mode, json) in [
+            (UppercaseMode::Full, r#""full""#),
+            (UppercaseMode::Ascii, r#""ascii""#),
+            (UppercaseMode::Turkic, r#""turkic""#),
+            (UppercaseMode::Lithuanian, r#""lithuanian""#),
+            (UppercaseMode::Fold, r#""fold""#),
+            (UppercaseMode::CapitalSharpS, r#""capital_sharp_s""#),
+        ] {
+            assert_eq!(serde_json::to_string(&mode).unwrap(), json);
+            assert_eq!(serde_json::from_str::<UppercaseMode>(json).unwrap(), mode);
+        }
+    }
+
+    #[test]
+    fn serialized_names_parse_with_try_from() {
+        for mode in [
+            LowercaseMode::Ascii,
+            LowercaseMode::Turkic,
+            LowercaseMode::Lithuanian,
+            LowercaseMode::Fold,
+        ] {
+            let name = super::lowercase_mode_name(mode);
+            assert_eq!(LowercaseMode::try_from(name), Ok(mode));
+        }
+        for mode in [
+            UppercaseMode::Ascii,
+            UppercaseMode::Turkic,
+            UppercaseMode::Lithuanian,
+            UppercaseMode::Fold,
+        ] {
+            let name = super::uppercase_mode_name(mode);
+            assert_eq!(UppercaseMode::try_from(name), Ok(mode));
+        }
+    }
+
+    #[test]
+    fn unknown_modes_are_rejected() {
+        let message = InvalidCaseMappingMode::new().message();
+
+        let err = serde_json::from_str::<LowercaseMode>(r#""shouty""#).unwrap_err();
+        assert!(err.to_string().starts_with(message));
+        let err = serde_json::from_str::<UppercaseMode>(r#""shouty""#).unwrap_err();
+        assert!(err.to_string().starts_with(message));
+        let err = serde_json::from_str::<UppercaseMode>(r#""""#).unwrap_err();
+        assert!(err.to_string().starts_with(message));
+
+        // Mode names are case sensitive.
+        assert!(serde_json::from_str::<LowercaseMode>(r#""ASCII""#).is_err());
+        // Modes are strings.
+        assert!(serde_json::from_str::<LowercaseMode>("0").is_err());
+    }
+}
@@ -0,0 +1,170 @@
+use core::array;
+use core::iter::Take;
+
+use crate::utf8::incomplete_suffix_len;
+
+// A char case maps to at most three chars.
+const MAPPING_MAX_BYTES: usize = 3 * 4;
+
+/// The case mapped bytes of a UTF-8 sequence which was split across chunks.
+pub(crate) type Mapped = Take<array::IntoIter<u8, MAPPING_MAX_BYTES>>;
+
+/// A UTF-8 sequence, or invalid UTF-8 byte sequence, of at most four bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Sequence {
+    bytes: [u8; 4],
+    len: usize,
+}
+
+impl Sequence {
+    const fn new() -> Self {
+        Self {
+            bytes: [0; 4],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+/// Collect the bytes yielded by case mapping a single [`Sequence`].
+pub(crate) fn collect_mapped<I>(iter: I) -> Mapped
+where
+    I: Iterator<Item = u8>,
+{
+    let mut bytes = [0; MAPPING_MAX_BYTES];
+    let mut len = 0;
+    for byte in iter {
+        bytes[len] = byte;
+        len += 1;
+    }
+    IntoIterator::into_iter(bytes).take(len)
+}
+
+/// The incomplete UTF-8 sequence which ends the last chunk pushed to a
+/// streamer.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Carry {
+    bytes: [u8; 3],
+    len: usize,
+}
+
+impl Carry {
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0; 3],
+            len: 0,
+        }
+    }
+
+    /// Split the given chunk into the sequence which is completed by its
+    /// leading bytes and the bytes which follow it.
+    ///
+    /// An incomplete UTF-8 sequence at the end of the chunk is carried over to
+    /// the next call and is not part of the returned slice.
+    pub(crate) fn split<'a>(&mut self, mut chunk: &'a [u8]) -> (Sequence, &'a [u8]) {
+        let mut head = Sequence::new();
+        if self.len > 0 {
+            // An incomplete sequence is followed by at most three bytes which
+            // complete it.
+            let needed = chunk.len().min(head.bytes.len() - self.len);
+            let total = self.len + needed;
+            head.bytes[..self.len].copy_from_slice(&self.bytes[..self.len]);
+            head.bytes[self.len..total].copy_from_slice(&chunk[..needed]);
+            if incomplete_suffix_len(&head.bytes[..total]) == total {
+                // The chunk is too short to complete the sequence.
+                self.bytes[..total].copy_from_slice(&head.bytes[..total]);
+                self.len = total;
+                return (Sequence::new(), &[]);
+            }
+            let (_, size) = bstr::decode_utf8(&head.bytes[..total]);
+            // The carried bytes are a prefix of a valid UTF-8 sequence, so they
+            // are never split.
+            debug_assert!(size >= self.len);
+            head.len = size;
+            chunk = &chunk[size - self.len..];
+        }
+        let tail = incomplete_suffix_len(chunk);
+        let (body, rest) = chunk.split_at(chunk.len() - tail);
+        self.bytes[..tail].copy_from_slice(rest);
+        self.len = tail;
+        (head, body)
+    }
+
+    /// Take the carried bytes, leaving the carry empty.
+    pub(crate) fn take(&mut self) -> Sequence {
+        let mut seq = Sequence::new();
+        seq.bytes[..self.len].copy_from_slice(&self.bytes[..self.len]);
+        seq.len = self.len;
+        self.len = 0;
+        seq
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Carry;
+
+    fn split_all(chunks: &[&[u8]]) -> Vec<u8> {
+        let mut carry = Carry::new();
+        let mut out = Vec::new();
+        for chunk in chunks {
+            let (head, body) = carry.split(chunk);
+            out.extend_from_slice(head.as_slice());
+            out.extend_from_slice(body);
+        }
+        out.extend_from_slice(carry.take().as_slice());
+        out
+    }
+
+    #[test]
+    fn complete_chunks_are_not_carried() {
+        let mut carry = Carry::new();
+        let (head, body) = carry.split("abc αύριο".as_bytes());
+        assert_eq!(head.as_slice(), b"");
+        assert_eq!(body, "abc αύριο".as_bytes());
+        assert_eq!(carry.take().as_slice(), b"");
+    }
+
+    #[test]
+    fn incomplete_sequence_is_carried() {
+        let mut carry = Carry::new();
+        let (head, body) = carry.split(b"a\xF0\x9F");
+        assert_eq!(head.as_slice(), b"");
+        assert_eq!(body, b"a");
+        let (head, body) = carry.split(b"\x98");
+        assert_eq!(head.as_slice(), b"");
+        assert_eq!(body, b"");
+        let (head, body) = carry.split(b"\x80z");
+        assert_eq!(head.as_slice(), "\u{1F600}".as_bytes());
+        assert_eq!(body, b"z");
+    }
+
+    #[test]
+    fn invalid_sequences_are_not_carried() {
+        let mut carry = Carry::new();
+        let (_, body) = carry.split(b"a\xFF");
+        assert_eq!(body, b"a\xFF");
+        let (_, body) = carry.split(b"a\xE2\x82");
+        assert_eq!(body, b"a");
+        // The carried prefix is not completed by the chunk.
+        let (head, body) = carry.split(b"z");
+        assert_eq!(head.as_slice(), b"\xE2\x82");
+        assert_eq!(body, b"z");
+    }
+
+    #[test]
+    fn every_split_reassembles_input() {
+        let input = ["aΑΎΡΙΟ \u{1F600}".as_bytes(), b"\xFF\xF0\x9F\x87"].concat();
+        for mid in 0..=input.len() {
+            for end in mid..=input.len() {
+                let chunks = [&input[..mid], &input[mid..end], &input[end..]];
+                assert_eq!(split_all(&chunks), input);
+            }
+        }
+    }
+}
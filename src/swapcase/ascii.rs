@@ -0,0 +1,145 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+#[derive(Clone)]
+#[must_use = "Swapcase is a Iterator and must be used"]
+pub struct Swapcase<'a> {
+    source: &'a [u8],
+    slice: &'a [u8],
+}
+
+impl fmt::Debug for Swapcase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Swapcase")
+            .field("slice", &self.slice.as_bstr())
+            .finish()
+    }
+}
+
+impl<'a> From<&'a [u8]> for Swapcase<'a> {
+    fn from(slice: &'a [u8]) -> Self {
+        Self::with_slice(slice)
+    }
+}
+
+impl<'a> Swapcase<'a> {
+    pub const fn with_slice(slice: &'a [u8]) -> Self {
+        Self {
+            source: slice,
+            slice,
+        }
+    }
+
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    pub fn reset(&mut self) {
+        self.slice = self.source;
+    }
+
+    /// Skip up to `n` bytes without mapping them and return the number of
+    /// bytes skipped.
+    pub(crate) fn advance(&mut self, n: usize) -> usize {
+        let n = n.min(self.slice.len());
+        self.slice = &self.slice[n..];
+        n
+    }
+}
+
+const fn swap_ascii_case(byte: u8) -> u8 {
+    if byte.is_ascii_uppercase() {
+        byte.to_ascii_lowercase()
+    } else {
+        byte.to_ascii_uppercase()
+    }
+}
+
+impl Iterator for Swapcase<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&byte, remainder) = self.slice.split_first()?;
+        self.slice = remainder;
+        Some(swap_ascii_case(byte))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.slice.len()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // Skip the first `n` bytes without mapping them.
+        self.advance(n);
+        self.next()
+    }
+}
+
+impl DoubleEndedIterator for Swapcase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (&byte, remainder) = self.slice.split_last()?;
+        self.slice = remainder;
+        Some(swap_ascii_case(byte))
+    }
+}
+
+impl ExactSizeIterator for Swapcase<'_> {}
+
+impl FusedIterator for Swapcase<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::Swapcase;
+
+    #[test]
+    fn empty() {
+        let iter = Swapcase::from(&b""[..]);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+    }
+
+    #[test]
+    fn ascii() {
+        let iter = Swapcase::from(&b"abc"[..]);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"ABC".as_bstr());
+
+        let iter = Swapcase::from(&b"aBC, 123, xYz!"[..]);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"Abc, 123, XyZ!".as_bstr()
+        );
+    }
+
+    #[test]
+    fn non_ascii_is_unchanged() {
+        let iter = Swapcase::from("Straße ΑΎΡΙΟ".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "sTRAßE ΑΎΡΙΟ".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let iter = Swapcase::from(&b"aB\xFF\xFEcD"[..]);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"Ab\xFF\xFECd".as_bstr()
+        );
+    }
+
+    #[test]
+    fn rev() {
+        let iter = Swapcase::from(&b"aBc"[..]).rev();
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"CbA".as_bstr());
+    }
+}
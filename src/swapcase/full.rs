@@ -0,0 +1,374 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use crate::buffer::OutputBuffer;
+use crate::unicode::swapcase::ToSwapcase;
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, UppercaseMode};
+
+// Mapped output is buffered separately for each end of the iterator. Once
+// `slice` is exhausted, each end drains the buffers of the other end.
+#[derive(Clone)]
+#[must_use = "Swapcase is a Iterator and must be used"]
+pub struct Swapcase<'a> {
+    source: &'a [u8],
+    slice: &'a [u8],
+    next_bytes: OutputBuffer,
+    swapcase: Option<ToSwapcase>,
+    next_back_bytes: OutputBuffer,
+    swapcase_back: Option<ToSwapcase>,
+}
+
+impl fmt::Debug for Swapcase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Swapcase")
+            .field("slice", &self.slice.as_bstr())
+            .field("next_bytes", &self.next_bytes)
+            .field("swapcase", &self.swapcase)
+            .field("next_back_bytes", &self.next_back_bytes)
+            .field("swapcase_back", &self.swapcase_back)
+            .finish()
+    }
+}
+
+impl<'a> From<&'a [u8]> for Swapcase<'a> {
+    fn from(slice: &'a [u8]) -> Self {
+        Self::with_slice(slice)
+    }
+}
+
+impl<'a> Swapcase<'a> {
+    pub const fn with_slice(slice: &'a [u8]) -> Self {
+        Self {
+            source: slice,
+            slice,
+            next_bytes: OutputBuffer::new(),
+            swapcase: None,
+            next_back_bytes: OutputBuffer::new(),
+            swapcase_back: None,
+        }
+    }
+
+    fn pending_chars(&self) -> usize {
+        let front = self.swapcase.as_ref().map_or(0, ExactSizeIterator::len);
+        let back = self
+            .swapcase_back
+            .as_ref()
+            .map_or(0, ExactSizeIterator::len);
+        front + back
+    }
+
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::with_slice(self.source);
+    }
+}
+
+impl Iterator for Swapcase<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_bytes.next_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self.swapcase.as_mut().and_then(Iterator::next) {
+            self.next_bytes.push_char(ch);
+            return self.next_bytes.next_byte();
+        }
+
+        self.swapcase = None;
+
+        match bstr::decode_utf8(self.slice) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // reverse iteration.
+                if let Some(ch) = self.swapcase_back.as_mut().and_then(Iterator::next) {
+                    self.next_bytes.push_char(ch);
+                    return self.next_bytes.next_byte();
+                }
+                self.next_back_bytes.next_byte()
+            }
+            (Some(ch), size) => {
+                self.slice = &self.slice[size..];
+                let mut swapcase = ToSwapcase::new(ch);
+                let ch = swapcase
+                    .next()
+                    .expect("ToSwapcase yields at least one char");
+                self.next_bytes.push_char(ch);
+
+                self.swapcase = Some(swapcase);
+                self.next_bytes.next_byte()
+            }
+            (None, size) => {
+                let (bytes, remainder) = self.slice.split_at(size);
+                self.slice = remainder;
+
+                self.next_bytes.push_bytes(bytes);
+                self.next_bytes.next_byte()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        const UTF_8_CHAR_MAX_BYTES: usize = 4;
+        // Swapcase applies either the uppercase or the lowercase mapping to
+        // each char. Uppercasing has the larger maximum expansion ratio.
+        const SWAPCASE_RATIO: (usize, usize) =
+            max_expansion_ratio(CaseMapping::Uppercase(UppercaseMode::Full));
+        // Bytes of the current chars which have not been yielded yet plus the
+        // chars of the current swapcase expansions which have not been
+        // encoded.
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        let pending_chars = self.pending_chars();
+        let pending_min = pending_bytes + pending_chars;
+        let pending_max = pending_bytes + pending_chars * UTF_8_CHAR_MAX_BYTES;
+        let len = self.slice.len();
+        if self.slice.is_ascii() {
+            (len + pending_min, Some(len + pending_max))
+        } else {
+            // Every char and invalid UTF-8 byte sequence in the remaining
+            // slice yields at least one byte, and no mapping expands by more
+            // than the maximum expansion ratio.
+            let (num, den) = SWAPCASE_RATIO;
+            let max = len.checked_mul(num).map(|max| max / den);
+            (
+                lead_byte_count(self.slice) + pending_min,
+                max.and_then(|max| max.checked_add(pending_max)),
+            )
+        }
+    }
+
+    fn count(self) -> usize {
+        if self.slice.is_ascii() && self.pending_chars() == 0 {
+            self.next_bytes.len() + self.next_back_bytes.len() + self.slice.len()
+        } else {
+            self.fold(0, |acc, _| acc + 1)
+        }
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        // The last byte is the last byte of the final scalar's mapping, which
+        // reverse iteration maps without visiting the rest of the slice.
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Swapcase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_back_bytes.next_back_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self
+            .swapcase_back
+            .as_mut()
+            .and_then(DoubleEndedIterator::next_back)
+        {
+            self.next_back_bytes.push_char(ch);
+            return self.next_back_bytes.next_back_byte();
+        }
+
+        self.swapcase_back = None;
+
+        match bstr::decode_last_utf8(self.slice) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // forward iteration.
+                if let Some(ch) = self
+                    .swapcase
+                    .as_mut()
+                    .and_then(DoubleEndedIterator::next_back)
+                {
+                    self.next_back_bytes.push_char(ch);
+                    return self.next_back_bytes.next_back_byte();
+                }
+                self.next_bytes.next_back_byte()
+            }
+            (Some(ch), size) => {
+                self.slice = &self.slice[..self.slice.len() - size];
+                // Multi-char mappings are yielded back to front, starting with
+                // the last char of the mapping.
+                let mut swapcase = ToSwapcase::new(ch);
+                let ch = swapcase
+                    .next_back()
+                    .expect("ToSwapcase yields at least one char");
+                self.next_back_bytes.push_char(ch);
+
+                self.swapcase_back = Some(swapcase);
+                self.next_back_bytes.next_back_byte()
+            }
+            (None, size) => {
+                let (remainder, bytes) = self.slice.split_at(self.slice.len() - size);
+                self.slice = remainder;
+
+                self.next_back_bytes.push_bytes(bytes);
+                self.next_back_bytes.next_back_byte()
+            }
+        }
+    }
+}
+
+impl FusedIterator for Swapcase<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::Swapcase;
+
+    fn swapcase(s: &[u8]) -> Vec<u8> {
+        Swapcase::from(s).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(swapcase(b"").as_bstr(), b"".as_bstr());
+    }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(
+            swapcase(b"Hello, World 123").as_bstr(),
+            b"hELLO, wORLD 123".as_bstr()
+        );
+    }
+
+    #[test]
+    fn mixed_latin_and_greek() {
+        assert_eq!(
+            swapcase("Αύριο ΚΑΙ école".as_bytes()).as_bstr(),
+            "αΎΡΙΟ και ÉCOLE".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            swapcase("ΣίσυφοςAndÉmile".as_bytes()).as_bstr(),
+            "σΊΣΥΦΟΣaNDéMILE".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn expansions() {
+        assert_eq!(
+            swapcase("Straße".as_bytes()).as_bstr(),
+            b"sTRASSE".as_bstr()
+        );
+        assert_eq!(swapcase("ﬃ".as_bytes()).as_bstr(), b"FFI".as_bstr());
+        assert_eq!(
+            swapcase("ΐ".as_bytes()).as_bstr(),
+            "Ι\u{308}\u{301}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn titlecase_scalars_swap_to_lowercase() {
+        assert_eq!(swapcase("ǈ".as_bytes()).as_bstr(), "ǉ".as_bytes().as_bstr());
+        assert_eq!(
+            swapcase("ǅemal ǲ".as_bytes()).as_bstr(),
+            "ǆEMAL ǳ".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn uncased_characters_are_unchanged() {
+        assert_eq!(
+            swapcase("0123 -_!? € 中文 \u{1F600}".as_bytes()).as_bstr(),
+            "0123 -_!? € 中文 \u{1F600}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        assert_eq!(
+            swapcase(b"aB\xFF\xFEcD").as_bstr(),
+            b"Ab\xFF\xFECd".as_bstr()
+        );
+
+        let mut s = "αΩ".as_bytes().to_vec();
+        s.extend_from_slice(b"\xF0\x9F\x87");
+        s.extend_from_slice("Ωα".as_bytes());
+        let mut expected = "Αω".as_bytes().to_vec();
+        expected.extend_from_slice(b"\xF0\x9F\x87");
+        expected.extend_from_slice("ωΑ".as_bytes());
+        assert_eq!(swapcase(&s).as_bstr(), expected.as_bstr());
+    }
+
+    #[test]
+    fn rev() {
+        let inputs: [&[u8]; 7] = [
+            b"",
+            b"Hello, World 123",
+            "é Αύριο ΚΑΙ école".as_bytes(),
+            "Straße ﬃ ΐ".as_bytes(),
+            "ǅemal ǲ ΟΔΟΣ".as_bytes(),
+            "0123 € 中文 \u{1F600}".as_bytes(),
+            b"aB\xF0\x9F\x87\xFF\xFEcD",
+        ];
+        for input in inputs {
+            let mut rev = Swapcase::from(input).rev().collect::<Vec<_>>();
+            rev.reverse();
+            assert_eq!(rev.as_bstr(), swapcase(input).as_bstr());
+        }
+    }
+
+    #[test]
+    fn next_and_next_back_interleaved() {
+        // `ß` uppercases to `SS` and `ﬃ` uppercases to `FFI`.
+        let mut iter = Swapcase::from("ßﬃ".as_bytes());
+        assert_eq!(iter.next_back(), Some(b'I'));
+        assert_eq!(iter.next(), Some(b'S'));
+        assert_eq!(iter.next_back(), Some(b'F'));
+        assert_eq!(iter.next_back(), Some(b'F'));
+        assert_eq!(iter.next_back(), Some(b'S'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = Swapcase::from("é".as_bytes());
+        assert_eq!(iter.next_back(), Some(0x89));
+        assert_eq!(iter.next(), Some(0xC3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(Swapcase::with_slice(b"").count(), 0);
+        assert_eq!(Swapcase::with_slice(b"abc, XYZ").count(), 8);
+        assert_eq!(Swapcase::with_slice("ß".as_bytes()).count(), 2);
+        assert_eq!(Swapcase::with_slice("ΐ".as_bytes()).count(), 6);
+
+        let mut iter = Swapcase::with_slice("ﬃ".as_bytes());
+        assert_eq!(iter.next(), Some(b'F'));
+        assert_eq!(iter.count(), 2);
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        let inputs: [&[u8]; 7] = [
+            b"",
+            b"abc, XYZ",
+            "ß ﬃ".as_bytes(),
+            "\u{212A}\u{212A}".as_bytes(),
+            "ẞıı".as_bytes(),
+            "ΐΰ".as_bytes(),
+            b"A\xFF\xFEb",
+        ];
+        for input in inputs {
+            let mut iter = Swapcase::with_slice(input);
+            loop {
+                let (min, max) = iter.size_hint();
+                let count = iter.clone().count();
+                assert!(min <= count);
+                assert!(count <= max.unwrap());
+                if iter.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+}
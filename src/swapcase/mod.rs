@@ -0,0 +1,622 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+mod ascii;
+mod full;
+
+#[derive(Debug, Clone)]
+#[allow(variant_size_differences)]
+enum Inner<'a> {
+    Empty,
+    Full(full::Swapcase<'a>),
+    // Full Unicode case mapping over a slice which is entirely ASCII produces
+    // the same output as ASCII case mapping. Slices which are detected to be
+    // ASCII-only at construction time use the cheaper ASCII iterator.
+    FullAscii(ascii::Swapcase<'a>),
+    Ascii(ascii::Swapcase<'a>),
+}
+
+/// An iterator that yields the swapcase equivalent of a conventionally UTF-8
+/// byte string.
+///
+/// This iterator yields [bytes].
+///
+/// This struct is created by the [`swapcase`] function. See its documentation
+/// for more.
+///
+/// # Reverse Iteration
+///
+/// `Swapcase` implements [`DoubleEndedIterator`] so it can be reversed:
+///
+/// ```
+/// # use roe::Swapcase;
+/// let swapcase = Swapcase::with_ascii_slice(b"abcXYZ");
+/// assert_eq!(swapcase.rev().collect::<Vec<_>>(), b"zyxCBA");
+///
+/// let swapcase = Swapcase::with_slice("Éa".as_bytes());
+/// assert_eq!(swapcase.rev().collect::<Vec<_>>(), b"A\xA9\xC3");
+/// ```
+///
+/// [bytes]: u8
+/// [`swapcase`]: crate::swapcase()
+#[derive(Debug, Clone)]
+#[must_use = "Swapcase is a Iterator and must be used"]
+pub struct Swapcase<'a> {
+    iter: Inner<'a>,
+}
+
+impl<'a> Swapcase<'a> {
+    /// Create a new, empty swapcase iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let mut swapcase = Swapcase::new();
+    /// assert_eq!(swapcase.next(), None);
+    /// ```
+    pub const fn new() -> Self {
+        Self { iter: Inner::Empty }
+    }
+
+    /// Create a new swapcase iterator with the given byte slice using full
+    /// Unicode case mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let mut swapcase = Swapcase::with_slice(b"abcXYZ");
+    /// assert_eq!(swapcase.next(), Some(b'A'));
+    /// assert_eq!(swapcase.next(), Some(b'B'));
+    /// assert_eq!(swapcase.next(), Some(b'C'));
+    /// assert_eq!(swapcase.next(), Some(b'x'));
+    /// assert_eq!(swapcase.next(), Some(b'y'));
+    /// assert_eq!(swapcase.next(), Some(b'z'));
+    /// assert_eq!(swapcase.next(), None);
+    /// ```
+    ///
+    /// Non-ASCII characters are case mapped:
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let swapcase = Swapcase::with_slice("Αύριο".as_bytes());
+    /// assert_eq!(swapcase.collect::<Vec<_>>(), "αΎΡΙΟ".as_bytes());
+    ///
+    /// let swapcase = Swapcase::with_slice("ﬃ".as_bytes());
+    /// assert_eq!(swapcase.collect::<Vec<_>>(), "FFI".as_bytes());
+    /// ```
+    ///
+    /// Invalid UTF-8 bytes are yielded as is without impacting Unicode
+    /// characters:
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let mut s = "Αύριο".to_string().into_bytes();
+    /// s.extend(b"\xFF\xFE");
+    /// let swapcase = Swapcase::with_slice(s.as_slice());
+    ///
+    /// let mut expected = "αΎΡΙΟ".to_string().into_bytes();
+    /// expected.extend(b"\xFF\xFE");
+    /// assert_eq!(swapcase.collect::<Vec<_>>(), expected);
+    /// ```
+    pub const fn with_slice(slice: &'a [u8]) -> Self {
+        if slice.is_ascii() {
+            Self {
+                iter: Inner::FullAscii(ascii::Swapcase::with_slice(slice)),
+            }
+        } else {
+            Self {
+                iter: Inner::Full(full::Swapcase::with_slice(slice)),
+            }
+        }
+    }
+
+    /// Create a new swapcase iterator with the given byte slice using ASCII
+    /// case mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let mut swapcase = Swapcase::with_ascii_slice(b"abcXYZ");
+    /// assert_eq!(swapcase.next(), Some(b'A'));
+    /// assert_eq!(swapcase.next(), Some(b'B'));
+    /// assert_eq!(swapcase.next(), Some(b'C'));
+    /// assert_eq!(swapcase.next(), Some(b'x'));
+    /// assert_eq!(swapcase.next(), Some(b'y'));
+    /// assert_eq!(swapcase.next(), Some(b'z'));
+    /// assert_eq!(swapcase.next(), None);
+    /// ```
+    ///
+    /// Non-ASCII characters are ignored:
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let swapcase = Swapcase::with_ascii_slice("αΎρΙο".as_bytes());
+    /// assert_eq!(swapcase.collect::<Vec<_>>(), "αΎρΙο".as_bytes());
+    /// ```
+    ///
+    /// Invalid UTF-8 bytes are yielded as is without impacting ASCII bytes:
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let swapcase = Swapcase::with_ascii_slice(b"abc\xFF\xFEXYZ");
+    /// assert_eq!(swapcase.collect::<Vec<_>>(), b"ABC\xFF\xFExyz");
+    /// ```
+    pub const fn with_ascii_slice(slice: &'a [u8]) -> Self {
+        Self {
+            iter: Inner::Ascii(ascii::Swapcase::with_slice(slice)),
+        }
+    }
+
+    /// Views the not yet case mapped portion of the source slice.
+    ///
+    /// This has the same lifetime as the original slice, so the iterator can
+    /// be dropped and the remaining bytes recovered, like
+    /// [`Chars::as_str`].
+    ///
+    /// Bytes of a character whose case mapping is only partially yielded are
+    /// not part of the returned slice: the character has already been
+    /// consumed from the source slice and the rest of its mapping is buffered
+    /// in the iterator. The returned slice may therefore begin in the middle
+    /// of an expansion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let mut swapcase = Swapcase::with_slice("ßtraße".as_bytes());
+    /// assert_eq!(swapcase.as_slice(), "ßtraße".as_bytes());
+    ///
+    /// assert_eq!(swapcase.next(), Some(b'S'));
+    /// // The expansion of the first character is in progress.
+    /// assert_eq!(swapcase.as_slice(), "traße".as_bytes());
+    /// ```
+    ///
+    /// [`Chars::as_str`]: core::str::Chars::as_str
+    #[must_use]
+    pub const fn as_slice(&self) -> &'a [u8] {
+        match self.iter {
+            Inner::Empty => &[],
+            Inner::Full(ref iter) => iter.as_slice(),
+            Inner::FullAscii(ref iter) | Inner::Ascii(ref iter) => iter.as_slice(),
+        }
+    }
+
+    /// Rewind the iterator to the start of the slice it was created with.
+    ///
+    /// Case mapped bytes which are buffered but not yet yielded are discarded,
+    /// so after a reset the iterator yields the same bytes as a freshly
+    /// constructed one. This lets a tight loop map the same slice repeatedly
+    /// without cloning the iterator up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let mut swapcase = Swapcase::with_slice("ßtRAßE".as_bytes());
+    /// assert_eq!(swapcase.next(), Some(b'S'));
+    /// swapcase.reset();
+    /// assert_eq!(swapcase.collect::<Vec<_>>(), "SSTraSSe".as_bytes());
+    /// ```
+    pub fn reset(&mut self) {
+        match self.iter {
+            Inner::Empty => {}
+            Inner::Full(ref mut iter) => iter.reset(),
+            Inner::FullAscii(ref mut iter) | Inner::Ascii(ref mut iter) => iter.reset(),
+        }
+    }
+
+    /// Skip the next `n` bytes of output and return the iterator.
+    ///
+    /// Iterators which map with ASCII case mapping, including full case
+    /// mapping of an ASCII-only slice, skip the bytes in constant time by
+    /// slicing the remaining input forward. Other iterators map and discard
+    /// the skipped bytes. If fewer than `n` bytes remain, the returned iterator
+    /// is exhausted.
+    ///
+    /// Unlike [`Iterator::skip`], the returned iterator is a `Swapcase`, so its
+    /// inherent methods like [`as_slice`](Self::as_slice) are still available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let swapcase = Swapcase::with_slice(b"abc, XYZ").skip_bytes(5);
+    /// assert_eq!(swapcase.as_slice(), b"XYZ");
+    /// assert_eq!(swapcase.collect::<Vec<_>>(), b"xyz");
+    /// ```
+    pub fn skip_bytes(mut self, n: usize) -> Self {
+        match self.iter {
+            Inner::FullAscii(ref mut iter) | Inner::Ascii(ref mut iter) => {
+                iter.advance(n);
+            }
+            _ => {
+                if let Some(n) = n.checked_sub(1) {
+                    self.nth(n);
+                }
+            }
+        }
+        self
+    }
+
+    /// Returns the exact number of bytes remaining in the iterator, if it is
+    /// known without case mapping the remaining slice.
+    ///
+    /// ASCII case mapping, and full case mapping of an ASCII-only slice, never
+    /// changes the length of the slice, so this method returns [`Some`] for
+    /// iterators which map with ASCII case mapping. It returns [`None`] for
+    /// iterators which map non-ASCII slices with Unicode case mapping, since
+    /// the output may be longer or shorter than the input.
+    ///
+    /// When this method returns `Some`, the value is equal to the lower and
+    /// upper bounds of the iterator's [size hint].
+    ///
+    /// `Swapcase` does not implement [`ExactSizeIterator`] since the length of
+    /// a full Unicode case mapping is not known until the slice is mapped.
+    /// Code which needs the exact length of an empty or ASCII iterator can call
+    /// this method instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let mut swapcase = Swapcase::with_ascii_slice(b"abc XYZ");
+    /// assert_eq!(swapcase.exact_len(), Some(7));
+    /// swapcase.next();
+    /// assert_eq!(swapcase.exact_len(), Some(6));
+    ///
+    /// let swapcase = Swapcase::with_slice("ß".as_bytes());
+    /// assert_eq!(swapcase.exact_len(), None);
+    ///
+    /// assert_eq!(Swapcase::new().exact_len(), Some(0));
+    /// ```
+    ///
+    /// [size hint]: Iterator::size_hint
+    #[must_use]
+    pub fn exact_len(&self) -> Option<usize> {
+        match self.iter {
+            Inner::Empty => Some(0),
+            Inner::FullAscii(ref iter) | Inner::Ascii(ref iter) => Some(iter.len()),
+            Inner::Full(_) => None,
+        }
+    }
+}
+
+impl Default for Swapcase<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> From<&'a [u8]> for Swapcase<'a> {
+    /// Create a new swapcase iterator with the given byte slice using full
+    /// Unicode case mapping.
+    ///
+    /// This is the same as [`Swapcase::with_slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let swapcase = Swapcase::from(&b"aBc"[..]);
+    /// assert_eq!(swapcase.collect::<Vec<_>>(), b"AbC");
+    /// ```
+    #[inline]
+    fn from(slice: &'a [u8]) -> Self {
+        Self::with_slice(slice)
+    }
+}
+
+impl<'a> From<&'a str> for Swapcase<'a> {
+    /// Create a new swapcase iterator with the bytes of the given string using
+    /// full Unicode case mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Swapcase;
+    /// let swapcase = Swapcase::from("Straße");
+    /// assert_eq!(swapcase.collect::<Vec<_>>(), "sTRASSE".as_bytes());
+    /// ```
+    #[inline]
+    fn from(s: &'a str) -> Self {
+        Self::with_slice(s.as_bytes())
+    }
+}
+
+impl Iterator for Swapcase<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(ref mut iter) => iter.next(),
+            Inner::FullAscii(ref mut iter) | Inner::Ascii(ref mut iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.iter {
+            Inner::Empty => (0, Some(0)),
+            Inner::Full(ref iter) => iter.size_hint(),
+            Inner::FullAscii(ref iter) | Inner::Ascii(ref iter) => iter.size_hint(),
+        }
+    }
+
+    fn count(self) -> usize {
+        match self.iter {
+            Inner::Empty => 0,
+            Inner::Full(iter) => iter.count(),
+            Inner::FullAscii(iter) | Inner::Ascii(iter) => iter.count(),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(ref mut iter) => iter.nth(n),
+            Inner::FullAscii(ref mut iter) | Inner::Ascii(ref mut iter) => iter.nth(n),
+        }
+    }
+}
+
+impl DoubleEndedIterator for Swapcase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(ref mut iter) => iter.next_back(),
+            Inner::FullAscii(ref mut iter) | Inner::Ascii(ref mut iter) => iter.next_back(),
+        }
+    }
+}
+
+impl FusedIterator for Swapcase<'_> {}
+
+/// Formats the remaining case mapped bytes as a conventionally UTF-8 string.
+///
+/// Invalid UTF-8 byte sequences are rendered as U+FFFD REPLACEMENT CHARACTER,
+/// the same as the [`Display`] implementation of [`BStr`]. Formatting maps a
+/// clone of the iterator and does not allocate or advance `self`.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{SwapcaseMode, Swapcase};
+/// let swapcase = roe::swapcase("Αύριο".as_bytes(), SwapcaseMode::Full);
+/// assert_eq!(swapcase.to_string(), "αΎΡΙΟ");
+///
+/// let swapcase = Swapcase::with_ascii_slice(b"abc\xFF");
+/// assert_eq!(format!("{swapcase}"), "ABC\u{FFFD}");
+/// ```
+///
+/// [`Display`]: fmt::Display
+/// [`BStr`]: bstr::BStr
+impl fmt::Display for Swapcase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::utf8::fmt_lossy(self.clone(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::{Inner, Swapcase};
+    use crate::SwapcaseMode;
+
+    #[test]
+    fn empty() {
+        let iter = Swapcase::new();
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+
+        let iter = Swapcase::with_slice(b"");
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+
+        let iter = Swapcase::with_ascii_slice(b"");
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+    }
+
+    #[test]
+    fn full_mode_ascii_slice_uses_ascii_iterator() {
+        let iter = Swapcase::with_slice(b"aBC, xyz");
+        assert!(matches!(iter.iter, Inner::FullAscii(_)));
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"Abc, XYZ".as_bstr());
+
+        let iter = Swapcase::with_slice("αύριο ABC".as_bytes());
+        assert!(matches!(iter.iter, Inner::Full(_)));
+
+        let iter = Swapcase::with_slice(b"abc\xFF");
+        assert!(matches!(iter.iter, Inner::Full(_)));
+    }
+
+    #[test]
+    fn full_and_ascii_modes_differ_on_non_ascii() {
+        let input = "Ωmega and ωMEGA".as_bytes();
+        assert_eq!(
+            Swapcase::with_slice(input).collect::<Vec<_>>().as_bstr(),
+            "ωMEGA AND Ωmega".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            Swapcase::with_ascii_slice(input)
+                .collect::<Vec<_>>()
+                .as_bstr(),
+            "ΩMEGA AND ωmega".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn size_hint() {
+        assert_eq!(Swapcase::new().size_hint(), (0, Some(0)));
+        assert_eq!(Swapcase::with_slice(b"abc, xyz").size_hint(), (8, Some(8)));
+        assert_eq!(
+            Swapcase::with_ascii_slice("Έτος".as_bytes()).size_hint(),
+            (8, Some(8))
+        );
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(Swapcase::new().count(), 0);
+        assert_eq!(Swapcase::with_slice(b"abc, xyz").count(), 8);
+        assert_eq!(Swapcase::with_slice("Straße".as_bytes()).count(), 7);
+        assert_eq!(Swapcase::with_ascii_slice("Straße".as_bytes()).count(), 7);
+    }
+
+    #[test]
+    fn as_slice_after_partial_consumption() {
+        let mut iter = Swapcase::with_slice("ßτΣ".as_bytes());
+        assert_eq!(iter.next(), Some(b'S'));
+        assert_eq!(iter.as_slice().as_bstr(), "τΣ".as_bytes().as_bstr());
+
+        let mut iter = Swapcase::with_ascii_slice(b"aBc");
+        iter.next();
+        assert_eq!(iter.as_slice().as_bstr(), b"Bc".as_bstr());
+
+        assert_eq!(Swapcase::new().as_slice(), b"");
+    }
+
+    #[test]
+    fn exact_len() {
+        assert_eq!(Swapcase::new().exact_len(), Some(0));
+        assert_eq!(Swapcase::default().exact_len(), Some(0));
+
+        let mut iter = Swapcase::with_ascii_slice("ß abc".as_bytes());
+        let len = iter.clone().count();
+        for remaining in (0..=len).rev() {
+            assert_eq!(iter.exact_len(), Some(remaining));
+            assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+            iter.next();
+        }
+
+        let iter = Swapcase::with_slice("ß".as_bytes());
+        assert_eq!(iter.exact_len(), None);
+        assert_eq!(Swapcase::with_slice(b"abc XYZ").exact_len(), Some(7));
+        assert_eq!(Swapcase::with_slice("Αύριο".as_bytes()).exact_len(), None);
+    }
+
+    #[test]
+    fn rev() {
+        assert_eq!(Swapcase::new().next_back(), None);
+        assert_eq!(Swapcase::default().rev().count(), 0);
+
+        let iter = Swapcase::with_ascii_slice("aBc ß".as_bytes());
+        let mut expected = "AbC ß".as_bytes().to_vec();
+        expected.reverse();
+        assert_eq!(iter.rev().collect::<Vec<_>>().as_bstr(), expected.as_bstr());
+
+        let iter = Swapcase::with_slice(b"aBc XyZ");
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>().as_bstr(),
+            b"zYx CbA".as_bstr()
+        );
+
+        let mut iter = Swapcase::with_slice(b"aBc");
+        assert_eq!(iter.next(), Some(b'A'));
+        assert_eq!(iter.next_back(), Some(b'C'));
+        assert_eq!(iter.next(), Some(b'b'));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn rev_full_non_ascii() {
+        let iter = crate::swapcase("é".as_bytes(), SwapcaseMode::Full);
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>().as_bstr(),
+            b"\x89\xC3".as_bstr()
+        );
+
+        let input = "Αύριο ΚΑΙ école Straße ǅ".as_bytes();
+        let mut rev = crate::swapcase(input, SwapcaseMode::Full)
+            .rev()
+            .collect::<Vec<_>>();
+        rev.reverse();
+        let forward = crate::swapcase(input, SwapcaseMode::Full).collect::<Vec<_>>();
+        assert_eq!(rev.as_bstr(), forward.as_bstr());
+    }
+
+    #[test]
+    fn display_matches_bstr() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"abc, XYZ",
+            "Αύριο ß İ ǅ".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF\xFE",
+            b"\xF0\x9F\x98\x80 \xE1\xBA\x9E \xCE",
+        ];
+        for input in inputs {
+            for mode in [SwapcaseMode::Full, SwapcaseMode::Ascii] {
+                let iter = crate::swapcase(input, mode);
+                let expected = iter.clone().collect::<Vec<_>>();
+                assert_eq!(format!("{iter}"), format!("{}", expected.as_bstr()));
+                // Formatting does not advance the iterator.
+                assert_eq!(iter.collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn reset_matches_fresh_iterator() {
+        let inputs: [&[u8]; 4] = [
+            b"abc, XYZ",
+            "ΑΎΡΙΟ İ ß ﬃ".as_bytes(),
+            b"ABC\xFF\xFE\xCE\xA3XYZ\xF0\x9F\x87",
+            b"",
+        ];
+        for input in inputs {
+            for mode in [SwapcaseMode::Full, SwapcaseMode::Ascii] {
+                let expected = crate::swapcase(input, mode).collect::<Vec<_>>();
+                // Reset after consuming any prefix of the output, including
+                // from the middle of an expansion and after exhaustion.
+                for consumed in 0..=expected.len() {
+                    let mut iter = crate::swapcase(input, mode);
+                    iter.by_ref().take(consumed).for_each(drop);
+                    iter.reset();
+                    assert_eq!(
+                        iter.collect::<Vec<_>>().as_bstr(),
+                        expected.as_bstr(),
+                        "{mode:?} after {consumed} bytes"
+                    );
+                }
+            }
+            // Progress from both ends is discarded.
+            for mode in [SwapcaseMode::Full, SwapcaseMode::Ascii] {
+                let expected = crate::swapcase(input, mode).collect::<Vec<_>>();
+                let mut iter = crate::swapcase(input, mode);
+                iter.next();
+                iter.next_back();
+                iter.reset();
+                assert_eq!(
+                    iter.collect::<Vec<_>>().as_bstr(),
+                    expected.as_bstr(),
+                    "{mode:?}"
+                );
+            }
+        }
+
+        let mut iter = Swapcase::new();
+        iter.reset();
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn skip_bytes_matches_nth() {
+        let inputs: [&[u8]; 3] = [b"abc, XYZ", b"ABC\xFF\xFExyz", "ΑΎΡΙΟ ß".as_bytes()];
+        for input in inputs {
+            for mode in [SwapcaseMode::Full, SwapcaseMode::Ascii] {
+                let len = crate::swapcase(input, mode).count();
+                for n in 0..=len + 1 {
+                    let mut nth = crate::swapcase(input, mode);
+                    let expected = nth.nth(n);
+                    let mut skipped = crate::swapcase(input, mode).skip_bytes(n);
+                    assert_eq!(skipped.next(), expected, "{mode:?} skipping {n} bytes");
+                    assert_eq!(skipped.as_slice().as_bstr(), nth.as_slice().as_bstr());
+                    assert!(skipped.eq(nth));
+                }
+            }
+        }
+    }
+}
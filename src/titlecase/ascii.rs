@@ -6,11 +6,12 @@ use bstr::ByteSlice;
 #[derive(Clone)]
 #[must_use = "Titlecase is a Iterator and must be used"]
 pub struct Titlecase<'a> {
+    source: &'a [u8],
     slice: &'a [u8],
     head_yielded: bool,
 }
 
-impl<'a> fmt::Debug for Titlecase<'a> {
+impl fmt::Debug for Titlecase<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Titlecase")
             .field("slice", &self.slice.as_bstr())
@@ -27,13 +28,34 @@ impl<'a> From<&'a [u8]> for Titlecase<'a> {
 impl<'a> Titlecase<'a> {
     pub const fn with_slice(slice: &'a [u8]) -> Self {
         Self {
+            source: slice,
             slice,
             head_yielded: false,
         }
     }
+
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::with_slice(self.source);
+    }
+
+    /// Skip up to `n` bytes without mapping them and return the number of
+    /// bytes skipped.
+    pub(crate) fn advance(&mut self, n: usize) -> usize {
+        let n = n.min(self.slice.len());
+        self.slice = &self.slice[n..];
+        if n > 0 {
+            // Skipped bytes include the head once any byte is skipped.
+            self.head_yielded = true;
+        }
+        n
+    }
 }
 
-impl<'a> Iterator for Titlecase<'a> {
+impl Iterator for Titlecase<'_> {
     type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -55,9 +77,15 @@ impl<'a> Iterator for Titlecase<'a> {
     fn count(self) -> usize {
         self.slice.len()
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // Skip the first `n` bytes without mapping them.
+        self.advance(n);
+        self.next()
+    }
 }
 
-impl<'a> DoubleEndedIterator for Titlecase<'a> {
+impl DoubleEndedIterator for Titlecase<'_> {
     fn next_back(&mut self) -> Option<Self::Item> {
         let (&byte, remainder) = self.slice.split_last()?;
         self.slice = remainder;
@@ -74,9 +102,9 @@ impl<'a> DoubleEndedIterator for Titlecase<'a> {
     }
 }
 
-impl<'a> ExactSizeIterator for Titlecase<'a> {}
+impl ExactSizeIterator for Titlecase<'_> {}
 
-impl<'a> FusedIterator for Titlecase<'a> {}
+impl FusedIterator for Titlecase<'_> {}
 
 #[cfg(test)]
 mod tests {
@@ -313,4 +341,49 @@ mod tests {
         assert_eq!(iter.next_back(), Some(b'c'));
         assert_eq!(iter.next_back(), Some(b'b'));
     }
+
+    #[test]
+    fn mixed_direction_capitalizes_exactly_one_byte() {
+        let s = b"abcdef";
+        for pattern in 0_u32..(1 << s.len()) {
+            let mut iter = Titlecase::with_slice(s);
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+            for bit in 0..s.len() {
+                if pattern & (1 << bit) == 0 {
+                    front.push(iter.next().unwrap());
+                } else {
+                    back.push(iter.next_back().unwrap());
+                }
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+            back.reverse();
+            front.extend(back);
+            assert_eq!(front.as_bstr(), b"Abcdef".as_bstr());
+            assert_eq!(
+                front
+                    .iter()
+                    .filter(|byte| byte.is_ascii_uppercase())
+                    .count(),
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn advance_consumes_head() {
+        let mut iter = Titlecase::with_slice(b"aBC");
+        assert_eq!(iter.advance(0), 0);
+        assert_eq!(iter.next(), Some(b'A'));
+
+        let mut iter = Titlecase::with_slice(b"aBC");
+        assert_eq!(iter.advance(1), 1);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"bc".as_bstr());
+
+        let mut iter = Titlecase::with_slice(b"aBC");
+        assert_eq!(iter.nth(1), Some(b'b'));
+        assert_eq!(iter.next_back(), Some(b'c'));
+        assert_eq!(iter.next(), None);
+    }
 }
@@ -1,17 +1,26 @@
-use core::char::ToLowercase;
 use core::fmt;
 use core::iter::FusedIterator;
 use core::ops::Range;
 
 use bstr::ByteSlice;
 
-pub use crate::unicode::Titlecase as TitlecaseForChar;
-use crate::unicode::ToTitlecase;
+use crate::buffer::OutputBuffer;
+use crate::unicode::lithuanian::ToLithuanianLowercase;
+use crate::unicode::sigma::{lowercase_sigma, GREEK_CAPITAL_LETTER_SIGMA};
+use crate::unicode::{case_map_char, CaseMapChar, CaseOp};
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, CaseMappingMode, TitlecaseMode};
+
+// A char case maps to at most three chars.
+const MAPPING_MAX_BYTES: usize = 3 * 4;
+
+/// U+FEFF ZERO WIDTH NO-BREAK SPACE, which is used as a byte order mark.
+const BYTE_ORDER_MARK: char = '\u{FEFF}';
 
 #[derive(Clone, Debug)]
 enum ToCase {
-    ToLowercase(ToLowercase),
-    ToTitlecase(ToTitlecase),
+    CaseMapChar(CaseMapChar),
+    ToLithuanianLowercase(ToLithuanianLowercase),
 }
 
 impl Iterator for ToCase {
@@ -19,30 +28,66 @@ impl Iterator for ToCase {
 
     fn next(&mut self) -> Option<Self::Item> {
         match self {
-            ToCase::ToLowercase(iter) => iter.next(),
-            ToCase::ToTitlecase(iter) => iter.next(),
+            ToCase::CaseMapChar(iter) => iter.next(),
+            ToCase::ToLithuanianLowercase(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            ToCase::CaseMapChar(iter) => iter.size_hint(),
+            ToCase::ToLithuanianLowercase(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for ToCase {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            ToCase::CaseMapChar(iter) => iter.next_back(),
+            ToCase::ToLithuanianLowercase(iter) => iter.next_back(),
         }
     }
 }
 
+impl ExactSizeIterator for ToCase {}
+
+// Bytes which are not yet yielded are laid out, in order, as:
+//
+// 1. `next_bytes`, the rest of the front char.
+// 2. The chars of `case_iter`, the rest of the front case mapping.
+// 3. The case mapping of `slice[..len]`.
+// 4. `next_back_bytes[next_back_range]`, the rest of the back case mapping.
+//
+// The bytes of `slice` after `len` have been case mapped by `next_back`, but
+// are kept around as context for the final sigma and Lithuanian rules.
+// `slice` is a suffix of `source`, the rest of which is kept around as
+// context for the final sigma rule and for finding the first char.
 #[derive(Clone)]
 #[must_use = "Titlecase is a Iterator and must be used"]
 pub struct Titlecase<'a> {
+    source: &'a [u8],
     slice: &'a [u8],
-    next_bytes: [u8; 4],
-    next_range: Range<usize>,
+    len: usize,
+    next_bytes: OutputBuffer,
+    next_back_bytes: [u8; MAPPING_MAX_BYTES],
+    next_back_range: Range<usize>,
     case_iter: Option<ToCase>,
     first: bool,
+    lithuanian: bool,
+    skip_bom: bool,
 }
 
-impl<'a> fmt::Debug for Titlecase<'a> {
+impl fmt::Debug for Titlecase<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Titlecase")
-            .field("slice", &self.slice.as_bstr())
+            .field("slice", &self.as_slice().as_bstr())
             .field("next_bytes", &self.next_bytes)
-            .field("next_range", &self.next_range)
+            .field("next_back_bytes", &self.next_back_bytes)
+            .field("next_back_range", &self.next_back_range)
             .field("case", &self.case_iter)
             .field("first", &self.first)
+            .field("lithuanian", &self.lithuanian)
             .finish()
     }
 }
@@ -54,102 +99,259 @@ impl<'a> From<&'a [u8]> for Titlecase<'a> {
 }
 
 impl<'a> Titlecase<'a> {
-    pub const fn with_slice(slice: &'a [u8]) -> Self {
+    const fn new(slice: &'a [u8], lithuanian: bool, skip_bom: bool) -> Self {
         Self {
+            source: slice,
             slice,
-            next_bytes: [0; 4],
-            next_range: 0..0,
+            len: slice.len(),
+            next_bytes: OutputBuffer::new(),
+            next_back_bytes: [0; MAPPING_MAX_BYTES],
+            next_back_range: 0..0,
             case_iter: None,
             first: true,
+            lithuanian,
+            skip_bom,
         }
     }
+
+    pub const fn with_slice(slice: &'a [u8]) -> Self {
+        Self::new(slice, false, false)
+    }
+
+    /// Titlecase the given slice, lowercasing all characters after the first
+    /// with the Lithuanian rules in `SpecialCasing.txt`.
+    pub const fn with_lithuanian_slice(slice: &'a [u8]) -> Self {
+        Self::new(slice, true, false)
+    }
+
+    /// Titlecase the given slice, treating the char after a leading byte order
+    /// mark as the first char.
+    pub const fn with_slice_skip_bom(slice: &'a [u8]) -> Self {
+        Self::new(slice, false, true)
+    }
+
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.slice.split_at(self.len).0
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new(self.source, self.lithuanian, self.skip_bom);
+    }
+
+    pub const fn is_lithuanian(&self) -> bool {
+        self.lithuanian
+    }
+
+    /// Whether the remaining slice ends with an `I` or `J` which is lowercased
+    /// with the Lithuanian rules and whose `More_Above` context has been
+    /// consumed by reverse iteration.
+    ///
+    /// Forward iteration looks for the context in the rest of the source, so
+    /// the letter still keeps its dot and expands to three bytes.
+    fn ends_with_detached_soft_dotted(&self) -> bool {
+        self.lithuanian
+            && self.len < self.slice.len()
+            && matches!(self.as_slice().last(), Some(b'I' | b'J'))
+    }
+
+    /// The bytes of the source which precede the front of the slice.
+    fn preceding(&self) -> &'a [u8] {
+        &self.source[..self.source.len() - self.slice.len()]
+    }
+
+    /// Whether `ch`, which is preceded by `preceding` in the source, is the
+    /// first char of the source.
+    ///
+    /// Invalid UTF-8 byte sequences do not count as chars, and neither does a
+    /// leading byte order mark if it is skipped.
+    fn is_first_char(&self, ch: char, preceding: &[u8]) -> bool {
+        let mut preceding = preceding;
+        if self.skip_bom {
+            if ch == BYTE_ORDER_MARK && preceding.is_empty() {
+                return false;
+            }
+            let mut bom = [0; 3];
+            let bom = BYTE_ORDER_MARK.encode_utf8(&mut bom).as_bytes();
+            if let Some(rest) = preceding.strip_prefix(bom) {
+                preceding = rest;
+            }
+        }
+        preceding
+            .utf8_chunks()
+            .all(|chunk| chunk.valid().is_empty())
+    }
+
+    fn to_case(&self, ch: char, preceding: &[u8], following: &[u8], titlecase: bool) -> ToCase {
+        let (ch, op) = if titlecase {
+            (ch, CaseOp::Title)
+        } else if ch == GREEK_CAPITAL_LETTER_SIGMA {
+            // Both forms of lowercase sigma lowercase to themselves. The
+            // Lithuanian rules do not apply to sigma.
+            (lowercase_sigma(preceding, following), CaseOp::Lower)
+        } else if self.lithuanian {
+            return ToCase::ToLithuanianLowercase(ToLithuanianLowercase::new(ch, following));
+        } else {
+            (ch, CaseOp::Lower)
+        };
+        let mapping = case_map_char(ch, op, CaseMappingMode::Full)
+            .expect("Full mode supports titlecasing and lowercasing");
+        ToCase::CaseMapChar(mapping)
+    }
 }
 
-impl<'a> Iterator for Titlecase<'a> {
+impl Iterator for Titlecase<'_> {
     type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(idx) = self.next_range.next() {
-            debug_assert!(self.next_bytes.get(idx).is_some());
-
-            return Some(self.next_bytes[idx]);
+        if let Some(byte) = self.next_bytes.next_byte() {
+            return Some(byte);
         }
 
         if let Some(ch) = self.case_iter.as_mut().and_then(Iterator::next) {
-            let enc = ch.encode_utf8(&mut self.next_bytes);
-
-            self.next_range = 1..enc.len();
-            debug_assert!(self.next_bytes.get(self.next_range.clone()).is_some());
-
-            return Some(self.next_bytes[0]);
+            self.next_bytes.push_char(ch);
+            return self.next_bytes.next_byte();
         }
 
         self.case_iter = None;
 
-        match bstr::decode_utf8(self.slice) {
-            (_, 0) => None,
+        match bstr::decode_utf8(self.as_slice()) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // reverse iteration.
+                let idx = self.next_back_range.next()?;
+                Some(self.next_back_bytes[idx])
+            }
             (Some(ch), size) => {
+                let preceding = self.preceding();
                 self.slice = &self.slice[size..];
-                let mut case_iter = if self.first {
+                self.len -= size;
+                let titlecase = self.first && self.is_first_char(ch, preceding);
+                if titlecase {
                     self.first = false;
-                    ToCase::ToTitlecase(ch.to_titlecase())
-                } else {
-                    ToCase::ToLowercase(ch.to_lowercase())
-                };
-                let ch = case_iter
-                    .next()
-                    .expect("ToTitlecase or ToLowercase yields at least one char");
-                let enc = ch.encode_utf8(&mut self.next_bytes);
-
-                self.next_range = 1..enc.len();
-                debug_assert!(self.next_bytes.get(self.next_range.clone()).is_some());
+                }
+                let mut case_iter = self.to_case(ch, preceding, self.slice, titlecase);
+                let ch = case_iter.next().expect("ToCase yields at least one char");
+                self.next_bytes.push_char(ch);
 
                 self.case_iter = Some(case_iter);
-                Some(self.next_bytes[0])
+                self.next_bytes.next_byte()
             }
             (None, size) => {
                 let (bytes, remainder) = self.slice.split_at(size);
                 self.slice = remainder;
+                self.len -= size;
 
-                // Invalid byte sequences are at most three bytes.
-                debug_assert!(self.next_bytes.get(..bytes.len()).is_some());
-
-                self.next_bytes[..bytes.len()].copy_from_slice(bytes);
-                self.next_range = 1..bytes.len();
-                Some(self.next_bytes[0])
+                self.next_bytes.push_bytes(bytes);
+                self.next_bytes.next_byte()
             }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        const TO_LOWER_OR_TITLE_EXPAND: usize = 3;
         const UTF_8_CHAR_MAX_BYTES: usize = 4;
-        if self.slice.is_empty() {
-            (0, Some(0))
-        } else if self.slice.is_ascii() {
-            let len = self.slice.len();
-            (len, Some(len))
+        const TO_LOWER_OR_TITLE_RATIO: (usize, usize) =
+            max_expansion_ratio(CaseMapping::Titlecase(TitlecaseMode::Full));
+        // Bytes of the front and back chars which have not been yielded yet
+        // plus the chars of the current case mapping which have not been
+        // encoded.
+        let pending_bytes = self.next_bytes.len() + self.next_back_range.len();
+        let pending_chars = self.case_iter.as_ref().map_or(0, ExactSizeIterator::len);
+        let pending_min = pending_bytes + pending_chars;
+        let pending_max = pending_bytes + pending_chars * UTF_8_CHAR_MAX_BYTES;
+        let remaining = self.as_slice();
+        let len = remaining.len();
+        if remaining.is_ascii() && !self.ends_with_detached_soft_dotted() {
+            (len + pending_min, Some(len + pending_max))
         } else {
-            let len = self.slice.len();
+            // Every char and invalid UTF-8 byte sequence in the remaining
+            // slice yields at least one byte. The titlecase mapping of the
+            // leading char and the lowercase mappings of the rest, including
+            // the Lithuanian rules, expand by at most the maximum expansion
+            // ratio of titlecasing. This covers a trailing `I` or `J` which
+            // keeps its dot because of an accent that reverse iteration has
+            // already consumed.
+            let (num, den) = TO_LOWER_OR_TITLE_RATIO;
+            let max = len.checked_mul(num).map(|max| max / den);
             (
-                len,
-                Some(len * TO_LOWER_OR_TITLE_EXPAND * UTF_8_CHAR_MAX_BYTES),
+                lead_byte_count(remaining) + pending_min,
+                max.and_then(|max| max.checked_add(pending_max)),
             )
         }
     }
 
     fn count(self) -> usize {
-        if self.slice.is_empty() {
-            0
-        } else if self.slice.is_ascii() {
-            self.slice.len()
+        let pending_chars = self.case_iter.as_ref().map_or(0, ExactSizeIterator::len);
+        let remaining = self.as_slice();
+        if remaining.is_ascii() && !self.ends_with_detached_soft_dotted() && pending_chars == 0 {
+            self.next_bytes.len() + remaining.len() + self.next_back_range.len()
         } else {
             self.fold(0, |acc, _| acc + 1)
         }
     }
+
+    fn last(mut self) -> Option<Self::Item> {
+        // The last byte is the last byte of the final scalar's mapping, which
+        // reverse iteration maps without visiting the rest of the slice.
+        self.next_back()
+    }
 }
 
-impl<'a> FusedIterator for Titlecase<'a> {}
+// Reverse iteration case maps one scalar of the slice at a time, from the back,
+// and buffers its complete mapping in `next_back_bytes`. The buffered bytes are
+// yielded back to front before the preceding scalar is decoded, so
+// `next_back` never splits a scalar's mapping with the front of the iterator.
+impl DoubleEndedIterator for Titlecase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(idx) = self.next_back_range.next_back() {
+            return Some(self.next_back_bytes[idx]);
+        }
+
+        let (remaining, following) = self.slice.split_at(self.len);
+        let mut bytes = [0; MAPPING_MAX_BYTES];
+        let len = match bstr::decode_last_utf8(remaining) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // forward iteration. The chars of the front case mapping
+                // follow the rest of the front char.
+                if let Some(ch) = self
+                    .case_iter
+                    .as_mut()
+                    .and_then(DoubleEndedIterator::next_back)
+                {
+                    let enc = ch.encode_utf8(&mut self.next_back_bytes);
+                    let len = enc.len();
+                    self.next_back_range = 0..len - 1;
+                    return Some(self.next_back_bytes[len - 1]);
+                }
+                return self.next_bytes.next_back_byte();
+            }
+            (Some(ch), size) => {
+                self.len -= size;
+                // Only the first char is titlecased.
+                let preceding = &self.source[..self.source.len() - self.slice.len() + self.len];
+                let titlecase = self.first && self.is_first_char(ch, preceding);
+                if titlecase {
+                    self.first = false;
+                }
+                let mut len = 0;
+                for ch in self.to_case(ch, preceding, following, titlecase) {
+                    len += ch.encode_utf8(&mut bytes[len..]).len();
+                }
+                len
+            }
+            (None, size) => {
+                self.len -= size;
+                bytes[..size].copy_from_slice(&remaining[self.len..]);
+                size
+            }
+        };
+        self.next_back_bytes = bytes;
+        self.next_back_range = 0..len - 1;
+        Some(self.next_back_bytes[len - 1])
+    }
+}
+
+impl FusedIterator for Titlecase<'_> {}
 
 #[cfg(test)]
 mod tests {
@@ -254,6 +456,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn leading_invalid_utf8_does_not_consume_first_char() {
+        // Invalid UTF-8 byte sequences are not chars, so the first valid char
+        // after them is titlecased, in either iteration direction.
+        let inputs: [(&[u8], &[u8]); 4] = [
+            (b"\xFFabc", b"\xFFAbc"),
+            (b"\xFFABC", b"\xFFAbc"),
+            (b"\xF0\x9F\x87\xFFabc XYZ", b"\xF0\x9F\x87\xFFAbc xyz"),
+            // `ǆ` U+01C6 titlecases to `ǅ` U+01C5.
+            (b"\xFF\xC7\x86UNGLA", b"\xFF\xC7\x85ungla"),
+        ];
+        for (input, expected) in inputs {
+            for iter in [
+                Titlecase::with_slice(input),
+                Titlecase::with_lithuanian_slice(input),
+            ] {
+                let forward = iter.clone().collect::<Vec<_>>();
+                assert_eq!(forward.as_bstr(), expected.as_bstr());
+
+                let mut reversed = iter.clone().rev().collect::<Vec<_>>();
+                reversed.reverse();
+                assert_eq!(reversed.as_bstr(), expected.as_bstr());
+
+                let mut iter = iter;
+                let back = iter.next_back();
+                let mut mixed = iter.collect::<Vec<_>>();
+                mixed.extend(back);
+                assert_eq!(mixed.as_bstr(), expected.as_bstr());
+            }
+        }
+    }
+
     #[test]
     fn unicode_replacement_character() {
         let s = "�".as_bytes();
@@ -341,26 +575,26 @@ mod tests {
         assert_eq!(Titlecase::with_slice(b"abc, xyz").size_hint(), (8, Some(8)));
         assert_eq!(
             Titlecase::with_slice(b"abc, \xFF\xFE, xyz").size_hint(),
-            (12, Some(144))
+            (12, Some(36))
         );
         assert_eq!(
             Titlecase::with_slice("�".as_bytes()).size_hint(),
-            (3, Some(36))
+            (1, Some(9))
         );
         assert_eq!(
             Titlecase::with_slice("Έτος".as_bytes()).size_hint(),
-            (8, Some(96))
+            (4, Some(24))
         );
         assert_eq!(
             Titlecase::with_slice("ZȺȾ".as_bytes()).size_hint(),
-            (5, Some(60))
+            (3, Some(15))
         );
 
         let mut utf8_with_invalid_bytes = b"\xFF\xFE".to_vec();
         utf8_with_invalid_bytes.extend_from_slice("Έτος".as_bytes());
         assert_eq!(
             Titlecase::with_slice(&utf8_with_invalid_bytes).size_hint(),
-            (10, Some(120))
+            (6, Some(30))
         );
     }
 
@@ -431,7 +665,350 @@ mod tests {
         let iter = Titlecase::from(s);
         assert_eq!(
             format!("{iter:?}"),
-            "Titlecase { slice: \"Αύριο\", next_bytes: [0, 0, 0, 0], next_range: 0..0, case: None, first: true }"
+            "Titlecase { slice: \"Αύριο\", next_bytes: [], next_back_bytes: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], next_back_range: 0..0, case: None, first: true, lithuanian: false }"
+        );
+    }
+
+    #[test]
+    fn size_hint_covers_count_after_partial_consumption() {
+        let inputs: [&[u8]; 5] = [
+            "ﬃ".as_bytes(),
+            "ΐ ΐ".as_bytes(),
+            "xẞẞ".as_bytes(),
+            "\u{212A}\u{212A}".as_bytes(),
+            b"a\xF0\x9F\x87\xFFZ",
+        ];
+        for input in inputs {
+            let mut iter = Titlecase::from(input);
+            loop {
+                let (min, max) = iter.size_hint();
+                let count = iter.clone().count();
+                assert!(min <= count);
+                assert!(count <= max.unwrap());
+                if iter.next().is_none() {
+                    break;
+                }
+            }
+        }
+
+        // The rest of the `Ffi` expansion is buffered once the slice is empty.
+        let mut iter = Titlecase::from("ﬃ".as_bytes());
+        assert_eq!(iter.next(), Some(b'F'));
+        assert_eq!(iter.size_hint(), (2, Some(8)));
+        assert_eq!(iter.count(), 2);
+    }
+
+    #[test]
+    fn lithuanian_size_hint_covers_count_after_next_back() {
+        let inputs: [&[u8]; 4] = [
+            "aI\u{301}".as_bytes(),
+            "xI\u{300}J\u{301}".as_bytes(),
+            "ÌI\u{301}".as_bytes(),
+            "AJ\u{323}\u{301}".as_bytes(),
+        ];
+        for input in inputs {
+            for back_steps in 0..=input.len() * 3 {
+                let mut iter = Titlecase::with_lithuanian_slice(input);
+                for _ in 0..back_steps {
+                    iter.next_back();
+                }
+                loop {
+                    let (min, max) = iter.size_hint();
+                    let count = iter.clone().count();
+                    assert_eq!(count, iter.clone().fold(0, |acc, _| acc + 1));
+                    assert!(min <= count);
+                    assert!(count <= max.unwrap());
+                    if iter.next().is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn next_then_drain_with_next_back() {
+        let mut iter = Titlecase::from("ǆUNGLA".as_bytes());
+        // The first byte of `ǅ` claims the titlecase mapping for the front.
+        assert_eq!(iter.next(), Some("ǅ".as_bytes()[0]));
+        let mut back = Vec::new();
+        while let Some(byte) = iter.next_back() {
+            back.push(byte);
+        }
+        back.reverse();
+        assert_eq!(back.as_bstr(), "\u{1C5}ungla".as_bytes()[1..].as_bstr());
+        assert_eq!(iter.next(), None);
+
+        let mut iter = Titlecase::from("ﬃ ẞ".as_bytes());
+        assert_eq!(iter.next(), Some(b'F'));
+        let mut back = iter.rev().collect::<Vec<_>>();
+        back.reverse();
+        assert_eq!(back.as_bstr(), "fi ß".as_bytes().as_bstr());
+    }
+
+    #[test]
+    fn rev_titlecases_first_char() {
+        let inputs: [&[u8]; 6] = [
+            b"",
+            b"aBC, xyz",
+            "ǆUNGLA ǅ".as_bytes(),
+            "ﬃ ẞ ΐ".as_bytes(),
+            b"\xFF\xFEabc",
+            b"aB\xF0\x9F\x87Yz\xFF\xFE",
+        ];
+        for input in inputs {
+            let mut rev = Titlecase::from(input).rev().collect::<Vec<_>>();
+            rev.reverse();
+            let expected = Titlecase::from(input).collect::<Vec<_>>();
+            assert_eq!(rev.as_bstr(), expected.as_bstr());
+        }
+    }
+
+    #[test]
+    fn rev_steps_across_scalar_boundaries() {
+        let mut iter = Titlecase::from("ﬄxyz".as_bytes());
+        assert_eq!(iter.next_back(), Some(b'z'));
+        assert_eq!(iter.next_back(), Some(b'y'));
+        assert_eq!(iter.next_back(), Some(b'x'));
+        // `ﬄ` is the first scalar, so its titlecase mapping `Ffl` is yielded
+        // from back to front.
+        assert_eq!(iter.next_back(), Some(b'l'));
+        assert_eq!(iter.next_back(), Some(b'f'));
+        assert_eq!(iter.next_back(), Some(b'F'));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+
+        // The front and back meet inside the mapping of `ﬄ`.
+        let mut iter = Titlecase::from("ﬄxyz".as_bytes());
+        assert_eq!(iter.next(), Some(b'F'));
+        assert_eq!(iter.next_back(), Some(b'z'));
+        assert_eq!(iter.next_back(), Some(b'y'));
+        assert_eq!(iter.next_back(), Some(b'x'));
+        assert_eq!(iter.next_back(), Some(b'l'));
+        assert_eq!(iter.next(), Some(b'f'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        // A trailing `ﬄ` is lowercased, which leaves it unchanged, and its
+        // bytes are yielded from back to front.
+        let rev = Titlecase::from("xyzﬄ".as_bytes()).rev().collect::<Vec<_>>();
+        assert_eq!(rev.as_bstr(), b"\x84\xAC\xEFzyX".as_bstr());
+    }
+
+    #[test]
+    fn mixed_direction_titlecases_exactly_one_char() {
+        for input in ["ǆUN", "ﬃẞ", "\u{FFFD}İa", "iI\u{300}"] {
+            let expected = Titlecase::from(input.as_bytes()).collect::<Vec<_>>();
+            let lithuanian = Titlecase::with_lithuanian_slice(input.as_bytes()).collect::<Vec<_>>();
+            for (iter, expected) in [
+                (Titlecase::from(input.as_bytes()), expected),
+                (
+                    Titlecase::with_lithuanian_slice(input.as_bytes()),
+                    lithuanian,
+                ),
+            ] {
+                for pattern in 0_u32..(1 << expected.len()) {
+                    let mut iter = iter.clone();
+                    let mut front = Vec::new();
+                    let mut back = Vec::new();
+                    for bit in 0..expected.len() {
+                        let (min, max) = iter.size_hint();
+                        let count = iter.clone().count();
+                        assert!(min <= count);
+                        assert!(count <= max.unwrap());
+                        if pattern & (1 << bit) == 0 {
+                            front.push(iter.next().unwrap());
+                        } else {
+                            back.push(iter.next_back().unwrap());
+                        }
+                    }
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.next_back(), None);
+                    back.reverse();
+                    front.extend(back);
+                    assert_eq!(front.as_bstr(), expected.as_bstr());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fused_after_invalid_utf8_tail() {
+        let inputs: [&[u8]; 5] = [
+            b"abc\xFF",
+            b"ABC\xFF",
+            "ǆﬃ".as_bytes(),
+            b"\xC7\x86\xF0\x9F\x87",
+            b"\xFF\xFE",
+        ];
+        for input in inputs {
+            let mut iter = Titlecase::from(input);
+            while iter.next().is_some() {}
+            for _ in 0..4 {
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+                assert_eq!(iter.size_hint(), (0, Some(0)));
+            }
+
+            // Partially buffered output from reverse iteration is drained
+            // once, not resurrected after exhaustion.
+            let mut iter = Titlecase::from(input);
+            let back = iter.next_back();
+            assert!(back.is_some());
+            let forward = iter.by_ref().collect::<Vec<_>>();
+            assert_eq!(forward.len() + 1, Titlecase::from(input).count());
+            for _ in 0..4 {
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn last_matches_collected_last() {
+        let inputs: [&[u8]; 8] = [
+            b"",
+            b"abc XYZ",
+            "ǆ".as_bytes(),
+            "ﬃ".as_bytes(),
+            "aß".as_bytes(),
+            b"abc\xFF",
+            b"abc\xF0\x9F\x87",
+            b"\xFF\xFE",
+        ];
+        for input in inputs {
+            let expected = Titlecase::from(input).collect::<Vec<_>>();
+            assert_eq!(Titlecase::from(input).last(), expected.last().copied());
+
+            // Partially consumed iterators, including those in the middle of
+            // an expansion, yield the same last byte.
+            for skip in 0..expected.len() {
+                let mut iter = Titlecase::from(input);
+                for _ in 0..skip {
+                    iter.next();
+                }
+                assert_eq!(iter.last(), expected.last().copied());
+            }
+
+            let mut iter = Titlecase::from(input);
+            if iter.next_back().is_some() {
+                let len = expected.len();
+                assert_eq!(iter.last(), expected[..len - 1].last().copied());
+            }
+        }
+    }
+
+    #[test]
+    fn skip_bom() {
+        let input = "\u{FEFF}hello".as_bytes();
+        let titlecase = Titlecase::with_slice_skip_bom(input).collect::<Vec<_>>();
+        assert_eq!(titlecase.as_bstr(), "\u{FEFF}Hello".as_bytes().as_bstr());
+        let mut rev = Titlecase::with_slice_skip_bom(input)
+            .rev()
+            .collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev.as_bstr(), "\u{FEFF}Hello".as_bytes().as_bstr());
+
+        // Without skipping, the byte order mark is the first char.
+        let titlecase = Titlecase::with_slice(input).collect::<Vec<_>>();
+        assert_eq!(titlecase.as_bstr(), input.as_bstr());
+    }
+
+    #[test]
+    fn skip_bom_only_skips_a_leading_bom() {
+        let inputs = [
+            ("\u{FEFF}", "\u{FEFF}"),
+            ("\u{FEFF}\u{FEFF}hello", "\u{FEFF}\u{FEFF}hello"),
+            ("a\u{FEFF}BC", "A\u{FEFF}bc"),
+            ("\u{FEFF}ǆUNGLA", "\u{FEFF}ǅungla"),
+            ("\u{FEFF} hello", "\u{FEFF} hello"),
+            (" \u{FEFF}hello", " \u{FEFF}hello"),
+        ];
+        for (input, expected) in inputs {
+            let titlecase = Titlecase::with_slice_skip_bom(input.as_bytes()).collect::<Vec<_>>();
+            assert_eq!(titlecase.as_bstr(), expected.as_bytes().as_bstr());
+            let mut rev = Titlecase::with_slice_skip_bom(input.as_bytes())
+                .rev()
+                .collect::<Vec<_>>();
+            rev.reverse();
+            assert_eq!(rev.as_bstr(), expected.as_bytes().as_bstr());
+        }
+
+        // Invalid UTF-8 before the byte order mark means it is not leading.
+        let titlecase =
+            Titlecase::with_slice_skip_bom(b"\xFF\xEF\xBB\xBFhello").collect::<Vec<_>>();
+        assert_eq!(titlecase.as_bstr(), b"\xFF\xEF\xBB\xBFhello".as_bstr());
+    }
+
+    #[test]
+    fn skip_bom_mixed_direction() {
+        let input = "\u{FEFF}hello".as_bytes();
+        let expected = "\u{FEFF}Hello".as_bytes();
+        for pattern in 0_u32..(1 << expected.len()) {
+            let mut iter = Titlecase::with_slice_skip_bom(input);
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+            for bit in 0..expected.len() {
+                if pattern & (1 << bit) == 0 {
+                    front.push(iter.next().unwrap());
+                } else {
+                    back.push(iter.next_back().unwrap());
+                }
+            }
+            assert_eq!(iter.next(), None);
+            back.reverse();
+            front.extend(back);
+            assert_eq!(front.as_bstr(), expected.as_bstr());
+        }
+    }
+
+    #[test]
+    fn final_sigma_in_tail() {
+        let cases: [(&str, &str); 8] = [
+            ("hELLO", "Hello"),
+            ("ǆUNGLA", "ǅungla"),
+            ("ǄUNGLA", "ǅungla"),
+            ("ΑΣ", "Ας"),
+            ("ΟΔΟΣ ΟΔΟΣ", "Οδος οδος"),
+            ("ΣΑΣ.", "Σας."),
+            ("ΑΣΑ", "Ασα"),
+            ("Σ", "Σ"),
+        ];
+        for (input, expected) in cases {
+            let titlecase = Titlecase::from(input.as_bytes()).collect::<Vec<_>>();
+            assert_eq!(titlecase.as_bstr(), expected.as_bytes().as_bstr());
+
+            // Reverse iteration sees the same context.
+            let mut rev = Titlecase::from(input.as_bytes()).rev().collect::<Vec<_>>();
+            rev.reverse();
+            assert_eq!(rev.as_bstr(), expected.as_bytes().as_bstr());
+
+            let titlecase =
+                crate::titlecase(input.as_bytes(), crate::TitlecaseMode::Full).collect::<Vec<_>>();
+            assert_eq!(titlecase.as_bstr(), expected.as_bytes().as_bstr());
+
+            // Lithuanian mode lowercases the tail with the same sigma rule.
+            let titlecase = Titlecase::with_lithuanian_slice(input.as_bytes()).collect::<Vec<_>>();
+            assert_eq!(titlecase.as_bstr(), expected.as_bytes().as_bstr());
+
+            let mut rev = Titlecase::with_lithuanian_slice(input.as_bytes())
+                .rev()
+                .collect::<Vec<_>>();
+            rev.reverse();
+            assert_eq!(rev.as_bstr(), expected.as_bytes().as_bstr());
+
+            let titlecase = crate::titlecase(input.as_bytes(), crate::TitlecaseMode::Lithuanian)
+                .collect::<Vec<_>>();
+            assert_eq!(titlecase.as_bstr(), expected.as_bytes().as_bstr());
+        }
+
+        // The context extends past output already yielded from either end.
+        let mut iter = Titlecase::from("ΑΣ ΑΣ".as_bytes());
+        assert_eq!(iter.next_back(), Some(0x82));
+        assert_eq!(iter.next(), Some(0xCE));
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"\x91\xCF\x82 \xCE\xB1\xCF".as_bstr()
         );
     }
 }
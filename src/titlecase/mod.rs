@@ -1,14 +1,39 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
 use core::iter::FusedIterator;
 
+#[cfg(feature = "alloc")]
+use crate::buffer::MappedBytes;
+use crate::TitlecaseMode;
+
 mod ascii;
 mod full;
+#[cfg(feature = "alloc")]
+mod owned;
+mod words;
+
+#[cfg(feature = "alloc")]
+pub use owned::{extend_titlecase, to_titlecase_string};
+#[cfg(feature = "alloc")]
+pub(crate) use words::split_word;
+pub use words::TitlecaseWords;
 
 #[derive(Debug, Clone)]
 #[allow(variant_size_differences)]
 enum Inner<'a> {
     Empty,
     Full(full::Titlecase<'a>),
+    // Full Unicode case mapping over a slice which is entirely ASCII produces
+    // the same output as ASCII case mapping. Slices which are detected to be
+    // ASCII-only at construction time use the cheaper ASCII iterator. The
+    // same holds for Lithuanian case mapping, so the mode is kept alongside.
+    FullAscii(ascii::Titlecase<'a>, TitlecaseMode),
     Ascii(ascii::Titlecase<'a>),
+    // A slice owned by the iterator is case mapped up front with the
+    // borrowing iterators above.
+    #[cfg(feature = "alloc")]
+    Owned(MappedBytes, TitlecaseMode),
 }
 
 /// An iterator that yields the titlecase equivalent of a conventionally UTF-8
@@ -19,8 +44,30 @@ enum Inner<'a> {
 /// This struct is created by the [`titlecase`] function. See its documentation
 /// for more.
 ///
+/// # Reverse Iteration
+///
+/// `Titlecase` implements [`DoubleEndedIterator`] for every mode. Reverse
+/// iteration steps backward one scalar at a time: [`next_back`] case maps the
+/// last unvisited scalar of the slice as a unit and then yields the bytes of
+/// its mapping from back to front before stepping to the preceding scalar.
+/// The first scalar is titlecased no matter which end reaches it, so reversing
+/// the reversed output yields the same bytes as forward iteration:
+///
+/// ```
+/// # use roe::{Titlecase, TitlecaseMode};
+/// let titlecase = roe::titlecase("ﬄxyz".as_bytes(), TitlecaseMode::Full);
+/// assert_eq!(titlecase.rev().collect::<Vec<_>>(), b"zyxlfF");
+///
+/// let titlecase = Titlecase::with_ascii_slice(b"abc");
+/// assert_eq!(titlecase.rev().collect::<Vec<_>>(), b"cbA");
+/// ```
+///
+/// Because the iterator yields bytes, the reversed output of a multi-byte
+/// mapping is not valid UTF-8 until it is reversed again.
+///
 /// [bytes]: u8
 /// [`titlecase`]: crate::titlecase()
+/// [`next_back`]: DoubleEndedIterator::next_back
 #[derive(Debug, Clone)]
 #[must_use = "Titlecase is a Iterator and must be used"]
 pub struct Titlecase<'a> {
@@ -37,6 +84,7 @@ impl<'a> Titlecase<'a> {
     /// let mut titlecase = Titlecase::new();
     /// assert_eq!(titlecase.next(), None);
     /// ```
+    #[allow(clippy::new_without_default)]
     pub const fn new() -> Self {
         Self { iter: Inner::Empty }
     }
@@ -84,8 +132,90 @@ impl<'a> Titlecase<'a> {
     /// assert_eq!(titlecase.collect::<Vec<_>>(), expected);
     /// ```
     pub const fn with_slice(slice: &'a [u8]) -> Self {
-        Self {
-            iter: Inner::Full(full::Titlecase::with_slice(slice)),
+        if slice.is_ascii() {
+            Self {
+                iter: Inner::FullAscii(ascii::Titlecase::with_slice(slice), TitlecaseMode::Full),
+            }
+        } else {
+            Self {
+                iter: Inner::Full(full::Titlecase::with_slice(slice)),
+            }
+        }
+    }
+
+    /// Create a new titlecase iterator with the given byte slice using full
+    /// Unicode case mapping, skipping a leading byte order mark when choosing
+    /// the first character.
+    ///
+    /// Text which begins with a UTF-8 byte order mark, U+FEFF ZERO WIDTH
+    /// NO-BREAK SPACE, normally has the byte order mark titlecased as its first
+    /// character. The byte order mark is uncased, so the letter which follows
+    /// it is lowercased. This constructor yields a leading byte order mark as
+    /// is and titlecases the character after it instead.
+    ///
+    /// Only a byte order mark at the very start of the slice is skipped. All
+    /// other characters are mapped the same as by [`with_slice`], which is
+    /// also the iterator returned by [`capitalize`] in [full] mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Titlecase;
+    /// let titlecase = Titlecase::with_slice_skip_bom("\u{FEFF}hello".as_bytes());
+    /// assert_eq!(titlecase.collect::<Vec<_>>(), "\u{FEFF}Hello".as_bytes());
+    ///
+    /// let titlecase = Titlecase::with_slice("\u{FEFF}hello".as_bytes());
+    /// assert_eq!(titlecase.collect::<Vec<_>>(), "\u{FEFF}hello".as_bytes());
+    /// ```
+    ///
+    /// [`with_slice`]: Self::with_slice
+    /// [`capitalize`]: crate::capitalize()
+    /// [full]: TitlecaseMode::Full
+    pub const fn with_slice_skip_bom(slice: &'a [u8]) -> Self {
+        // A byte order mark is not ASCII, so ASCII-only slices never begin
+        // with one.
+        if slice.is_ascii() {
+            Self {
+                iter: Inner::FullAscii(ascii::Titlecase::with_slice(slice), TitlecaseMode::Full),
+            }
+        } else {
+            Self {
+                iter: Inner::Full(full::Titlecase::with_slice_skip_bom(slice)),
+            }
+        }
+    }
+
+    /// Create a new titlecase iterator with the given byte slice using full
+    /// Unicode case mapping adapted for Lithuanian.
+    ///
+    /// All characters after the first are lowercased with the Lithuanian
+    /// rules, which retain the dot on a lowercase `i` or `j` that is followed
+    /// by an accent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Titlecase;
+    /// let titlecase = Titlecase::with_lithuanian_slice("xI\u{301}".as_bytes());
+    /// assert_eq!(titlecase.collect::<Vec<_>>(), "Xi\u{307}\u{301}".as_bytes());
+    ///
+    /// let titlecase = Titlecase::with_lithuanian_slice("ÌÌ".as_bytes());
+    /// assert_eq!(titlecase.collect::<Vec<_>>(), "Ìi\u{307}\u{300}".as_bytes());
+    /// ```
+    pub const fn with_lithuanian_slice(slice: &'a [u8]) -> Self {
+        // The Lithuanian rules only apply to letters followed by combining
+        // marks, so ASCII-only slices map the same as for full case mapping.
+        if slice.is_ascii() {
+            Self {
+                iter: Inner::FullAscii(
+                    ascii::Titlecase::with_slice(slice),
+                    TitlecaseMode::Lithuanian,
+                ),
+            }
+        } else {
+            Self {
+                iter: Inner::Full(full::Titlecase::with_lithuanian_slice(slice)),
+            }
         }
     }
 
@@ -126,24 +256,305 @@ impl<'a> Titlecase<'a> {
             iter: Inner::Ascii(ascii::Titlecase::with_slice(slice)),
         }
     }
+
+    /// Views the not yet case mapped portion of the source slice.
+    ///
+    /// This has the same lifetime as the original slice, so the iterator can
+    /// be dropped and the remaining bytes recovered, like
+    /// [`Chars::as_str`].
+    ///
+    /// Bytes of a character whose case mapping is only partially yielded are
+    /// not part of the returned slice: the character has already been
+    /// consumed from the source slice and the rest of its mapping is buffered
+    /// in the iterator. The returned slice may therefore begin in the middle
+    /// of an expansion.
+    ///
+    /// An iterator created with `with_owned` case maps its slice up front, so
+    /// the returned slice is always empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Titlecase;
+    /// let mut titlecase = Titlecase::with_slice("ﬃ and ǆ".as_bytes());
+    /// assert_eq!(titlecase.as_slice(), "ﬃ and ǆ".as_bytes());
+    ///
+    /// assert_eq!(titlecase.next(), Some(b'F'));
+    /// // The expansion of the first character is in progress.
+    /// assert_eq!(titlecase.as_slice(), " and ǆ".as_bytes());
+    /// ```
+    ///
+    /// [`Chars::as_str`]: core::str::Chars::as_str
+    #[must_use]
+    pub const fn as_slice(&self) -> &'a [u8] {
+        match self.iter {
+            Inner::Empty => &[],
+            Inner::Full(ref iter) => iter.as_slice(),
+            Inner::FullAscii(ref iter, _) | Inner::Ascii(ref iter) => iter.as_slice(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(..) => &[],
+        }
+    }
+
+    /// Rewind the iterator to the start of the slice it was created with.
+    ///
+    /// Case mapped bytes which are buffered but not yet yielded are discarded,
+    /// so after a reset the iterator yields the same bytes as a freshly
+    /// constructed one. This lets a tight loop map the same slice repeatedly
+    /// without cloning the iterator up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Titlecase;
+    /// let mut titlecase = Titlecase::with_slice("ﬄUENT".as_bytes());
+    /// assert_eq!(titlecase.next(), Some(b'F'));
+    /// titlecase.reset();
+    /// assert_eq!(titlecase.collect::<Vec<_>>(), "Ffluent".as_bytes());
+    /// ```
+    pub fn reset(&mut self) {
+        match self.iter {
+            Inner::Empty => {}
+            Inner::Full(ref mut iter) => iter.reset(),
+            Inner::FullAscii(ref mut iter, _) | Inner::Ascii(ref mut iter) => iter.reset(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.reset(),
+        }
+    }
+
+    /// Skip the next `n` bytes of output and return the iterator.
+    ///
+    /// Iterators which map with ASCII case mapping, including full case
+    /// mapping of an ASCII-only slice, skip the bytes in constant time by
+    /// slicing the remaining input forward, as do iterators over an owned
+    /// slice. Other iterators map and discard the skipped bytes. If fewer than
+    /// `n` bytes remain, the returned iterator is exhausted.
+    ///
+    /// Unlike [`Iterator::skip`], the returned iterator is a `Titlecase`, so its
+    /// inherent methods like [`as_slice`](Self::as_slice) are still available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Titlecase;
+    /// let titlecase = Titlecase::with_slice(b"abc, XYZ").skip_bytes(5);
+    /// assert_eq!(titlecase.as_slice(), b"XYZ");
+    /// assert_eq!(titlecase.collect::<Vec<_>>(), b"xyz");
+    /// ```
+    pub fn skip_bytes(mut self, n: usize) -> Self {
+        match self.iter {
+            Inner::FullAscii(ref mut iter, _) | Inner::Ascii(ref mut iter) => {
+                iter.advance(n);
+            }
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.advance(n),
+            _ => {
+                if let Some(n) = n.checked_sub(1) {
+                    self.nth(n);
+                }
+            }
+        }
+        self
+    }
+
+    /// Returns the exact number of bytes remaining in the iterator, if it is
+    /// known without case mapping the remaining slice.
+    ///
+    /// ASCII case mapping, and full case mapping of an ASCII-only slice, never
+    /// changes the length of the slice, so this method returns [`Some`] for
+    /// iterators which map with ASCII case mapping. It returns [`None`] for
+    /// iterators which map non-ASCII slices with Unicode case mapping, since
+    /// the output may be longer or shorter than the input. Iterators created
+    /// with `with_owned` have already case mapped their slice and always
+    /// return `Some`.
+    ///
+    /// When this method returns `Some`, the value is equal to the lower and
+    /// upper bounds of the iterator's [size hint].
+    ///
+    /// Unlike [`count`], this method does not consume the iterator, so it can
+    /// be used to size an output buffer before mapping without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Titlecase;
+    /// let mut titlecase = Titlecase::with_ascii_slice(b"abc XYZ");
+    /// assert_eq!(titlecase.exact_len(), Some(7));
+    /// titlecase.next();
+    /// assert_eq!(titlecase.exact_len(), Some(6));
+    ///
+    /// let titlecase = Titlecase::with_slice("ﬃ".as_bytes());
+    /// assert_eq!(titlecase.exact_len(), None);
+    ///
+    /// assert_eq!(Titlecase::new().exact_len(), Some(0));
+    /// ```
+    ///
+    /// Sizing a stack buffer:
+    ///
+    /// ```
+    /// # use roe::Titlecase;
+    /// let titlecase = Titlecase::with_ascii_slice(b"abc XYZ");
+    /// let mut buf = [0; 16];
+    /// let len = titlecase.exact_len().unwrap();
+    /// for (dst, byte) in buf[..len].iter_mut().zip(titlecase) {
+    ///     *dst = byte;
+    /// }
+    /// assert_eq!(&buf[..len], b"Abc xyz");
+    /// ```
+    ///
+    /// [`count`]: Iterator::count
+    /// [size hint]: Iterator::size_hint
+    #[must_use]
+    #[doc(alias = "byte_len")]
+    pub fn exact_len(&self) -> Option<usize> {
+        match self.iter {
+            Inner::Empty => Some(0),
+            Inner::FullAscii(ref iter, _) | Inner::Ascii(ref iter) => Some(iter.len()),
+            Inner::Full(_) => None,
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref iter, _) => Some(iter.len()),
+        }
+    }
+
+    /// Returns the [`TitlecaseMode`] this iterator was created with, or
+    /// [`None`] if it was created with [`Titlecase::new`].
+    ///
+    /// [`titlecase`] returns an empty iterator for an empty slice, whose mode is
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{Titlecase, TitlecaseMode};
+    /// assert_eq!(Titlecase::with_slice(b"abc").mode(), Some(TitlecaseMode::Full));
+    /// assert_eq!(Titlecase::with_ascii_slice(b"abc").mode(), Some(TitlecaseMode::Ascii));
+    /// assert_eq!(Titlecase::new().mode(), None);
+    /// ```
+    ///
+    /// [`titlecase`]: crate::titlecase()
+    #[must_use]
+    pub const fn mode(&self) -> Option<TitlecaseMode> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(ref iter) if iter.is_lithuanian() => Some(TitlecaseMode::Lithuanian),
+            Inner::Full(_) => Some(TitlecaseMode::Full),
+            Inner::FullAscii(_, mode) => Some(mode),
+            Inner::Ascii(_) => Some(TitlecaseMode::Ascii),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(_, mode) => Some(mode),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Titlecase<'static> {
+    /// Create a new titlecase iterator which takes ownership of the given byte
+    /// vector and maps it with the given [`TitlecaseMode`].
+    ///
+    /// The returned iterator does not borrow, so it can be built from a
+    /// temporary buffer and returned or stored as a `'static` value.
+    ///
+    /// The vector is case mapped in full by [`titlecase`] when the iterator is
+    /// created and its mapping is yielded from an exactly sized buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{Titlecase, TitlecaseMode};
+    /// fn titlecase_name() -> Titlecase<'static> {
+    ///     let name = String::from("ǆUNGLA");
+    ///     Titlecase::with_owned(name.into_bytes(), TitlecaseMode::Full)
+    /// }
+    ///
+    /// assert_eq!(titlecase_name().collect::<Vec<_>>(), "ǅungla".as_bytes());
+    ///
+    /// let titlecase = Titlecase::with_owned("ÌS".into(), TitlecaseMode::Lithuanian);
+    /// assert_eq!(titlecase.collect::<Vec<_>>(), "Ìs".as_bytes());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics for the same unimplemented modes as [`titlecase`].
+    ///
+    /// [`titlecase`]: crate::titlecase()
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    // The vector is taken by value so callers can hand over a temporary
+    // buffer. It is dropped once its mapping is collected.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn with_owned(slice: Vec<u8>, mode: TitlecaseMode) -> Self {
+        Self {
+            iter: Inner::Owned(MappedBytes::with_iter(crate::titlecase(&slice, mode)), mode),
+        }
+    }
 }
 
-impl<'a> Iterator for Titlecase<'a> {
+impl<'a> From<&'a [u8]> for Titlecase<'a> {
+    /// Create a new titlecase iterator with the given byte slice using full
+    /// Unicode case mapping.
+    ///
+    /// This is the same as [`Titlecase::with_slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Titlecase;
+    /// let titlecase = Titlecase::from(&b"aBC"[..]);
+    /// assert_eq!(titlecase.collect::<Vec<_>>(), b"Abc");
+    /// ```
+    #[inline]
+    fn from(slice: &'a [u8]) -> Self {
+        Self::with_slice(slice)
+    }
+}
+
+impl<'a> From<&'a str> for Titlecase<'a> {
+    /// Create a new titlecase iterator with the bytes of the given string using
+    /// full Unicode case mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Titlecase;
+    /// let titlecase = Titlecase::from("ǆUNGLA");
+    /// assert_eq!(titlecase.collect::<Vec<_>>(), "ǅungla".as_bytes());
+    /// ```
+    #[inline]
+    fn from(s: &'a str) -> Self {
+        Self::with_slice(s.as_bytes())
+    }
+}
+
+impl Iterator for Titlecase<'_> {
     type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.iter {
             Inner::Empty => None,
             Inner::Full(ref mut iter) => iter.next(),
-            Inner::Ascii(ref mut iter) => iter.next(),
+            Inner::FullAscii(ref mut iter, _) | Inner::Ascii(ref mut iter) => iter.next(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.next(),
         }
     }
 
+    /// Returns the bounds on the remaining length of the iterator.
+    ///
+    /// The lower bound is the number of chars and invalid UTF-8 byte sequences
+    /// in the remaining slice, each of which yields at least one byte. The
+    /// upper bound is the remaining slice length scaled by the
+    /// [maximum expansion ratio] of the case mapping. Both bounds include the
+    /// bytes of a partially yielded case mapping.
+    ///
+    /// ASCII-only slices have an exact size hint.
+    ///
+    /// [maximum expansion ratio]: crate::max_expansion_ratio()
     fn size_hint(&self) -> (usize, Option<usize>) {
         match self.iter {
             Inner::Empty => (0, Some(0)),
             Inner::Full(ref iter) => iter.size_hint(),
-            Inner::Ascii(ref iter) => iter.size_hint(),
+            Inner::FullAscii(ref iter, _) | Inner::Ascii(ref iter) => iter.size_hint(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref iter, _) => iter.size_hint(),
         }
     }
 
@@ -151,19 +562,113 @@ impl<'a> Iterator for Titlecase<'a> {
         match self.iter {
             Inner::Empty => 0,
             Inner::Full(iter) => iter.count(),
-            Inner::Ascii(iter) => iter.count(),
+            Inner::FullAscii(iter, _) | Inner::Ascii(iter) => iter.count(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(iter, _) => iter.count(),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(ref mut iter) => iter.nth(n),
+            Inner::FullAscii(ref mut iter, _) | Inner::Ascii(ref mut iter) => iter.nth(n),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.nth(n),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(iter) => iter.last(),
+            Inner::FullAscii(mut iter, _) | Inner::Ascii(mut iter) => iter.next_back(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(iter, _) => iter.last(),
         }
     }
 }
 
-impl<'a> FusedIterator for Titlecase<'a> {}
+impl DoubleEndedIterator for Titlecase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(ref mut iter) => iter.next_back(),
+            Inner::FullAscii(ref mut iter, _) | Inner::Ascii(ref mut iter) => iter.next_back(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.next_back(),
+        }
+    }
+}
+
+impl FusedIterator for Titlecase<'_> {}
+
+/// Formats the remaining case mapped bytes as a conventionally UTF-8 string.
+///
+/// Invalid UTF-8 byte sequences are rendered as U+FFFD REPLACEMENT CHARACTER,
+/// the same as the [`Display`] implementation of [`BStr`]. Formatting maps a
+/// clone of the iterator and does not allocate or advance `self`.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{TitlecaseMode, Titlecase};
+/// let titlecase = roe::titlecase("ǆUNGLA".as_bytes(), TitlecaseMode::Full);
+/// assert_eq!(titlecase.to_string(), "ǅungla");
+///
+/// let titlecase = Titlecase::with_ascii_slice(b"abc\xFF");
+/// assert_eq!(format!("{titlecase}"), "Abc\u{FFFD}");
+/// ```
+///
+/// [`Display`]: fmt::Display
+/// [`BStr`]: bstr::BStr
+impl fmt::Display for Titlecase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::utf8::fmt_lossy(self.clone(), f)
+    }
+}
+
+/// Compares the remaining case mapped bytes with a byte slice.
+///
+/// Comparison maps a clone of the iterator and stops at the first byte which
+/// differs, so it does not allocate or advance `self`. Slices with a length
+/// outside of the iterator's [size hint] compare unequal without mapping any
+/// bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::TitlecaseMode;
+/// assert_eq!(roe::titlecase(b"abc XYZ", TitlecaseMode::Ascii), b"Abc xyz"[..]);
+/// assert_eq!(roe::titlecase("ǆUNGLA".as_bytes(), TitlecaseMode::Full), "ǅungla".as_bytes());
+/// assert_ne!(roe::titlecase(b"abc", TitlecaseMode::Ascii), b"Ab"[..]);
+/// ```
+///
+/// [size hint]: Iterator::size_hint
+impl PartialEq<[u8]> for Titlecase<'_> {
+    fn eq(&self, other: &[u8]) -> bool {
+        let (min, max) = self.size_hint();
+        if other.len() < min || max.is_some_and(|max| other.len() > max) {
+            return false;
+        }
+        Iterator::eq(self.clone(), other.iter().copied())
+    }
+}
+
+impl PartialEq<&[u8]> for Titlecase<'_> {
+    fn eq(&self, other: &&[u8]) -> bool {
+        *self == **other
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use alloc::format;
     use alloc::vec::Vec;
     use bstr::ByteSlice;
 
-    use super::Titlecase;
+    use super::{Inner, Titlecase};
+    use crate::TitlecaseMode;
 
     #[test]
     fn empty() {
@@ -177,6 +682,19 @@ mod tests {
         assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
     }
 
+    #[test]
+    fn full_mode_ascii_slice_uses_ascii_iterator() {
+        let iter = Titlecase::with_slice(b"aBC, xyz");
+        assert!(matches!(iter.iter, Inner::FullAscii(..)));
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"Abc, xyz".as_bstr());
+
+        let iter = Titlecase::with_slice("αύριο ABC".as_bytes());
+        assert!(matches!(iter.iter, Inner::Full(_)));
+
+        let iter = Titlecase::with_slice(b"abc\xFF");
+        assert!(matches!(iter.iter, Inner::Full(_)));
+    }
+
     #[test]
     fn size_hint() {
         assert_eq!(Titlecase::new().size_hint(), (0, Some(0)));
@@ -184,26 +702,26 @@ mod tests {
         assert_eq!(Titlecase::with_slice(b"abc, xyz").size_hint(), (8, Some(8)));
         assert_eq!(
             Titlecase::with_slice(b"abc, \xFF\xFE, xyz").size_hint(),
-            (12, Some(144))
+            (12, Some(36))
         );
         assert_eq!(
             Titlecase::with_slice("�".as_bytes()).size_hint(),
-            (3, Some(36))
+            (1, Some(9))
         );
         assert_eq!(
             Titlecase::with_slice("Έτος".as_bytes()).size_hint(),
-            (8, Some(96))
+            (4, Some(24))
         );
         assert_eq!(
             Titlecase::with_slice("ZȺȾ".as_bytes()).size_hint(),
-            (5, Some(60))
+            (3, Some(15))
         );
 
         let mut utf8_with_invalid_bytes = b"\xFF\xFE".to_vec();
         utf8_with_invalid_bytes.extend_from_slice("Έτος".as_bytes());
         assert_eq!(
             Titlecase::with_slice(&utf8_with_invalid_bytes).size_hint(),
-            (10, Some(120))
+            (6, Some(30))
         );
 
         assert_eq!(
@@ -235,6 +753,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mode() {
+        assert_eq!(Titlecase::new().mode(), None);
+        for input in [&b"abc"[..], "αύριο".as_bytes()] {
+            assert_eq!(
+                Titlecase::with_slice(input).mode(),
+                Some(TitlecaseMode::Full)
+            );
+            assert_eq!(
+                Titlecase::with_ascii_slice(input).mode(),
+                Some(TitlecaseMode::Ascii)
+            );
+            assert_eq!(
+                Titlecase::with_lithuanian_slice(input).mode(),
+                Some(TitlecaseMode::Lithuanian)
+            );
+        }
+        for mode in [
+            TitlecaseMode::Full,
+            TitlecaseMode::Ascii,
+            TitlecaseMode::Lithuanian,
+        ] {
+            assert_eq!(crate::titlecase(b"xyz", mode).mode(), Some(mode));
+        }
+    }
+
     #[test]
     fn count() {
         assert_eq!(Titlecase::new().count(), 0);
@@ -274,4 +818,383 @@ mod tests {
         assert!(min <= count);
         assert!(count <= max.unwrap());
     }
+
+    #[test]
+    fn lithuanian_retains_dot_on_lowercased_remainder() {
+        let iter = Titlecase::with_lithuanian_slice("Ìabc".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "Ìabc".as_bytes().as_bstr()
+        );
+
+        // The `I` is followed by an accent so it keeps an explicit dot.
+        let iter = Titlecase::with_lithuanian_slice("XI\u{301}".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "Xi\u{307}\u{301}".as_bytes().as_bstr()
+        );
+        let iter = Titlecase::with_slice("XI\u{301}".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "Xi\u{301}".as_bytes().as_bstr()
+        );
+
+        let iter = Titlecase::with_lithuanian_slice("ÌÌ".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "Ìi\u{307}\u{300}".as_bytes().as_bstr()
+        );
+        let iter = Titlecase::with_slice("ÌÌ".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "Ìì".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn lithuanian_next_back_consumes_more_above_context() {
+        // The `I` keeps its dot even though its accent has already been
+        // yielded from the back.
+        let mut iter = crate::titlecase("aI\u{301}".as_bytes(), TitlecaseMode::Lithuanian);
+        assert_eq!(iter.next_back(), Some(0x81));
+        let (min, max) = iter.size_hint();
+        assert!(min <= 5 && max.is_some_and(|max| max >= 5));
+        assert_eq!(iter.clone().count(), 5);
+        let remaining = iter.clone().collect::<Vec<_>>();
+        assert_eq!(remaining.as_bstr(), b"Ai\xCC\x87\xCC".as_bstr());
+        assert!(iter == remaining[..]);
+
+        let mut iter = Titlecase::with_lithuanian_slice("aI\u{300}J".as_bytes());
+        assert_eq!(iter.next_back(), Some(b'j'));
+        assert_eq!(iter.next_back(), Some(0x80));
+        assert_eq!(iter.count(), 5);
+    }
+
+    #[test]
+    fn lithuanian_first_letter_uses_titlecase() {
+        // Only the remainder uses the Lithuanian lowercase rules.
+        let iter = Titlecase::with_lithuanian_slice("i\u{300}".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "I\u{300}".as_bytes().as_bstr()
+        );
+
+        let iter = Titlecase::with_lithuanian_slice("ﬃ Ĩ".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "Ffi i\u{307}\u{303}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn lithuanian_ascii_slice_uses_ascii_iterator() {
+        let iter = Titlecase::with_lithuanian_slice(b"iJI");
+        assert!(matches!(iter.iter, Inner::FullAscii(..)));
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"Iji".as_bstr());
+    }
+
+    #[test]
+    fn as_slice_after_partial_consumption() {
+        let mut iter = Titlecase::with_slice("ﬃ and ǆ".as_bytes());
+        assert_eq!(iter.next(), Some(b'F'));
+        assert_eq!(iter.as_slice().as_bstr(), " and ǆ".as_bytes().as_bstr());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "fi and ǆ".as_bytes().as_bstr()
+        );
+
+        let mut iter = Titlecase::with_ascii_slice(b"abc");
+        assert_eq!(iter.as_slice().as_bstr(), b"abc".as_bstr());
+        iter.next();
+        assert_eq!(iter.as_slice().as_bstr(), b"bc".as_bstr());
+
+        let mut iter = Titlecase::with_lithuanian_slice("xÌ y".as_bytes());
+        iter.next();
+        iter.next();
+        assert_eq!(iter.as_slice().as_bstr(), b" y".as_bstr());
+
+        assert_eq!(Titlecase::new().as_slice(), b"");
+    }
+
+    #[test]
+    fn exact_len() {
+        assert_eq!(Titlecase::new().exact_len(), Some(0));
+
+        let mut iter = Titlecase::with_ascii_slice("ﬃ abc".as_bytes());
+        let len = iter.clone().count();
+        for remaining in (0..=len).rev() {
+            assert_eq!(iter.exact_len(), Some(remaining));
+            assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+            iter.next();
+        }
+
+        let iter = Titlecase::with_slice("ﬃ".as_bytes());
+        assert_eq!(iter.exact_len(), None);
+        assert_eq!(Titlecase::with_slice(b"abc XYZ").exact_len(), Some(7));
+        assert_eq!(Titlecase::with_slice("ǆungla".as_bytes()).exact_len(), None);
+    }
+
+    #[test]
+    fn exact_len_does_not_consume() {
+        let iter = Titlecase::with_ascii_slice(b"abc XYZ");
+        assert_eq!(iter.exact_len(), Some(7));
+        assert_eq!(iter.exact_len(), Some(7));
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"Abc xyz".as_bstr());
+    }
+
+    #[test]
+    fn display_matches_bstr() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"abc, XYZ",
+            "ǆUNGLA ß İ ǅ".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF\xFE",
+            b"\xF0\x9F\x98\x80 \xE1\xBA\x9E \xCE",
+        ];
+        for input in inputs {
+            for mode in [TitlecaseMode::Full, TitlecaseMode::Ascii] {
+                let iter = crate::titlecase(input, mode);
+                let expected = iter.clone().collect::<Vec<_>>();
+                assert_eq!(format!("{iter}"), format!("{}", expected.as_bstr()));
+                // Formatting does not advance the iterator.
+                assert_eq!(iter.collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn rev() {
+        for mode in [
+            TitlecaseMode::Full,
+            TitlecaseMode::Lithuanian,
+            TitlecaseMode::Ascii,
+        ] {
+            for input in ["", "aBC, XYZ", "ǆUNGLA ß", "ﬃ \u{212A}"] {
+                let mut rev = crate::titlecase(input.as_bytes(), mode)
+                    .rev()
+                    .collect::<Vec<_>>();
+                rev.reverse();
+                let expected = crate::titlecase(input.as_bytes(), mode).collect::<Vec<_>>();
+                assert_eq!(rev.as_bstr(), expected.as_bstr());
+            }
+        }
+    }
+
+    #[test]
+    fn skip_bom() {
+        let titlecase = Titlecase::with_slice_skip_bom("\u{FEFF}hello".as_bytes());
+        assert_eq!(titlecase.mode(), Some(TitlecaseMode::Full));
+        assert_eq!(
+            titlecase.collect::<Vec<_>>().as_bstr(),
+            "\u{FEFF}Hello".as_bytes().as_bstr()
+        );
+        let titlecase = Titlecase::with_slice_skip_bom(b"hELLO");
+        assert_eq!(titlecase.mode(), Some(TitlecaseMode::Full));
+        assert_eq!(titlecase.collect::<Vec<_>>().as_bstr(), b"Hello".as_bstr());
+    }
+
+    #[test]
+    fn rev_expansion() {
+        for mode in [TitlecaseMode::Full, TitlecaseMode::Lithuanian] {
+            let rev = crate::titlecase("ﬄxyz".as_bytes(), mode)
+                .rev()
+                .collect::<Vec<_>>();
+            assert_eq!(rev.as_bstr(), b"zyxlfF".as_bstr());
+        }
+        // ASCII mode does not map `ﬄ`, so its bytes are reversed as is.
+        let rev = crate::titlecase("ﬄxyz".as_bytes(), TitlecaseMode::Ascii)
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(rev.as_bstr(), b"zyx\x84\xAC\xEF".as_bstr());
+    }
+
+    #[test]
+    fn partial_eq_slice() {
+        let iter = crate::titlecase("ǆUNGLA ß".as_bytes(), TitlecaseMode::Full);
+        assert_eq!(iter, "ǅungla ß".as_bytes());
+        assert_eq!(iter, *"ǅungla ß".as_bytes());
+        // Comparison does not advance the iterator.
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "ǅungla ß".as_bytes().as_bstr()
+        );
+
+        let iter = crate::titlecase("ß".as_bytes(), TitlecaseMode::Full);
+        assert_eq!(iter, b"Ss"[..]);
+
+        // Unequal lengths.
+        let iter = crate::titlecase("ǆUNGLA ß".as_bytes(), TitlecaseMode::Full);
+        let expected = "ǅungla ß".as_bytes();
+        assert_ne!(iter, expected[..expected.len() - 1]);
+        assert_ne!(iter, [expected, b"!"].concat()[..]);
+        assert_ne!(iter, b""[..]);
+        assert_eq!(crate::titlecase(b"", TitlecaseMode::Full), b""[..]);
+
+        // Unequal contents.
+        assert_ne!(iter, "ǆUNGLA ß".as_bytes());
+
+        // Invalid UTF-8 byte sequences are compared as is.
+        assert_eq!(
+            crate::titlecase(b"aBC\xFF\xFE", TitlecaseMode::Full),
+            b"Abc\xFF\xFE"[..]
+        );
+        assert_ne!(
+            crate::titlecase(b"aBC\xFF\xFE", TitlecaseMode::Full),
+            "Abc\u{FFFD}".as_bytes()
+        );
+    }
+
+    #[test]
+    fn last_matches_collected_last() {
+        let inputs: [&[u8]; 4] = [
+            b"abc, xyz",
+            "ǆungla".as_bytes(),
+            "ﬃ".as_bytes(),
+            b"abc\xF0\x9F\x87",
+        ];
+        for input in inputs {
+            for mode in [
+                TitlecaseMode::Full,
+                TitlecaseMode::Ascii,
+                TitlecaseMode::Lithuanian,
+            ] {
+                let expected = crate::titlecase(input, mode).collect::<Vec<_>>();
+                assert_eq!(
+                    crate::titlecase(input, mode).last(),
+                    expected.last().copied()
+                );
+            }
+        }
+        assert_eq!(Titlecase::new().last(), None);
+    }
+
+    #[test]
+    fn reset_matches_fresh_iterator() {
+        let inputs: [&[u8]; 4] = [
+            b"abc, XYZ",
+            "ΑΎΡΙΟ İ ß ﬃ".as_bytes(),
+            b"ABC\xFF\xFE\xCE\xA3XYZ\xF0\x9F\x87",
+            b"",
+        ];
+        for input in inputs {
+            for mode in [
+                TitlecaseMode::Full,
+                TitlecaseMode::Ascii,
+                TitlecaseMode::Lithuanian,
+            ] {
+                let expected = crate::titlecase(input, mode).collect::<Vec<_>>();
+                // Reset after consuming any prefix of the output, including
+                // from the middle of an expansion and after exhaustion.
+                for consumed in 0..=expected.len() {
+                    let mut iter = crate::titlecase(input, mode);
+                    iter.by_ref().take(consumed).for_each(drop);
+                    iter.reset();
+                    assert_eq!(
+                        iter.collect::<Vec<_>>().as_bstr(),
+                        expected.as_bstr(),
+                        "{mode:?} after {consumed} bytes"
+                    );
+                }
+            }
+            // Buffered output from both ends is discarded.
+            let expected = crate::titlecase(input, TitlecaseMode::Full).collect::<Vec<_>>();
+            let mut iter = crate::titlecase(input, TitlecaseMode::Full);
+            iter.next();
+            iter.next_back();
+            iter.reset();
+            assert_eq!(iter.collect::<Vec<_>>().as_bstr(), expected.as_bstr());
+        }
+
+        let mut iter = Titlecase::new();
+        iter.reset();
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn skip_bytes_matches_nth() {
+        let inputs: [&[u8]; 3] = [b"abc, XYZ", b"ABC\xFF\xFExyz", "ΑΎΡΙΟ ß".as_bytes()];
+        for input in inputs {
+            for mode in [TitlecaseMode::Full, TitlecaseMode::Ascii] {
+                let len = crate::titlecase(input, mode).count();
+                for n in 0..=len + 1 {
+                    let mut nth = crate::titlecase(input, mode);
+                    let expected = nth.nth(n);
+                    let mut skipped = crate::titlecase(input, mode).skip_bytes(n);
+                    assert_eq!(skipped.next(), expected, "{mode:?} skipping {n} bytes");
+                    assert_eq!(skipped.as_slice().as_bstr(), nth.as_slice().as_bstr());
+                    assert!(skipped.eq(nth));
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn owned_titlecase_from_temporary() -> Titlecase<'static> {
+        let mut source = Vec::new();
+        source.extend_from_slice("ǆUNGLA ".as_bytes());
+        source.extend_from_slice(b"\xFF abc XYZ");
+        Titlecase::with_owned(source, TitlecaseMode::Full)
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn with_owned_outlives_source_scope() {
+        use alloc::boxed::Box;
+
+        let mut source = Vec::new();
+        source.extend_from_slice("ǆUNGLA ".as_bytes());
+        source.extend_from_slice(b"\xFF abc XYZ");
+        let expected = crate::titlecase(&source, TitlecaseMode::Full).collect::<Vec<_>>();
+        drop(source);
+        let iter = owned_titlecase_from_temporary();
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), expected.as_bstr());
+        assert!(expected.starts_with("ǅungla ".as_bytes()));
+
+        // The iterator is `'static` and can be boxed as a trait object.
+        let boxed: Box<dyn Iterator<Item = u8>> = Box::new(owned_titlecase_from_temporary());
+        assert_eq!(boxed.count(), expected.len());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn with_owned_matches_borrowed() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"abc, XYZ",
+            "ΑΎΡΙΟ ΟΔΟΣ ß İ ΐ ǆ".as_bytes(),
+            "Ì I\u{301} i\u{307}".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF",
+        ];
+        for input in inputs {
+            for mode in [
+                TitlecaseMode::Full,
+                TitlecaseMode::Ascii,
+                TitlecaseMode::Lithuanian,
+            ] {
+                let expected = crate::titlecase(input, mode).collect::<Vec<_>>();
+                let mut owned = Titlecase::with_owned(input.to_vec(), mode);
+                assert_eq!(owned.mode(), Some(mode));
+                assert_eq!(owned.exact_len(), Some(expected.len()));
+                assert_eq!(owned.size_hint(), (expected.len(), Some(expected.len())));
+                assert!(owned.as_slice().is_empty());
+                assert_eq!(
+                    owned.clone().collect::<Vec<_>>().as_bstr(),
+                    expected.as_bstr()
+                );
+
+                let mut rev = owned.clone().rev().collect::<Vec<_>>();
+                rev.reverse();
+                assert_eq!(rev.as_bstr(), expected.as_bstr());
+
+                owned.next();
+                owned.reset();
+                assert_eq!(owned.clone().count(), expected.len());
+                let skipped = owned.skip_bytes(2).collect::<Vec<_>>();
+                assert_eq!(
+                    skipped.as_bstr(),
+                    expected.get(2..).unwrap_or_default().as_bstr()
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,177 @@
+use alloc::string::{FromUtf8Error, String};
+use alloc::vec::Vec;
+
+use crate::expansion::collect_exact;
+use crate::TitlecaseMode;
+
+/// Returns a [`String`] containing a copy of the given slice with its first
+/// character replaced with its titlecase counterpart and the remaining
+/// characters replaced with their lowercase counterparts.
+///
+/// This function collects the iterator returned by [`titlecase`] into an exactly
+/// sized [`Vec`] and converts it to a `String` with [`String::from_utf8`].
+///
+/// The case mapping mode is determined by the given [`TitlecaseMode`]. See
+/// [`titlecase`] for details on how the input slice is mapped.
+///
+/// # Errors
+///
+/// Case mapping valid UTF-8 always produces valid UTF-8, so this function only
+/// returns an error if the given slice is not valid UTF-8. Invalid UTF-8 byte
+/// sequences are passed through the case mapping as is. The mapped bytes can
+/// be recovered with [`FromUtf8Error::into_bytes`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{to_titlecase_string, TitlecaseMode};
+/// assert_eq!(
+///     to_titlecase_string("ǆUNGLA".as_bytes(), TitlecaseMode::Full).unwrap(),
+///     "ǅungla"
+/// );
+///
+/// let err = to_titlecase_string(b"abc\xFFxyz", TitlecaseMode::Full).unwrap_err();
+/// assert_eq!(err.utf8_error().valid_up_to(), 3);
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`TitlecaseMode`]s as [`titlecase`].
+///
+/// [`titlecase`]: crate::titlecase()
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn to_titlecase_string(slice: &[u8], options: TitlecaseMode) -> Result<String, FromUtf8Error> {
+    String::from_utf8(collect_exact(crate::titlecase(slice, options)))
+}
+
+/// Appends a copy of the given slice with its first character replaced with
+/// its titlecase counterpart and the remaining characters replaced with their
+/// lowercase counterparts to `out`, returning the number of bytes appended.
+///
+/// The existing contents of `out` are preserved. Before any bytes are appended,
+/// capacity is reserved for the upper bound of the [size hint] of the iterator
+/// returned by [`titlecase`], so `out` grows at most once per call. Reusing the
+/// same `out` for many slices amortizes the allocation across calls.
+///
+/// The case mapping mode is determined by the given [`TitlecaseMode`]. See
+/// [`titlecase`] for details on how the input slice is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{extend_titlecase, TitlecaseMode};
+/// let mut out = b"> ".to_vec();
+/// let appended = extend_titlecase(&mut out, "ǆUNGLA".as_bytes(), TitlecaseMode::Full);
+/// assert_eq!(out, "> ǅungla".as_bytes());
+/// assert_eq!(appended, "ǅungla".len());
+/// ```
+///
+/// # Panics
+///
+/// This function panics for the same [`TitlecaseMode`]s as [`titlecase`].
+///
+/// [`titlecase`]: crate::titlecase()
+/// [size hint]: Iterator::size_hint
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn extend_titlecase(out: &mut Vec<u8>, slice: &[u8], options: TitlecaseMode) -> usize {
+    let iter = crate::titlecase(slice, options);
+    let (min, max) = iter.size_hint();
+    let len = out.len();
+    out.reserve(max.unwrap_or(min));
+    out.extend(iter);
+    out.len() - len
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use bstr::ByteSlice;
+
+    use super::{extend_titlecase, to_titlecase_string};
+    use crate::TitlecaseMode;
+
+    #[test]
+    fn string_round_trip() {
+        let inputs = ["", "abc, XYZ", "αύριο ΑΎΡΙΟ", "ﬃ ß ẞ İ", "\u{1F600} ǅ"];
+        for input in inputs {
+            for mode in [TitlecaseMode::Full, TitlecaseMode::Ascii] {
+                let string = to_titlecase_string(input.as_bytes(), mode).unwrap();
+                let expected = crate::titlecase(input.as_bytes(), mode).collect::<Vec<_>>();
+                assert_eq!(string.as_bytes(), expected);
+            }
+        }
+        assert_eq!(
+            to_titlecase_string("ﬃ".as_bytes(), TitlecaseMode::Full).unwrap(),
+            "Ffi"
+        );
+    }
+
+    #[test]
+    fn string_invalid_utf8() {
+        for mode in [TitlecaseMode::Full, TitlecaseMode::Ascii] {
+            let err = to_titlecase_string(b"abc\xFFxyz\xF0\x9F\x87", mode).unwrap_err();
+            assert_eq!(err.utf8_error().valid_up_to(), 3);
+            let expected = crate::titlecase(b"abc\xFFxyz\xF0\x9F\x87", mode).collect::<Vec<_>>();
+            assert_eq!(err.into_bytes(), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "titlecase Turkic mode is not yet implemented")]
+    fn turkic_mode_panics() {
+        let _ = to_titlecase_string(b"abc", TitlecaseMode::Turkic);
+    }
+
+    #[test]
+    fn extend_preserves_existing_content() {
+        let input = "ǆUNGLA ΑΎΡΙΟ ß İ".as_bytes();
+        for mode in [TitlecaseMode::Full, TitlecaseMode::Ascii] {
+            let mut out = b"prefix ".to_vec();
+            let appended = extend_titlecase(&mut out, input, mode);
+            let expected = crate::titlecase(input, mode).collect::<Vec<_>>();
+            assert_eq!(appended, expected.len());
+            assert_eq!(out[..7].as_bstr(), b"prefix ".as_bstr());
+            assert_eq!(out[7..].as_bstr(), expected.as_bstr());
+
+            assert_eq!(extend_titlecase(&mut out, b"", mode), 0);
+            assert_eq!(out.len(), 7 + expected.len());
+        }
+    }
+
+    #[test]
+    fn extend_reserves_capacity_once() {
+        let input = "ǆUNGLA ΑΎΡΙΟ ß İ".as_bytes();
+        let mut out = b"prefix ".to_vec();
+        let (_, max) = crate::titlecase(input, TitlecaseMode::Full).size_hint();
+        // `out` is expected to grow exactly as if the whole upper bound were
+        // reserved up front.
+        let mut reserved = out.clone();
+        reserved.reserve(max.unwrap());
+        extend_titlecase(&mut out, input, TitlecaseMode::Full);
+        assert_eq!(out.capacity(), reserved.capacity());
+
+        // Spare capacity is reused without growing.
+        let mut out = Vec::with_capacity(64);
+        let ptr = out.as_ptr();
+        extend_titlecase(&mut out, b"abc", TitlecaseMode::Full);
+        extend_titlecase(&mut out, b"xyz", TitlecaseMode::Full);
+        assert_eq!(out.capacity(), 64);
+        assert_eq!(out.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn exactly_sized() {
+        let inputs = ["", "abc, XYZ", "ǆUNGLA ΟΔΟΣ", "ﬃ ß ΐ", "\u{1F600} ǅ"];
+        for input in inputs {
+            for mode in [
+                TitlecaseMode::Full,
+                TitlecaseMode::Ascii,
+                TitlecaseMode::Lithuanian,
+            ] {
+                let titlecase = to_titlecase_string(input.as_bytes(), mode).unwrap();
+                assert_eq!(titlecase.len(), titlecase.capacity());
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use super::Titlecase;
+use crate::unicode::properties::is_cased;
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, TitlecaseMode};
+
+const fn is_word_boundary(ch: char) -> bool {
+    ch.is_whitespace() || ch == '_'
+}
+
+/// An iterator that yields a conventionally UTF-8 byte string with the first
+/// cased letter of each word replaced with its titlecase counterpart and all
+/// remaining letters replaced with their lowercase counterparts.
+///
+/// This iterator yields [bytes].
+///
+/// This struct is created by the [`titlecase_words`] function. See its
+/// documentation for more.
+///
+/// [bytes]: u8
+/// [`titlecase_words`]: crate::titlecase_words()
+#[derive(Clone)]
+#[must_use = "TitlecaseWords is a Iterator and must be used"]
+pub struct TitlecaseWords<'a> {
+    // Word boundaries and uncased characters which precede a word's first
+    // cased character are yielded as is.
+    verbatim: &'a [u8],
+    word: Titlecase<'a>,
+    remainder: &'a [u8],
+    mode: TitlecaseMode,
+}
+
+impl fmt::Debug for TitlecaseWords<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TitlecaseWords")
+            .field("verbatim", &self.verbatim.as_bstr())
+            .field("word", &self.word)
+            .field("remainder", &self.remainder.as_bstr())
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+impl<'a> TitlecaseWords<'a> {
+    pub(crate) fn with_slice(slice: &'a [u8], mode: TitlecaseMode) -> Self {
+        let mut iter = Self {
+            verbatim: b"",
+            word: Titlecase::new(),
+            remainder: slice,
+            mode,
+        };
+        iter.next_word();
+        iter
+    }
+
+    /// Split the next word off of the remainder.
+    fn next_word(&mut self) {
+        let (verbatim, word, remainder) = split_word(self.remainder);
+        self.verbatim = verbatim;
+        self.word = crate::titlecase(word, self.mode);
+        self.remainder = remainder;
+    }
+}
+
+/// Split the first word off of the given slice.
+///
+/// Returns the uncased characters which precede the word, the word itself, and
+/// the rest of the slice. A word begins with its first cased character and
+/// ends at the next word boundary. The word is empty if the slice contains no
+/// cased characters.
+pub(crate) fn split_word(slice: &[u8]) -> (&[u8], &[u8], &[u8]) {
+    // Invalid UTF-8 byte sequences decode to U+FFFD, which is uncased.
+    let word_start = slice
+        .char_indices()
+        .find(|&(_, _, ch)| is_cased(ch))
+        .map_or(slice.len(), |(start, _, _)| start);
+    let (verbatim, rest) = slice.split_at(word_start);
+    let word_end = rest
+        .char_indices()
+        .find(|&(_, _, ch)| is_word_boundary(ch))
+        .map_or(rest.len(), |(start, _, _)| start);
+    let (word, remainder) = rest.split_at(word_end);
+    (verbatim, word, remainder)
+}
+
+impl Iterator for TitlecaseWords<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((&byte, verbatim)) = self.verbatim.split_first() {
+                self.verbatim = verbatim;
+                return Some(byte);
+            }
+            if let Some(byte) = self.word.next() {
+                return Some(byte);
+            }
+            if self.remainder.is_empty() {
+                return None;
+            }
+            self.next_word();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (min, max) = self.word.size_hint();
+        let pending = self.verbatim.len();
+        // The remainder is mapped no more than it would be by `titlecase`.
+        let (num, den) = max_expansion_ratio(CaseMapping::Titlecase(self.mode));
+        let remainder_max = self.remainder.len().checked_mul(num).map(|max| max / den);
+        let min = min + pending + lead_byte_count(self.remainder);
+        let max = max
+            .and_then(|max| max.checked_add(pending))
+            .and_then(|max| max.checked_add(remainder_max?));
+        (min, max)
+    }
+}
+
+impl FusedIterator for TitlecaseWords<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::TitlecaseWords;
+    use crate::TitlecaseMode;
+
+    fn titlecase_words(slice: &[u8], mode: TitlecaseMode) -> Vec<u8> {
+        TitlecaseWords::with_slice(slice, mode).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            titlecase_words(b"", TitlecaseMode::Full).as_bstr(),
+            b"".as_bstr()
+        );
+        assert_eq!(
+            titlecase_words(b" \t_\n", TitlecaseMode::Full).as_bstr(),
+            b" \t_\n".as_bstr()
+        );
+    }
+
+    #[test]
+    fn each_word_is_titlecased() {
+        for mode in [
+            TitlecaseMode::Full,
+            TitlecaseMode::Ascii,
+            TitlecaseMode::Lithuanian,
+        ] {
+            assert_eq!(
+                titlecase_words(b"hello world", mode).as_bstr(),
+                b"Hello World".as_bstr()
+            );
+            assert_eq!(
+                titlecase_words(b"hELLO wORLD", mode).as_bstr(),
+                b"Hello World".as_bstr()
+            );
+            assert_eq!(
+                titlecase_words(b"snake_case_IDENT", mode).as_bstr(),
+                b"Snake_Case_Ident".as_bstr()
+            );
+        }
+    }
+
+    #[test]
+    fn leading_and_repeated_whitespace() {
+        assert_eq!(
+            titlecase_words(b"  hello\t\tworld  ", TitlecaseMode::Full).as_bstr(),
+            b"  Hello\t\tWorld  ".as_bstr()
+        );
+        assert_eq!(
+            titlecase_words(b"\nabc", TitlecaseMode::Full).as_bstr(),
+            b"\nAbc".as_bstr()
+        );
+    }
+
+    #[test]
+    fn first_cased_letter_is_titlecased() {
+        assert_eq!(
+            titlecase_words(b"(hello) 'world' 42nd", TitlecaseMode::Full).as_bstr(),
+            b"(Hello) 'World' 42Nd".as_bstr()
+        );
+        assert_eq!(
+            titlecase_words(b"\xFFabc \xFE", TitlecaseMode::Full).as_bstr(),
+            b"\xFFAbc \xFE".as_bstr()
+        );
+    }
+
+    #[test]
+    fn leading_uncased_characters_are_skipped() {
+        // `titlecase` maps the first char of the slice, which is uncased in
+        // each of these examples, so it leaves the rest lowercase.
+        let examples = [
+            ("'hello", "'hello", "'Hello"),
+            ("1abc", "1abc", "1Abc"),
+            ("\u{2BC}n", "\u{2BC}n", "\u{2BC}N"),
+            ("...ABC", "...abc", "...Abc"),
+        ];
+        for (input, titlecase, words) in examples {
+            let expected = crate::titlecase(input.as_bytes(), TitlecaseMode::Full);
+            assert_eq!(
+                expected.collect::<Vec<_>>().as_bstr(),
+                titlecase.as_bytes().as_bstr()
+            );
+            assert_eq!(
+                titlecase_words(input.as_bytes(), TitlecaseMode::Full).as_bstr(),
+                words.as_bytes().as_bstr()
+            );
+        }
+    }
+
+    #[test]
+    fn unicode() {
+        assert_eq!(
+            titlecase_words("ǆungla αύριο ΟΔΟΙ ﬃ".as_bytes(), TitlecaseMode::Full).as_bstr(),
+            "ǅungla Αύριο Οδοι Ffi".as_bytes().as_bstr()
+        );
+        // Unicode whitespace separates words.
+        assert_eq!(
+            titlecase_words("hello\u{3000}world".as_bytes(), TitlecaseMode::Full).as_bstr(),
+            "Hello\u{3000}World".as_bytes().as_bstr()
+        );
+        // ASCII mode leaves non-ASCII characters unchanged.
+        assert_eq!(
+            titlecase_words("éCOLE ÉCOLE".as_bytes(), TitlecaseMode::Ascii).as_bstr(),
+            "école École".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"hello world",
+            "  ǆungla ß_ﬃ ".as_bytes(),
+            b"\xFFabc \xFE",
+            "(ΐ) 42nd".as_bytes(),
+        ];
+        for input in inputs {
+            let mut iter = TitlecaseWords::with_slice(input, TitlecaseMode::Full);
+            loop {
+                let (min, max) = iter.size_hint();
+                let count = iter.clone().count();
+                assert!(min <= count);
+                assert!(count <= max.unwrap());
+                if iter.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not yet implemented")]
+    fn turkic_mode_panics() {
+        let _ = TitlecaseWords::with_slice(b"istanbul", TitlecaseMode::Turkic);
+    }
+}
@@ -0,0 +1,418 @@
+use core::iter::FusedIterator;
+
+use crate::unicode::fold::to_casefold;
+use crate::unicode::lithuanian::to_lithuanian_lowercase;
+use crate::unicode::special_casing::{
+    to_lowercase_special, to_uppercase_special, CasingContext, CasingLanguage, SpecialCaseMappings,
+};
+use crate::unicode::std_case_mapping_iter::CaseMappingIter;
+use crate::unicode::to_titlecase;
+use crate::{CaseMappingMode, InvalidCaseMappingMode};
+
+fn collect_mapping(mapping: impl Iterator<Item = char>) -> [char; 3] {
+    let mut chars = ['\0'; 3];
+    for (slot, ch) in chars.iter_mut().zip(mapping) {
+        *slot = ch;
+    }
+    chars
+}
+
+// A lone `char` is never preceded by `I` and is not followed by a dot above, so
+// only the `Not_Before_Dot` context holds.
+fn to_turkic(mappings: SpecialCaseMappings) -> Option<[char; 3]> {
+    mappings
+        .find_in_context(Some(CasingLanguage::Turkish), |context| {
+            context == CasingContext::NotBeforeDot
+        })
+        .map(|mapping| mapping.mapping())
+}
+
+/// Returns an iterator that yields the Unicode lowercase of a [`char`].
+///
+/// The mapping is the one applied by [`lowercase`] in [full] mode. A lone
+/// `char` has no surrounding context, so context-sensitive mappings like final
+/// sigma are not applied and `Σ` always maps to `σ`.
+///
+/// # Examples
+///
+/// ```
+/// use roe::to_lowercase_char;
+///
+/// assert!(to_lowercase_char('Ǆ').eq(['ǆ']));
+///
+/// // Expansions
+/// assert!(to_lowercase_char('İ').eq(['i', '\u{307}']));
+///
+/// // Locale is ignored
+/// assert!(to_lowercase_char('I').eq(['i']));
+///
+/// // A character already lowercased maps to itself
+/// assert!(to_lowercase_char('a').eq(['a']));
+/// ```
+///
+/// [`lowercase`]: crate::lowercase()
+/// [full]: crate::LowercaseMode::Full
+#[must_use]
+pub fn to_lowercase_char(c: char) -> ToLowercase {
+    ToLowercase(CaseMappingIter::new(collect_mapping(c.to_lowercase())))
+}
+
+/// Returns an iterator that yields the Unicode uppercase of a [`char`].
+///
+/// The mapping is the one applied by [`uppercase`] in [full] mode.
+///
+/// # Examples
+///
+/// ```
+/// use roe::to_uppercase_char;
+///
+/// assert!(to_uppercase_char('ǆ').eq(['Ǆ']));
+///
+/// // Expansions
+/// assert!(to_uppercase_char('ß').eq(['S', 'S']));
+/// assert!(to_uppercase_char('ﬄ').eq(['F', 'F', 'L']));
+///
+/// // Locale is ignored
+/// assert!(to_uppercase_char('i').eq(['I']));
+///
+/// // A character already uppercased maps to itself
+/// assert!(to_uppercase_char('A').eq(['A']));
+/// ```
+///
+/// [`uppercase`]: crate::uppercase()
+/// [full]: crate::UppercaseMode::Full
+#[must_use]
+pub fn to_uppercase_char(c: char) -> ToUppercase {
+    ToUppercase(CaseMappingIter::new(collect_mapping(c.to_uppercase())))
+}
+
+/// The case mapping operation applied by [`case_map_char`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CaseOp {
+    /// Map the `char` to its lowercase counterpart.
+    Lower,
+    /// Map the `char` to its uppercase counterpart.
+    Upper,
+    /// Map the `char` to its titlecase counterpart.
+    Title,
+}
+
+/// Returns an iterator that yields the case mapping of a [`char`].
+///
+/// The mapping is selected by the given [`CaseOp`] and [`CaseMappingMode`] and
+/// is one to three `char`s long. This is the per-scalar mapping applied by the
+/// slice iterators, like [`lowercase`], [`uppercase`], and [`titlecase`].
+///
+/// A lone `char` has no surrounding context, so context-sensitive mappings are
+/// not applied: `Σ` always lowercases to `σ`, in [Turkic] mode `I` always
+/// lowercases to `ı`, and in [Lithuanian] mode `I` never gains an explicit dot.
+/// Titlecasing maps the `char` as the first letter of a word.
+///
+/// In [ASCII] mode, `char`s outside of `'A'..='Z'` and `'a'..='z'` map to
+/// themselves. In [Fold] mode, both lowercasing and uppercasing map the `char`
+/// to its full Unicode case folding.
+///
+/// # Examples
+///
+/// ```
+/// use roe::{case_map_char, CaseMappingMode, CaseOp};
+///
+/// let upper = case_map_char('ﬃ', CaseOp::Upper, CaseMappingMode::Full)?;
+/// assert!(upper.eq(['F', 'F', 'I']));
+/// let title = case_map_char('ﬃ', CaseOp::Title, CaseMappingMode::Full)?;
+/// assert!(title.eq(['F', 'f', 'i']));
+/// let ascii = case_map_char('ﬃ', CaseOp::Upper, CaseMappingMode::Ascii)?;
+/// assert!(ascii.eq(['ﬃ']));
+///
+/// let turkic = case_map_char('i', CaseOp::Upper, CaseMappingMode::Turkic)?;
+/// assert!(turkic.eq(['İ']));
+/// let fold = case_map_char('ẞ', CaseOp::Upper, CaseMappingMode::Fold)?;
+/// assert!(fold.eq(['s', 's']));
+/// # Ok::<(), roe::InvalidCaseMappingMode>(())
+/// ```
+///
+/// # Errors
+///
+/// Titlecasing does not support case folding. If `op` is [`CaseOp::Title`]
+/// and `mode` is [Fold], an [`InvalidCaseMappingMode`] error is returned, the
+/// same error returned by [`CaseMappingMode::resolve_titlecase`].
+///
+/// [`lowercase`]: crate::lowercase()
+/// [`uppercase`]: crate::uppercase()
+/// [`titlecase`]: crate::titlecase()
+/// [Turkic]: CaseMappingMode::Turkic
+/// [Lithuanian]: CaseMappingMode::Lithuanian
+/// [ASCII]: CaseMappingMode::Ascii
+/// [Fold]: CaseMappingMode::Fold
+pub fn case_map_char(
+    c: char,
+    op: CaseOp,
+    mode: CaseMappingMode,
+) -> Result<CaseMapChar, InvalidCaseMappingMode> {
+    let chars =
+        match (mode, op, c) {
+            (CaseMappingMode::Fold, CaseOp::Title, _) => return Err(InvalidCaseMappingMode::new()),
+            (CaseMappingMode::Fold, CaseOp::Lower | CaseOp::Upper, _) => to_casefold(c),
+            (CaseMappingMode::Ascii, CaseOp::Lower, _) => [c.to_ascii_lowercase(), '\0', '\0'],
+            (CaseMappingMode::Ascii, CaseOp::Upper | CaseOp::Title, _) => {
+                [c.to_ascii_uppercase(), '\0', '\0']
+            }
+            (CaseMappingMode::Turkic, CaseOp::Lower, _) => to_turkic(to_lowercase_special(c))
+                .unwrap_or_else(|| collect_mapping(c.to_lowercase())),
+            (CaseMappingMode::Turkic, CaseOp::Upper, _) => to_turkic(to_uppercase_special(c))
+                .unwrap_or_else(|| collect_mapping(c.to_uppercase())),
+            // The Turkic titlecase mappings are the same as the uppercase ones.
+            (CaseMappingMode::Turkic, CaseOp::Title, _) => {
+                to_turkic(to_uppercase_special(c)).unwrap_or_else(|| to_titlecase(c))
+            }
+            (CaseMappingMode::Lithuanian, CaseOp::Lower, _) => to_lithuanian_lowercase(c, b""),
+            (_, CaseOp::Lower, _) => collect_mapping(c.to_lowercase()),
+            (_, CaseOp::Upper, _) => collect_mapping(c.to_uppercase()),
+            (_, CaseOp::Title, _) => to_titlecase(c),
+        };
+    Ok(CaseMapChar(CaseMappingIter::new(chars)))
+}
+
+/// Returns an iterator that yields the case mapping of a `char`.
+///
+/// This `struct` is created by the [`case_map_char`] function.
+#[derive(Clone, Debug)]
+pub struct CaseMapChar(CaseMappingIter);
+
+impl Iterator for CaseMapChar {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for CaseMapChar {
+    fn next_back(&mut self) -> Option<char> {
+        self.0.next_back()
+    }
+}
+
+impl FusedIterator for CaseMapChar {}
+
+impl ExactSizeIterator for CaseMapChar {}
+
+/// Returns an iterator that yields the lowercase equivalent of a `char`.
+///
+/// This `struct` is created by the [`to_lowercase_char`] function.
+#[derive(Clone, Debug)]
+pub struct ToLowercase(CaseMappingIter);
+
+impl Iterator for ToLowercase {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ToLowercase {
+    fn next_back(&mut self) -> Option<char> {
+        self.0.next_back()
+    }
+}
+
+impl FusedIterator for ToLowercase {}
+
+impl ExactSizeIterator for ToLowercase {}
+
+/// Returns an iterator that yields the uppercase equivalent of a `char`.
+///
+/// This `struct` is created by the [`to_uppercase_char`] function.
+#[derive(Clone, Debug)]
+pub struct ToUppercase(CaseMappingIter);
+
+impl Iterator for ToUppercase {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ToUppercase {
+    fn next_back(&mut self) -> Option<char> {
+        self.0.next_back()
+    }
+}
+
+impl FusedIterator for ToUppercase {}
+
+impl ExactSizeIterator for ToUppercase {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use bstr::ByteSlice;
+
+    use super::{case_map_char, to_lowercase_char, to_uppercase_char, CaseMapChar, CaseOp};
+    use crate::{CaseMappingMode, InvalidCaseMappingMode, LowercaseMode, UppercaseMode};
+
+    const CHARS: [char; 14] = [
+        'a', 'A', 'ß', 'ẞ', 'Ǆ', 'ǅ', 'ǆ', 'İ', 'ı', 'ΐ', 'Σ', 'ﬄ', '中', '7',
+    ];
+
+    #[test]
+    fn test_char_to_lowercase() {
+        assert_eq!(to_lowercase_char('ẞ').collect::<Vec<_>>(), ['ß']);
+        assert_eq!(to_lowercase_char('ǅ').collect::<Vec<_>>(), ['ǆ']);
+        assert_eq!(to_lowercase_char('İ').collect::<Vec<_>>(), ['i', '\u{307}']);
+        assert_eq!(to_lowercase_char('Σ').collect::<Vec<_>>(), ['σ']);
+        assert_eq!(to_lowercase_char('ﬄ').collect::<Vec<_>>(), ['ﬄ']);
+    }
+
+    #[test]
+    fn test_char_to_uppercase() {
+        assert_eq!(to_uppercase_char('ß').collect::<Vec<_>>(), ['S', 'S']);
+        assert_eq!(to_uppercase_char('ǅ').collect::<Vec<_>>(), ['Ǆ']);
+        assert_eq!(to_uppercase_char('ı').collect::<Vec<_>>(), ['I']);
+        assert_eq!(
+            to_uppercase_char('ΐ').collect::<Vec<_>>(),
+            ['Ι', '\u{308}', '\u{301}']
+        );
+    }
+
+    #[test]
+    fn test_next_back() {
+        let mut iter = to_uppercase_char('ﬄ');
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some('L'));
+        assert_eq!(iter.next_back(), Some('F'));
+        assert_eq!(iter.next_back(), Some('F'));
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = to_lowercase_char('İ');
+        assert_eq!(iter.next_back(), Some('\u{307}'));
+        assert_eq!(iter.next_back(), Some('i'));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn matches_slice_iterators() {
+        let mut buf = [0; 4];
+        for c in CHARS {
+            let encoded = c.encode_utf8(&mut buf).as_bytes();
+            let lowercase = crate::lowercase(encoded, LowercaseMode::Full).collect::<Vec<_>>();
+            assert!(to_lowercase_char(c).eq(lowercase.chars()), "{:?}", c);
+            let uppercase = crate::uppercase(encoded, UppercaseMode::Full).collect::<Vec<_>>();
+            assert!(to_uppercase_char(c).eq(uppercase.chars()), "{:?}", c);
+        }
+    }
+
+    fn map(c: char, op: CaseOp, mode: CaseMappingMode) -> CaseMapChar {
+        case_map_char(c, op, mode).unwrap()
+    }
+
+    #[test]
+    fn case_map_char_ascii_is_identity_for_non_letters() {
+        for c in ['7', ' ', '_', '\0', '\u{7F}', 'ß', 'É', 'ﬃ', '中'] {
+            for op in [CaseOp::Lower, CaseOp::Upper, CaseOp::Title] {
+                assert!(map(c, op, CaseMappingMode::Ascii).eq([c]), "{:?}", c);
+            }
+        }
+        assert!(map('a', CaseOp::Upper, CaseMappingMode::Ascii).eq(['A']));
+        assert!(map('a', CaseOp::Title, CaseMappingMode::Ascii).eq(['A']));
+        assert!(map('Z', CaseOp::Lower, CaseMappingMode::Ascii).eq(['z']));
+    }
+
+    #[test]
+    fn case_map_char_expansions() {
+        let mut iter = map('ﬃ', CaseOp::Upper, CaseMappingMode::Full);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some('F'));
+        assert_eq!(iter.next_back(), Some('I'));
+        assert_eq!(iter.next(), Some('F'));
+        assert_eq!(iter.next(), None);
+
+        assert!(map('ﬃ', CaseOp::Title, CaseMappingMode::Full).eq(['F', 'f', 'i']));
+        assert!(map('ﬃ', CaseOp::Lower, CaseMappingMode::Full).eq(['ﬃ']));
+        assert!(map('ß', CaseOp::Upper, CaseMappingMode::Turkic).eq(['S', 'S']));
+        assert!(map('İ', CaseOp::Lower, CaseMappingMode::Full).eq(['i', '\u{307}']));
+    }
+
+    #[test]
+    fn case_map_char_turkic() {
+        let lower = |c| map(c, CaseOp::Lower, CaseMappingMode::Turkic);
+        let upper = |c| map(c, CaseOp::Upper, CaseMappingMode::Turkic);
+        let title = |c| map(c, CaseOp::Title, CaseMappingMode::Turkic);
+        assert!(lower('I').eq(['ı']));
+        assert!(lower('İ').eq(['i']));
+        assert!(upper('i').eq(['İ']));
+        assert!(title('i').eq(['İ']));
+        assert!(upper('ı').eq(['I']));
+        assert!(lower('A').eq(['a']));
+        assert!(title('ǆ').eq(['ǅ']));
+    }
+
+    #[test]
+    fn case_map_char_lithuanian() {
+        let lower = |c| map(c, CaseOp::Lower, CaseMappingMode::Lithuanian);
+        assert!(lower('Ì').eq(['i', '\u{307}', '\u{300}']));
+        // A lone `I` is not followed by an accent.
+        assert!(lower('I').eq(['i']));
+        assert!(map('i', CaseOp::Upper, CaseMappingMode::Lithuanian).eq(['I']));
+        assert!(map('ǆ', CaseOp::Title, CaseMappingMode::Lithuanian).eq(['ǅ']));
+    }
+
+    #[test]
+    fn case_map_char_fold() {
+        for op in [CaseOp::Lower, CaseOp::Upper] {
+            assert!(map('ẞ', op, CaseMappingMode::Fold).eq(['s', 's']));
+            assert!(map('ς', op, CaseMappingMode::Fold).eq(['σ']));
+            assert!(map('A', op, CaseMappingMode::Fold).eq(['a']));
+        }
+        assert_eq!(
+            case_map_char('a', CaseOp::Title, CaseMappingMode::Fold).unwrap_err(),
+            InvalidCaseMappingMode::new()
+        );
+    }
+
+    #[test]
+    fn case_map_char_matches_slice_iterators() {
+        let mut buf = [0; 4];
+        for c in CHARS {
+            let encoded = c.encode_utf8(&mut buf).as_bytes();
+            for mode in [
+                CaseMappingMode::Full,
+                CaseMappingMode::Ascii,
+                CaseMappingMode::Lithuanian,
+                CaseMappingMode::Fold,
+            ] {
+                let lowercase = mode.resolve_lowercase().unwrap();
+                let expected = crate::lowercase(encoded, lowercase).collect::<Vec<_>>();
+                let mapped = map(c, CaseOp::Lower, mode);
+                assert!(mapped.eq(expected.chars()), "{:?}", c);
+                let uppercase = mode.resolve_uppercase().unwrap();
+                let expected = crate::uppercase(encoded, uppercase).collect::<Vec<_>>();
+                let mapped = map(c, CaseOp::Upper, mode);
+                assert!(mapped.eq(expected.chars()), "{:?}", c);
+                if let Ok(titlecase) = mode.resolve_titlecase() {
+                    let expected = crate::titlecase(encoded, titlecase).collect::<Vec<_>>();
+                    let mapped = map(c, CaseOp::Title, mode);
+                    assert!(mapped.eq(expected.chars()), "{:?}", c);
+                }
+            }
+            let expected = crate::uppercase(encoded, UppercaseMode::Turkic).collect::<Vec<_>>();
+            let mapped = map(c, CaseOp::Upper, CaseMappingMode::Turkic);
+            assert!(mapped.eq(expected.chars()), "{:?}", c);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::unicode::properties::{is_case_ignorable, is_cased};
+
+/// Returns the next cased character in `slice`, skipping any leading
+/// case-ignorable characters.
+///
+/// Returns [`None`] if the first character which is not case-ignorable is
+/// uncased or if the slice ends first. `slice` is treated as a conventionally
+/// UTF-8 byte string and invalid UTF-8 byte sequences end the context.
+pub(crate) fn next_cased_char(mut slice: &[u8]) -> Option<char> {
+    loop {
+        match bstr::decode_utf8(slice) {
+            (Some(ch), size) if is_case_ignorable(ch) => slice = &slice[size..],
+            (Some(ch), _) if is_cased(ch) => return Some(ch),
+            _ => return None,
+        }
+    }
+}
+
+/// Returns whether `consumed` ends with a cased character followed by zero or
+/// more case-ignorable characters.
+///
+/// `consumed` is treated as a conventionally UTF-8 byte string and invalid
+/// UTF-8 byte sequences end the context.
+pub(crate) fn has_preceding_cased(mut consumed: &[u8]) -> bool {
+    loop {
+        match bstr::decode_last_utf8(consumed) {
+            (Some(ch), size) if is_case_ignorable(ch) => {
+                consumed = &consumed[..consumed.len() - size];
+            }
+            (Some(ch), _) => return is_cased(ch),
+            (None, _) => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{has_preceding_cased, next_cased_char};
+
+    #[test]
+    fn next_cased_char_skips_case_ignorable() {
+        assert_eq!(next_cased_char(b"abc"), Some('a'));
+        assert_eq!(next_cased_char("Σx".as_bytes()), Some('Σ'));
+        assert_eq!(next_cased_char(b"'.:a"), Some('a'));
+        // COMBINING ACUTE ACCENT is case-ignorable.
+        assert_eq!(next_cased_char("\u{301}\u{301}Ω".as_bytes()), Some('Ω'));
+    }
+
+    #[test]
+    fn next_cased_char_stops_at_uncased() {
+        assert_eq!(next_cased_char(b""), None);
+        assert_eq!(next_cased_char(b"'"), None);
+        assert_eq!(next_cased_char(b" a"), None);
+        assert_eq!(next_cased_char(b"1a"), None);
+        assert_eq!(next_cased_char("中a".as_bytes()), None);
+        assert_eq!(next_cased_char(b"\xFFa"), None);
+        assert_eq!(next_cased_char(b"'\xF0\x9F\x87a"), None);
+    }
+
+    #[test]
+    fn has_preceding_cased_skips_case_ignorable() {
+        assert!(has_preceding_cased(b"a"));
+        assert!(has_preceding_cased("ΟΔΟ".as_bytes()));
+        assert!(has_preceding_cased(b"a'."));
+        assert!(has_preceding_cased("Α\u{301}".as_bytes()));
+        // Only the characters closest to the end are inspected.
+        assert!(has_preceding_cased(b"\xFF 1a"));
+    }
+
+    #[test]
+    fn has_preceding_cased_stops_at_uncased() {
+        assert!(!has_preceding_cased(b""));
+        assert!(!has_preceding_cased(b"'"));
+        assert!(!has_preceding_cased(b"a "));
+        assert!(!has_preceding_cased(b"a1"));
+        assert!(!has_preceding_cased(b"a\xFF"));
+        assert!(!has_preceding_cased(b"a\xF0\x9F\x87'"));
+    }
+}
@@ -0,0 +1,149 @@
+use core::iter::FusedIterator;
+
+use crate::unicode::std_case_mapping_iter::CaseMappingIter;
+use crate::unicode::ucd_generated_case_folding::SORTED_CASE_FOLDING;
+
+/// Take a [`char`] and return its full Unicode case folding as 3 `char`s.
+///
+/// Full case folding applies the common (C) and full (F) mappings in
+/// `CaseFolding.txt`. Turkic (T) mappings are not applied. Characters without
+/// a case folding map to themselves.
+///
+/// Trailing NUL bytes in the returned array should be ignored.
+#[must_use]
+pub fn to_casefold(c: char) -> [char; 3] {
+    let codepoint = c as u32;
+    if let Ok(index) = SORTED_CASE_FOLDING.binary_search_by(|&(key, _)| key.cmp(&codepoint)) {
+        let chars = SORTED_CASE_FOLDING[index].1;
+        [
+            char::from_u32(chars[0]).unwrap_or(c),
+            char::from_u32(chars[1]).unwrap_or('\0'),
+            char::from_u32(chars[2]).unwrap_or('\0'),
+        ]
+    } else {
+        [c, '\0', '\0']
+    }
+}
+
+/// Take a [`char`] and return its Turkic Unicode case folding as 3 `char`s.
+///
+/// Turkic case folding applies the Turkic (T) mappings in `CaseFolding.txt`,
+/// which fold `I` to dotless `ı` and `İ` to `i`, and otherwise applies the
+/// common and full mappings like [`to_casefold`].
+///
+/// Trailing NUL bytes in the returned array should be ignored.
+#[must_use]
+pub fn to_turkic_casefold(c: char) -> [char; 3] {
+    match c {
+        'I' => ['ı', '\0', '\0'],
+        'İ' => ['i', '\0', '\0'],
+        _ => to_casefold(c),
+    }
+}
+
+/// Returns an iterator that yields the full Unicode case folding of a `char`.
+///
+/// This `struct` is created by the [`ToCasefold::new`] function.
+#[derive(Clone, Debug)]
+pub struct ToCasefold(CaseMappingIter);
+
+impl ToCasefold {
+    /// Create an iterator over the full Unicode case folding of `c`.
+    ///
+    /// See [`to_casefold`].
+    pub fn new(c: char) -> Self {
+        Self(CaseMappingIter::new(to_casefold(c)))
+    }
+
+    /// Create an iterator over the Turkic Unicode case folding of `c`.
+    ///
+    /// See [`to_turkic_casefold`].
+    pub fn new_turkic(c: char) -> Self {
+        Self(CaseMappingIter::new(to_turkic_casefold(c)))
+    }
+}
+
+impl Iterator for ToCasefold {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ToCasefold {
+    fn next_back(&mut self) -> Option<char> {
+        self.0.next_back()
+    }
+}
+
+impl FusedIterator for ToCasefold {}
+
+impl ExactSizeIterator for ToCasefold {}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_casefold, to_turkic_casefold, ToCasefold};
+
+    #[test]
+    fn common_mappings() {
+        assert_eq!(to_casefold('A'), ['a', '\0', '\0']);
+        assert_eq!(to_casefold('Σ'), ['σ', '\0', '\0']);
+        // Final sigma folds to the medial form.
+        assert_eq!(to_casefold('ς'), ['σ', '\0', '\0']);
+        // MICRO SIGN folds to GREEK SMALL LETTER MU.
+        assert_eq!(to_casefold('µ'), ['μ', '\0', '\0']);
+        // KELVIN SIGN folds to ASCII.
+        assert_eq!(to_casefold('\u{212A}'), ['k', '\0', '\0']);
+    }
+
+    #[test]
+    fn full_mappings() {
+        assert_eq!(to_casefold('ß'), ['s', 's', '\0']);
+        assert_eq!(to_casefold('ẞ'), ['s', 's', '\0']);
+        assert_eq!(to_casefold('ﬀ'), ['f', 'f', '\0']);
+        assert_eq!(to_casefold('ﬃ'), ['f', 'f', 'i']);
+        assert_eq!(to_casefold('İ'), ['i', '\u{307}', '\0']);
+        assert_eq!(to_casefold('ΐ'), ['ι', '\u{308}', '\u{301}']);
+    }
+
+    #[test]
+    fn turkic_mappings_are_excluded() {
+        assert_eq!(to_casefold('I'), ['i', '\0', '\0']);
+        assert_eq!(to_casefold('ı'), ['ı', '\0', '\0']);
+    }
+
+    #[test]
+    fn turkic_mappings() {
+        assert_eq!(to_turkic_casefold('I'), ['ı', '\0', '\0']);
+        assert_eq!(to_turkic_casefold('İ'), ['i', '\0', '\0']);
+        // Dotless `ı` and `i` fold to themselves.
+        assert_eq!(to_turkic_casefold('ı'), ['ı', '\0', '\0']);
+        assert_eq!(to_turkic_casefold('i'), ['i', '\0', '\0']);
+        // All other chars fold like full case folding.
+        for c in ['A', 'ß', 'ﬃ', 'Σ', '\u{212A}', 'J', '7'] {
+            assert_eq!(to_turkic_casefold(c), to_casefold(c));
+        }
+    }
+
+    #[test]
+    fn unmapped_chars_are_unchanged() {
+        for c in ['a', 'σ', '7', '中', '\u{1F600}'] {
+            assert_eq!(to_casefold(c), [c, '\0', '\0']);
+        }
+    }
+
+    #[test]
+    fn iterator() {
+        let mut iter = ToCasefold::new('ﬃ');
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some('f'));
+        assert_eq!(iter.next(), Some('f'));
+        assert_eq!(iter.next(), Some('i'));
+        assert_eq!(iter.next(), None);
+    }
+}
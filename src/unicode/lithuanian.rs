@@ -0,0 +1,251 @@
+use core::iter::FusedIterator;
+
+use crate::unicode::properties::{
+    canonical_combining_class, is_soft_dotted, COMBINING_CLASS_ABOVE,
+};
+use crate::unicode::special_casing::{
+    to_lowercase_special, to_uppercase_special, CasingContext, CasingLanguage,
+};
+use crate::unicode::std_case_mapping_iter::CaseMappingIter;
+
+/// Take a [`char`] and the bytes which follow it and return its lowercase
+/// mapping under the Lithuanian rules in `SpecialCasing.txt` as 3 `char`s.
+///
+/// Lithuanian retains the dot on a lowercase `i` or `j` when it is followed by
+/// an accent by inserting U+0307 COMBINING DOT ABOVE:
+///
+/// - `I`, `J`, and `Į` gain an explicit dot when they are followed by
+///   [`More_Above`] combining marks.
+/// - `Ì`, `Í`, and `Ĩ` always decompose to `i`, an explicit dot, and the
+///   accent.
+///
+/// Characters not covered by a Lithuanian rule use their full Unicode lowercase
+/// mapping.
+///
+/// `following` is treated as a conventionally UTF-8 byte string. Invalid UTF-8
+/// byte sequences end the `More_Above` context.
+///
+/// Trailing NUL bytes in the returned array should be ignored.
+///
+/// [`More_Above`]: https://www.unicode.org/versions/Unicode15.0.0/ch03.pdf#G53636
+pub fn to_lithuanian_lowercase(c: char, following: &[u8]) -> [char; 3] {
+    let lithuanian = to_lowercase_special(c)
+        .find_in_context(Some(CasingLanguage::Lithuanian), |context| {
+            context == CasingContext::MoreAbove && is_more_above(following)
+        });
+    if let Some(mapping) = lithuanian {
+        return mapping.mapping();
+    }
+    let mut chars = ['\0'; 3];
+    for (slot, ch) in chars.iter_mut().zip(c.to_lowercase()) {
+        *slot = ch;
+    }
+    chars
+}
+
+/// Returns whether the given bytes begin with a combining mark of class Above
+/// with no intervening character of combining class 0 or Above.
+fn is_more_above(mut following: &[u8]) -> bool {
+    loop {
+        match bstr::decode_utf8(following) {
+            (Some(ch), size) => match canonical_combining_class(ch) {
+                COMBINING_CLASS_ABOVE => return true,
+                0 => return false,
+                _ => following = &following[size..],
+            },
+            (None, _) => return false,
+        }
+    }
+}
+
+/// Take a [`char`] and the bytes which precede it and return whether it is
+/// removed when uppercasing or titlecasing under the Lithuanian rules in
+/// `SpecialCasing.txt`.
+///
+/// Lithuanian removes the explicit dot added by lowercasing when a letter which
+/// lost its dot is uppercased again: U+0307 COMBINING DOT ABOVE is removed when
+/// it is [`After_Soft_Dotted`], i.e. when it follows a [`Soft_Dotted`] `char`,
+/// like `i` or `j`, with no intervening `char` of combining class 0 or Above.
+///
+/// `preceding` is treated as a conventionally UTF-8 byte string. Invalid UTF-8
+/// byte sequences end the `After_Soft_Dotted` context.
+///
+/// [`After_Soft_Dotted`]: https://www.unicode.org/versions/Unicode15.0.0/ch03.pdf#G53636
+/// [`Soft_Dotted`]: https://www.unicode.org/reports/tr44/#Soft_Dotted
+pub fn is_removed_by_lithuanian_uppercase(c: char, preceding: &[u8]) -> bool {
+    to_uppercase_special(c)
+        .find_in_context(Some(CasingLanguage::Lithuanian), |context| {
+            context == CasingContext::AfterSoftDotted && is_after_soft_dotted(preceding, false)
+        })
+        .is_some_and(|mapping| mapping.mapping() == ['\0'; 3])
+}
+
+/// Returns whether the given bytes end with a [`Soft_Dotted`] `char` followed
+/// by no `char` of combining class 0 or Above.
+///
+/// `default` is returned when `preceding` contains no `char` of combining class
+/// 0 or Above, which lets callers carry the context over from earlier input.
+///
+/// [`Soft_Dotted`]: https://www.unicode.org/reports/tr44/#Soft_Dotted
+pub fn is_after_soft_dotted(mut preceding: &[u8], default: bool) -> bool {
+    loop {
+        match bstr::decode_last_utf8(preceding) {
+            (_, 0) => return default,
+            (Some(ch), size) => match canonical_combining_class(ch) {
+                0 | COMBINING_CLASS_ABOVE => return is_soft_dotted(ch),
+                _ => preceding = &preceding[..preceding.len() - size],
+            },
+            (None, _) => return false,
+        }
+    }
+}
+
+/// Returns an iterator that yields the Lithuanian lowercase equivalent of a
+/// `char`.
+///
+/// This `struct` is created by the [`ToLithuanianLowercase::new`] function.
+#[derive(Clone, Debug)]
+pub struct ToLithuanianLowercase(CaseMappingIter);
+
+impl ToLithuanianLowercase {
+    /// Create an iterator over the Lithuanian lowercase mapping of `c` given
+    /// the bytes which follow it.
+    ///
+    /// See [`to_lithuanian_lowercase`].
+    pub fn new(c: char, following: &[u8]) -> Self {
+        Self(CaseMappingIter::new(to_lithuanian_lowercase(c, following)))
+    }
+}
+
+impl Iterator for ToLithuanianLowercase {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ToLithuanianLowercase {
+    fn next_back(&mut self) -> Option<char> {
+        self.0.next_back()
+    }
+}
+
+impl FusedIterator for ToLithuanianLowercase {}
+
+impl ExactSizeIterator for ToLithuanianLowercase {}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_removed_by_lithuanian_uppercase, to_lithuanian_lowercase};
+
+    // Examples from the Lithuanian section of `SpecialCasing.txt`.
+    #[test]
+    fn special_casing_unconditional() {
+        assert_eq!(
+            to_lithuanian_lowercase('Ì', b""),
+            ['i', '\u{307}', '\u{300}']
+        );
+        assert_eq!(
+            to_lithuanian_lowercase('Í', b""),
+            ['i', '\u{307}', '\u{301}']
+        );
+        assert_eq!(
+            to_lithuanian_lowercase('Ĩ', b""),
+            ['i', '\u{307}', '\u{303}']
+        );
+    }
+
+    #[test]
+    fn special_casing_more_above() {
+        let grave = "\u{300}".as_bytes();
+        assert_eq!(to_lithuanian_lowercase('I', grave), ['i', '\u{307}', '\0']);
+        assert_eq!(to_lithuanian_lowercase('J', grave), ['j', '\u{307}', '\0']);
+        assert_eq!(to_lithuanian_lowercase('Į', grave), ['į', '\u{307}', '\0']);
+    }
+
+    #[test]
+    fn not_more_above() {
+        assert_eq!(to_lithuanian_lowercase('I', b""), ['i', '\0', '\0']);
+        assert_eq!(to_lithuanian_lowercase('J', b"abc"), ['j', '\0', '\0']);
+        assert_eq!(to_lithuanian_lowercase('Į', b""), ['į', '\0', '\0']);
+        // A starter between the letter and the accent ends the context.
+        assert_eq!(
+            to_lithuanian_lowercase('I', "a\u{300}".as_bytes()),
+            ['i', '\0', '\0']
+        );
+        // COMBINING DOT BELOW attaches below the letter.
+        assert_eq!(
+            to_lithuanian_lowercase('I', "\u{323}".as_bytes()),
+            ['i', '\0', '\0']
+        );
+        assert_eq!(
+            to_lithuanian_lowercase('I', b"\xFF\xCC\x80"),
+            ['i', '\0', '\0']
+        );
+    }
+
+    #[test]
+    fn more_above_skips_other_combining_classes() {
+        // COMBINING OGONEK (class 202) followed by COMBINING ACUTE ACCENT.
+        assert_eq!(
+            to_lithuanian_lowercase('I', "\u{328}\u{301}".as_bytes()),
+            ['i', '\u{307}', '\0']
+        );
+    }
+
+    #[test]
+    fn other_characters_use_full_mapping() {
+        assert_eq!(to_lithuanian_lowercase('A', b""), ['a', '\0', '\0']);
+        assert_eq!(to_lithuanian_lowercase('İ', b""), ['i', '\u{307}', '\0']);
+        assert_eq!(to_lithuanian_lowercase('Σ', b""), ['σ', '\0', '\0']);
+        assert_eq!(
+            to_lithuanian_lowercase('a', "\u{300}".as_bytes()),
+            ['a', '\0', '\0']
+        );
+    }
+
+    // The Lithuanian uppercase rule in `SpecialCasing.txt`:
+    //
+    //     0307; 0307; ; ; lt After_Soft_Dotted; # COMBINING DOT ABOVE
+    #[test]
+    fn dot_above_after_soft_dotted_is_removed() {
+        assert!(is_removed_by_lithuanian_uppercase('\u{307}', b"i"));
+        assert!(is_removed_by_lithuanian_uppercase('\u{307}', b"abj"));
+        assert!(is_removed_by_lithuanian_uppercase(
+            '\u{307}',
+            "į".as_bytes()
+        ));
+        // Combining marks which are not of class 0 or Above do not end the
+        // context. COMBINING OGONEK is class 202.
+        assert!(is_removed_by_lithuanian_uppercase(
+            '\u{307}',
+            "i\u{328}".as_bytes()
+        ));
+    }
+
+    #[test]
+    fn dot_above_not_after_soft_dotted_is_kept() {
+        assert!(!is_removed_by_lithuanian_uppercase('\u{307}', b""));
+        assert!(!is_removed_by_lithuanian_uppercase('\u{307}', b"a"));
+        assert!(!is_removed_by_lithuanian_uppercase('\u{307}', b"I"));
+        // An accent above between the letter and the dot ends the context.
+        assert!(!is_removed_by_lithuanian_uppercase(
+            '\u{307}',
+            "i\u{301}".as_bytes()
+        ));
+        // Only the first explicit dot is removed.
+        assert!(!is_removed_by_lithuanian_uppercase(
+            '\u{307}',
+            "i\u{307}".as_bytes()
+        ));
+        assert!(!is_removed_by_lithuanian_uppercase('\u{307}', b"i\xFF"));
+        // Other chars are never removed.
+        assert!(!is_removed_by_lithuanian_uppercase('\u{301}', b"i"));
+        assert!(!is_removed_by_lithuanian_uppercase('i', b"i"));
+    }
+}
@@ -1,5 +1,26 @@
-mod std_case_mapping_iter;
+pub mod case_mapping;
+pub(crate) mod context;
+pub mod fold;
+pub mod lithuanian;
+pub mod properties;
+pub mod sigma;
+pub mod special_casing;
+pub(crate) mod std_case_mapping_iter;
+pub mod swapcase;
 pub mod titlecase;
+mod ucd_generated_case_folding;
+mod ucd_generated_case_ignorable;
 mod ucd_generated_case_mapping;
+mod ucd_generated_combining_class;
+mod ucd_generated_soft_dotted;
 
-pub use titlecase::{to_titlecase, Titlecase, ToTitlecase};
+pub use case_mapping::{
+    case_map_char, to_lowercase_char, to_uppercase_char, CaseMapChar, CaseOp, ToLowercase,
+    ToUppercase,
+};
+pub use special_casing::{
+    to_lowercase_special, to_uppercase_special, CasingContext, CasingLanguage, SpecialCaseMapping,
+    SpecialCaseMappings,
+};
+pub use swapcase::to_swapcase;
+pub use titlecase::to_titlecase;
@@ -0,0 +1,180 @@
+use core::cmp::Ordering;
+
+use crate::unicode::ucd_generated_case_ignorable::SORTED_CASE_IGNORABLE;
+use crate::unicode::ucd_generated_combining_class::SORTED_CANONICAL_COMBINING_CLASS;
+use crate::unicode::ucd_generated_soft_dotted::SORTED_SOFT_DOTTED;
+
+/// The `Canonical_Combining_Class` of combining marks which attach above the
+/// base character.
+pub const COMBINING_CLASS_ABOVE: u8 = 230;
+
+/// Returns whether the given `char` is [cased].
+///
+/// A character is cased if it has the `Lowercase` or `Uppercase` property or
+/// has a general category of `Titlecase_Letter`. Titlecase letters are the only
+/// characters which are neither lowercase nor uppercase but still change when
+/// lowercased, which is how they are detected here.
+///
+/// [cased]: https://www.unicode.org/versions/Unicode15.0.0/ch03.pdf#G33992
+pub fn is_cased(c: char) -> bool {
+    if c.is_lowercase() || c.is_uppercase() {
+        return true;
+    }
+    let mut lowercase = c.to_lowercase();
+    !matches!((lowercase.next(), lowercase.next()), (Some(lower), None) if lower == c)
+}
+
+/// Returns whether the given `char` is [case-ignorable].
+///
+/// Case-ignorable characters, like combining marks, modifier letters, and
+/// apostrophes, are skipped when determining the casing context of a
+/// character.
+///
+/// [case-ignorable]: https://www.unicode.org/versions/Unicode15.0.0/ch03.pdf#G33992
+pub fn is_case_ignorable(c: char) -> bool {
+    let codepoint = u32::from(c);
+    SORTED_CASE_IGNORABLE
+        .binary_search_by(|&(start, end)| {
+            if end < codepoint {
+                Ordering::Less
+            } else if start > codepoint {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Returns whether the given `char` is [`Soft_Dotted`].
+///
+/// Soft dotted characters, like `i` and `j`, have a dot which is removed when
+/// an accent is placed above them.
+///
+/// [`Soft_Dotted`]: https://www.unicode.org/reports/tr44/#Soft_Dotted
+pub fn is_soft_dotted(c: char) -> bool {
+    let codepoint = u32::from(c);
+    SORTED_SOFT_DOTTED
+        .binary_search_by(|&(start, end)| {
+            if end < codepoint {
+                Ordering::Less
+            } else if start > codepoint {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Returns the [`Canonical_Combining_Class`] of the given `char`.
+///
+/// Characters which are not combining marks have a combining class of `0`.
+///
+/// [`Canonical_Combining_Class`]: https://www.unicode.org/reports/tr44/#Canonical_Combining_Class_Values
+pub fn canonical_combining_class(c: char) -> u8 {
+    let codepoint = u32::from(c);
+    let search = SORTED_CANONICAL_COMBINING_CLASS.binary_search_by(|&(start, end, _)| {
+        if end < codepoint {
+            Ordering::Less
+        } else if start > codepoint {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    });
+    match search {
+        Ok(index) => SORTED_CANONICAL_COMBINING_CLASS[index].2,
+        Err(_) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        canonical_combining_class, is_case_ignorable, is_cased, is_soft_dotted,
+        COMBINING_CLASS_ABOVE,
+    };
+
+    #[test]
+    fn ascii_letters_are_cased() {
+        for ch in ('a'..='z').chain('A'..='Z') {
+            assert!(is_cased(ch), "expected {:?} to be cased", ch);
+        }
+    }
+
+    #[test]
+    fn titlecase_letters_are_cased() {
+        assert!(is_cased('ǅ'));
+        assert!(is_cased('ǈ'));
+        assert!(is_cased('ᾈ'));
+    }
+
+    #[test]
+    fn uncased() {
+        assert!(!is_cased('1'));
+        assert!(!is_cased(' '));
+        assert!(!is_cased('_'));
+        assert!(!is_cased('中'));
+        assert!(!is_cased('\u{FFFD}'));
+    }
+
+    #[test]
+    fn case_ignorable() {
+        // Apostrophes and word-internal punctuation
+        assert!(is_case_ignorable('\''));
+        assert!(is_case_ignorable('.'));
+        assert!(is_case_ignorable(':'));
+        assert!(is_case_ignorable('\u{2019}'));
+        // COMBINING ACUTE ACCENT
+        assert!(is_case_ignorable('\u{301}'));
+        // MODIFIER LETTER SMALL H
+        assert!(is_case_ignorable('\u{2B0}'));
+        // SOFT HYPHEN
+        assert!(is_case_ignorable('\u{AD}'));
+        assert!(is_case_ignorable('^'));
+
+        assert!(!is_case_ignorable('a'));
+        assert!(!is_case_ignorable('Σ'));
+        assert!(!is_case_ignorable(' '));
+        assert!(!is_case_ignorable(','));
+        assert!(!is_case_ignorable('-'));
+        assert!(!is_case_ignorable('\u{FFFD}'));
+    }
+
+    #[test]
+    fn soft_dotted() {
+        assert!(is_soft_dotted('i'));
+        assert!(is_soft_dotted('j'));
+        assert!(is_soft_dotted('į'));
+        // CYRILLIC SMALL LETTER JE
+        assert!(is_soft_dotted('\u{458}'));
+        // MATHEMATICAL MONOSPACE SMALL J
+        assert!(is_soft_dotted('\u{1D693}'));
+        // CYRILLIC SUBSCRIPT SMALL LETTER BYELORUSSIAN-UKRAINIAN I, the last
+        // entry in the table
+        assert!(is_soft_dotted('\u{1E068}'));
+
+        assert!(!is_soft_dotted('I'));
+        assert!(!is_soft_dotted('J'));
+        assert!(!is_soft_dotted('ı'));
+        assert!(!is_soft_dotted('\u{307}'));
+        assert!(!is_soft_dotted('\u{FFFD}'));
+    }
+
+    #[test]
+    fn combining_class() {
+        assert_eq!(canonical_combining_class('a'), 0);
+        assert_eq!(canonical_combining_class('\u{FFFD}'), 0);
+        // COMBINING GRAVE ACCENT
+        assert_eq!(canonical_combining_class('\u{300}'), COMBINING_CLASS_ABOVE);
+        // COMBINING DOT ABOVE
+        assert_eq!(canonical_combining_class('\u{307}'), COMBINING_CLASS_ABOVE);
+        // COMBINING OGONEK
+        assert_eq!(canonical_combining_class('\u{328}'), 202);
+        // COMBINING GREEK YPOGEGRAMMENI
+        assert_eq!(canonical_combining_class('\u{345}'), 240);
+        // ADLAM NUKTA, the last entry in the table
+        assert_eq!(canonical_combining_class('\u{1E94A}'), 7);
+    }
+}
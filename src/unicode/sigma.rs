@@ -0,0 +1,84 @@
+use crate::unicode::context::{has_preceding_cased, next_cased_char};
+use crate::unicode::special_casing::{to_lowercase_special, CasingContext};
+
+/// U+03A3 GREEK CAPITAL LETTER SIGMA, the only character with a context
+/// dependent lowercase mapping in the default case mappings.
+pub const GREEK_CAPITAL_LETTER_SIGMA: char = 'Σ';
+
+const GREEK_SMALL_LETTER_SIGMA: char = 'σ';
+
+/// Take the bytes which precede and follow a `Σ` and return its lowercase
+/// mapping.
+///
+/// Capital sigma lowercases to final sigma `ς` when it is in the
+/// [`Final_Sigma`] context and to `σ` otherwise. A sigma is final when it is
+/// preceded by a cased letter and is not followed by a cased letter, skipping
+/// case-ignorable characters in either direction.
+///
+/// `preceding` and `following` are treated as conventionally UTF-8 byte
+/// strings. Invalid UTF-8 byte sequences end the context.
+///
+/// [`Final_Sigma`]: https://www.unicode.org/versions/Unicode15.0.0/ch03.pdf#G54277
+pub fn lowercase_sigma(preceding: &[u8], following: &[u8]) -> char {
+    lowercase_sigma_in_context(
+        has_preceding_cased(preceding),
+        next_cased_char(following).is_some(),
+    )
+}
+
+/// Return the lowercase mapping of a `Σ` given whether it is preceded and
+/// followed by a cased letter, skipping case-ignorable characters.
+///
+/// See [`lowercase_sigma`].
+pub(crate) fn lowercase_sigma_in_context(preceded_by_cased: bool, followed_by_cased: bool) -> char {
+    to_lowercase_special(GREEK_CAPITAL_LETTER_SIGMA)
+        .find_in_context(None, |context| {
+            context == CasingContext::FinalSigma && preceded_by_cased && !followed_by_cased
+        })
+        .map_or(GREEK_SMALL_LETTER_SIGMA, |mapping| mapping.mapping()[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lowercase_sigma;
+
+    #[test]
+    fn word_final() {
+        assert_eq!(lowercase_sigma("ΟΔΟ".as_bytes(), b""), 'ς');
+        assert_eq!(lowercase_sigma("ΟΔΟ".as_bytes(), b" "), 'ς');
+        assert_eq!(lowercase_sigma(b"A", b"."), 'ς');
+        assert_eq!(lowercase_sigma(b"A", b"1"), 'ς');
+    }
+
+    #[test]
+    fn word_internal() {
+        assert_eq!(lowercase_sigma("Ο".as_bytes(), "Ο".as_bytes()), 'σ');
+        assert_eq!(lowercase_sigma(b"A", b"a"), 'σ');
+        assert_eq!(lowercase_sigma(b"A", "Σ".as_bytes()), 'σ');
+    }
+
+    #[test]
+    fn word_initial_and_isolated() {
+        assert_eq!(lowercase_sigma(b"", "Ο".as_bytes()), 'σ');
+        assert_eq!(lowercase_sigma(b"", b""), 'σ');
+        assert_eq!(lowercase_sigma(b" ", b" "), 'σ');
+        assert_eq!(lowercase_sigma(b"1", b""), 'σ');
+    }
+
+    #[test]
+    fn case_ignorable_characters_are_skipped() {
+        // COMBINING ACUTE ACCENT
+        assert_eq!(lowercase_sigma("Α\u{301}".as_bytes(), b""), 'ς');
+        assert_eq!(lowercase_sigma(b"A", "\u{301}".as_bytes()), 'ς');
+        assert_eq!(lowercase_sigma(b"A", "\u{301}a".as_bytes()), 'σ');
+        assert_eq!(lowercase_sigma(b"A'", b""), 'ς');
+        assert_eq!(lowercase_sigma(b"A", b"'a"), 'σ');
+        assert_eq!(lowercase_sigma(b"'", b""), 'σ');
+    }
+
+    #[test]
+    fn invalid_utf8_ends_context() {
+        assert_eq!(lowercase_sigma(b"A\xFF", b""), 'σ');
+        assert_eq!(lowercase_sigma(b"A", b"\xFFa"), 'ς');
+    }
+}
@@ -0,0 +1,324 @@
+use core::iter::FusedIterator;
+use core::slice;
+
+use crate::unicode::ucd_generated_case_mapping::{
+    SORTED_LOWERCASE_SPECIAL, SORTED_UPPERCASE_SPECIAL,
+};
+
+type Entry = (u32, [u32; 3], Option<CasingLanguage>, Option<CasingContext>);
+
+/// A language which a mapping in `SpecialCasing.txt` is tailored to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CasingLanguage {
+    /// Lithuanian, language tag `lt`.
+    Lithuanian,
+    /// Turkish, language tag `tr`.
+    Turkish,
+    /// Azerbaijani, language tag `az`.
+    Azerbaijani,
+}
+
+impl CasingLanguage {
+    /// The language tag used for this language in `SpecialCasing.txt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use roe::CasingLanguage;
+    ///
+    /// assert_eq!(CasingLanguage::Turkish.tag(), "tr");
+    /// ```
+    #[must_use]
+    pub const fn tag(self) -> &'static str {
+        match self {
+            Self::Lithuanian => "lt",
+            Self::Turkish => "tr",
+            Self::Azerbaijani => "az",
+        }
+    }
+}
+
+/// A casing context from Table 3-17 of the Unicode Standard which must
+/// surround a `char` for a mapping in `SpecialCasing.txt` to apply.
+///
+/// See [Context Specification for Casing].
+///
+/// [Context Specification for Casing]: https://www.unicode.org/versions/Unicode15.0.0/ch03.pdf#G53636
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CasingContext {
+    /// `Final_Sigma`: the `char` is preceded by a cased letter and is not
+    /// followed by a cased letter, skipping case-ignorable characters.
+    FinalSigma,
+    /// `After_Soft_Dotted`: the `char` follows a `Soft_Dotted` `char` with no
+    /// intervening `char` of combining class 0 or Above.
+    AfterSoftDotted,
+    /// `More_Above`: the `char` is followed by a combining mark of class Above
+    /// with no intervening `char` of combining class 0 or Above.
+    MoreAbove,
+    /// `Not_Before_Dot`: the `char` is not followed by U+0307 COMBINING DOT
+    /// ABOVE with no intervening `char` of combining class 0 or Above.
+    NotBeforeDot,
+    /// `After_I`: the `char` follows an uppercase `I` with no intervening `char`
+    /// of combining class 0 or Above.
+    AfterI,
+}
+
+impl CasingContext {
+    /// The name used for this context in `SpecialCasing.txt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use roe::CasingContext;
+    ///
+    /// assert_eq!(CasingContext::FinalSigma.name(), "Final_Sigma");
+    /// ```
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::FinalSigma => "Final_Sigma",
+            Self::AfterSoftDotted => "After_Soft_Dotted",
+            Self::MoreAbove => "More_Above",
+            Self::NotBeforeDot => "Not_Before_Dot",
+            Self::AfterI => "After_I",
+        }
+    }
+}
+
+/// A case mapping from `SpecialCasing.txt` and the conditions under which it
+/// applies.
+///
+/// This `struct` is yielded by the iterators returned by
+/// [`to_lowercase_special`] and [`to_uppercase_special`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpecialCaseMapping {
+    mapping: [char; 3],
+    language: Option<CasingLanguage>,
+    context: Option<CasingContext>,
+}
+
+impl SpecialCaseMapping {
+    fn from_entry(&(_, mapping, language, context): &Entry) -> Self {
+        Self {
+            mapping: [
+                char::from_u32(mapping[0]).unwrap_or('\0'),
+                char::from_u32(mapping[1]).unwrap_or('\0'),
+                char::from_u32(mapping[2]).unwrap_or('\0'),
+            ],
+            language,
+            context,
+        }
+    }
+
+    /// The mapped `char`s.
+    ///
+    /// Trailing NUL bytes in the returned array should be ignored. Some
+    /// conditional mappings remove the character, in which case every element
+    /// is NUL.
+    #[must_use]
+    pub const fn mapping(&self) -> [char; 3] {
+        self.mapping
+    }
+
+    /// The language which the mapping is tailored to, or [`None`] if it
+    /// applies to all languages.
+    #[must_use]
+    pub const fn language(&self) -> Option<CasingLanguage> {
+        self.language
+    }
+
+    /// The casing context which must surround the character for the mapping to
+    /// apply, or [`None`] if it applies in any context.
+    #[must_use]
+    pub const fn context(&self) -> Option<CasingContext> {
+        self.context
+    }
+}
+
+/// Returns an iterator over the special case mappings of a `char`.
+///
+/// This `struct` is created by the [`to_lowercase_special`] and
+/// [`to_uppercase_special`] functions.
+#[derive(Debug, Clone)]
+pub struct SpecialCaseMappings(slice::Iter<'static, Entry>);
+
+impl Iterator for SpecialCaseMappings {
+    type Item = SpecialCaseMapping;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(SpecialCaseMapping::from_entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for SpecialCaseMappings {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(SpecialCaseMapping::from_entry)
+    }
+}
+
+impl SpecialCaseMappings {
+    /// Return the first mapping tailored to `language` whose context, if any,
+    /// holds according to `in_context`.
+    ///
+    /// Passing [`None`] for `language` finds mappings which apply to all
+    /// languages.
+    pub(crate) fn find_in_context(
+        mut self,
+        language: Option<CasingLanguage>,
+        mut in_context: impl FnMut(CasingContext) -> bool,
+    ) -> Option<SpecialCaseMapping> {
+        self.find(|mapping| {
+            mapping.language == language && mapping.context.is_none_or(&mut in_context)
+        })
+    }
+}
+
+impl FusedIterator for SpecialCaseMappings {}
+
+impl ExactSizeIterator for SpecialCaseMappings {}
+
+fn lookup(table: &'static [Entry], c: char) -> SpecialCaseMappings {
+    let codepoint = c as u32;
+    let start = table.partition_point(|&(key, _, _, _)| key < codepoint);
+    let end = start + table[start..].partition_point(|&(key, _, _, _)| key == codepoint);
+    SpecialCaseMappings(table[start..end].iter())
+}
+
+/// Take a [`char`] and return an iterator over its lowercase mappings in
+/// `SpecialCasing.txt`.
+///
+/// Each mapping carries the language and context conditions under which it
+/// applies, in the order they are listed in `SpecialCasing.txt`. Most `char`s
+/// have no special lowercase mapping and their iterator is empty.
+///
+/// # Examples
+///
+/// ```
+/// use roe::{to_lowercase_special, CasingContext, CasingLanguage};
+///
+/// let mut mappings = to_lowercase_special('Σ');
+/// let final_sigma = mappings.next().unwrap();
+/// assert_eq!(final_sigma.mapping(), ['ς', '\0', '\0']);
+/// assert_eq!(final_sigma.language(), None);
+/// assert_eq!(final_sigma.context(), Some(CasingContext::FinalSigma));
+/// assert!(mappings.next().is_none());
+///
+/// let mappings = to_lowercase_special('İ').collect::<Vec<_>>();
+/// assert_eq!(mappings.len(), 3);
+/// assert_eq!(mappings[0].mapping(), ['i', '\u{307}', '\0']);
+/// assert_eq!(mappings[1].language(), Some(CasingLanguage::Turkish));
+///
+/// assert_eq!(to_lowercase_special('A').count(), 0);
+/// ```
+#[must_use]
+pub fn to_lowercase_special(c: char) -> SpecialCaseMappings {
+    lookup(SORTED_LOWERCASE_SPECIAL, c)
+}
+
+/// Take a [`char`] and return an iterator over its uppercase mappings in
+/// `SpecialCasing.txt`.
+///
+/// Each mapping carries the language and context conditions under which it
+/// applies, in the order they are listed in `SpecialCasing.txt`. Most `char`s
+/// have no special uppercase mapping and their iterator is empty.
+///
+/// # Examples
+///
+/// ```
+/// use roe::{to_uppercase_special, CasingLanguage};
+///
+/// let mut mappings = to_uppercase_special('ß');
+/// assert_eq!(mappings.next().unwrap().mapping(), ['S', 'S', '\0']);
+/// assert!(mappings.next().is_none());
+///
+/// let turkic = to_uppercase_special('i').next().unwrap();
+/// assert_eq!(turkic.mapping(), ['İ', '\0', '\0']);
+/// assert_eq!(turkic.language(), Some(CasingLanguage::Turkish));
+///
+/// assert_eq!(to_uppercase_special('a').count(), 0);
+/// ```
+#[must_use]
+pub fn to_uppercase_special(c: char) -> SpecialCaseMappings {
+    lookup(SORTED_UPPERCASE_SPECIAL, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{to_lowercase_special, to_uppercase_special, CasingContext, CasingLanguage};
+
+    #[test]
+    fn conditional_lowercase() {
+        let mappings = to_lowercase_special('I').collect::<Vec<_>>();
+        let conditions = mappings
+            .iter()
+            .map(|mapping| (mapping.language(), mapping.context()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            conditions,
+            [
+                (
+                    Some(CasingLanguage::Lithuanian),
+                    Some(CasingContext::MoreAbove)
+                ),
+                (
+                    Some(CasingLanguage::Turkish),
+                    Some(CasingContext::NotBeforeDot)
+                ),
+                (
+                    Some(CasingLanguage::Azerbaijani),
+                    Some(CasingContext::NotBeforeDot)
+                ),
+            ]
+        );
+        assert_eq!(mappings[0].mapping(), ['i', '\u{307}', '\0']);
+        assert_eq!(mappings[1].mapping(), ['ı', '\0', '\0']);
+    }
+
+    #[test]
+    fn removed_characters_map_to_nothing() {
+        let after_i = to_lowercase_special('\u{307}').collect::<Vec<_>>();
+        assert_eq!(after_i.len(), 2);
+        assert!(after_i.iter().all(|mapping| mapping.mapping() == ['\0'; 3]
+            && mapping.context() == Some(CasingContext::AfterI)));
+
+        let mut soft_dotted = to_uppercase_special('\u{307}');
+        let mapping = soft_dotted.next().unwrap();
+        assert_eq!(mapping.mapping(), ['\0'; 3]);
+        assert_eq!(mapping.language(), Some(CasingLanguage::Lithuanian));
+        assert_eq!(mapping.context(), Some(CasingContext::AfterSoftDotted));
+        assert!(soft_dotted.next().is_none());
+    }
+
+    #[test]
+    fn unconditional_mappings_match_std() {
+        for ch in ['ß', 'ﬃ', 'ΐ', 'ῷ', 'ᾳ'] {
+            let mut special = to_uppercase_special(ch);
+            let mapping = special.next().unwrap();
+            assert_eq!(mapping.language(), None);
+            assert_eq!(mapping.context(), None);
+            let mapped = mapping.mapping();
+            let mapped = mapped.iter().copied().filter(|&ch| ch != '\0');
+            assert!(mapped.eq(ch.to_uppercase()));
+        }
+        let mapping = to_lowercase_special('İ').next().unwrap();
+        let mapped = mapping.mapping();
+        let mapped = mapped.iter().copied().filter(|&ch| ch != '\0');
+        assert!(mapped.eq('İ'.to_lowercase()));
+    }
+
+    #[test]
+    fn chars_without_special_mappings() {
+        for ch in ['a', 'A', 'σ', '\u{1F600}', '\0', char::MAX] {
+            assert_eq!(to_lowercase_special(ch).len(), 0);
+            assert_eq!(to_uppercase_special(ch).len(), 0);
+        }
+        // `ß` lowercases to itself.
+        assert_eq!(to_lowercase_special('ß').len(), 0);
+    }
+}
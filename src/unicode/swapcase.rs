@@ -0,0 +1,178 @@
+use core::iter::FusedIterator;
+
+use crate::unicode::std_case_mapping_iter::CaseMappingIter;
+
+/// Take a [`char`] and return its Unicode swapcase as 3 `char`s.
+///
+/// Lowercase characters are mapped to their full Unicode uppercase equivalent.
+/// Uppercase and titlecase characters are mapped to their full Unicode
+/// lowercase equivalent. All other characters, including digits, punctuation,
+/// and letters from scripts without case, map to themselves.
+///
+/// Only actual case pairs are flipped. Cased letters which have no opposite
+/// case, like `ĸ` U+0138 LATIN SMALL LETTER KRA, also map to themselves.
+///
+/// Trailing NUL bytes in the returned array should be ignored.
+///
+/// # Examples
+///
+/// ```
+/// use roe::to_swapcase;
+///
+/// assert_eq!(to_swapcase('a'), ['A', '\0', '\0']);
+/// assert_eq!(to_swapcase('A'), ['a', '\0', '\0']);
+///
+/// // Titlecase letters map to lowercase
+/// assert_eq!(to_swapcase('ǈ'), ['ǉ', '\0', '\0']);
+///
+/// // Expansions
+/// assert_eq!(to_swapcase('ß'), ['S', 'S', '\0']);
+///
+/// // Characters without case map to themselves
+/// assert_eq!(to_swapcase('中'), ['中', '\0', '\0']);
+/// assert_eq!(to_swapcase('7'), ['7', '\0', '\0']);
+/// ```
+#[must_use]
+pub fn to_swapcase(c: char) -> [char; 3] {
+    let mut chars = ['\0'; 3];
+    // Characters which are neither uppercase nor titlecase have an identity
+    // lowercase mapping, so only lowercase characters need to be uppercased.
+    if c.is_lowercase() {
+        for (slot, ch) in chars.iter_mut().zip(c.to_uppercase()) {
+            *slot = ch;
+        }
+    } else {
+        for (slot, ch) in chars.iter_mut().zip(c.to_lowercase()) {
+            *slot = ch;
+        }
+    }
+    chars
+}
+
+/// Returns an iterator that yields the Unicode swapcase of a `char`.
+///
+/// This `struct` is created by the [`ToSwapcase::new`] function.
+#[derive(Clone, Debug)]
+pub struct ToSwapcase(CaseMappingIter);
+
+impl ToSwapcase {
+    /// Create an iterator over the Unicode swapcase of `c`.
+    ///
+    /// See [`to_swapcase`].
+    pub fn new(c: char) -> Self {
+        Self(CaseMappingIter::new(to_swapcase(c)))
+    }
+}
+
+impl Iterator for ToSwapcase {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ToSwapcase {
+    fn next_back(&mut self) -> Option<char> {
+        self.0.next_back()
+    }
+}
+
+impl FusedIterator for ToSwapcase {}
+
+impl ExactSizeIterator for ToSwapcase {}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_swapcase, ToSwapcase};
+    use crate::unicode::properties::is_cased;
+
+    fn assert_identity(c: char) {
+        assert_eq!(
+            to_swapcase(c),
+            [c, '\0', '\0'],
+            "expected {c:?} to be unchanged"
+        );
+    }
+
+    #[test]
+    fn case_pairs_are_flipped() {
+        assert_eq!(to_swapcase('z'), ['Z', '\0', '\0']);
+        assert_eq!(to_swapcase('Z'), ['z', '\0', '\0']);
+        assert_eq!(to_swapcase('σ'), ['Σ', '\0', '\0']);
+        assert_eq!(to_swapcase('Σ'), ['σ', '\0', '\0']);
+        assert_eq!(to_swapcase('ǅ'), ['ǆ', '\0', '\0']);
+        assert_eq!(to_swapcase('ΐ'), ['Ι', '\u{308}', '\u{301}']);
+    }
+
+    #[test]
+    fn iterator_yields_mapping() {
+        let mut iter = ToSwapcase::new('ß');
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some('S'));
+        assert_eq!(iter.next(), Some('S'));
+        assert_eq!(iter.next(), None);
+
+        let iter = ToSwapcase::new('ǈ');
+        assert!(iter.eq(['ǉ']));
+    }
+
+    #[test]
+    fn cjk_is_unchanged() {
+        assert_identity('中');
+        assert_identity('漢');
+        assert_identity('ひ');
+        assert_identity('한');
+    }
+
+    #[test]
+    fn digits_are_unchanged() {
+        for c in '0'..='9' {
+            assert_identity(c);
+        }
+        // ARABIC-INDIC DIGIT THREE
+        assert_identity('٣');
+    }
+
+    #[test]
+    fn caseless_script_letters_are_unchanged() {
+        // HEBREW LETTER ALEF
+        assert_identity('א');
+        // ARABIC LETTER BEH
+        assert_identity('ب');
+        // DEVANAGARI LETTER KA
+        assert_identity('क');
+        // THAI CHARACTER KO KAI
+        assert_identity('ก');
+    }
+
+    #[test]
+    fn cased_letters_without_a_pair_are_unchanged() {
+        // LATIN SMALL LETTER KRA is lowercase with no uppercase mapping.
+        assert_identity('ĸ');
+        // FEMININE ORDINAL INDICATOR is Other_Lowercase.
+        assert_identity('ª');
+        // LATIN LETTER SMALL CAPITAL A is lowercase with no uppercase mapping.
+        assert_identity('ᴀ');
+    }
+
+    #[test]
+    fn punctuation_and_symbols_are_unchanged() {
+        for c in [' ', '-', '_', '!', '€', '\u{FFFD}', '\u{1F600}'] {
+            assert_identity(c);
+        }
+    }
+
+    #[test]
+    fn uncased_characters_are_unchanged() {
+        for c in (0..=0x10_FFFF).filter_map(char::from_u32) {
+            if !is_cased(c) {
+                assert_identity(c);
+            }
+        }
+    }
+}
@@ -1,6 +1,4 @@
-use crate::unicode::std_case_mapping_iter::CaseMappingIter;
 use crate::unicode::ucd_generated_case_mapping::SORTED_TITLECASE_MAPPING;
-use core::iter::FusedIterator;
 
 /// Take a [`char`] and return its Unicode titlecase as 3 `char`s.
 ///
@@ -25,74 +23,134 @@ use core::iter::FusedIterator;
 #[allow(clippy::module_name_repetitions)]
 #[must_use]
 pub fn to_titlecase(c: char) -> [char; 3] {
-    let codepoint = c as u32;
-    if let Ok(index) = SORTED_TITLECASE_MAPPING.binary_search_by(|&(key, _)| key.cmp(&codepoint)) {
-        let chars = SORTED_TITLECASE_MAPPING[index].1;
-        [
-            char::from_u32(chars[0]).unwrap_or(c),
-            char::from_u32(chars[1]).unwrap_or('\0'),
-            char::from_u32(chars[2]).unwrap_or('\0'),
-        ]
-    } else {
-        [c, '\0', '\0']
-    }
+    titlecase_mapping(c).unwrap_or([c, '\0', '\0'])
 }
 
-/// Returns an iterator that yields the titlecase equivalent of a `char`.
+/// Look up the titlecase mapping of a [`char`] in the generated titlecase
+/// table.
 ///
-/// This `struct` is created by the [`to_titlecase`] method.
-#[allow(clippy::module_name_repetitions)]
-#[derive(Clone, Debug)]
-pub struct ToTitlecase(CaseMappingIter);
-
-impl Iterator for ToTitlecase {
-    type Item = char;
-    fn next(&mut self) -> Option<char> {
-        self.0.next()
-    }
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
-    }
+/// Returns [`None`] if the table has no entry for the given `char`, in which
+/// case it titlecases to itself.
+pub(crate) fn titlecase_mapping(c: char) -> Option<[char; 3]> {
+    let codepoint = c as u32;
+    let index = SORTED_TITLECASE_MAPPING
+        .binary_search_by(|&(key, _)| key.cmp(&codepoint))
+        .ok()?;
+    let chars = SORTED_TITLECASE_MAPPING[index].1;
+    Some([
+        char::from_u32(chars[0]).unwrap_or(c),
+        char::from_u32(chars[1]).unwrap_or('\0'),
+        char::from_u32(chars[2]).unwrap_or('\0'),
+    ])
 }
 
-impl DoubleEndedIterator for ToTitlecase {
-    fn next_back(&mut self) -> Option<char> {
-        self.0.next_back()
-    }
-}
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::ops::RangeInclusive;
 
-impl FusedIterator for ToTitlecase {}
+    use super::titlecase_mapping;
+    use crate::unicode::{case_map_char, CaseMapChar, CaseOp};
+    use crate::CaseMappingMode;
 
-impl ExactSizeIterator for ToTitlecase {}
+    fn titlecase_char(c: char) -> CaseMapChar {
+        case_map_char(c, CaseOp::Title, CaseMappingMode::Full).unwrap()
+    }
 
-pub trait Titlecase {
-    fn to_titlecase(self) -> ToTitlecase;
-}
+    // Codepoints whose titlecase mapping legitimately differs from their
+    // uppercase mapping.
+    const TITLECASE_NOT_UPPERCASE: [RangeInclusive<u32>; 18] = [
+        // LATIN SMALL LETTER SHARP S
+        0x00DF..=0x00DF,
+        // Latin digraphs, including the titlecase digraphs, which titlecase to
+        // themselves
+        0x01C4..=0x01CC,
+        0x01F1..=0x01F3,
+        // ARMENIAN SMALL LIGATURE ECH YIWN
+        0x0587..=0x0587,
+        // Georgian Mkhedruli letters, which titlecase to themselves but
+        // uppercase to Mtavruli
+        0x10D0..=0x10FA,
+        0x10FD..=0x10FF,
+        // Greek letters with ypogegrammeni and prosgegrammeni
+        0x1F80..=0x1FAF,
+        0x1FB2..=0x1FB4,
+        0x1FB7..=0x1FB7,
+        0x1FBC..=0x1FBC,
+        0x1FC2..=0x1FC4,
+        0x1FC7..=0x1FC7,
+        0x1FCC..=0x1FCC,
+        0x1FF2..=0x1FF4,
+        0x1FF7..=0x1FF7,
+        0x1FFC..=0x1FFC,
+        // Latin ligatures
+        0xFB00..=0xFB06,
+        // Armenian ligatures
+        0xFB13..=0xFB17,
+    ];
 
-impl Titlecase for char {
-    fn to_titlecase(self) -> ToTitlecase {
-        ToTitlecase(CaseMappingIter::new(to_titlecase(self)))
+    // The codepoints assigned in the version of the UCD the titlecase table
+    // was generated from. The case mappings of `char` may be from a newer
+    // version of Unicode, which can map assigned characters to characters the
+    // table does not know about.
+    fn assigned_codepoints() -> Vec<u32> {
+        include_str!("../../generated/ucd/UnicodeData.txt")
+            .lines()
+            .filter_map(|line| line.split(';').next())
+            .map(|codepoint| u32::from_str_radix(codepoint, 16).unwrap())
+            .collect()
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use alloc::vec::Vec;
 
-    use crate::unicode::titlecase::Titlecase;
+    #[test]
+    fn titlecase_table_coverage() {
+        let assigned = assigned_codepoints();
+        let is_assigned = |c: char| assigned.binary_search(&u32::from(c)).is_ok();
+        let chars = (0..=u32::from(char::MAX))
+            .filter_map(char::from_u32)
+            .filter(|&c| is_assigned(c) && c.to_uppercase().all(is_assigned));
+        for c in chars {
+            let titlecase = titlecase_char(c);
+            let uppercase = c.to_uppercase();
+            let whitelisted = TITLECASE_NOT_UPPERCASE
+                .iter()
+                .any(|range| range.contains(&u32::from(c)));
+            if whitelisted {
+                assert!(!titlecase.eq(uppercase), "{:?} titlecases to uppercase", c);
+            } else {
+                assert!(
+                    titlecase.eq(uppercase),
+                    "{:?} titlecases to {:?}, table entry {:?}",
+                    c,
+                    titlecase_char(c).collect::<Vec<_>>(),
+                    titlecase_mapping(c)
+                );
+            }
+        }
+    }
 
     #[test]
     fn test_char_to_titlecase() {
-        assert_eq!('ß'.to_titlecase().collect::<Vec<_>>(), ['S', 's']);
-        assert_eq!('Ǆ'.to_titlecase().collect::<Vec<_>>(), ['ǅ']);
-        assert_eq!('ﬄ'.to_titlecase().collect::<Vec<_>>(), ['F', 'f', 'l']);
-        assert_eq!('i'.to_titlecase().collect::<Vec<_>>(), ['I']);
-        assert_eq!('A'.to_titlecase().collect::<Vec<_>>(), ['A']);
+        assert_eq!(titlecase_char('ß').collect::<Vec<_>>(), ['S', 's']);
+        assert_eq!(titlecase_char('Ǆ').collect::<Vec<_>>(), ['ǅ']);
+        assert_eq!(titlecase_char('ﬄ').collect::<Vec<_>>(), ['F', 'f', 'l']);
+        assert_eq!(titlecase_char('i').collect::<Vec<_>>(), ['I']);
+        assert_eq!(titlecase_char('A').collect::<Vec<_>>(), ['A']);
+    }
+
+    #[test]
+    fn test_titlecase_mapping() {
+        assert_eq!(titlecase_mapping('a'), Some(['A', '\0', '\0']));
+        assert_eq!(titlecase_mapping('ǆ'), Some(['ǅ', '\0', '\0']));
+        assert_eq!(titlecase_mapping('ﬄ'), Some(['F', 'f', 'l']));
+        // Titlecase digraphs have an entry which maps to themselves.
+        assert_eq!(titlecase_mapping('ǅ'), Some(['ǅ', '\0', '\0']));
+        assert_eq!(titlecase_mapping('A'), None);
+        assert_eq!(titlecase_mapping('7'), None);
     }
 
     #[test]
     fn test_next_back() {
-        let mut iter = 'ﬄ'.to_titlecase();
+        let mut iter = titlecase_char('ﬄ');
         assert_eq!(iter.next_back(), Some('l'));
         assert_eq!(iter.next_back(), Some('f'));
         assert_eq!(iter.next_back(), Some('F'));
@@ -0,0 +1,29 @@
+include!("../../generated/case_folding.rs");
+
+pub use CASE_FOLDING as SORTED_CASE_FOLDING;
+#[cfg(test)]
+mod tests {
+    pub use super::CASE_FOLDING as SORTED_CASE_FOLDING;
+
+    #[test]
+    fn test_case_folding_is_sorted() {
+        let mut prev: Option<&u32> = None;
+        for (curr, _) in SORTED_CASE_FOLDING {
+            if let Some(prev) = prev {
+                assert!(curr > prev);
+            }
+            prev = Some(curr);
+        }
+    }
+
+    #[test]
+    fn test_case_folding_is_valid() {
+        for &(codepoint, folded) in SORTED_CASE_FOLDING {
+            assert!(char::from_u32(codepoint).is_some());
+            assert!(char::from_u32(folded[0]).is_some());
+            assert!(folded[1..]
+                .iter()
+                .all(|&cp| cp == !0 || char::from_u32(cp).is_some()));
+        }
+    }
+}
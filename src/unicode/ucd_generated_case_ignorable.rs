@@ -0,0 +1,20 @@
+include!("../../generated/case_ignorable.rs");
+
+pub use CASE_IGNORABLE as SORTED_CASE_IGNORABLE;
+#[cfg(test)]
+mod tests {
+    pub use super::CASE_IGNORABLE as SORTED_CASE_IGNORABLE;
+
+    #[test]
+    fn test_case_ignorable_is_sorted() {
+        let mut prev: Option<&u32> = None;
+        for (start, end) in SORTED_CASE_IGNORABLE {
+            assert!(start <= end);
+            if let Some(prev) = prev {
+                // Adjacent ranges are coalesced.
+                assert!(*start > prev + 1);
+            }
+            prev = Some(end);
+        }
+    }
+}
@@ -1,9 +1,15 @@
+use crate::unicode::special_casing::{CasingContext, CasingLanguage};
+
 include!("../../generated/case_mapping.rs");
 
+pub use LOWERCASE_SPECIAL as SORTED_LOWERCASE_SPECIAL;
 pub use TITLE as SORTED_TITLECASE_MAPPING;
+pub use UPPERCASE_SPECIAL as SORTED_UPPERCASE_SPECIAL;
 #[cfg(test)]
 mod tests {
+    pub use super::LOWERCASE_SPECIAL as SORTED_LOWERCASE_SPECIAL;
     pub use super::TITLE as SORTED_TITLECASE_MAPPING;
+    pub use super::UPPERCASE_SPECIAL as SORTED_UPPERCASE_SPECIAL;
 
     #[test]
     fn test_case_mapping_is_sorted() {
@@ -15,4 +21,38 @@ mod tests {
             prev = Some(curr);
         }
     }
+
+    #[test]
+    fn test_special_casing_is_sorted() {
+        // A codepoint has one entry for each of its conditional mappings, so
+        // keys may repeat.
+        for table in [SORTED_LOWERCASE_SPECIAL, SORTED_UPPERCASE_SPECIAL] {
+            let mut prev: Option<&u32> = None;
+            for (curr, _, _, _) in table {
+                if let Some(prev) = prev {
+                    assert!(curr >= prev);
+                }
+                prev = Some(curr);
+            }
+        }
+    }
+
+    #[test]
+    fn test_special_casing_is_valid() {
+        for table in [SORTED_LOWERCASE_SPECIAL, SORTED_UPPERCASE_SPECIAL] {
+            for (idx, &(codepoint, mapping, language, context)) in table.iter().enumerate() {
+                assert!(char::from_u32(codepoint).is_some());
+                assert!(mapping
+                    .iter()
+                    .all(|&cp| cp == !0 || char::from_u32(cp).is_some()));
+                // Padding only trails the mapped codepoints.
+                assert!(mapping.windows(2).all(|w| w[0] != !0 || w[1] == !0));
+                // Each mapping of a codepoint applies under distinct conditions.
+                assert!(table[..idx]
+                    .iter()
+                    .filter(|&&(cp, _, _, _)| cp == codepoint)
+                    .all(|&(_, _, lang, ctx)| (lang, ctx) != (language, context)));
+            }
+        }
+    }
 }
@@ -0,0 +1,26 @@
+include!("../../generated/combining_class.rs");
+
+pub use CANONICAL_COMBINING_CLASS as SORTED_CANONICAL_COMBINING_CLASS;
+#[cfg(test)]
+mod tests {
+    pub use super::CANONICAL_COMBINING_CLASS as SORTED_CANONICAL_COMBINING_CLASS;
+
+    #[test]
+    fn test_combining_class_is_sorted() {
+        let mut prev: Option<&u32> = None;
+        for (start, end, _) in SORTED_CANONICAL_COMBINING_CLASS {
+            assert!(start <= end);
+            if let Some(prev) = prev {
+                assert!(start > prev);
+            }
+            prev = Some(end);
+        }
+    }
+
+    #[test]
+    fn test_combining_class_is_nonzero() {
+        assert!(SORTED_CANONICAL_COMBINING_CLASS
+            .iter()
+            .all(|&(_, _, ccc)| ccc != 0));
+    }
+}
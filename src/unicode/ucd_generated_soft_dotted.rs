@@ -0,0 +1,20 @@
+include!("../../generated/soft_dotted.rs");
+
+pub use SOFT_DOTTED as SORTED_SOFT_DOTTED;
+#[cfg(test)]
+mod tests {
+    pub use super::SOFT_DOTTED as SORTED_SOFT_DOTTED;
+
+    #[test]
+    fn test_soft_dotted_is_sorted() {
+        let mut prev: Option<&u32> = None;
+        for (start, end) in SORTED_SOFT_DOTTED {
+            assert!(start <= end);
+            if let Some(prev) = prev {
+                // Adjacent ranges are coalesced.
+                assert!(*start > prev + 1);
+            }
+            prev = Some(end);
+        }
+    }
+}
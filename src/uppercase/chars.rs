@@ -0,0 +1,232 @@
+use core::char::REPLACEMENT_CHARACTER;
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use crate::unicode::fold::to_casefold;
+use crate::unicode::lithuanian::is_removed_by_lithuanian_uppercase;
+use crate::unicode::std_case_mapping_iter::CaseMappingIter;
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, UppercaseMode};
+
+const LATIN_CAPITAL_LETTER_I_WITH_DOT_ABOVE: char = '\u{130}';
+const LATIN_CAPITAL_LETTER_SHARP_S: char = '\u{1E9E}';
+
+/// Returns an iterator that yields the [`char`]s of the given slice with all
+/// lowercase letters replaced with their uppercase counterparts.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string] and
+/// maps the same as [`uppercase`], but yields `char`s instead of bytes. Case
+/// mappings are yielded directly without being encoded as UTF-8. Each invalid
+/// UTF-8 byte sequence is replaced with a single U+FFFD REPLACEMENT CHARACTER,
+/// following the "substitution of maximal subparts" strategy.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::UppercaseMode;
+/// let uppercase = roe::uppercase_chars("αύριο ß".as_bytes(), UppercaseMode::Full);
+/// assert_eq!(uppercase.collect::<String>(), "ΑΎΡΙΟ SS");
+///
+/// let uppercase = roe::uppercase_chars(b"abc\xF0\x9F\x87xyz", UppercaseMode::Full);
+/// assert_eq!(uppercase.collect::<String>(), "ABC\u{FFFD}XYZ");
+/// ```
+///
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/0.2.*/bstr/#when-should-i-use-byte-strings
+/// [`uppercase`]: crate::uppercase()
+pub const fn uppercase_chars(slice: &[u8], options: UppercaseMode) -> UppercaseChars<'_> {
+    UppercaseChars {
+        source: slice,
+        slice,
+        mode: options,
+        uppercase: CaseMappingIter::Zero,
+    }
+}
+
+/// An iterator that yields the uppercase equivalent of a conventionally UTF-8
+/// byte string as [`char`]s.
+///
+/// This struct is created by the [`uppercase_chars`] function. See its
+/// documentation for more.
+///
+/// [`uppercase_chars`]: crate::uppercase_chars()
+#[derive(Clone)]
+#[must_use = "UppercaseChars is a Iterator and must be used"]
+pub struct UppercaseChars<'a> {
+    // `slice` is a suffix of `source`, the rest of which is kept around as
+    // context for the Lithuanian `After_Soft_Dotted` rule.
+    source: &'a [u8],
+    slice: &'a [u8],
+    mode: UppercaseMode,
+    uppercase: CaseMappingIter,
+}
+
+impl fmt::Debug for UppercaseChars<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UppercaseChars")
+            .field("slice", &self.slice.as_bstr())
+            .field("mode", &self.mode)
+            .field("uppercase", &self.uppercase)
+            .finish()
+    }
+}
+
+impl<'a> UppercaseChars<'a> {
+    /// Views the not yet case mapped portion of the source slice.
+    ///
+    /// Like [`Uppercase::as_slice`], the returned slice may begin in the middle
+    /// of an expansion.
+    ///
+    /// [`Uppercase::as_slice`]: crate::Uppercase::as_slice
+    #[must_use]
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.slice
+    }
+}
+
+impl Iterator for UppercaseChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(ch) = self.uppercase.next() {
+            return Some(ch);
+        }
+        let ch = loop {
+            let (ch, size) = match bstr::decode_utf8(self.slice) {
+                (_, 0) => return None,
+                (Some(ch), size) => (ch, size),
+                (None, size) => {
+                    self.slice = &self.slice[size..];
+                    return Some(REPLACEMENT_CHARACTER);
+                }
+            };
+            let preceding = &self.source[..self.source.len() - self.slice.len()];
+            self.slice = &self.slice[size..];
+            if self.mode == UppercaseMode::Lithuanian
+                && is_removed_by_lithuanian_uppercase(ch, preceding)
+            {
+                continue;
+            }
+            break ch;
+        };
+        let chars = match (self.mode, ch) {
+            (UppercaseMode::Ascii, _) => return Some(ch.to_ascii_uppercase()),
+            // Turkic languages keep the dot when uppercasing `i`.
+            (UppercaseMode::Turkic, 'i') => return Some(LATIN_CAPITAL_LETTER_I_WITH_DOT_ABOVE),
+            (UppercaseMode::CapitalSharpS, 'ß') => return Some(LATIN_CAPITAL_LETTER_SHARP_S),
+            (UppercaseMode::Fold, _) => to_casefold(ch),
+            (
+                UppercaseMode::Full
+                | UppercaseMode::Turkic
+                | UppercaseMode::Lithuanian
+                | UppercaseMode::CapitalSharpS,
+                _,
+            ) => {
+                let mut chars = ['\0'; 3];
+                for (slot, ch) in chars.iter_mut().zip(ch.to_uppercase()) {
+                    *slot = ch;
+                }
+                chars
+            }
+        };
+        self.uppercase = CaseMappingIter::new(chars);
+        self.uppercase.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every char and invalid UTF-8 byte sequence in the remaining slice
+        // yields at least one char. Every yielded char corresponds to at least
+        // one byte yielded by `uppercase`, whose output is bounded by the
+        // maximum expansion ratio.
+        let (pending, _) = self.uppercase.size_hint();
+        let (num, den) = max_expansion_ratio(CaseMapping::Uppercase(self.mode));
+        let max = self.slice.len().checked_mul(num).map(|max| max / den);
+        // Lithuanian uppercasing may remove explicit dots.
+        let dots = if self.mode == UppercaseMode::Lithuanian {
+            self.slice.find_iter("\u{307}").count()
+        } else {
+            0
+        };
+        (
+            lead_byte_count(self.slice) - dots + pending,
+            max.and_then(|max| max.checked_add(pending)),
+        )
+    }
+}
+
+impl FusedIterator for UppercaseChars<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::uppercase_chars;
+    use crate::UppercaseMode;
+
+    const INPUTS: [&[u8]; 9] = [
+        b"",
+        b"abc, XYZ",
+        "i\u{307}\u{301} j\u{307} a\u{307}".as_bytes(),
+        "αύριο ΑΎΡΙΟ".as_bytes(),
+        "ß ẞ ΐ ﬃ \u{212A}".as_bytes(),
+        "ǆungla ǅ".as_bytes(),
+        "istanbul ılık".as_bytes(),
+        b"\xFF\xFEabc",
+        b"aB\xF0\x9F\x87Yz\xFF\xFE\xCE",
+    ];
+
+    #[test]
+    fn matches_uppercase_decoded_lossily() {
+        for input in INPUTS {
+            for mode in [
+                UppercaseMode::Full,
+                UppercaseMode::Ascii,
+                UppercaseMode::Turkic,
+                UppercaseMode::Lithuanian,
+                UppercaseMode::Fold,
+                UppercaseMode::CapitalSharpS,
+            ] {
+                let chars = uppercase_chars(input, mode).collect::<String>();
+                let bytes = crate::uppercase(input, mode).collect::<Vec<_>>();
+                assert_eq!(chars, String::from_utf8_lossy(&bytes));
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_utf8_maximal_subparts() {
+        let uppercase = uppercase_chars(b"a\xF0\x9F\x87z\xFF\xFE", UppercaseMode::Full);
+        assert_eq!(uppercase.collect::<String>(), "A\u{FFFD}Z\u{FFFD}\u{FFFD}");
+    }
+
+    #[test]
+    fn as_slice() {
+        let mut uppercase = uppercase_chars("ßtx".as_bytes(), UppercaseMode::Full);
+        assert_eq!(uppercase.next(), Some('S'));
+        // The second `S` of the expansion has not been yielded yet.
+        assert_eq!(uppercase.as_slice(), b"tx");
+        assert_eq!(uppercase.next(), Some('S'));
+        assert_eq!(uppercase.next(), Some('T'));
+        assert_eq!(uppercase.as_slice(), b"x");
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        for input in INPUTS {
+            for mode in [UppercaseMode::Full, UppercaseMode::Lithuanian] {
+                let mut uppercase = uppercase_chars(input, mode);
+                loop {
+                    let (min, max) = uppercase.size_hint();
+                    let count = uppercase.clone().count();
+                    assert!(min <= count);
+                    assert!(count <= max.unwrap());
+                    if uppercase.next().is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use super::Uppercase;
+use crate::UppercaseMode;
+
+/// An iterator that yields a conventionally UTF-8 byte string with its first
+/// word replaced with its uppercase equivalent.
+///
+/// This iterator yields [bytes].
+///
+/// This struct is created by the [`upcase_first_word`] function. See its
+/// documentation for more.
+///
+/// [bytes]: u8
+/// [`upcase_first_word`]: crate::upcase_first_word()
+#[derive(Clone)]
+#[must_use = "UpcaseFirstWord is a Iterator and must be used"]
+pub struct UpcaseFirstWord<'a> {
+    first_word: Uppercase<'a>,
+    remainder: &'a [u8],
+}
+
+impl fmt::Debug for UpcaseFirstWord<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UpcaseFirstWord")
+            .field("first_word", &self.first_word)
+            .field("remainder", &self.remainder.as_bstr())
+            .finish()
+    }
+}
+
+impl<'a> UpcaseFirstWord<'a> {
+    pub(crate) fn with_slice(slice: &'a [u8], mode: UppercaseMode) -> Self {
+        let mut seen_word = false;
+        let mut split_at = slice.len();
+        for (start, _, ch) in slice.char_indices() {
+            if ch.is_whitespace() {
+                if seen_word {
+                    split_at = start;
+                    break;
+                }
+            } else {
+                seen_word = true;
+            }
+        }
+        let (first_word, remainder) = slice.split_at(split_at);
+        Self {
+            first_word: crate::uppercase(first_word, mode),
+            remainder,
+        }
+    }
+}
+
+impl Iterator for UpcaseFirstWord<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.first_word.next() {
+            return Some(byte);
+        }
+        let (&byte, remainder) = self.remainder.split_first()?;
+        self.remainder = remainder;
+        Some(byte)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (min, max) = self.first_word.size_hint();
+        let min = min.saturating_add(self.remainder.len());
+        let max = max.and_then(|max| max.checked_add(self.remainder.len()));
+        (min, max)
+    }
+}
+
+impl FusedIterator for UpcaseFirstWord<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::UpcaseFirstWord;
+    use crate::UppercaseMode;
+
+    #[test]
+    fn empty() {
+        let iter = UpcaseFirstWord::with_slice(b"", UppercaseMode::Full);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+
+        let iter = UpcaseFirstWord::with_slice(b"  \t", UppercaseMode::Full);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"  \t".as_bstr());
+    }
+
+    #[test]
+    fn remainder_is_verbatim() {
+        let iter = UpcaseFirstWord::with_slice(b"note: Keep this As-Is", UppercaseMode::Full);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"NOTE: Keep this As-Is".as_bstr()
+        );
+
+        let iter = UpcaseFirstWord::with_slice(b"warn\tall caps", UppercaseMode::Ascii);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"WARN\tall caps".as_bstr()
+        );
+    }
+
+    #[test]
+    fn leading_whitespace() {
+        let iter = UpcaseFirstWord::with_slice(b"  todo: fix me", UppercaseMode::Full);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"  TODO: fix me".as_bstr()
+        );
+
+        let iter = UpcaseFirstWord::with_slice("\u{3000}todo fix".as_bytes(), UppercaseMode::Full);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "\u{3000}TODO fix".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn no_whitespace() {
+        let iter = UpcaseFirstWord::with_slice(b"label", UppercaseMode::Full);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"LABEL".as_bstr());
+    }
+
+    #[test]
+    fn multi_byte_first_word() {
+        let iter = UpcaseFirstWord::with_slice("straße und weg".as_bytes(), UppercaseMode::Full);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "STRASSE und weg".as_bytes().as_bstr()
+        );
+
+        let iter = UpcaseFirstWord::with_slice("αύριο\u{A0}αύριο".as_bytes(), UppercaseMode::Full);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "ΑΎΡΙΟ\u{A0}αύριο".as_bytes().as_bstr()
+        );
+
+        let iter = UpcaseFirstWord::with_slice("straße und weg".as_bytes(), UppercaseMode::Ascii);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "STRAßE und weg".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let iter = UpcaseFirstWord::with_slice(b"ab\xFFcd ef\xFE", UppercaseMode::Full);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"AB\xFFCD ef\xFE".as_bstr()
+        );
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        for s in ["", "label", "  todo: fix me", "straße und weg", "ΐ ΐ"] {
+            let iter = UpcaseFirstWord::with_slice(s.as_bytes(), UppercaseMode::Full);
+            let (min, max) = iter.size_hint();
+            let count = iter.count();
+            assert!(min <= count);
+            assert!(count <= max.unwrap());
+        }
+    }
+
+    #[test]
+    fn turkic_mode() {
+        let iter = UpcaseFirstWord::with_slice(b"istanbul is big", UppercaseMode::Turkic);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "İSTANBUL is big".as_bytes().as_bstr()
+        );
+    }
+}
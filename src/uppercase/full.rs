@@ -1,26 +1,42 @@
-use core::char::ToUppercase;
 use core::fmt;
 use core::iter::FusedIterator;
-use core::ops::Range;
 
 use bstr::ByteSlice;
 
+use crate::buffer::OutputBuffer;
+use crate::unicode::{case_map_char, CaseMapChar, CaseOp};
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, CaseMappingMode, UppercaseMode};
+
+// Mapped output is buffered separately for each end of the iterator. Bytes
+// which are not yet yielded are laid out, in order, as:
+//
+// 1. `next_bytes`, the rest of the front char.
+// 2. The chars remaining in `uppercase`.
+// 3. The uppercase mapping of `slice`.
+// 4. The chars remaining in `uppercase_back`.
+// 5. `next_back_bytes`, the rest of the back char.
+//
+// Once `slice` is exhausted, each end drains the buffers of the other end.
 #[derive(Clone)]
 #[must_use = "Uppercase is a Iterator and must be used"]
 pub struct Uppercase<'a> {
+    source: &'a [u8],
     slice: &'a [u8],
-    next_bytes: [u8; 4],
-    next_range: Range<usize>,
-    uppercase: Option<ToUppercase>,
+    next_bytes: OutputBuffer,
+    uppercase: Option<CaseMapChar>,
+    next_back_bytes: OutputBuffer,
+    uppercase_back: Option<CaseMapChar>,
 }
 
-impl<'a> fmt::Debug for Uppercase<'a> {
+impl fmt::Debug for Uppercase<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Uppercase")
             .field("slice", &self.slice.as_bstr())
             .field("next_bytes", &self.next_bytes)
-            .field("next_range", &self.next_range)
             .field("uppercase", &self.uppercase)
+            .field("next_back_bytes", &self.next_back_bytes)
+            .field("uppercase_back", &self.uppercase_back)
             .finish()
     }
 }
@@ -34,91 +50,220 @@ impl<'a> From<&'a [u8]> for Uppercase<'a> {
 impl<'a> Uppercase<'a> {
     pub const fn with_slice(slice: &'a [u8]) -> Self {
         Self {
+            source: slice,
             slice,
-            next_bytes: [0; 4],
-            next_range: 0..0,
+            next_bytes: OutputBuffer::new(),
             uppercase: None,
+            next_back_bytes: OutputBuffer::new(),
+            uppercase_back: None,
         }
     }
+
+    fn pending_chars(&self) -> usize {
+        let front = self.uppercase.as_ref().map_or(0, ExactSizeIterator::len);
+        let back = self
+            .uppercase_back
+            .as_ref()
+            .map_or(0, ExactSizeIterator::len);
+        front + back
+    }
+
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::with_slice(self.source);
+    }
 }
 
-impl<'a> Iterator for Uppercase<'a> {
+impl Iterator for Uppercase<'_> {
     type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(idx) = self.next_range.next() {
-            debug_assert!(self.next_bytes.get(idx).is_some());
-
-            return Some(self.next_bytes[idx]);
+        if let Some(byte) = self.next_bytes.next_byte() {
+            return Some(byte);
         }
 
         if let Some(ch) = self.uppercase.as_mut().and_then(Iterator::next) {
-            let enc = ch.encode_utf8(&mut self.next_bytes);
-
-            self.next_range = 1..enc.len();
-            debug_assert!(self.next_bytes.get(self.next_range.clone()).is_some());
-
-            return Some(self.next_bytes[0]);
+            self.next_bytes.push_char(ch);
+            return self.next_bytes.next_byte();
         }
 
         self.uppercase = None;
 
+        // ASCII bytes are mapped directly, without decoding and re-encoding a
+        // UTF-8 scalar. Only non-ASCII bytes engage the Unicode case mapping.
+        if let Some((&byte, remainder)) = self.slice.split_first() {
+            if byte.is_ascii() {
+                self.slice = remainder;
+                return Some(byte.to_ascii_uppercase());
+            }
+        }
+
         match bstr::decode_utf8(self.slice) {
-            (_, 0) => None,
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // reverse iteration.
+                if let Some(ch) = self.uppercase_back.as_mut().and_then(Iterator::next) {
+                    self.next_bytes.push_char(ch);
+                    return self.next_bytes.next_byte();
+                }
+                self.next_back_bytes.next_byte()
+            }
             (Some(ch), size) => {
                 self.slice = &self.slice[size..];
-                let mut uppercase = ch.to_uppercase();
+                let mut uppercase = case_map_char(ch, CaseOp::Upper, CaseMappingMode::Full)
+                    .expect("Full mode supports uppercasing");
                 let ch = uppercase
                     .next()
-                    .expect("ToUppercase yields at least one char");
-                let enc = ch.encode_utf8(&mut self.next_bytes);
-
-                self.next_range = 1..enc.len();
-                debug_assert!(self.next_bytes.get(self.next_range.clone()).is_some());
+                    .expect("CaseMapChar yields at least one char");
+                self.next_bytes.push_char(ch);
 
                 self.uppercase = Some(uppercase);
-                Some(self.next_bytes[0])
+                self.next_bytes.next_byte()
             }
             (None, size) => {
                 let (bytes, remainder) = self.slice.split_at(size);
                 self.slice = remainder;
 
-                // Invalid byte sequences are at most three bytes.
-                debug_assert!(self.next_bytes.get(..bytes.len()).is_some());
-
-                self.next_bytes[..bytes.len()].copy_from_slice(bytes);
-                self.next_range = 1..bytes.len();
-                Some(self.next_bytes[0])
+                self.next_bytes.push_bytes(bytes);
+                self.next_bytes.next_byte()
             }
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        const TO_UPPER_EXPAND: usize = 3;
         const UTF_8_CHAR_MAX_BYTES: usize = 4;
-        if self.slice.is_empty() {
-            (0, Some(0))
-        } else if self.slice.is_ascii() {
+        const TO_UPPER_RATIO: (usize, usize) =
+            max_expansion_ratio(CaseMapping::Uppercase(UppercaseMode::Full));
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        let pending_chars = self.pending_chars();
+        let pending_min = pending_bytes + pending_chars;
+        let pending_max = pending_bytes + pending_chars * UTF_8_CHAR_MAX_BYTES;
+        if self.slice.is_ascii() {
             let len = self.slice.len();
-            (len, Some(len))
+            (len + pending_min, Some(len + pending_max))
         } else {
-            let len = self.slice.len();
-            (len, Some(len * TO_UPPER_EXPAND * UTF_8_CHAR_MAX_BYTES))
+            // Every char and invalid UTF-8 byte sequence in the remaining
+            // slice yields at least one byte, and no mapping expands by more
+            // than the maximum expansion ratio.
+            let (num, den) = TO_UPPER_RATIO;
+            let max = self.slice.len().checked_mul(num).map(|max| max / den);
+            (
+                lead_byte_count(self.slice) + pending_min,
+                max.and_then(|max| max.checked_add(pending_max)),
+            )
         }
     }
 
     fn count(self) -> usize {
-        if self.slice.is_empty() {
-            0
-        } else if self.slice.is_ascii() {
-            self.slice.len()
-        } else {
-            self.fold(0, |acc, _| acc + 1)
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        if self.slice.is_ascii() && self.pending_chars() == 0 {
+            return pending_bytes + self.slice.len();
         }
+        // Counting only needs the encoded length of each mapped char, so the
+        // mapped chars are never encoded.
+        let pending = self
+            .uppercase
+            .into_iter()
+            .flatten()
+            .chain(self.uppercase_back.into_iter().flatten())
+            .map(char::len_utf8)
+            .sum::<usize>();
+        let mapped = self
+            .slice
+            .utf8_chunks()
+            .map(|chunk| {
+                let valid = chunk
+                    .valid()
+                    .chars()
+                    .map(|ch| {
+                        if ch.is_ascii() {
+                            1
+                        } else {
+                            ch.to_uppercase().map(char::len_utf8).sum()
+                        }
+                    })
+                    .sum::<usize>();
+                // Invalid UTF-8 byte sequences are yielded as is.
+                valid + chunk.invalid().len()
+            })
+            .sum::<usize>();
+        pending_bytes + pending + mapped
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        // The last byte is the last byte of the final scalar's mapping, which
+        // reverse iteration maps without visiting the rest of the slice.
+        self.next_back()
     }
 }
 
-impl<'a> FusedIterator for Uppercase<'a> {}
+impl DoubleEndedIterator for Uppercase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_back_bytes.next_back_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self
+            .uppercase_back
+            .as_mut()
+            .and_then(DoubleEndedIterator::next_back)
+        {
+            self.next_back_bytes.push_char(ch);
+            return self.next_back_bytes.next_back_byte();
+        }
+
+        self.uppercase_back = None;
+
+        if let Some((&byte, remainder)) = self.slice.split_last() {
+            if byte.is_ascii() {
+                self.slice = remainder;
+                return Some(byte.to_ascii_uppercase());
+            }
+        }
+
+        match bstr::decode_last_utf8(self.slice) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // forward iteration.
+                if let Some(ch) = self
+                    .uppercase
+                    .as_mut()
+                    .and_then(DoubleEndedIterator::next_back)
+                {
+                    self.next_back_bytes.push_char(ch);
+                    return self.next_back_bytes.next_back_byte();
+                }
+                self.next_bytes.next_back_byte()
+            }
+            (Some(ch), size) => {
+                self.slice = &self.slice[..self.slice.len() - size];
+                // Multi-char mappings are yielded back to front, starting with
+                // the last char of the mapping.
+                let mut uppercase = case_map_char(ch, CaseOp::Upper, CaseMappingMode::Full)
+                    .expect("Full mode supports uppercasing");
+                let ch = uppercase
+                    .next_back()
+                    .expect("CaseMapChar yields at least one char");
+                self.next_back_bytes.push_char(ch);
+
+                self.uppercase_back = Some(uppercase);
+                self.next_back_bytes.next_back_byte()
+            }
+            (None, size) => {
+                let (remainder, bytes) = self.slice.split_at(self.slice.len() - size);
+                self.slice = remainder;
+
+                self.next_back_bytes.push_bytes(bytes);
+                self.next_back_bytes.next_back_byte()
+            }
+        }
+    }
+}
+
+impl FusedIterator for Uppercase<'_> {}
 
 #[cfg(test)]
 mod tests {
@@ -255,6 +400,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn four_byte_scalar_to_four_byte_scalar() {
+        // Every byte of the mapped scalar is yielded, in order, one `next` call
+        // at a time, and the iterator is exhausted after the fourth byte.
+        //
+        // DESERET SMALL LETTER LONG I uppercases to U+10400.
+        let mut iter = Uppercase::from("\u{10428}".as_bytes());
+        assert_eq!(iter.next(), Some(0xF0));
+        assert_eq!(iter.next(), Some(0x90));
+        assert_eq!(iter.next(), Some(0x90));
+        assert_eq!(iter.next(), Some(0x80));
+        assert_eq!(iter.next(), None);
+
+        // Reverse iteration yields the same bytes back to front.
+        let mut iter = Uppercase::from("\u{10428}".as_bytes());
+        assert_eq!(iter.next_back(), Some(0x80));
+        assert_eq!(iter.next_back(), Some(0x90));
+        assert_eq!(iter.next_back(), Some(0x90));
+        assert_eq!(iter.next_back(), Some(0xF0));
+        assert_eq!(iter.next_back(), None);
+
+        // OSAGE SMALL LETTER A uppercases to U+104B0, which differs from
+        // it in the last two bytes.
+        let mut iter = Uppercase::from("\u{104D8}".as_bytes());
+        assert_eq!(iter.next(), Some(0xF0));
+        assert_eq!(iter.next(), Some(0x90));
+        assert_eq!(iter.next(), Some(0x92));
+        assert_eq!(iter.next(), Some(0xB0));
+        assert_eq!(iter.next(), None);
+
+        // ADLAM SMALL LETTER ALIF uppercases to U+1E900.
+        let mut iter = Uppercase::from("\u{1E922}".as_bytes());
+        assert_eq!(iter.next(), Some(0xF0));
+        assert_eq!(iter.next(), Some(0x9E));
+        assert_eq!(iter.next(), Some(0xA4));
+        assert_eq!(iter.next(), Some(0x80));
+        assert_eq!(iter.next(), None);
+
+        // Consecutive four byte mappings do not bleed into each other.
+        let mut iter = Uppercase::from("a\u{10428}\u{10428}z".as_bytes());
+        assert_eq!(iter.next(), Some(b'A'));
+        for _ in 0..2 {
+            assert_eq!(iter.next(), Some(0xF0));
+            assert_eq!(iter.next(), Some(0x90));
+            assert_eq!(iter.next(), Some(0x90));
+            assert_eq!(iter.next(), Some(0x80));
+        }
+        assert_eq!(iter.next(), Some(b'Z'));
+        assert_eq!(iter.next(), None);
+    }
+
     #[test]
     fn case_map_to_two_chars() {
         let s = "և".as_bytes();
@@ -313,29 +509,57 @@ mod tests {
         assert_eq!(Uppercase::with_slice(b"abc, xyz").size_hint(), (8, Some(8)));
         assert_eq!(
             Uppercase::with_slice(b"abc, \xFF\xFE, xyz").size_hint(),
-            (12, Some(144))
+            (12, Some(36))
         );
         assert_eq!(
             Uppercase::with_slice("�".as_bytes()).size_hint(),
-            (3, Some(36))
+            (1, Some(9))
         );
         assert_eq!(
             Uppercase::with_slice("Έτος".as_bytes()).size_hint(),
-            (8, Some(96))
+            (4, Some(24))
         );
         assert_eq!(
             Uppercase::with_slice("ZȺȾ".as_bytes()).size_hint(),
-            (5, Some(60))
+            (3, Some(15))
         );
 
         let mut utf8_with_invalid_bytes = b"\xFF\xFE".to_vec();
         utf8_with_invalid_bytes.extend_from_slice("Έτος".as_bytes());
         assert_eq!(
             Uppercase::with_slice(&utf8_with_invalid_bytes).size_hint(),
-            (10, Some(120))
+            (6, Some(30))
         );
     }
 
+    #[test]
+    fn count_matches_collected_len() {
+        let inputs: [&[u8]; 7] = [
+            "ZȺȾ".as_bytes(),
+            "zⱥⱦ".as_bytes(),
+            b"abc, \xFF\xFE, xyz",
+            b"\xFF\xFE\xCE",
+            b"aB\xF0\x9F\x87Yz",
+            // `ß`, an invalid byte, `ﬃ`, and a truncated 4-byte sequence.
+            b"\xC3\x9F\xFF\xEF\xAC\x83\xF0\x9F\x87",
+            "\u{FFFD}ⱥ\u{1F600}".as_bytes(),
+        ];
+        let mut mixed = b"\xFF\xFE".to_vec();
+        mixed.extend_from_slice("ZȺȾ".as_bytes());
+        mixed.extend_from_slice(b"\xE2\x84");
+        for input in inputs.iter().copied().chain([&mixed[..]]) {
+            let iter = Uppercase::with_slice(input);
+            assert_eq!(iter.clone().count(), iter.collect::<Vec<_>>().len());
+
+            // Partially consume both ends so the count includes buffered
+            // bytes and chars.
+            let mut iter = Uppercase::with_slice(input);
+            iter.next();
+            iter.next_back();
+            assert_eq!(iter.clone().count(), iter.collect::<Vec<_>>().len());
+        }
+    }
+
     #[test]
     fn count() {
         assert_eq!(Uppercase::with_slice(b"").count(), 0);
@@ -397,13 +621,220 @@ mod tests {
         assert!(count <= max.unwrap());
     }
 
+    #[test]
+    fn rev() {
+        let inputs: [&[u8]; 7] = [
+            b"",
+            b"abc, xyz",
+            "ßﬃ".as_bytes(),
+            "Αύριο Έτος".as_bytes(),
+            "zⱥⱦ ǅ ὖ ῷ".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF\xFE",
+            "\u{1F600} ẞ İ ΐ".as_bytes(),
+        ];
+        for input in inputs {
+            let mut rev = Uppercase::from(input).rev().collect::<Vec<_>>();
+            rev.reverse();
+            let forward = Uppercase::from(input).collect::<Vec<_>>();
+            assert_eq!(rev.as_bstr(), forward.as_bstr());
+        }
+    }
+
+    #[test]
+    fn next_and_next_back_interleaved() {
+        // `ß` uppercases to `SS` and `ﬃ` uppercases to `FFI`.
+        let mut iter = Uppercase::from("ßﬃ".as_bytes());
+        assert_eq!(iter.next(), Some(b'S'));
+        assert_eq!(iter.next_back(), Some(b'I'));
+        assert_eq!(iter.next(), Some(b'S'));
+        assert_eq!(iter.next_back(), Some(b'F'));
+        assert_eq!(iter.next(), Some(b'F'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        // Consuming one mapping from both ends.
+        let mut iter = Uppercase::from("ﬃ".as_bytes());
+        assert_eq!(iter.next_back(), Some(b'I'));
+        assert_eq!(iter.next(), Some(b'F'));
+        assert_eq!(iter.next_back(), Some(b'F'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = Uppercase::from("ßﬃ".as_bytes());
+        assert_eq!(iter.next_back(), Some(b'I'));
+        assert_eq!(iter.next_back(), Some(b'F'));
+        assert_eq!(iter.next_back(), Some(b'F'));
+        assert_eq!(iter.next(), Some(b'S'));
+        assert_eq!(iter.next_back(), Some(b'S'));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn next_and_next_back_interleaved_multi_byte() {
+        // Every split of interleaved `next` and `next_back` calls yields the
+        // same bytes as forward iteration.
+        let inputs: [&[u8]; 4] = [
+            "ßﬃ".as_bytes(),
+            "ὖῷ".as_bytes(),
+            "Αύριο".as_bytes(),
+            b"a\xF0\x9F\x87\xFFz",
+        ];
+        for input in inputs {
+            let expected = Uppercase::from(input).collect::<Vec<_>>();
+            for pattern in 0_u32..(1 << expected.len()) {
+                let mut iter = Uppercase::from(input);
+                let mut front = Vec::new();
+                let mut back = Vec::new();
+                for bit in 0..expected.len() {
+                    if pattern & (1 << bit) == 0 {
+                        front.push(iter.next().unwrap());
+                    } else {
+                        back.push(iter.next_back().unwrap());
+                    }
+                    let (min, max) = iter.size_hint();
+                    let count = iter.clone().count();
+                    assert!(min <= count);
+                    assert!(count <= max.unwrap());
+                }
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+                back.reverse();
+                front.extend(back);
+                assert_eq!(front.as_bstr(), expected.as_bstr());
+            }
+        }
+    }
+
     #[test]
     fn test_fmt() {
         let s = "Αύριο".as_bytes();
         let iter = Uppercase::from(s);
         assert_eq!(
             format!("{iter:?}"),
-            "Uppercase { slice: \"Αύριο\", next_bytes: [0, 0, 0, 0], next_range: 0..0, uppercase: None }"
+            "Uppercase { slice: \"Αύριο\", next_bytes: [], uppercase: None, next_back_bytes: [], uppercase_back: None }"
         );
     }
+
+    fn per_scalar_uppercase(slice: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut buf = [0; 4];
+        let mut slice = slice;
+        while !slice.is_empty() {
+            let (ch, size) = bstr::decode_utf8(slice);
+            match ch {
+                Some(ch) => {
+                    for ch in ch.to_uppercase() {
+                        out.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+                    }
+                }
+                None => out.extend_from_slice(&slice[..size]),
+            }
+            slice = &slice[size..];
+        }
+        out
+    }
+
+    #[test]
+    fn mostly_ascii_matches_per_scalar_mapping() {
+        let mut input = Vec::new();
+        for i in 0..512 {
+            input.extend_from_slice(b"The Quick Brown Fox Jumps Over The Lazy Dog. ");
+            match i % 4 {
+                0 => input.extend_from_slice("École ΑΎΡΙΟ ß İ ".as_bytes()),
+                1 => input.extend_from_slice(b"\xFF\xF0\x9F\x87 "),
+                _ => {}
+            }
+        }
+        let expected = per_scalar_uppercase(&input);
+
+        let forward = Uppercase::from(input.as_slice()).collect::<Vec<_>>();
+        assert_eq!(forward.as_bstr(), expected.as_bstr());
+
+        let mut rev = Uppercase::from(input.as_slice()).rev().collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev.as_bstr(), expected.as_bstr());
+
+        assert_eq!(Uppercase::from(input.as_slice()).count(), expected.len());
+    }
+
+    #[test]
+    fn ascii_run_then_non_ascii() {
+        let inputs: [&[u8]; 4] = [
+            "abcXYZ ǅ".as_bytes(),
+            "ǅ abcXYZ".as_bytes(),
+            b"abc\xFFXYZ",
+            "ßaBcß".as_bytes(),
+        ];
+        for input in inputs {
+            let expected = per_scalar_uppercase(input);
+            let iter = Uppercase::from(input);
+            assert_eq!(iter.collect::<Vec<_>>().as_bstr(), expected.as_bstr());
+        }
+    }
+
+    #[test]
+    fn fused_after_invalid_utf8_tail() {
+        let inputs: [&[u8]; 5] = [
+            b"abc\xFF",
+            b"ABC\xFF",
+            "ßﬃ".as_bytes(),
+            b"\xC3\x9F\xF0\x9F\x87",
+            b"\xFF\xFE",
+        ];
+        for input in inputs {
+            let mut iter = Uppercase::from(input);
+            while iter.next().is_some() {}
+            for _ in 0..4 {
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+                assert_eq!(iter.size_hint(), (0, Some(0)));
+            }
+
+            // Partially buffered output from reverse iteration is drained
+            // once, not resurrected after exhaustion.
+            let mut iter = Uppercase::from(input);
+            let back = iter.next_back();
+            assert!(back.is_some());
+            let forward = iter.by_ref().collect::<Vec<_>>();
+            assert_eq!(forward.len() + 1, Uppercase::from(input).count());
+            for _ in 0..4 {
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn last_matches_collected_last() {
+        let inputs: [&[u8]; 8] = [
+            b"",
+            b"abc XYZ",
+            "ß".as_bytes(),
+            "aﬃ".as_bytes(),
+            "ΐ".as_bytes(),
+            b"abc\xFF",
+            b"abc\xF0\x9F\x87",
+            b"\xFF\xFE",
+        ];
+        for input in inputs {
+            let expected = Uppercase::from(input).collect::<Vec<_>>();
+            assert_eq!(Uppercase::from(input).last(), expected.last().copied());
+
+            // Partially consumed iterators, including those in the middle of
+            // an expansion, yield the same last byte.
+            for skip in 0..expected.len() {
+                let mut iter = Uppercase::from(input);
+                for _ in 0..skip {
+                    iter.next();
+                }
+                assert_eq!(iter.last(), expected.last().copied());
+            }
+
+            let mut iter = Uppercase::from(input);
+            if iter.next_back().is_some() {
+                let len = expected.len();
+                assert_eq!(iter.last(), expected[..len - 1].last().copied());
+            }
+        }
+    }
 }
@@ -0,0 +1,198 @@
+use alloc::vec::Vec;
+
+use bstr::ByteSlice;
+
+use crate::unicode::lithuanian::is_removed_by_lithuanian_uppercase;
+use crate::UppercaseMode;
+
+/// Returns a copy of the given slice with all lowercase letters replaced with
+/// their uppercase counterparts along with whether the uppercased bytes form a
+/// valid identifier.
+///
+/// An identifier is a non-empty UTF-8 string whose first character is either
+/// `_` or has the Unicode [`XID_Start`] property and whose remaining characters
+/// have the Unicode [`XID_Continue`] property. This matches the grammar for
+/// non-keyword identifiers in Rust and is a conservative subset of the
+/// identifiers accepted by Ruby.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string].
+/// Invalid UTF-8 byte sequences are copied to the output as is and cause the
+/// output to be reported as an invalid identifier.
+///
+/// The identifier check is performed on the uppercased output, not the input,
+/// and happens in the same pass as the case mapping.
+///
+/// The case mapping mode is determined by the given [`UppercaseMode`]. See
+/// [`uppercase`] for details on how the input slice is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{uppercase_checked_ident, UppercaseMode};
+/// let (uppercase, is_ident) = uppercase_checked_ident(b"max_depth", UppercaseMode::Full);
+/// assert_eq!(uppercase, b"MAX_DEPTH");
+/// assert!(is_ident);
+///
+/// let (uppercase, is_ident) = uppercase_checked_ident(b"content-type", UppercaseMode::Full);
+/// assert_eq!(uppercase, b"CONTENT-TYPE");
+/// assert!(!is_ident);
+///
+/// let (uppercase, is_ident) = uppercase_checked_ident(b"2nd", UppercaseMode::Full);
+/// assert_eq!(uppercase, b"2ND");
+/// assert!(!is_ident);
+/// ```
+///
+/// [`XID_Start`]: https://www.unicode.org/reports/tr31/#Default_Identifier_Syntax
+/// [`XID_Continue`]: https://www.unicode.org/reports/tr31/#Default_Identifier_Syntax
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [`uppercase`]: crate::uppercase()
+#[must_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "properties")))]
+pub fn uppercase_checked_ident(slice: &[u8], options: UppercaseMode) -> (Vec<u8>, bool) {
+    let mut uppercase = Vec::with_capacity(slice.len());
+    let mut is_ident = !slice.is_empty();
+
+    let mut remaining = slice;
+    while !remaining.is_empty() {
+        let preceding = &slice[..slice.len() - remaining.len()];
+        let (scalar, remainder) = match bstr::decode_utf8(remaining) {
+            // Mapped alone, an explicit dot would lose the soft dotted letter
+            // it follows.
+            (Some(ch), size)
+                if options == UppercaseMode::Lithuanian
+                    && is_removed_by_lithuanian_uppercase(ch, preceding) =>
+            {
+                remaining = &remaining[size..];
+                continue;
+            }
+            (Some(_), size) => remaining.split_at(size),
+            (None, size) => {
+                is_ident = false;
+                remaining.split_at(size)
+            }
+        };
+        let start = uppercase.len();
+        uppercase.extend(crate::uppercase(scalar, options));
+        if is_ident {
+            let is_first = start == 0;
+            is_ident = uppercase[start..]
+                .chars()
+                .enumerate()
+                .all(|(idx, ch)| is_ident_char(ch, is_first && idx == 0));
+        }
+        remaining = remainder;
+    }
+
+    (uppercase, is_ident)
+}
+
+fn is_ident_char(ch: char, is_first: bool) -> bool {
+    if is_first {
+        ch == '_' || unicode_ident::is_xid_start(ch)
+    } else {
+        unicode_ident::is_xid_continue(ch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::uppercase_checked_ident;
+    use crate::UppercaseMode;
+
+    #[test]
+    fn empty() {
+        let (uppercase, is_ident) = uppercase_checked_ident(b"", UppercaseMode::Full);
+        assert!(uppercase.is_empty());
+        assert!(!is_ident);
+    }
+
+    #[test]
+    fn ascii_identifiers() {
+        for s in [&b"abc"[..], b"_private", b"snake_case_123", b"x", b"_"] {
+            let (uppercase, is_ident) = uppercase_checked_ident(s, UppercaseMode::Full);
+            assert_eq!(uppercase.as_bstr(), s.to_ascii_uppercase().as_bstr());
+            assert!(is_ident, "{:?} should be an identifier", s.as_bstr());
+        }
+    }
+
+    #[test]
+    fn ascii_non_identifiers() {
+        for s in [
+            &b"1abc"[..],
+            b"kebab-case",
+            b"with space",
+            b"dot.ted",
+            b"a$",
+        ] {
+            let (uppercase, is_ident) = uppercase_checked_ident(s, UppercaseMode::Full);
+            assert_eq!(uppercase.as_bstr(), s.to_ascii_uppercase().as_bstr());
+            assert!(!is_ident, "{:?} should not be an identifier", s.as_bstr());
+        }
+    }
+
+    #[test]
+    fn unicode_identifiers() {
+        let (uppercase, is_ident) =
+            uppercase_checked_ident("αύριο".as_bytes(), UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), "ΑΎΡΙΟ".as_bytes().as_bstr());
+        assert!(is_ident);
+
+        let (uppercase, is_ident) =
+            uppercase_checked_ident("straße".as_bytes(), UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), b"STRASSE".as_bstr());
+        assert!(is_ident);
+
+        let (uppercase, is_ident) = uppercase_checked_ident("😀".as_bytes(), UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), "😀".as_bytes().as_bstr());
+        assert!(!is_ident);
+    }
+
+    #[test]
+    fn check_is_performed_on_output() {
+        // U+0345 COMBINING GREEK YPOGEGRAMMENI is XID_Continue but not
+        // XID_Start. It uppercases to U+0399 GREEK CAPITAL LETTER IOTA, which
+        // is XID_Start.
+        let (uppercase, is_ident) =
+            uppercase_checked_ident("\u{345}".as_bytes(), UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), "\u{399}".as_bytes().as_bstr());
+        assert!(is_ident);
+
+        // ASCII mode leaves the combining mark as is.
+        let (uppercase, is_ident) =
+            uppercase_checked_ident("\u{345}".as_bytes(), UppercaseMode::Ascii);
+        assert_eq!(uppercase.as_bstr(), "\u{345}".as_bytes().as_bstr());
+        assert!(!is_ident);
+    }
+
+    #[test]
+    fn lithuanian_removes_explicit_dots() {
+        for (s, expected) in [
+            ("i\u{307}", "I"),
+            ("ij\u{307}", "IJ"),
+            ("i\u{323}\u{307}", "I\u{323}"),
+            ("a\u{307}", "A\u{307}"),
+        ] {
+            let (uppercase, is_ident) =
+                uppercase_checked_ident(s.as_bytes(), UppercaseMode::Lithuanian);
+            assert_eq!(uppercase.as_bstr(), expected.as_bytes().as_bstr());
+            let full = crate::uppercase(s.as_bytes(), UppercaseMode::Lithuanian);
+            assert_eq!(uppercase.as_bstr(), full.collect::<Vec<_>>().as_bstr());
+            assert!(is_ident);
+        }
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let (uppercase, is_ident) = uppercase_checked_ident(b"abc\xFF", UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), b"ABC\xFF".as_bstr());
+        assert!(!is_ident);
+
+        let (uppercase, is_ident) =
+            uppercase_checked_ident(b"\xF0\x9F\x87abc", UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), b"\xF0\x9F\x87ABC".as_bstr());
+        assert!(!is_ident);
+    }
+}
@@ -0,0 +1,428 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use crate::buffer::OutputBuffer;
+use crate::unicode::lithuanian::is_removed_by_lithuanian_uppercase;
+use crate::unicode::{to_uppercase_char, ToUppercase};
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, UppercaseMode};
+
+/// The UTF-8 encoding of U+0307 COMBINING DOT ABOVE.
+const COMBINING_DOT_ABOVE: &[u8] = "\u{307}".as_bytes();
+
+// Mapped output is buffered separately for each end of the iterator. Bytes
+// which are not yet yielded are laid out, in order, as:
+//
+// 1. `next_bytes`, the rest of the front char.
+// 2. The chars remaining in `uppercase`.
+// 3. The uppercase mapping of `source[start..end]`.
+// 4. The chars remaining in `uppercase_back`.
+// 5. `next_back_bytes`, the rest of the back char.
+//
+// The rest of `source` is kept around as context for the `After_Soft_Dotted`
+// rule. Once the slice is exhausted, each end drains the buffers of the other
+// end.
+#[derive(Clone)]
+#[must_use = "Uppercase is a Iterator and must be used"]
+pub struct Uppercase<'a> {
+    source: &'a [u8],
+    start: usize,
+    end: usize,
+    next_bytes: OutputBuffer,
+    uppercase: Option<ToUppercase>,
+    next_back_bytes: OutputBuffer,
+    uppercase_back: Option<ToUppercase>,
+}
+
+impl fmt::Debug for Uppercase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Uppercase")
+            .field("slice", &self.as_slice().as_bstr())
+            .field("next_bytes", &self.next_bytes)
+            .field("uppercase", &self.uppercase)
+            .field("next_back_bytes", &self.next_back_bytes)
+            .field("uppercase_back", &self.uppercase_back)
+            .finish()
+    }
+}
+
+impl<'a> From<&'a [u8]> for Uppercase<'a> {
+    fn from(slice: &'a [u8]) -> Self {
+        Self::with_slice(slice)
+    }
+}
+
+impl<'a> Uppercase<'a> {
+    pub const fn with_slice(slice: &'a [u8]) -> Self {
+        Self {
+            source: slice,
+            start: 0,
+            end: slice.len(),
+            next_bytes: OutputBuffer::new(),
+            uppercase: None,
+            next_back_bytes: OutputBuffer::new(),
+            uppercase_back: None,
+        }
+    }
+
+    fn pending_chars(&self) -> usize {
+        let front = self.uppercase.as_ref().map_or(0, ExactSizeIterator::len);
+        let back = self
+            .uppercase_back
+            .as_ref()
+            .map_or(0, ExactSizeIterator::len);
+        front + back
+    }
+
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.source.split_at(self.end).0.split_at(self.start).1
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::with_slice(self.source);
+    }
+}
+
+impl Iterator for Uppercase<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_bytes.next_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self.uppercase.as_mut().and_then(Iterator::next) {
+            self.next_bytes.push_char(ch);
+            return self.next_bytes.next_byte();
+        }
+
+        self.uppercase = None;
+
+        loop {
+            let slice = self.as_slice();
+            if let Some(&byte) = slice.first() {
+                if byte.is_ascii() {
+                    self.start += 1;
+                    return Some(byte.to_ascii_uppercase());
+                }
+            }
+
+            match bstr::decode_utf8(slice) {
+                (_, 0) => {
+                    // The slice is exhausted, so drain the output buffered by
+                    // reverse iteration.
+                    if let Some(ch) = self.uppercase_back.as_mut().and_then(Iterator::next) {
+                        self.next_bytes.push_char(ch);
+                        return self.next_bytes.next_byte();
+                    }
+                    return self.next_back_bytes.next_byte();
+                }
+                (Some(ch), size) => {
+                    let preceding = &self.source[..self.start];
+                    self.start += size;
+                    if is_removed_by_lithuanian_uppercase(ch, preceding) {
+                        continue;
+                    }
+                    let mut uppercase = to_uppercase_char(ch);
+                    let ch = uppercase
+                        .next()
+                        .expect("ToUppercase yields at least one char");
+                    self.next_bytes.push_char(ch);
+
+                    self.uppercase = Some(uppercase);
+                    return self.next_bytes.next_byte();
+                }
+                (None, size) => {
+                    self.next_bytes.push_bytes(&slice[..size]);
+                    self.start += size;
+                    return self.next_bytes.next_byte();
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        const UTF_8_CHAR_MAX_BYTES: usize = 4;
+        const TO_UPPER_RATIO: (usize, usize) =
+            max_expansion_ratio(CaseMapping::Uppercase(UppercaseMode::Lithuanian));
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        let pending_chars = self.pending_chars();
+        let pending_min = pending_bytes + pending_chars;
+        let pending_max = pending_bytes + pending_chars * UTF_8_CHAR_MAX_BYTES;
+        let slice = self.as_slice();
+        if slice.is_ascii() {
+            let len = slice.len();
+            (len + pending_min, Some(len + pending_max))
+        } else {
+            // Every char and invalid UTF-8 byte sequence in the remaining
+            // slice yields at least one byte, except for explicit dots which
+            // may be removed, and no mapping expands by more than the maximum
+            // expansion ratio.
+            let (num, den) = TO_UPPER_RATIO;
+            let max = slice.len().checked_mul(num).map(|max| max / den);
+            let dots = slice.find_iter(COMBINING_DOT_ABOVE).count();
+            (
+                lead_byte_count(slice) - dots + pending_min,
+                max.and_then(|max| max.checked_add(pending_max)),
+            )
+        }
+    }
+
+    fn count(self) -> usize {
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        if self.as_slice().is_ascii() && self.pending_chars() == 0 {
+            return pending_bytes + self.as_slice().len();
+        }
+        self.fold(0, |acc, _| acc + 1)
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        // The last byte is the last byte of the final scalar's mapping, which
+        // reverse iteration maps without visiting the rest of the slice.
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Uppercase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_back_bytes.next_back_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self
+            .uppercase_back
+            .as_mut()
+            .and_then(DoubleEndedIterator::next_back)
+        {
+            self.next_back_bytes.push_char(ch);
+            return self.next_back_bytes.next_back_byte();
+        }
+
+        self.uppercase_back = None;
+
+        loop {
+            let slice = self.as_slice();
+            if let Some(&byte) = slice.last() {
+                if byte.is_ascii() {
+                    self.end -= 1;
+                    return Some(byte.to_ascii_uppercase());
+                }
+            }
+
+            match bstr::decode_last_utf8(slice) {
+                (_, 0) => {
+                    // The slice is exhausted, so drain the output buffered by
+                    // forward iteration.
+                    if let Some(ch) = self
+                        .uppercase
+                        .as_mut()
+                        .and_then(DoubleEndedIterator::next_back)
+                    {
+                        self.next_back_bytes.push_char(ch);
+                        return self.next_back_bytes.next_back_byte();
+                    }
+                    return self.next_bytes.next_back_byte();
+                }
+                (Some(ch), size) => {
+                    self.end -= size;
+                    let preceding = &self.source[..self.end];
+                    if is_removed_by_lithuanian_uppercase(ch, preceding) {
+                        continue;
+                    }
+                    // Multi-char mappings are yielded back to front, starting
+                    // with the last char of the mapping.
+                    let mut uppercase = to_uppercase_char(ch);
+                    let ch = uppercase
+                        .next_back()
+                        .expect("ToUppercase yields at least one char");
+                    self.next_back_bytes.push_char(ch);
+
+                    self.uppercase_back = Some(uppercase);
+                    return self.next_back_bytes.next_back_byte();
+                }
+                (None, size) => {
+                    self.next_back_bytes
+                        .push_bytes(&slice[slice.len() - size..]);
+                    self.end -= size;
+                    return self.next_back_bytes.next_back_byte();
+                }
+            }
+        }
+    }
+}
+
+impl FusedIterator for Uppercase<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::Uppercase;
+
+    fn uppercase(s: &str) -> Vec<u8> {
+        Uppercase::from(s.as_bytes()).collect()
+    }
+
+    fn uppercase_rev(s: &str) -> Vec<u8> {
+        let mut bytes = Uppercase::from(s.as_bytes()).rev().collect::<Vec<_>>();
+        bytes.reverse();
+        bytes
+    }
+
+    #[test]
+    fn empty() {
+        let iter = Uppercase::from(&b""[..]);
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+    }
+
+    // Lowercasing `Ì`, `Í`, and `Ĩ` with the Lithuanian rules in
+    // `SpecialCasing.txt` inserts an explicit dot between `i` and the accent.
+    // Uppercasing removes the explicit dot again:
+    //
+    //     0307; 0307; ; ; lt After_Soft_Dotted; # COMBINING DOT ABOVE
+    #[test]
+    fn special_casing_examples() {
+        let examples = [
+            ("i\u{307}\u{300}", "I\u{300}"),
+            ("i\u{307}\u{301}", "I\u{301}"),
+            ("i\u{307}\u{303}", "I\u{303}"),
+            ("j\u{307}\u{301}", "J\u{301}"),
+            ("į\u{307}\u{301}", "Į\u{301}"),
+            ("i\u{307}", "I"),
+        ];
+        for (lower, upper) in examples {
+            assert_eq!(uppercase(lower).as_bstr(), upper.as_bytes().as_bstr());
+            assert_eq!(uppercase_rev(lower).as_bstr(), upper.as_bytes().as_bstr());
+        }
+    }
+
+    #[test]
+    fn round_trips_lithuanian_lowercase() {
+        for s in ["Ì", "Í", "Ĩ", "I\u{300}", "J\u{301}", "Į\u{303}", "KLIŪTIS"] {
+            let lower = crate::lowercase(s.as_bytes(), crate::LowercaseMode::Lithuanian)
+                .collect::<Vec<_>>();
+            let upper = Uppercase::from(lower.as_slice()).collect::<Vec<_>>();
+            // The precomposed accented letters round trip to their canonical
+            // decompositions.
+            let expected = s
+                .replace('Ì', "I\u{300}")
+                .replace('Í', "I\u{301}")
+                .replace('Ĩ', "I\u{303}");
+            assert_eq!(upper.as_bstr(), expected.as_bytes().as_bstr());
+        }
+    }
+
+    #[test]
+    fn dot_is_kept_outside_of_context() {
+        // The dot does not follow a soft dotted letter.
+        assert_eq!(
+            uppercase("a\u{307}").as_bstr(),
+            "A\u{307}".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            uppercase("\u{307}").as_bstr(),
+            "\u{307}".as_bytes().as_bstr()
+        );
+        // An accent above between the letter and the dot ends the context.
+        assert_eq!(
+            uppercase("i\u{301}\u{307}").as_bstr(),
+            "I\u{301}\u{307}".as_bytes().as_bstr()
+        );
+        // Only the first explicit dot is removed.
+        assert_eq!(
+            uppercase("i\u{307}\u{307}").as_bstr(),
+            "I\u{307}".as_bytes().as_bstr()
+        );
+        assert_eq!(
+            uppercase_rev("i\u{307}\u{307}").as_bstr(),
+            "I\u{307}".as_bytes().as_bstr()
+        );
+        // Invalid UTF-8 ends the context.
+        let iter = Uppercase::from(&b"i\xFF\xCC\x87"[..]);
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"I\xFF\xCC\x87".as_bstr()
+        );
+    }
+
+    #[test]
+    fn dot_is_removed_after_marks_below() {
+        // COMBINING DOT BELOW (class 220) does not end the context.
+        assert_eq!(
+            uppercase("i\u{323}\u{307}\u{301}").as_bstr(),
+            "I\u{323}\u{301}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn other_characters_match_full_mode() {
+        let inputs: [&[u8]; 6] = [
+            b"abc, xyz",
+            "ß".as_bytes(),
+            "Αύριο".as_bytes(),
+            "ﬃ ὖ ῷ".as_bytes(),
+            "ǅ ǆ zⱥⱦ İ".as_bytes(),
+            b"ab\xF0\x9F\x87Yz\xFF\xFE",
+        ];
+        for input in inputs {
+            let lithuanian = Uppercase::from(input).collect::<Vec<_>>();
+            let full = crate::uppercase::full::Uppercase::from(input).collect::<Vec<_>>();
+            assert_eq!(lithuanian.as_bstr(), full.as_bstr());
+        }
+    }
+
+    #[test]
+    fn next_and_next_back_interleaved() {
+        let input = "ai\u{307}\u{301}ßj\u{307}z".as_bytes();
+        let expected = "AI\u{301}SSJZ".as_bytes();
+        for split in 0..=expected.len() {
+            let mut iter = Uppercase::from(input);
+            let mut front = Vec::new();
+            for _ in 0..split {
+                front.extend(iter.next());
+            }
+            let mut back = iter.rev().collect::<Vec<_>>();
+            back.reverse();
+            front.extend(back);
+            assert_eq!(front.as_bstr(), expected.as_bstr(), "split at {split}");
+        }
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        let inputs: [&[u8]; 6] = [
+            b"",
+            b"abc, xyz",
+            "i\u{307}\u{300}".as_bytes(),
+            "i\u{307}\u{307}\u{307}".as_bytes(),
+            "ﬃ ὖ ῷ".as_bytes(),
+            b"i\xFF\xCC\x87",
+        ];
+        for input in inputs {
+            let mut iter = Uppercase::with_slice(input);
+            loop {
+                let (min, max) = iter.size_hint();
+                let count = iter.clone().count();
+                assert!(min <= count);
+                assert!(count <= max.unwrap());
+                if iter.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn as_slice_and_reset() {
+        let mut iter = Uppercase::from("i\u{307}x".as_bytes());
+        assert_eq!(iter.next(), Some(b'I'));
+        assert_eq!(iter.as_slice().as_bstr(), "\u{307}x".as_bytes().as_bstr());
+        assert_eq!(iter.next(), Some(b'X'));
+        assert_eq!(iter.as_slice(), b"");
+        iter.reset();
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"IX".as_bstr());
+    }
+}
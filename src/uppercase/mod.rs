@@ -1,14 +1,57 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
 use core::iter::FusedIterator;
 
+#[cfg(feature = "alloc")]
+use crate::buffer::MappedBytes;
+use crate::UppercaseMode;
+
 mod ascii;
+mod chars;
+mod first_word;
 mod full;
+#[cfg(feature = "properties")]
+mod ident;
+mod lithuanian;
+#[cfg(feature = "alloc")]
+mod offsets;
+#[cfg(feature = "alloc")]
+mod owned;
+mod stream;
+mod tailored;
+
+pub use chars::{uppercase_chars, UppercaseChars};
+pub use first_word::UpcaseFirstWord;
+#[cfg(feature = "properties")]
+pub use ident::uppercase_checked_ident;
+#[cfg(feature = "alloc")]
+pub use offsets::uppercase_with_offsets;
+#[cfg(feature = "alloc")]
+pub use owned::{extend_uppercase, to_uppercase, to_uppercase_string};
+pub use stream::UppercaseStreamer;
 
 #[derive(Debug, Clone)]
 #[allow(variant_size_differences)]
 enum Inner<'a> {
     Empty,
     Full(full::Uppercase<'a>),
+    // Full Unicode case mapping over a slice which is entirely ASCII produces
+    // the same output as ASCII case mapping. Slices which are detected to be
+    // ASCII-only at construction time use the cheaper ASCII iterator.
+    FullAscii(ascii::Uppercase<'a>),
     Ascii(ascii::Uppercase<'a>),
+    // Turkic and capital sharp s case mapping tailor the mapping of a single
+    // char. Turkic case mapping never uses the ASCII iterator since `i`
+    // uppercases to the non-ASCII `İ`.
+    Tailored(tailored::Uppercase<'a>),
+    Lithuanian(lithuanian::Uppercase<'a>),
+    // Case folding is implemented by the lowercase iterator.
+    Fold(crate::Lowercase<'a>),
+    // A slice owned by the iterator is case mapped up front with the
+    // borrowing iterators above.
+    #[cfg(feature = "alloc")]
+    Owned(MappedBytes, UppercaseMode),
 }
 
 /// An iterator that yields the uppercase equivalent of a conventionally UTF-8
@@ -19,6 +62,20 @@ enum Inner<'a> {
 /// This struct is created by the [`uppercase`] function. See its documentation
 /// for more.
 ///
+/// # Reverse Iteration
+///
+/// `Uppercase` implements [`DoubleEndedIterator`] so iterators created with
+/// any case mapping mode can be reversed:
+///
+/// ```
+/// # use roe::Uppercase;
+/// let uppercase = Uppercase::with_ascii_slice(b"abc");
+/// assert_eq!(uppercase.rev().collect::<Vec<_>>(), b"CBA");
+///
+/// let uppercase = Uppercase::with_slice("ßﬃ".as_bytes());
+/// assert_eq!(uppercase.rev().collect::<Vec<_>>(), b"IFFSS");
+/// ```
+///
 /// [bytes]: u8
 /// [`uppercase`]: crate::uppercase()
 #[derive(Debug, Clone)]
@@ -37,6 +94,7 @@ impl<'a> Uppercase<'a> {
     /// let mut uppercase = Uppercase::new();
     /// assert_eq!(uppercase.next(), None);
     /// ```
+    #[allow(clippy::new_without_default)]
     pub const fn new() -> Self {
         Self { iter: Inner::Empty }
     }
@@ -80,8 +138,14 @@ impl<'a> Uppercase<'a> {
     /// assert_eq!(uppercase.collect::<Vec<_>>(), expected);
     /// ```
     pub const fn with_slice(slice: &'a [u8]) -> Self {
-        Self {
-            iter: Inner::Full(full::Uppercase::with_slice(slice)),
+        if slice.is_ascii() {
+            Self {
+                iter: Inner::FullAscii(ascii::Uppercase::with_slice(slice)),
+            }
+        } else {
+            Self {
+                iter: Inner::Full(full::Uppercase::with_slice(slice)),
+            }
         }
     }
 
@@ -122,24 +186,427 @@ impl<'a> Uppercase<'a> {
             iter: Inner::Ascii(ascii::Uppercase::with_slice(slice)),
         }
     }
+
+    /// Create a new uppercase iterator with the given byte slice using full
+    /// Unicode case mapping with Turkic tailorings.
+    ///
+    /// Turkic languages keep the dot when uppercasing `i` to `İ`. The dotless
+    /// `ı` uppercases to `I`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let uppercase = Uppercase::with_turkic_slice(b"istanbul");
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), "İSTANBUL".as_bytes());
+    ///
+    /// let uppercase = Uppercase::with_turkic_slice("ılık".as_bytes());
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), b"ILIK");
+    /// ```
+    ///
+    /// Invalid UTF-8 bytes are yielded as is without impacting Unicode
+    /// characters:
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let uppercase = Uppercase::with_turkic_slice(b"i\xFF\xFEi");
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), b"\xC4\xB0\xFF\xFE\xC4\xB0");
+    /// ```
+    pub const fn with_turkic_slice(slice: &'a [u8]) -> Self {
+        Self {
+            iter: Inner::Tailored(tailored::Uppercase::with_slice(
+                slice,
+                tailored::Tailoring::Turkic,
+            )),
+        }
+    }
+
+    /// Create a new uppercase iterator with the given byte slice using full
+    /// Unicode case mapping with Lithuanian tailorings.
+    ///
+    /// Lithuanian lowercasing keeps the dot on `i` and `j` under an accent by
+    /// inserting U+0307 COMBINING DOT ABOVE. Uppercasing removes the explicit
+    /// dot again when it follows a soft dotted letter like `i` or `j`. See
+    /// [`UppercaseMode::Lithuanian`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let uppercase = Uppercase::with_lithuanian_slice("i\u{307}\u{301}".as_bytes());
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), "I\u{301}".as_bytes());
+    ///
+    /// let uppercase = Uppercase::with_slice("i\u{307}\u{301}".as_bytes());
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), "I\u{307}\u{301}".as_bytes());
+    /// ```
+    ///
+    /// [`UppercaseMode::Lithuanian`]: crate::UppercaseMode::Lithuanian
+    pub const fn with_lithuanian_slice(slice: &'a [u8]) -> Self {
+        Self {
+            iter: Inner::Lithuanian(lithuanian::Uppercase::with_slice(slice)),
+        }
+    }
+
+    /// Create a new uppercase iterator with the given byte slice using full
+    /// Unicode case folding.
+    ///
+    /// This iterator yields the same bytes as [`Lowercase::with_fold_slice`].
+    /// See [`UppercaseMode::Fold`] for why this mode exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let uppercase = Uppercase::with_fold_slice("Straße".as_bytes());
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), b"strasse");
+    /// ```
+    ///
+    /// [`Lowercase::with_fold_slice`]: crate::Lowercase::with_fold_slice
+    /// [`UppercaseMode::Fold`]: crate::UppercaseMode::Fold
+    pub const fn with_fold_slice(slice: &'a [u8]) -> Self {
+        Self {
+            iter: Inner::Fold(crate::Lowercase::with_fold_slice(slice)),
+        }
+    }
+
+    /// Create a new uppercase iterator with the given byte slice using full
+    /// Unicode case mapping which maps `ß` to the capital sharp s `ẞ`.
+    ///
+    /// See [`UppercaseMode::CapitalSharpS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let uppercase = Uppercase::with_capital_sharp_s_slice("Straße".as_bytes());
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), "STRAẞE".as_bytes());
+    /// ```
+    ///
+    /// [`UppercaseMode::CapitalSharpS`]: crate::UppercaseMode::CapitalSharpS
+    pub const fn with_capital_sharp_s_slice(slice: &'a [u8]) -> Self {
+        Self {
+            iter: Inner::Tailored(tailored::Uppercase::with_slice(
+                slice,
+                tailored::Tailoring::CapitalSharpS,
+            )),
+        }
+    }
+
+    /// Views the not yet case mapped portion of the source slice.
+    ///
+    /// This has the same lifetime as the original slice, so the iterator can
+    /// be dropped and the remaining bytes recovered, like
+    /// [`Chars::as_str`].
+    ///
+    /// Bytes of a character whose case mapping is only partially yielded are
+    /// not part of the returned slice: the character has already been
+    /// consumed from the source slice and the rest of its mapping is buffered
+    /// in the iterator. The returned slice may therefore begin in the middle
+    /// of an expansion.
+    ///
+    /// An iterator created with `with_owned` case maps its slice up front, so
+    /// the returned slice is always empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let mut uppercase = Uppercase::with_slice("ßtraße".as_bytes());
+    /// assert_eq!(uppercase.as_slice(), "ßtraße".as_bytes());
+    ///
+    /// assert_eq!(uppercase.next(), Some(b'S'));
+    /// // The expansion of the first character is in progress.
+    /// assert_eq!(uppercase.as_slice(), "traße".as_bytes());
+    /// ```
+    ///
+    /// [`Chars::as_str`]: core::str::Chars::as_str
+    #[must_use]
+    pub const fn as_slice(&self) -> &'a [u8] {
+        match self.iter {
+            Inner::Empty => &[],
+            Inner::Full(ref iter) => iter.as_slice(),
+            Inner::FullAscii(ref iter) | Inner::Ascii(ref iter) => iter.as_slice(),
+            Inner::Tailored(ref iter) => iter.as_slice(),
+            Inner::Lithuanian(ref iter) => iter.as_slice(),
+            Inner::Fold(ref iter) => iter.as_slice(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(..) => &[],
+        }
+    }
+
+    /// Rewind the iterator to the start of the slice it was created with.
+    ///
+    /// Case mapped bytes which are buffered but not yet yielded are discarded,
+    /// so after a reset the iterator yields the same bytes as a freshly
+    /// constructed one. This lets a tight loop map the same slice repeatedly
+    /// without cloning the iterator up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let mut uppercase = Uppercase::with_slice("ßtraße".as_bytes());
+    /// assert_eq!(uppercase.next(), Some(b'S'));
+    /// uppercase.reset();
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), "SSTRASSE".as_bytes());
+    /// ```
+    pub fn reset(&mut self) {
+        match self.iter {
+            Inner::Empty => {}
+            Inner::Full(ref mut iter) => iter.reset(),
+            Inner::FullAscii(ref mut iter) | Inner::Ascii(ref mut iter) => iter.reset(),
+            Inner::Tailored(ref mut iter) => iter.reset(),
+            Inner::Lithuanian(ref mut iter) => iter.reset(),
+            Inner::Fold(ref mut iter) => iter.reset(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.reset(),
+        }
+    }
+
+    /// Skip the next `n` bytes of output and return the iterator.
+    ///
+    /// Iterators which map with ASCII case mapping, including full case
+    /// mapping of an ASCII-only slice, skip the bytes in constant time by
+    /// slicing the remaining input forward, as do iterators over an owned
+    /// slice. Other iterators map and discard the skipped bytes. If fewer than
+    /// `n` bytes remain, the returned iterator is exhausted.
+    ///
+    /// Unlike [`Iterator::skip`], the returned iterator is a `Uppercase`, so its
+    /// inherent methods like [`as_slice`](Self::as_slice) are still available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let uppercase = Uppercase::with_slice(b"abc, xyz").skip_bytes(5);
+    /// assert_eq!(uppercase.as_slice(), b"xyz");
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), b"XYZ");
+    /// ```
+    pub fn skip_bytes(mut self, n: usize) -> Self {
+        match self.iter {
+            Inner::FullAscii(ref mut iter) | Inner::Ascii(ref mut iter) => {
+                iter.advance(n);
+            }
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.advance(n),
+            _ => {
+                if let Some(n) = n.checked_sub(1) {
+                    self.nth(n);
+                }
+            }
+        }
+        self
+    }
+
+    /// Returns the exact number of bytes remaining in the iterator, if it is
+    /// known without case mapping the remaining slice.
+    ///
+    /// ASCII case mapping, and full case mapping of an ASCII-only slice, never
+    /// changes the length of the slice, so this method returns [`Some`] for
+    /// iterators which map with ASCII case mapping. It returns [`None`] for
+    /// iterators which map non-ASCII slices with Unicode case mapping, since
+    /// the output may be longer or shorter than the input. Iterators created
+    /// with `with_owned` have already case mapped their slice and always
+    /// return `Some`.
+    ///
+    /// When this method returns `Some`, the value is equal to the lower and
+    /// upper bounds of the iterator's [size hint].
+    ///
+    /// Unlike [`count`], this method does not consume the iterator, so it can
+    /// be used to size an output buffer before mapping without allocating.
+    ///
+    /// `Uppercase` does not implement [`ExactSizeIterator`] since the length of
+    /// a full Unicode case mapping is not known until the slice is mapped.
+    /// Code which needs the exact length of an empty or ASCII iterator can call
+    /// this method instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let mut uppercase = Uppercase::with_ascii_slice(b"abc XYZ");
+    /// assert_eq!(uppercase.exact_len(), Some(7));
+    /// uppercase.next();
+    /// assert_eq!(uppercase.exact_len(), Some(6));
+    ///
+    /// let uppercase = Uppercase::with_slice("αύριο".as_bytes());
+    /// assert_eq!(uppercase.exact_len(), None);
+    ///
+    /// assert_eq!(Uppercase::new().exact_len(), Some(0));
+    /// ```
+    ///
+    /// Sizing a stack buffer:
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let uppercase = Uppercase::with_ascii_slice(b"abc XYZ");
+    /// let mut buf = [0; 16];
+    /// let len = uppercase.exact_len().unwrap();
+    /// for (dst, byte) in buf[..len].iter_mut().zip(uppercase) {
+    ///     *dst = byte;
+    /// }
+    /// assert_eq!(&buf[..len], b"ABC XYZ");
+    /// ```
+    ///
+    /// [`count`]: Iterator::count
+    /// [size hint]: Iterator::size_hint
+    #[must_use]
+    #[doc(alias = "byte_len")]
+    pub fn exact_len(&self) -> Option<usize> {
+        match self.iter {
+            Inner::Empty => Some(0),
+            Inner::FullAscii(ref iter) | Inner::Ascii(ref iter) => Some(iter.len()),
+            Inner::Full(_) | Inner::Tailored(_) | Inner::Lithuanian(_) => None,
+            Inner::Fold(ref iter) => iter.exact_len(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref iter, _) => Some(iter.len()),
+        }
+    }
+
+    /// Returns the [`UppercaseMode`] this iterator was created with, or
+    /// [`None`] if it was created with [`Uppercase::new`].
+    ///
+    /// [`uppercase`] returns an empty iterator for an empty slice, whose mode is
+    /// [`None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{Uppercase, UppercaseMode};
+    /// assert_eq!(Uppercase::with_slice(b"abc").mode(), Some(UppercaseMode::Full));
+    /// assert_eq!(Uppercase::with_ascii_slice(b"abc").mode(), Some(UppercaseMode::Ascii));
+    /// assert_eq!(Uppercase::with_turkic_slice(b"abc").mode(), Some(UppercaseMode::Turkic));
+    /// assert_eq!(Uppercase::new().mode(), None);
+    /// ```
+    ///
+    /// [`uppercase`]: crate::uppercase()
+    #[must_use]
+    pub const fn mode(&self) -> Option<UppercaseMode> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(_) | Inner::FullAscii(_) => Some(UppercaseMode::Full),
+            Inner::Ascii(_) => Some(UppercaseMode::Ascii),
+            Inner::Tailored(ref iter) => Some(iter.mode()),
+            Inner::Lithuanian(_) => Some(UppercaseMode::Lithuanian),
+            Inner::Fold(_) => Some(UppercaseMode::Fold),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(_, mode) => Some(mode),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Uppercase<'static> {
+    /// Create a new uppercase iterator which takes ownership of the given byte
+    /// vector and maps it with the given [`UppercaseMode`].
+    ///
+    /// The returned iterator does not borrow, so it can be built from a
+    /// temporary buffer and returned or stored as a `'static` value.
+    ///
+    /// The vector is case mapped in full by [`uppercase`] when the iterator is
+    /// created and its mapping is yielded from an exactly sized buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{Uppercase, UppercaseMode};
+    /// fn uppercase_street() -> Uppercase<'static> {
+    ///     let street = String::from("straße");
+    ///     Uppercase::with_owned(street.into_bytes(), UppercaseMode::CapitalSharpS)
+    /// }
+    ///
+    /// assert_eq!(uppercase_street().collect::<Vec<_>>(), "STRAẞE".as_bytes());
+    ///
+    /// let uppercase = Uppercase::with_owned("istanbul".into(), UppercaseMode::Turkic);
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), "İSTANBUL".as_bytes());
+    /// ```
+    ///
+    /// [`uppercase`]: crate::uppercase()
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    // The vector is taken by value so callers can hand over a temporary
+    // buffer. It is dropped once its mapping is collected.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn with_owned(slice: Vec<u8>, mode: UppercaseMode) -> Self {
+        Self {
+            iter: Inner::Owned(MappedBytes::with_iter(crate::uppercase(&slice, mode)), mode),
+        }
+    }
 }
 
-impl<'a> Iterator for Uppercase<'a> {
+impl<'a> From<&'a [u8]> for Uppercase<'a> {
+    /// Create a new uppercase iterator with the given byte slice using full
+    /// Unicode case mapping.
+    ///
+    /// This is the same as [`Uppercase::with_slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let uppercase = Uppercase::from(&b"abc"[..]);
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), b"ABC");
+    /// ```
+    #[inline]
+    fn from(slice: &'a [u8]) -> Self {
+        Self::with_slice(slice)
+    }
+}
+
+impl<'a> From<&'a str> for Uppercase<'a> {
+    /// Create a new uppercase iterator with the bytes of the given string using
+    /// full Unicode case mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::Uppercase;
+    /// let uppercase = Uppercase::from("straße");
+    /// assert_eq!(uppercase.collect::<Vec<_>>(), "STRASSE".as_bytes());
+    /// ```
+    #[inline]
+    fn from(s: &'a str) -> Self {
+        Self::with_slice(s.as_bytes())
+    }
+}
+
+impl Iterator for Uppercase<'_> {
     type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.iter {
             Inner::Empty => None,
             Inner::Full(ref mut iter) => iter.next(),
-            Inner::Ascii(ref mut iter) => iter.next(),
+            Inner::FullAscii(ref mut iter) | Inner::Ascii(ref mut iter) => iter.next(),
+            Inner::Tailored(ref mut iter) => iter.next(),
+            Inner::Lithuanian(ref mut iter) => iter.next(),
+            Inner::Fold(ref mut iter) => iter.next(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.next(),
         }
     }
 
+    /// Returns the bounds on the remaining length of the iterator.
+    ///
+    /// The lower bound is the number of chars and invalid UTF-8 byte sequences
+    /// in the remaining slice, each of which yields at least one byte. The
+    /// upper bound is the remaining slice length scaled by the
+    /// [maximum expansion ratio] of the case mapping. Both bounds include the
+    /// bytes of a partially yielded case mapping.
+    ///
+    /// ASCII-only slices have an exact size hint, except in Turkic mode, where
+    /// `i` expands to the two byte `İ`.
+    ///
+    /// [maximum expansion ratio]: crate::max_expansion_ratio()
     fn size_hint(&self) -> (usize, Option<usize>) {
         match self.iter {
             Inner::Empty => (0, Some(0)),
             Inner::Full(ref iter) => iter.size_hint(),
-            Inner::Ascii(ref iter) => iter.size_hint(),
+            Inner::FullAscii(ref iter) | Inner::Ascii(ref iter) => iter.size_hint(),
+            Inner::Tailored(ref iter) => iter.size_hint(),
+            Inner::Lithuanian(ref iter) => iter.size_hint(),
+            Inner::Fold(ref iter) => iter.size_hint(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref iter, _) => iter.size_hint(),
         }
     }
 
@@ -147,19 +614,125 @@ impl<'a> Iterator for Uppercase<'a> {
         match self.iter {
             Inner::Empty => 0,
             Inner::Full(iter) => iter.count(),
-            Inner::Ascii(iter) => iter.count(),
+            Inner::FullAscii(iter) | Inner::Ascii(iter) => iter.count(),
+            Inner::Tailored(iter) => iter.count(),
+            Inner::Lithuanian(iter) => iter.count(),
+            Inner::Fold(iter) => iter.count(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(iter, _) => iter.count(),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(ref mut iter) => iter.nth(n),
+            Inner::FullAscii(ref mut iter) | Inner::Ascii(ref mut iter) => iter.nth(n),
+            Inner::Tailored(ref mut iter) => iter.nth(n),
+            Inner::Lithuanian(ref mut iter) => iter.nth(n),
+            Inner::Fold(ref mut iter) => iter.nth(n),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.nth(n),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(iter) => iter.last(),
+            Inner::FullAscii(mut iter) | Inner::Ascii(mut iter) => iter.next_back(),
+            Inner::Tailored(mut iter) => iter.next_back(),
+            Inner::Lithuanian(iter) => iter.last(),
+            Inner::Fold(iter) => iter.last(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(iter, _) => iter.last(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for Uppercase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter {
+            Inner::Empty => None,
+            Inner::Full(ref mut iter) => iter.next_back(),
+            Inner::FullAscii(ref mut iter) | Inner::Ascii(ref mut iter) => iter.next_back(),
+            Inner::Tailored(ref mut iter) => iter.next_back(),
+            Inner::Lithuanian(ref mut iter) => iter.next_back(),
+            Inner::Fold(ref mut iter) => iter.next_back(),
+            #[cfg(feature = "alloc")]
+            Inner::Owned(ref mut iter, _) => iter.next_back(),
         }
     }
 }
 
-impl<'a> FusedIterator for Uppercase<'a> {}
+impl FusedIterator for Uppercase<'_> {}
+
+/// Formats the remaining case mapped bytes as a conventionally UTF-8 string.
+///
+/// Invalid UTF-8 byte sequences are rendered as U+FFFD REPLACEMENT CHARACTER,
+/// the same as the [`Display`] implementation of [`BStr`]. Formatting maps a
+/// clone of the iterator and does not allocate or advance `self`.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{UppercaseMode, Uppercase};
+/// let uppercase = roe::uppercase("straße".as_bytes(), UppercaseMode::Full);
+/// assert_eq!(uppercase.to_string(), "STRASSE");
+///
+/// let uppercase = Uppercase::with_ascii_slice(b"abc\xFF");
+/// assert_eq!(format!("{uppercase}"), "ABC\u{FFFD}");
+/// ```
+///
+/// [`Display`]: fmt::Display
+/// [`BStr`]: bstr::BStr
+impl fmt::Display for Uppercase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::utf8::fmt_lossy(self.clone(), f)
+    }
+}
+
+/// Compares the remaining case mapped bytes with a byte slice.
+///
+/// Comparison maps a clone of the iterator and stops at the first byte which
+/// differs, so it does not allocate or advance `self`. Slices with a length
+/// outside of the iterator's [size hint] compare unequal without mapping any
+/// bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::UppercaseMode;
+/// assert_eq!(roe::uppercase(b"abc XYZ", UppercaseMode::Ascii), b"ABC XYZ"[..]);
+/// assert_eq!(roe::uppercase("αύριο".as_bytes(), UppercaseMode::Full), "ΑΎΡΙΟ".as_bytes());
+/// assert_ne!(roe::uppercase(b"abc", UppercaseMode::Ascii), b"AB"[..]);
+/// ```
+///
+/// [size hint]: Iterator::size_hint
+impl PartialEq<[u8]> for Uppercase<'_> {
+    fn eq(&self, other: &[u8]) -> bool {
+        let (min, max) = self.size_hint();
+        if other.len() < min || max.is_some_and(|max| other.len() > max) {
+            return false;
+        }
+        Iterator::eq(self.clone(), other.iter().copied())
+    }
+}
+
+impl PartialEq<&[u8]> for Uppercase<'_> {
+    fn eq(&self, other: &&[u8]) -> bool {
+        *self == **other
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use alloc::format;
     use alloc::vec::Vec;
     use bstr::ByteSlice;
 
-    use super::Uppercase;
+    use super::{Inner, Uppercase};
+    use crate::UppercaseMode;
 
     #[test]
     fn empty() {
@@ -171,6 +744,33 @@ mod tests {
 
         let iter = Uppercase::with_ascii_slice(b"");
         assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+
+        let iter = Uppercase::with_turkic_slice(b"");
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+    }
+
+    #[test]
+    fn turkic_mode_ascii_slice_uses_tailored_iterator() {
+        let iter = Uppercase::with_turkic_slice(b"ABC, xyz, i");
+        assert!(matches!(iter.iter, Inner::Tailored(_)));
+        assert_eq!(iter.mode(), Some(UppercaseMode::Turkic));
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "ABC, XYZ, İ".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn full_mode_ascii_slice_uses_ascii_iterator() {
+        let iter = Uppercase::with_slice(b"ABC, xyz");
+        assert!(matches!(iter.iter, Inner::FullAscii(_)));
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"ABC, XYZ".as_bstr());
+
+        let iter = Uppercase::with_slice("αύριο ABC".as_bytes());
+        assert!(matches!(iter.iter, Inner::Full(_)));
+
+        let iter = Uppercase::with_slice(b"abc\xFF");
+        assert!(matches!(iter.iter, Inner::Full(_)));
     }
 
     #[test]
@@ -180,26 +780,26 @@ mod tests {
         assert_eq!(Uppercase::with_slice(b"abc, xyz").size_hint(), (8, Some(8)));
         assert_eq!(
             Uppercase::with_slice(b"abc, \xFF\xFE, xyz").size_hint(),
-            (12, Some(144))
+            (12, Some(36))
         );
         assert_eq!(
             Uppercase::with_slice("�".as_bytes()).size_hint(),
-            (3, Some(36))
+            (1, Some(9))
         );
         assert_eq!(
             Uppercase::with_slice("Έτος".as_bytes()).size_hint(),
-            (8, Some(96))
+            (4, Some(24))
         );
         assert_eq!(
             Uppercase::with_slice("ZȺȾ".as_bytes()).size_hint(),
-            (5, Some(60))
+            (3, Some(15))
         );
 
         let mut utf8_with_invalid_bytes = b"\xFF\xFE".to_vec();
         utf8_with_invalid_bytes.extend_from_slice("Έτος".as_bytes());
         assert_eq!(
             Uppercase::with_slice(&utf8_with_invalid_bytes).size_hint(),
-            (10, Some(120))
+            (6, Some(30))
         );
 
         assert_eq!(
@@ -231,6 +831,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn nth_matches_repeated_next() {
+        let inputs: [&[u8]; 3] = [b"abc, xyz", b"abc, xyz\xFF\xFE", "Έτος ΑΎΡΙΟ".as_bytes()];
+        for input in inputs {
+            for n in 0..=input.len() + 1 {
+                for (mut nth, mut next) in [
+                    (Uppercase::with_slice(input), Uppercase::with_slice(input)),
+                    (
+                        Uppercase::with_ascii_slice(input),
+                        Uppercase::with_ascii_slice(input),
+                    ),
+                ] {
+                    for _ in 0..n {
+                        next.next();
+                    }
+                    assert_eq!(nth.nth(n), next.next());
+                    assert_eq!(nth.as_slice().as_bstr(), next.as_slice().as_bstr());
+                    assert!(nth.eq(next));
+                }
+            }
+        }
+
+        let mut iter = Uppercase::with_ascii_slice(b"abc, xyz");
+        assert_eq!(iter.nth(2), Some(b'C'));
+        assert_eq!(iter.nth(3), Some(b'Y'));
+        assert_eq!(iter.next(), Some(b'Z'));
+        assert_eq!(iter.nth(1), None);
+        assert_eq!(Uppercase::new().nth(1), None);
+    }
+
+    #[test]
+    fn mode() {
+        assert_eq!(Uppercase::new().mode(), None);
+        for input in [&b"abc"[..], "αύριο".as_bytes()] {
+            assert_eq!(
+                Uppercase::with_slice(input).mode(),
+                Some(UppercaseMode::Full)
+            );
+            assert_eq!(
+                Uppercase::with_ascii_slice(input).mode(),
+                Some(UppercaseMode::Ascii)
+            );
+            assert_eq!(
+                Uppercase::with_turkic_slice(input).mode(),
+                Some(UppercaseMode::Turkic)
+            );
+            assert_eq!(
+                Uppercase::with_fold_slice(input).mode(),
+                Some(UppercaseMode::Fold)
+            );
+            assert_eq!(
+                Uppercase::with_capital_sharp_s_slice(input).mode(),
+                Some(UppercaseMode::CapitalSharpS)
+            );
+            assert_eq!(
+                Uppercase::with_lithuanian_slice(input).mode(),
+                Some(UppercaseMode::Lithuanian)
+            );
+        }
+        assert_eq!(
+            crate::uppercase(b"abc", UppercaseMode::Lithuanian).mode(),
+            Some(UppercaseMode::Lithuanian)
+        );
+    }
+
     #[test]
     fn count() {
         assert_eq!(Uppercase::new().count(), 0);
@@ -262,6 +927,114 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rev() {
+        let iter = Uppercase::new();
+        assert_eq!(iter.rev().collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+
+        let iter = Uppercase::with_ascii_slice("abc, αύριο".as_bytes());
+        let mut rev = iter.rev().collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev.as_bstr(), "ABC, αύριο".as_bytes().as_bstr());
+
+        let iter = Uppercase::with_slice(b"abc, xyz");
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>().as_bstr(),
+            b"ZYX ,CBA".as_bstr()
+        );
+
+        let iter = Uppercase::with_slice("αύριο ß".as_bytes());
+        let mut rev = iter.rev().collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev.as_bstr(), "ΑΎΡΙΟ SS".as_bytes().as_bstr());
+    }
+
+    #[test]
+    fn next_and_next_back_meet_in_the_middle() {
+        let mut iter = Uppercase::with_slice("ßﬃ".as_bytes());
+        assert_eq!(iter.next(), Some(b'S'));
+        assert_eq!(iter.next_back(), Some(b'I'));
+        assert_eq!(iter.next(), Some(b'S'));
+        assert_eq!(iter.next_back(), Some(b'F'));
+        assert_eq!(iter.next(), Some(b'F'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn rev_capital_sharp_s_mode() {
+        let iter = crate::uppercase("ß".as_bytes(), UppercaseMode::CapitalSharpS);
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>().as_bstr(),
+            b"\x9E\xBA\xE1".as_bstr()
+        );
+
+        let input = "straße ẞß ﬃ".as_bytes();
+        let mut rev = crate::uppercase(input, UppercaseMode::CapitalSharpS)
+            .rev()
+            .collect::<Vec<_>>();
+        rev.reverse();
+        let forward = crate::uppercase(input, UppercaseMode::CapitalSharpS).collect::<Vec<_>>();
+        assert_eq!(rev.as_bstr(), forward.as_bstr());
+    }
+
+    #[test]
+    fn rev_turkic_mode() {
+        let iter = crate::uppercase(b"i", UppercaseMode::Turkic);
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>().as_bstr(),
+            b"\xB0\xC4".as_bstr()
+        );
+
+        let input = "diyarbakır iİ ßﬃ".as_bytes();
+        let mut rev = Uppercase::with_turkic_slice(input)
+            .rev()
+            .collect::<Vec<_>>();
+        rev.reverse();
+        let forward = Uppercase::with_turkic_slice(input).collect::<Vec<_>>();
+        assert_eq!(rev.as_bstr(), forward.as_bstr());
+    }
+
+    #[test]
+    fn fold_matches_lowercase_fold() {
+        let inputs: [&[u8]; 4] = [
+            b"",
+            b"abc, XYZ",
+            "Straße ẞ \u{212A} ΐ".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF\xFE",
+        ];
+        for input in inputs {
+            let iter = Uppercase::with_fold_slice(input);
+            let fold = crate::Lowercase::with_fold_slice(input);
+            assert_eq!(iter.as_slice(), input);
+            assert_eq!(iter.size_hint(), fold.size_hint());
+            assert_eq!(iter.exact_len(), fold.exact_len());
+            assert_eq!(iter.clone().count(), fold.clone().count());
+            assert_eq!(
+                iter.collect::<Vec<_>>().as_bstr(),
+                fold.collect::<Vec<_>>().as_bstr()
+            );
+        }
+
+        let iter = Uppercase::with_fold_slice(b"abc, XYZ");
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>().as_bstr(),
+            b"zyx ,cba".as_bstr()
+        );
+    }
+
+    #[test]
+    fn rev_fold_mode_non_ascii() {
+        let iter = crate::uppercase("ẞ".as_bytes(), UppercaseMode::Fold);
+        assert_eq!(iter.rev().collect::<Vec<_>>().as_bstr(), b"ss".as_bstr());
+
+        let input = "Straße ẞ \u{212A} ΐ".as_bytes();
+        let mut rev = Uppercase::with_fold_slice(input).rev().collect::<Vec<_>>();
+        rev.reverse();
+        let forward = Uppercase::with_fold_slice(input).collect::<Vec<_>>();
+        assert_eq!(rev.as_bstr(), forward.as_bstr());
+    }
+
     #[test]
     fn size_hint_covers_count() {
         let iter = Uppercase::new();
@@ -270,4 +1043,271 @@ mod tests {
         assert!(min <= count);
         assert!(count <= max.unwrap());
     }
+
+    #[test]
+    fn as_slice_after_partial_consumption() {
+        let mut iter = Uppercase::with_slice("ßtraße".as_bytes());
+        assert_eq!(iter.next(), Some(b'S'));
+        // The second `S` of the expansion has not been yielded yet.
+        assert_eq!(iter.as_slice().as_bstr(), "traße".as_bytes().as_bstr());
+        assert_eq!(iter.next(), Some(b'S'));
+        assert_eq!(iter.as_slice().as_bstr(), "traße".as_bytes().as_bstr());
+        assert_eq!(iter.next(), Some(b'T'));
+        assert_eq!(iter.as_slice().as_bstr(), "raße".as_bytes().as_bstr());
+        assert_eq!(iter.next_back(), Some(b'E'));
+        assert_eq!(iter.as_slice().as_bstr(), "raß".as_bytes().as_bstr());
+
+        let mut iter = Uppercase::with_turkic_slice("iii".as_bytes());
+        iter.next();
+        assert_eq!(iter.as_slice().as_bstr(), b"ii".as_bstr());
+
+        let mut iter = Uppercase::with_ascii_slice(b"abc");
+        iter.next();
+        assert_eq!(iter.as_slice().as_bstr(), b"bc".as_bstr());
+
+        assert_eq!(Uppercase::new().as_slice(), b"");
+    }
+
+    #[test]
+    fn exact_len() {
+        assert_eq!(Uppercase::new().exact_len(), Some(0));
+
+        let mut iter = Uppercase::with_ascii_slice("αύριο abc".as_bytes());
+        let len = iter.clone().count();
+        for remaining in (0..=len).rev() {
+            assert_eq!(iter.exact_len(), Some(remaining));
+            assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+            iter.next();
+        }
+
+        let iter = Uppercase::with_slice("αύριο".as_bytes());
+        assert_eq!(iter.exact_len(), None);
+        assert_eq!(Uppercase::with_slice(b"abc XYZ").exact_len(), Some(7));
+        assert_eq!(Uppercase::with_slice("ß".as_bytes()).exact_len(), None);
+        // `i` expands to `İ` in Turkic mode.
+        assert_eq!(Uppercase::with_turkic_slice(b"abc").exact_len(), None);
+    }
+
+    #[test]
+    fn display_matches_bstr() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"abc, XYZ",
+            "straße ß İ ǅ".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF\xFE",
+            b"\xF0\x9F\x98\x80 \xE1\xBA\x9E \xCE",
+        ];
+        for input in inputs {
+            for mode in [UppercaseMode::Full, UppercaseMode::Ascii] {
+                let iter = crate::uppercase(input, mode);
+                let expected = iter.clone().collect::<Vec<_>>();
+                assert_eq!(format!("{iter}"), format!("{}", expected.as_bstr()));
+                // Formatting does not advance the iterator.
+                assert_eq!(iter.collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn partial_eq_slice() {
+        let iter = crate::uppercase("αύριο ß".as_bytes(), UppercaseMode::Full);
+        assert_eq!(iter, "ΑΎΡΙΟ SS".as_bytes());
+        assert_eq!(iter, *"ΑΎΡΙΟ SS".as_bytes());
+        // Comparison does not advance the iterator.
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "ΑΎΡΙΟ SS".as_bytes().as_bstr()
+        );
+
+        let iter = crate::uppercase("i".as_bytes(), UppercaseMode::Turkic);
+        assert_eq!(iter, "İ".as_bytes()[..]);
+
+        // Unequal lengths.
+        let iter = crate::uppercase("αύριο ß".as_bytes(), UppercaseMode::Full);
+        let expected = "ΑΎΡΙΟ SS".as_bytes();
+        assert_ne!(iter, expected[..expected.len() - 1]);
+        assert_ne!(iter, [expected, b"!"].concat()[..]);
+        assert_ne!(iter, b""[..]);
+        assert_eq!(crate::uppercase(b"", UppercaseMode::Full), b""[..]);
+
+        // Unequal contents.
+        assert_ne!(iter, "αύριο ß".as_bytes());
+
+        // Invalid UTF-8 byte sequences are compared as is.
+        assert_eq!(
+            crate::uppercase(b"abc\xFF\xFE", UppercaseMode::Full),
+            b"ABC\xFF\xFE"[..]
+        );
+        assert_ne!(
+            crate::uppercase(b"abc\xFF\xFE", UppercaseMode::Full),
+            "Abc\u{FFFD}".as_bytes()
+        );
+    }
+
+    #[test]
+    fn last_matches_collected_last() {
+        let inputs: [&[u8]; 4] = [
+            b"abc, xyz",
+            "straße".as_bytes(),
+            "aﬃ".as_bytes(),
+            b"abc\xF0\x9F\x87",
+        ];
+        for input in inputs {
+            for mode in [
+                UppercaseMode::Full,
+                UppercaseMode::Ascii,
+                UppercaseMode::Turkic,
+                UppercaseMode::Lithuanian,
+                UppercaseMode::Fold,
+                UppercaseMode::CapitalSharpS,
+            ] {
+                let expected = crate::uppercase(input, mode).collect::<Vec<_>>();
+                assert_eq!(
+                    crate::uppercase(input, mode).last(),
+                    expected.last().copied()
+                );
+            }
+        }
+        assert_eq!(Uppercase::new().last(), None);
+    }
+
+    #[test]
+    fn reset_matches_fresh_iterator() {
+        let inputs: [&[u8]; 4] = [
+            b"abc, XYZ",
+            "ΑΎΡΙΟ İ ß ﬃ".as_bytes(),
+            b"ABC\xFF\xFE\xCE\xA3XYZ\xF0\x9F\x87",
+            b"",
+        ];
+        for input in inputs {
+            for mode in [
+                UppercaseMode::Full,
+                UppercaseMode::Ascii,
+                UppercaseMode::Turkic,
+                UppercaseMode::Lithuanian,
+                UppercaseMode::Fold,
+                UppercaseMode::CapitalSharpS,
+            ] {
+                let expected = crate::uppercase(input, mode).collect::<Vec<_>>();
+                // Reset after consuming any prefix of the output, including
+                // from the middle of an expansion and after exhaustion.
+                for consumed in 0..=expected.len() {
+                    let mut iter = crate::uppercase(input, mode);
+                    iter.by_ref().take(consumed).for_each(drop);
+                    iter.reset();
+                    assert_eq!(
+                        iter.collect::<Vec<_>>().as_bstr(),
+                        expected.as_bstr(),
+                        "{mode:?} after {consumed} bytes"
+                    );
+                }
+            }
+            // Buffered output from both ends is discarded.
+            let expected = crate::uppercase(input, UppercaseMode::Full).collect::<Vec<_>>();
+            let mut iter = crate::uppercase(input, UppercaseMode::Full);
+            iter.next();
+            iter.next_back();
+            iter.reset();
+            assert_eq!(iter.collect::<Vec<_>>().as_bstr(), expected.as_bstr());
+        }
+
+        let mut iter = Uppercase::new();
+        iter.reset();
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn skip_bytes_matches_nth() {
+        let inputs: [&[u8]; 3] = [b"abc, XYZ", b"ABC\xFF\xFExyz", "ΑΎΡΙΟ ß".as_bytes()];
+        for input in inputs {
+            for mode in [
+                UppercaseMode::Full,
+                UppercaseMode::Ascii,
+                UppercaseMode::Turkic,
+            ] {
+                let len = crate::uppercase(input, mode).count();
+                for n in 0..=len + 1 {
+                    let mut nth = crate::uppercase(input, mode);
+                    let expected = nth.nth(n);
+                    let mut skipped = crate::uppercase(input, mode).skip_bytes(n);
+                    assert_eq!(skipped.next(), expected, "{mode:?} skipping {n} bytes");
+                    assert_eq!(skipped.as_slice().as_bstr(), nth.as_slice().as_bstr());
+                    assert!(skipped.eq(nth));
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn owned_uppercase_from_temporary() -> Uppercase<'static> {
+        let mut source = Vec::new();
+        source.extend_from_slice("straße ".as_bytes());
+        source.extend_from_slice(b"\xFF abc XYZ");
+        Uppercase::with_owned(source, UppercaseMode::Full)
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn with_owned_outlives_source_scope() {
+        use alloc::boxed::Box;
+
+        let mut source = Vec::new();
+        source.extend_from_slice("straße ".as_bytes());
+        source.extend_from_slice(b"\xFF abc XYZ");
+        let expected = crate::uppercase(&source, UppercaseMode::Full).collect::<Vec<_>>();
+        drop(source);
+        let iter = owned_uppercase_from_temporary();
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), expected.as_bstr());
+        assert!(expected.starts_with("STRASSE ".as_bytes()));
+
+        // The iterator is `'static` and can be boxed as a trait object.
+        let boxed: Box<dyn Iterator<Item = u8>> = Box::new(owned_uppercase_from_temporary());
+        assert_eq!(boxed.count(), expected.len());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn with_owned_matches_borrowed() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"abc, XYZ",
+            "ΑΎΡΙΟ ΟΔΟΣ ß İ ΐ ǆ".as_bytes(),
+            "Ì I\u{301} i\u{307}".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF",
+        ];
+        for input in inputs {
+            for mode in [
+                UppercaseMode::Full,
+                UppercaseMode::Ascii,
+                UppercaseMode::Turkic,
+                UppercaseMode::Lithuanian,
+                UppercaseMode::Fold,
+                UppercaseMode::CapitalSharpS,
+            ] {
+                let expected = crate::uppercase(input, mode).collect::<Vec<_>>();
+                let mut owned = Uppercase::with_owned(input.to_vec(), mode);
+                assert_eq!(owned.mode(), Some(mode));
+                assert_eq!(owned.exact_len(), Some(expected.len()));
+                assert_eq!(owned.size_hint(), (expected.len(), Some(expected.len())));
+                assert!(owned.as_slice().is_empty());
+                assert_eq!(
+                    owned.clone().collect::<Vec<_>>().as_bstr(),
+                    expected.as_bstr()
+                );
+
+                let mut rev = owned.clone().rev().collect::<Vec<_>>();
+                rev.reverse();
+                assert_eq!(rev.as_bstr(), expected.as_bstr());
+
+                owned.next();
+                owned.reset();
+                assert_eq!(owned.clone().count(), expected.len());
+                let skipped = owned.skip_bytes(2).collect::<Vec<_>>();
+                assert_eq!(
+                    skipped.as_bstr(),
+                    expected.get(2..).unwrap_or_default().as_bstr()
+                );
+            }
+        }
+    }
 }
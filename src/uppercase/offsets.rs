@@ -0,0 +1,190 @@
+use alloc::vec::Vec;
+
+use crate::unicode::lithuanian::is_removed_by_lithuanian_uppercase;
+use crate::UppercaseMode;
+
+/// Returns a copy of the given slice with all lowercase letters replaced with
+/// their uppercase counterparts along with a table which maps each byte of the
+/// output to the byte offset in the input that produced it.
+///
+/// The returned offsets vector has exactly one entry per output byte. All bytes
+/// produced by mapping a UTF-8 scalar value share the byte offset of the start
+/// of that scalar in the input, including scalars which expand to several
+/// characters when uppercased, like `ß` to `SS`. Invalid UTF-8 bytes are copied
+/// to the output as is and each maps to its own offset in the input.
+///
+/// The case mapping mode is determined by the given [`UppercaseMode`]. See
+/// [`uppercase`] for details on how the input slice is mapped.
+///
+/// # Memory Cost
+///
+/// The offsets table stores a [`usize`] per output byte, so in addition to the
+/// mapped bytes this function allocates `size_of::<usize>()` bytes per output
+/// byte, 8x the size of the output on 64-bit targets. Prefer [`uppercase`] when
+/// offset translation is not required.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{uppercase_with_offsets, UppercaseMode};
+/// let (uppercase, offsets) = uppercase_with_offsets(b"abc", UppercaseMode::Full);
+/// assert_eq!(uppercase, b"ABC");
+/// assert_eq!(offsets, [0, 1, 2]);
+///
+/// // `ß` is two bytes in the input and expands to `SS`.
+/// let (uppercase, offsets) = uppercase_with_offsets("aßc".as_bytes(), UppercaseMode::Full);
+/// assert_eq!(uppercase, b"ASSC");
+/// assert_eq!(offsets, [0, 1, 1, 3]);
+/// ```
+///
+/// [`uppercase`]: crate::uppercase()
+#[must_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn uppercase_with_offsets(slice: &[u8], options: UppercaseMode) -> (Vec<u8>, Vec<usize>) {
+    let mut uppercase = Vec::with_capacity(slice.len());
+    let mut offsets = Vec::with_capacity(slice.len());
+
+    let mut offset = 0;
+    let mut remaining = slice;
+    while !remaining.is_empty() {
+        let (bytes, remainder) = match bstr::decode_utf8(remaining) {
+            (Some(ch), size) => {
+                let (scalar, remainder) = remaining.split_at(size);
+                // Mapped alone, an explicit dot would lose the soft dotted
+                // letter it follows.
+                let preceding = &slice[..offset];
+                let is_removed = options == UppercaseMode::Lithuanian
+                    && is_removed_by_lithuanian_uppercase(ch, preceding);
+                for byte in crate::uppercase(scalar, options).filter(|_| !is_removed) {
+                    uppercase.push(byte);
+                    offsets.push(offset);
+                }
+                (scalar, remainder)
+            }
+            (None, size) => {
+                let (invalid, remainder) = remaining.split_at(size);
+                uppercase.extend_from_slice(invalid);
+                offsets.extend(offset..offset + invalid.len());
+                (invalid, remainder)
+            }
+        };
+        offset += bytes.len();
+        remaining = remainder;
+    }
+
+    (uppercase, offsets)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::uppercase_with_offsets;
+    use crate::UppercaseMode;
+
+    #[test]
+    fn empty() {
+        let (uppercase, offsets) = uppercase_with_offsets(b"", UppercaseMode::Full);
+        assert!(uppercase.is_empty());
+        assert!(offsets.is_empty());
+    }
+
+    #[test]
+    fn ascii() {
+        let (uppercase, offsets) = uppercase_with_offsets(b"abc, XYZ", UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), b"ABC, XYZ".as_bstr());
+        assert_eq!(offsets, (0..8).collect::<Vec<_>>());
+
+        let (uppercase, offsets) = uppercase_with_offsets(b"abc, XYZ", UppercaseMode::Ascii);
+        assert_eq!(uppercase.as_bstr(), b"ABC, XYZ".as_bstr());
+        assert_eq!(offsets, (0..8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn multi_byte_scalars_share_offsets() {
+        let (uppercase, offsets) = uppercase_with_offsets("aύb".as_bytes(), UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), "AΎB".as_bytes().as_bstr());
+        assert_eq!(offsets, [0, 1, 1, 3]);
+    }
+
+    #[test]
+    fn expansions_share_offsets() {
+        let (uppercase, offsets) = uppercase_with_offsets("xﬃy".as_bytes(), UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), b"XFFIY".as_bstr());
+        assert_eq!(offsets, [0, 1, 1, 1, 4]);
+
+        let (uppercase, offsets) = uppercase_with_offsets("ßß".as_bytes(), UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), b"SSSS".as_bstr());
+        assert_eq!(offsets, [0, 0, 2, 2]);
+    }
+
+    #[test]
+    fn ascii_mode_ignores_unicode() {
+        let (uppercase, offsets) = uppercase_with_offsets("aßc".as_bytes(), UppercaseMode::Ascii);
+        assert_eq!(uppercase.as_bstr(), "AßC".as_bytes().as_bstr());
+        assert_eq!(offsets, [0, 1, 1, 3]);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let (uppercase, offsets) = uppercase_with_offsets(b"a\xFF\xFEb", UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), b"A\xFF\xFEB".as_bstr());
+        assert_eq!(offsets, [0, 1, 2, 3]);
+
+        let (uppercase, offsets) = uppercase_with_offsets(b"a\xF0\x9F\x87b", UppercaseMode::Full);
+        assert_eq!(uppercase.as_bstr(), b"A\xF0\x9F\x87B".as_bstr());
+        assert_eq!(offsets, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn lithuanian_removes_explicit_dots() {
+        let (uppercase, offsets) =
+            uppercase_with_offsets("i\u{307}x".as_bytes(), UppercaseMode::Lithuanian);
+        assert_eq!(uppercase.as_bstr(), b"IX".as_bstr());
+        assert_eq!(offsets, [0, 3]);
+
+        let (uppercase, offsets) =
+            uppercase_with_offsets("i\u{323}\u{307}".as_bytes(), UppercaseMode::Lithuanian);
+        assert_eq!(uppercase.as_bstr(), "I\u{323}".as_bytes().as_bstr());
+        assert_eq!(offsets, [0, 1, 1]);
+    }
+
+    #[test]
+    fn matches_uppercase() {
+        let inputs: [&[u8]; 7] = [
+            b"abc, XYZ",
+            "i\u{307}".as_bytes(),
+            "ij\u{307}".as_bytes(),
+            "i\u{323}\u{307}".as_bytes(),
+            "i\u{301}\u{307} a\u{307} j\u{307}\u{307}".as_bytes(),
+            "Αύριο ßtraße ﬃ İ".as_bytes(),
+            b"i\xFF\xCC\x87",
+        ];
+        let modes = [
+            UppercaseMode::Full,
+            UppercaseMode::Ascii,
+            UppercaseMode::Turkic,
+            UppercaseMode::Lithuanian,
+            UppercaseMode::Fold,
+            UppercaseMode::CapitalSharpS,
+        ];
+        for input in inputs {
+            for mode in modes {
+                let (uppercase, offsets) = uppercase_with_offsets(input, mode);
+                let expected = crate::uppercase(input, mode).collect::<Vec<_>>();
+                assert_eq!(uppercase.as_bstr(), expected.as_bstr(), "{mode:?}");
+                assert_eq!(uppercase.len(), offsets.len());
+            }
+        }
+    }
+
+    #[test]
+    fn offsets_match_output_length() {
+        let s = "ΑύριΟ ßtraße ﬃ \u{1F600}".as_bytes();
+        let (uppercase, offsets) = uppercase_with_offsets(s, UppercaseMode::Full);
+        assert_eq!(uppercase.len(), offsets.len());
+        assert!(offsets.windows(2).all(|w| w[0] <= w[1]));
+        assert!(offsets.iter().all(|&offset| offset < s.len()));
+    }
+}
@@ -0,0 +1,249 @@
+use alloc::string::{FromUtf8Error, String};
+use alloc::vec::Vec;
+
+use crate::expansion::collect_exact;
+use crate::UppercaseMode;
+
+/// Returns a vector containing a copy of the given slice with all lowercase
+/// letters replaced with their uppercase counterparts.
+///
+/// This function is equivalent to collecting the iterator returned by
+/// [`uppercase`] into a [`Vec`]. The mapped length is counted with a first pass
+/// over the slice, so the vector is allocated once, with exactly the capacity
+/// it needs.
+///
+/// The case mapping mode is determined by the given [`UppercaseMode`]. See
+/// [`uppercase`] for details on how the input slice is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{to_uppercase, UppercaseMode};
+/// assert_eq!(to_uppercase(b"ABCxyz", UppercaseMode::Full), b"ABCXYZ");
+/// assert_eq!(to_uppercase("αύριο".as_bytes(), UppercaseMode::Full), "ΑΎΡΙΟ".as_bytes());
+/// assert_eq!(to_uppercase("ß".as_bytes(), UppercaseMode::Full), b"SS");
+/// ```
+///
+/// [`uppercase`]: crate::uppercase()
+#[must_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn to_uppercase(slice: &[u8], options: UppercaseMode) -> Vec<u8> {
+    collect_exact(crate::uppercase(slice, options))
+}
+
+/// Returns a [`String`] containing a copy of the given slice with all lowercase
+/// letters replaced with their uppercase counterparts.
+///
+/// This function collects the iterator returned by [`uppercase`] into an exactly
+/// sized [`Vec`] and converts it to a `String` with [`String::from_utf8`].
+///
+/// The case mapping mode is determined by the given [`UppercaseMode`]. See
+/// [`uppercase`] for details on how the input slice is mapped.
+///
+/// # Errors
+///
+/// Case mapping valid UTF-8 always produces valid UTF-8, so this function only
+/// returns an error if the given slice is not valid UTF-8. Invalid UTF-8 byte
+/// sequences are passed through the case mapping as is. The mapped bytes can
+/// be recovered with [`FromUtf8Error::into_bytes`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{to_uppercase_string, UppercaseMode};
+/// assert_eq!(
+///     to_uppercase_string("straße".as_bytes(), UppercaseMode::Full).unwrap(),
+///     "STRASSE"
+/// );
+///
+/// let err = to_uppercase_string(b"abc\xFFxyz", UppercaseMode::Full).unwrap_err();
+/// assert_eq!(err.utf8_error().valid_up_to(), 3);
+/// ```
+///
+/// [`uppercase`]: crate::uppercase()
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn to_uppercase_string(slice: &[u8], options: UppercaseMode) -> Result<String, FromUtf8Error> {
+    String::from_utf8(collect_exact(crate::uppercase(slice, options)))
+}
+
+/// Appends a copy of the given slice with all lowercase letters replaced with
+/// their uppercase counterparts to `out`, returning the number of bytes
+/// appended.
+///
+/// The existing contents of `out` are preserved. Before any bytes are appended,
+/// capacity is reserved for the upper bound of the [size hint] of the iterator
+/// returned by [`uppercase`], so `out` grows at most once per call. Reusing the
+/// same `out` for many slices amortizes the allocation across calls.
+///
+/// The case mapping mode is determined by the given [`UppercaseMode`]. See
+/// [`uppercase`] for details on how the input slice is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{extend_uppercase, UppercaseMode};
+/// let mut out = b"> ".to_vec();
+/// let appended = extend_uppercase(&mut out, "αύριο".as_bytes(), UppercaseMode::Full);
+/// assert_eq!(out, "> ΑΎΡΙΟ".as_bytes());
+/// assert_eq!(appended, "ΑΎΡΙΟ".len());
+/// ```
+///
+/// [`uppercase`]: crate::uppercase()
+/// [size hint]: Iterator::size_hint
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn extend_uppercase(out: &mut Vec<u8>, slice: &[u8], options: UppercaseMode) -> usize {
+    let iter = crate::uppercase(slice, options);
+    let (min, max) = iter.size_hint();
+    let len = out.len();
+    out.reserve(max.unwrap_or(min));
+    out.extend(iter);
+    out.len() - len
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::{extend_uppercase, to_uppercase, to_uppercase_string};
+    use crate::UppercaseMode;
+
+    #[test]
+    fn empty() {
+        assert!(to_uppercase(b"", UppercaseMode::Full).is_empty());
+        assert!(to_uppercase(b"", UppercaseMode::Ascii).is_empty());
+    }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(
+            to_uppercase(b"abc, XYZ", UppercaseMode::Full).as_bstr(),
+            b"ABC, XYZ".as_bstr()
+        );
+        assert_eq!(
+            to_uppercase("abc, αύριο".as_bytes(), UppercaseMode::Ascii).as_bstr(),
+            "ABC, αύριο".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn multi_char_expansion() {
+        assert_eq!(
+            to_uppercase("ß".as_bytes(), UppercaseMode::Full).as_bstr(),
+            b"SS".as_bstr()
+        );
+        assert_eq!(
+            to_uppercase("ﬃ".as_bytes(), UppercaseMode::Full).as_bstr(),
+            b"FFI".as_bstr()
+        );
+        assert_eq!(
+            to_uppercase("ΐ".as_bytes(), UppercaseMode::Full).as_bstr(),
+            "\u{399}\u{308}\u{301}".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn matches_iterator() {
+        let s = b"ab\xFFc \xCE\xB1\xCF\x8D\xCF\x81\xCE\xB9\xCE\xBF \xC3\x9F";
+        for mode in [
+            UppercaseMode::Full,
+            UppercaseMode::Ascii,
+            UppercaseMode::Turkic,
+        ] {
+            assert!(to_uppercase(s, mode)
+                .into_iter()
+                .eq(crate::uppercase(s, mode)));
+        }
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let inputs = ["", "abc, XYZ", "Αύριο ΑΎΡΙΟ", "İ ß ẞ ﬃ", "\u{1F600} ǅ"];
+        for input in inputs {
+            for mode in [UppercaseMode::Full, UppercaseMode::Ascii] {
+                let string = to_uppercase_string(input.as_bytes(), mode).unwrap();
+                assert_eq!(string.as_bytes(), to_uppercase(input.as_bytes(), mode));
+            }
+        }
+    }
+
+    #[test]
+    fn string_invalid_utf8() {
+        for mode in [UppercaseMode::Full, UppercaseMode::Ascii] {
+            let err = to_uppercase_string(b"abc\xFFxyz\xF0\x9F\x87", mode).unwrap_err();
+            assert_eq!(err.utf8_error().valid_up_to(), 3);
+            assert_eq!(
+                err.into_bytes(),
+                to_uppercase(b"abc\xFFxyz\xF0\x9F\x87", mode)
+            );
+        }
+    }
+
+    #[test]
+    fn extend_preserves_existing_content() {
+        let input = "αύριο ß i ΐ ﬃ".as_bytes();
+        for mode in [
+            UppercaseMode::Full,
+            UppercaseMode::Ascii,
+            UppercaseMode::Turkic,
+        ] {
+            let mut out = b"prefix ".to_vec();
+            let appended = extend_uppercase(&mut out, input, mode);
+            let expected = crate::uppercase(input, mode).collect::<Vec<_>>();
+            assert_eq!(appended, expected.len());
+            assert_eq!(out[..7].as_bstr(), b"prefix ".as_bstr());
+            assert_eq!(out[7..].as_bstr(), expected.as_bstr());
+
+            assert_eq!(extend_uppercase(&mut out, b"", mode), 0);
+            assert_eq!(out.len(), 7 + expected.len());
+        }
+    }
+
+    #[test]
+    fn extend_reserves_capacity_once() {
+        let input = "αύριο ß i ΐ ﬃ".as_bytes();
+        let mut out = b"prefix ".to_vec();
+        let (_, max) = crate::uppercase(input, UppercaseMode::Full).size_hint();
+        // `out` is expected to grow exactly as if the whole upper bound were
+        // reserved up front.
+        let mut reserved = out.clone();
+        reserved.reserve(max.unwrap());
+        extend_uppercase(&mut out, input, UppercaseMode::Full);
+        assert_eq!(out.capacity(), reserved.capacity());
+
+        // Spare capacity is reused without growing.
+        let mut out = Vec::with_capacity(64);
+        let ptr = out.as_ptr();
+        extend_uppercase(&mut out, b"abc", UppercaseMode::Full);
+        extend_uppercase(&mut out, b"xyz", UppercaseMode::Full);
+        assert_eq!(out.capacity(), 64);
+        assert_eq!(out.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn exactly_sized() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"ABC, xyz",
+            "αύριο ΐ ß".as_bytes(),
+            "ﬃ ǆ istanbul".as_bytes(),
+            b"aB\xF0\x9F\x87Yz\xFF",
+        ];
+        for input in inputs {
+            for mode in [
+                UppercaseMode::Full,
+                UppercaseMode::Ascii,
+                UppercaseMode::Turkic,
+                UppercaseMode::Fold,
+                UppercaseMode::CapitalSharpS,
+            ] {
+                let uppercase = to_uppercase(input, mode);
+                assert_eq!(uppercase.len(), uppercase.capacity());
+                assert_eq!(uppercase, crate::uppercase(input, mode).collect::<Vec<_>>());
+            }
+            if let Ok(uppercase) = to_uppercase_string(input, UppercaseMode::Full) {
+                assert_eq!(uppercase.len(), uppercase.capacity());
+            }
+        }
+    }
+}
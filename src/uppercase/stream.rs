@@ -0,0 +1,207 @@
+use crate::stream::{collect_mapped, Carry};
+use crate::unicode::lithuanian::is_after_soft_dotted;
+use crate::unicode::properties::{canonical_combining_class, COMBINING_CLASS_ABOVE};
+use crate::UppercaseMode;
+
+/// U+0307 COMBINING DOT ABOVE.
+const COMBINING_DOT_ABOVE: char = '\u{307}';
+
+/// An uppercase case mapper for conventionally UTF-8 input which arrives in
+/// chunks.
+///
+/// Chunks may split UTF-8 byte sequences at any byte. An incomplete UTF-8
+/// sequence at the end of a chunk is buffered by the streamer and is case
+/// mapped once the chunks which follow complete it. Call [`finish`] after the
+/// last chunk to flush a sequence which is never completed.
+///
+/// In [`UppercaseMode::Lithuanian`], whether a chunk ends after a soft dotted
+/// letter like `i` is remembered, so an explicit dot which begins the next
+/// chunk is removed the same as by [`uppercase`].
+///
+/// # Examples
+///
+/// ```
+/// # use roe::{UppercaseMode, UppercaseStreamer};
+/// let input = "αύριο".as_bytes();
+/// let mut streamer = UppercaseStreamer::new(UppercaseMode::Full);
+/// let mut uppercase = streamer.push(&input[..3]).collect::<Vec<_>>();
+/// uppercase.extend(streamer.push(&input[3..]));
+/// uppercase.extend(streamer.finish());
+/// assert_eq!(uppercase, "ΑΎΡΙΟ".as_bytes());
+/// ```
+///
+/// [`finish`]: Self::finish
+/// [`uppercase`]: crate::uppercase()
+#[derive(Debug, Clone, Copy)]
+pub struct UppercaseStreamer {
+    mode: UppercaseMode,
+    carry: Carry,
+    // Whether the input pushed so far ends in the `After_Soft_Dotted` context,
+    // in which Lithuanian uppercasing removes U+0307 COMBINING DOT ABOVE.
+    after_soft_dotted: bool,
+}
+
+impl UppercaseStreamer {
+    /// Create a new streamer which uppercases its input with the given
+    /// [`UppercaseMode`].
+    #[must_use]
+    pub const fn new(mode: UppercaseMode) -> Self {
+        Self {
+            mode,
+            carry: Carry::new(),
+            after_soft_dotted: false,
+        }
+    }
+
+    /// Returns an iterator that yields the uppercase bytes of the given chunk.
+    ///
+    /// A UTF-8 sequence which begins in an earlier chunk and is completed by
+    /// this one is yielded first. An incomplete UTF-8 sequence at the end of
+    /// this chunk is not yielded until a later chunk completes it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{UppercaseMode, UppercaseStreamer};
+    /// let mut streamer = UppercaseStreamer::new(UppercaseMode::Full);
+    /// // The first byte of `ß`.
+    /// assert_eq!(streamer.push(b"a\xC3").collect::<Vec<_>>(), b"A");
+    /// assert_eq!(streamer.push(b"\x9F").collect::<Vec<_>>(), b"SS");
+    /// ```
+    pub fn push<'a>(&mut self, chunk: &'a [u8]) -> impl Iterator<Item = u8> + 'a {
+        let mode = self.mode;
+        let (head, body) = self.carry.split(chunk);
+        let (before, after) = self.split_removed_dot(head.as_slice());
+        let head =
+            collect_mapped(crate::uppercase(before, mode).chain(crate::uppercase(after, mode)));
+        let (before, after) = self.split_removed_dot(body);
+        head.chain(crate::uppercase(before, mode))
+            .chain(crate::uppercase(after, mode))
+    }
+
+    /// Split the given input around an explicit dot at its start which the
+    /// `After_Soft_Dotted` context of earlier input removes, and record the
+    /// context at the end of the input.
+    ///
+    /// The dot is not part of either returned slice. Input without such a dot
+    /// is returned whole as the first slice.
+    fn split_removed_dot<'a>(&mut self, slice: &'a [u8]) -> (&'a [u8], &'a [u8]) {
+        if self.mode != UppercaseMode::Lithuanian {
+            return (slice, &[]);
+        }
+        let mut split = (slice, &slice[slice.len()..]);
+        if self.after_soft_dotted {
+            let mut offset = 0;
+            while let (Some(ch), size) = bstr::decode_utf8(&slice[offset..]) {
+                if ch == COMBINING_DOT_ABOVE {
+                    split = (&slice[..offset], &slice[offset + size..]);
+                    break;
+                }
+                if matches!(canonical_combining_class(ch), 0 | COMBINING_CLASS_ABOVE) {
+                    break;
+                }
+                offset += size;
+            }
+        }
+        self.after_soft_dotted = is_after_soft_dotted(slice, self.after_soft_dotted);
+        split
+    }
+
+    /// Consume the streamer and return an iterator that yields the buffered
+    /// incomplete UTF-8 sequence, if any.
+    ///
+    /// The sequence is never completed, so it is yielded as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use roe::{UppercaseMode, UppercaseStreamer};
+    /// let mut streamer = UppercaseStreamer::new(UppercaseMode::Full);
+    /// assert_eq!(streamer.push(b"abc\xF0\x9F").collect::<Vec<_>>(), b"ABC");
+    /// assert_eq!(streamer.finish().collect::<Vec<_>>(), b"\xF0\x9F");
+    /// ```
+    pub fn finish(mut self) -> impl Iterator<Item = u8> {
+        let rest = self.carry.take();
+        collect_mapped(rest.as_slice().iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::UppercaseStreamer;
+    use crate::UppercaseMode;
+
+    fn stream(chunks: &[&[u8]], mode: UppercaseMode) -> Vec<u8> {
+        let mut streamer = UppercaseStreamer::new(mode);
+        let mut out = Vec::new();
+        for chunk in chunks {
+            out.extend(streamer.push(chunk));
+        }
+        out.extend(streamer.finish());
+        out
+    }
+
+    #[test]
+    fn split_between_every_byte() {
+        let input = "αύριο".as_bytes();
+        for mid in 0..=input.len() {
+            let chunks = [&input[..mid], &input[mid..]];
+            let uppercase = stream(&chunks, UppercaseMode::Full);
+            assert_eq!(uppercase.as_bstr(), "ΑΎΡΙΟ".as_bytes().as_bstr());
+        }
+    }
+
+    #[test]
+    fn matches_uppercase() {
+        let input = [
+            "i ß ΐ \u{1F600} abc i\u{307} j\u{323}\u{307}".as_bytes(),
+            b"\xFF\xF0\x9F\x87",
+        ]
+        .concat();
+        for mode in [
+            UppercaseMode::Full,
+            UppercaseMode::Ascii,
+            UppercaseMode::Turkic,
+            UppercaseMode::Lithuanian,
+            UppercaseMode::Fold,
+            UppercaseMode::CapitalSharpS,
+        ] {
+            let expected = crate::uppercase(&input, mode).collect::<Vec<_>>();
+            for mid in 0..=input.len() {
+                for end in mid..=input.len() {
+                    let chunks = [&input[..mid], &input[mid..end], &input[end..]];
+                    assert_eq!(stream(&chunks, mode).as_bstr(), expected.as_bstr());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lithuanian_context_spans_chunks() {
+        let examples: [(&[&[u8]], &[u8]); 5] = [
+            (&[b"i", b"\xCC\x87"], b"I"),
+            (&[b"i\xCC", b"\x87"], b"I"),
+            (&[b"j", b"\xCC\xA3", b"\xCC\x87x"], b"J\xCC\xA3X"),
+            (&[b"i", b"", b"\xCC\x81\xCC\x87"], b"I\xCC\x81\xCC\x87"),
+            (&[b"i\xFF", b"\xCC\x87"], b"I\xFF\xCC\x87"),
+        ];
+        for (chunks, expected) in examples {
+            let uppercase = stream(chunks, UppercaseMode::Lithuanian);
+            assert_eq!(uppercase.as_bstr(), expected.as_bstr());
+        }
+    }
+
+    #[test]
+    fn finish_flushes_incomplete_sequence() {
+        let mut streamer = UppercaseStreamer::new(UppercaseMode::Full);
+        assert_eq!(streamer.push(b"\xE2").count(), 0);
+        assert_eq!(streamer.push(b"\x82").count(), 0);
+        assert_eq!(streamer.finish().collect::<Vec<_>>(), b"\xE2\x82");
+
+        let streamer = UppercaseStreamer::new(UppercaseMode::Full);
+        assert_eq!(streamer.finish().count(), 0);
+    }
+}
@@ -0,0 +1,520 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use bstr::ByteSlice;
+
+use crate::buffer::OutputBuffer;
+use crate::unicode::{to_uppercase_char, ToUppercase};
+use crate::utf8::lead_byte_count;
+use crate::{max_expansion_ratio, CaseMapping, UppercaseMode};
+
+const LATIN_CAPITAL_LETTER_I_WITH_DOT_ABOVE: char = '\u{130}';
+const LATIN_CAPITAL_LETTER_SHARP_S: char = '\u{1E9E}';
+
+/// A tailoring of full Unicode uppercase mapping which replaces the mapping of
+/// a single lowercase `char` with a single uppercase `char`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tailoring {
+    /// Turkic languages uppercase the dotted `i` to `İ`, keeping the dot. This
+    /// is the only unconditional Turkic uppercase mapping in
+    /// `SpecialCasing.txt`. The dotless `ı` uppercases to `I` with the default
+    /// mappings.
+    Turkic,
+    /// `ß` uppercases to the single capital sharp `ẞ` instead of `SS`.
+    CapitalSharpS,
+}
+
+impl Tailoring {
+    const fn lowercase(self) -> char {
+        match self {
+            Self::Turkic => 'i',
+            Self::CapitalSharpS => 'ß',
+        }
+    }
+
+    const fn uppercase(self) -> char {
+        match self {
+            Self::Turkic => LATIN_CAPITAL_LETTER_I_WITH_DOT_ABOVE,
+            Self::CapitalSharpS => LATIN_CAPITAL_LETTER_SHARP_S,
+        }
+    }
+
+    const fn mode(self) -> UppercaseMode {
+        match self {
+            Self::Turkic => UppercaseMode::Turkic,
+            Self::CapitalSharpS => UppercaseMode::CapitalSharpS,
+        }
+    }
+}
+
+// Mapped output is buffered separately for each end of the iterator. Once
+// `slice` is exhausted, each end drains the buffers of the other end.
+#[derive(Clone)]
+#[must_use = "Uppercase is a Iterator and must be used"]
+pub struct Uppercase<'a> {
+    tailoring: Tailoring,
+    source: &'a [u8],
+    slice: &'a [u8],
+    next_bytes: OutputBuffer,
+    uppercase: Option<ToUppercase>,
+    next_back_bytes: OutputBuffer,
+    uppercase_back: Option<ToUppercase>,
+}
+
+impl fmt::Debug for Uppercase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Uppercase")
+            .field("tailoring", &self.tailoring)
+            .field("slice", &self.slice.as_bstr())
+            .field("next_bytes", &self.next_bytes)
+            .field("uppercase", &self.uppercase)
+            .field("next_back_bytes", &self.next_back_bytes)
+            .field("uppercase_back", &self.uppercase_back)
+            .finish()
+    }
+}
+
+impl<'a> Uppercase<'a> {
+    pub const fn with_slice(slice: &'a [u8], tailoring: Tailoring) -> Self {
+        Self {
+            tailoring,
+            source: slice,
+            slice,
+            next_bytes: OutputBuffer::new(),
+            uppercase: None,
+            next_back_bytes: OutputBuffer::new(),
+            uppercase_back: None,
+        }
+    }
+
+    fn pending_chars(&self) -> usize {
+        let front = self.uppercase.as_ref().map_or(0, ExactSizeIterator::len);
+        let back = self
+            .uppercase_back
+            .as_ref()
+            .map_or(0, ExactSizeIterator::len);
+        front + back
+    }
+
+    pub const fn as_slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    pub const fn mode(&self) -> UppercaseMode {
+        self.tailoring.mode()
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::with_slice(self.source, self.tailoring);
+    }
+}
+
+impl Iterator for Uppercase<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_bytes.next_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self.uppercase.as_mut().and_then(Iterator::next) {
+            self.next_bytes.push_char(ch);
+            return self.next_bytes.next_byte();
+        }
+
+        self.uppercase = None;
+
+        match bstr::decode_utf8(self.slice) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // reverse iteration.
+                if let Some(ch) = self.uppercase_back.as_mut().and_then(Iterator::next) {
+                    self.next_bytes.push_char(ch);
+                    return self.next_bytes.next_byte();
+                }
+                self.next_back_bytes.next_byte()
+            }
+            (Some(ch), size) if ch == self.tailoring.lowercase() => {
+                self.slice = &self.slice[size..];
+                self.next_bytes.push_char(self.tailoring.uppercase());
+                self.next_bytes.next_byte()
+            }
+            (Some(ch), size) => {
+                self.slice = &self.slice[size..];
+                let mut uppercase = to_uppercase_char(ch);
+                let ch = uppercase
+                    .next()
+                    .expect("ToUppercase yields at least one char");
+                self.next_bytes.push_char(ch);
+
+                self.uppercase = Some(uppercase);
+                self.next_bytes.next_byte()
+            }
+            (None, size) => {
+                let (bytes, remainder) = self.slice.split_at(size);
+                self.slice = remainder;
+
+                self.next_bytes.push_bytes(bytes);
+                self.next_bytes.next_byte()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        const UTF_8_CHAR_MAX_BYTES: usize = 4;
+        // Bytes of the current chars which have not been yielded yet plus the
+        // chars of the current uppercase expansions which have not been
+        // encoded.
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        let pending_chars = self.pending_chars();
+        let pending_min = pending_bytes + pending_chars;
+        let pending_max = pending_bytes + pending_chars * UTF_8_CHAR_MAX_BYTES;
+        let len = self.slice.len();
+        if self.slice.is_ascii() {
+            // In Turkic mode, `i` is one byte and `İ` is two bytes.
+            let expand = if self.tailoring.lowercase().is_ascii() {
+                self.tailoring.uppercase().len_utf8()
+            } else {
+                1
+            };
+            (len + pending_min, Some(len * expand + pending_max))
+        } else {
+            // Every char and invalid UTF-8 byte sequence in the remaining
+            // slice yields at least one byte, and no mapping expands by more
+            // than the maximum expansion ratio.
+            let (num, den) = max_expansion_ratio(CaseMapping::Uppercase(self.mode()));
+            let max = len.checked_mul(num).map(|max| max / den);
+            (
+                lead_byte_count(self.slice) + pending_min,
+                max.and_then(|max| max.checked_add(pending_max)),
+            )
+        }
+    }
+
+    fn count(self) -> usize {
+        let pending_bytes = self.next_bytes.len() + self.next_back_bytes.len();
+        if self.slice.is_empty() && self.pending_chars() == 0 {
+            pending_bytes
+        } else if self.slice.is_ascii() && self.pending_chars() == 0 {
+            let lowercase = self.tailoring.lowercase();
+            let tailored = if lowercase.is_ascii() {
+                let mut needle = [0; 4];
+                let needle = lowercase.encode_utf8(&mut needle).as_bytes();
+                let expand = self.tailoring.uppercase().len_utf8() - 1;
+                self.slice.find_iter(needle).count() * expand
+            } else {
+                0
+            };
+            pending_bytes + self.slice.len() + tailored
+        } else {
+            self.fold(0, |acc, _| acc + 1)
+        }
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        // The last byte is the last byte of the final scalar's mapping, which
+        // reverse iteration maps without visiting the rest of the slice.
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Uppercase<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(byte) = self.next_back_bytes.next_back_byte() {
+            return Some(byte);
+        }
+
+        if let Some(ch) = self
+            .uppercase_back
+            .as_mut()
+            .and_then(DoubleEndedIterator::next_back)
+        {
+            self.next_back_bytes.push_char(ch);
+            return self.next_back_bytes.next_back_byte();
+        }
+
+        self.uppercase_back = None;
+
+        match bstr::decode_last_utf8(self.slice) {
+            (_, 0) => {
+                // The slice is exhausted, so drain the output buffered by
+                // forward iteration.
+                if let Some(ch) = self
+                    .uppercase
+                    .as_mut()
+                    .and_then(DoubleEndedIterator::next_back)
+                {
+                    self.next_back_bytes.push_char(ch);
+                    return self.next_back_bytes.next_back_byte();
+                }
+                self.next_bytes.next_back_byte()
+            }
+            (Some(ch), size) if ch == self.tailoring.lowercase() => {
+                self.slice = &self.slice[..self.slice.len() - size];
+                self.next_back_bytes.push_char(self.tailoring.uppercase());
+                self.next_back_bytes.next_back_byte()
+            }
+            (Some(ch), size) => {
+                self.slice = &self.slice[..self.slice.len() - size];
+                // Multi-char mappings are yielded back to front, starting with
+                // the last char of the mapping.
+                let mut uppercase = to_uppercase_char(ch);
+                let ch = uppercase
+                    .next_back()
+                    .expect("ToUppercase yields at least one char");
+                self.next_back_bytes.push_char(ch);
+
+                self.uppercase_back = Some(uppercase);
+                self.next_back_bytes.next_back_byte()
+            }
+            (None, size) => {
+                let (remainder, bytes) = self.slice.split_at(self.slice.len() - size);
+                self.slice = remainder;
+
+                self.next_back_bytes.push_bytes(bytes);
+                self.next_back_bytes.next_back_byte()
+            }
+        }
+    }
+}
+
+impl FusedIterator for Uppercase<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use bstr::ByteSlice;
+
+    use super::{Tailoring, Uppercase};
+
+    const TAILORINGS: [Tailoring; 2] = [Tailoring::Turkic, Tailoring::CapitalSharpS];
+
+    fn turkic(slice: &[u8]) -> Uppercase<'_> {
+        Uppercase::with_slice(slice, Tailoring::Turkic)
+    }
+
+    fn sharp_s(slice: &[u8]) -> Uppercase<'_> {
+        Uppercase::with_slice(slice, Tailoring::CapitalSharpS)
+    }
+
+    #[test]
+    fn empty() {
+        for tailoring in TAILORINGS {
+            let iter = Uppercase::with_slice(b"", tailoring);
+            assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"".as_bstr());
+        }
+    }
+
+    #[test]
+    fn dotted_i_keeps_dot() {
+        let iter = turkic(b"i");
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), "İ".as_bytes().as_bstr());
+
+        let iter = turkic(b"istanbul");
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "İSTANBUL".as_bytes().as_bstr()
+        );
+
+        let iter = turkic("iİ".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "İİ".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn dotless_i_maps_to_capital_i() {
+        let iter = turkic("ı".as_bytes());
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), b"I".as_bstr());
+
+        let iter = turkic("ılık".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "ILIK".as_bytes().as_bstr()
+        );
+
+        let iter = turkic("diyarbakır".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "DİYARBAKIR".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn sharp_s_maps_to_capital_sharp_s() {
+        let iter = sharp_s("ß".as_bytes());
+        assert_eq!(iter.collect::<Vec<_>>().as_bstr(), "ẞ".as_bytes().as_bstr());
+
+        let iter = sharp_s("straße".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "STRAẞE".as_bytes().as_bstr()
+        );
+
+        // The capital sharp s is already uppercase.
+        let iter = sharp_s("ẞß".as_bytes());
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            "ẞẞ".as_bytes().as_bstr()
+        );
+    }
+
+    #[test]
+    fn other_characters_match_full_mode() {
+        let inputs: [&[u8]; 5] = [
+            b"abc, xyz",
+            "Αύριο".as_bytes(),
+            "ﬃ ὖ ῷ".as_bytes(),
+            "ǅ ǆ zⱥⱦ".as_bytes(),
+            b"ab\xF0\x9F\x87Yz\xFF\xFE",
+        ];
+        for input in inputs {
+            let full = crate::uppercase::full::Uppercase::from(input).collect::<Vec<_>>();
+            for tailoring in TAILORINGS {
+                let tailored = Uppercase::with_slice(input, tailoring).collect::<Vec<_>>();
+                assert_eq!(tailored.as_bstr(), full.as_bstr(), "{tailoring:?}");
+            }
+        }
+
+        // Each tailoring leaves the char of the other tailoring alone.
+        let full = crate::uppercase::full::Uppercase::from("ß".as_bytes()).collect::<Vec<_>>();
+        assert_eq!(
+            turkic("ß".as_bytes()).collect::<Vec<_>>().as_bstr(),
+            full.as_bstr()
+        );
+        assert_eq!(
+            sharp_s(b"istanbul").collect::<Vec<_>>().as_bstr(),
+            b"ISTANBUL".as_bstr()
+        );
+    }
+
+    #[test]
+    fn rev() {
+        let inputs: [&[u8]; 8] = [
+            b"",
+            b"istanbul",
+            "diyarbakır iİ".as_bytes(),
+            "straße ẞß".as_bytes(),
+            "ßﬃ ὖ ῷ".as_bytes(),
+            "ǅ ǆ zⱥⱦ".as_bytes(),
+            b"i\xF0\x9F\x87Yz\xFF\xFEi",
+            b"\xC3\x9F\xF0\x9F\x87Yz\xFF\xC3",
+        ];
+        for input in inputs {
+            for tailoring in TAILORINGS {
+                let mut rev = Uppercase::with_slice(input, tailoring)
+                    .rev()
+                    .collect::<Vec<_>>();
+                rev.reverse();
+                let forward = Uppercase::with_slice(input, tailoring).collect::<Vec<_>>();
+                assert_eq!(rev.as_bstr(), forward.as_bstr(), "{tailoring:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn next_and_next_back_interleaved() {
+        let mut iter = turkic(b"ii");
+        assert_eq!(iter.next_back(), Some(0xB0));
+        assert_eq!(iter.next(), Some(0xC4));
+        assert_eq!(iter.next_back(), Some(0xC4));
+        assert_eq!(iter.next(), Some(0xB0));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        // `ß` uppercases to `SS`.
+        let mut iter = turkic("ß".as_bytes());
+        assert_eq!(iter.next_back(), Some(b'S'));
+        assert_eq!(iter.next(), Some(b'S'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        // `ẞ` is three bytes long.
+        let mut iter = sharp_s("ß".as_bytes());
+        assert_eq!(iter.next_back(), Some(0x9E));
+        assert_eq!(iter.next(), Some(0xE1));
+        assert_eq!(iter.next_back(), Some(0xBA));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        // `ﬃ` uppercases to `FFI`.
+        let mut iter = sharp_s("ßﬃ".as_bytes());
+        assert_eq!(iter.next(), Some(0xE1));
+        assert_eq!(iter.next_back(), Some(b'I'));
+        assert_eq!(iter.next(), Some(0xBA));
+        assert_eq!(iter.next(), Some(0x9E));
+        assert_eq!(iter.next(), Some(b'F'));
+        assert_eq!(iter.next_back(), Some(b'F'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let iter = turkic(b"i\xFF\xFEi");
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"\xC4\xB0\xFF\xFE\xC4\xB0".as_bstr()
+        );
+
+        let iter = sharp_s(b"\xC3\x9F\xFF\xC3");
+        assert_eq!(
+            iter.collect::<Vec<_>>().as_bstr(),
+            b"\xE1\xBA\x9E\xFF\xC3".as_bstr()
+        );
+    }
+
+    #[test]
+    fn count() {
+        for tailoring in TAILORINGS {
+            assert_eq!(Uppercase::with_slice(b"", tailoring).count(), 0);
+            assert_eq!(Uppercase::with_slice(b"abc, xyz", tailoring).count(), 8);
+            assert_eq!(
+                Uppercase::with_slice("zⱥⱦ".as_bytes(), tailoring).count(),
+                5
+            );
+        }
+
+        assert_eq!(turkic(b"istanbul").count(), 9);
+        assert_eq!(turkic("ılık".as_bytes()).count(), 4);
+        let mut iter = turkic(b"ii");
+        assert_eq!(iter.next(), Some(0xC4));
+        assert_eq!(iter.count(), 3);
+
+        assert_eq!(sharp_s(b"istanbul").count(), 8);
+        assert_eq!(sharp_s("ß".as_bytes()).count(), 3);
+        let mut iter = sharp_s("ßß".as_bytes());
+        assert_eq!(iter.next(), Some(0xE1));
+        assert_eq!(iter.count(), 5);
+    }
+
+    #[test]
+    fn size_hint_covers_count() {
+        let inputs: [&[u8]; 9] = [
+            b"",
+            b"abc, xyz",
+            b"iiii",
+            "ılık".as_bytes(),
+            "ßßßß".as_bytes(),
+            "straße".as_bytes(),
+            "ﬃ ὖ ῷ".as_bytes(),
+            b"i\xFF\xFEi",
+            b"\xC3\x9F\xFF\xFE",
+        ];
+        for input in inputs {
+            for tailoring in TAILORINGS {
+                let mut iter = Uppercase::with_slice(input, tailoring);
+                loop {
+                    let (min, max) = iter.size_hint();
+                    let count = iter.clone().count();
+                    assert!(min <= count);
+                    assert!(count <= max.unwrap());
+                    if iter.next().is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use core::fmt::{self, Write};
+
+/// Size of the stack buffer case mapped bytes are collected into before they
+/// are decoded and written to a formatter.
+const FMT_CHUNK_SIZE: usize = 256;
+
+/// Returns the number of bytes in the given slice which are not UTF-8
+/// continuation bytes.
+///
+/// For valid UTF-8 this is the number of encoded scalars. Every UTF-8 scalar
+/// and every invalid UTF-8 byte sequence begins with at most one such byte, so
+/// this count is a lower bound on the number of scalars and invalid byte
+/// sequences a conventionally UTF-8 string decodes into.
+///
+/// Case mapping iterators yield at least one byte for each of them, which
+/// makes this count a lower bound on the length of their output which is
+/// cheap to compute.
+pub(crate) fn lead_byte_count(slice: &[u8]) -> usize {
+    // Continuation bytes have the bit pattern `0b10xx_xxxx`.
+    slice.iter().filter(|&&byte| byte & 0xC0 != 0x80).count()
+}
+
+/// Returns the number of invalid UTF-8 byte sequences in the given slice.
+///
+/// This function treats the given slice as a [conventionally UTF-8 string] and
+/// decodes it the same way the case mapping iterators do. Each maximal invalid
+/// subpart counts once, so the count is the number of U+FFFD REPLACEMENT
+/// CHARACTERs that [`lowercase_lossy`] substitutes and the number of times the
+/// case mapping iterators pass invalid bytes through as is.
+///
+/// A slice is valid UTF-8 if and only if this function returns zero.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(roe::count_invalid_subparts("Αύριο".as_bytes()), 0);
+///
+/// // A truncated four byte sequence is one maximal invalid subpart.
+/// assert_eq!(roe::count_invalid_subparts(b"aB\xF0\x9F\x87Yz"), 1);
+///
+/// // Bytes which can never begin a UTF-8 sequence are each their own subpart.
+/// assert_eq!(roe::count_invalid_subparts(b"\xFF\xFE"), 2);
+/// ```
+///
+/// [conventionally UTF-8 string]: https://docs.rs/bstr/1.*/bstr/#when-should-i-use-byte-strings
+/// [`lowercase_lossy`]: crate::lowercase_lossy()
+#[must_use]
+pub fn count_invalid_subparts(slice: &[u8]) -> usize {
+    let mut slice = slice;
+    let mut count = 0;
+    while !slice.is_empty() {
+        let (ch, size) = bstr::decode_utf8(slice);
+        if ch.is_none() {
+            count += 1;
+        }
+        slice = &slice[size..];
+    }
+    count
+}
+
+/// Write the bytes yielded by the given iterator to the formatter as a
+/// conventionally UTF-8 string, substituting U+FFFD REPLACEMENT CHARACTER for
+/// invalid UTF-8 byte sequences.
+///
+/// The output is the same as the [`Display`] implementation of [`BStr`] for the
+/// collected bytes but is produced without allocating.
+///
+/// [`Display`]: fmt::Display
+/// [`BStr`]: bstr::BStr
+pub(crate) fn fmt_lossy<I>(iter: I, f: &mut fmt::Formatter<'_>) -> fmt::Result
+where
+    I: Iterator<Item = u8>,
+{
+    let mut buf = [0; FMT_CHUNK_SIZE];
+    let mut len = 0;
+    for byte in iter {
+        buf[len] = byte;
+        len += 1;
+        if len == buf.len() {
+            // A UTF-8 sequence split across the end of the buffer must not be
+            // rendered as invalid, so it is carried over to the next chunk.
+            let carry = incomplete_suffix_len(&buf);
+            write_lossy(&buf[..len - carry], f)?;
+            buf.copy_within(len - carry.., 0);
+            len = carry;
+        }
+    }
+    write_lossy(&buf[..len], f)
+}
+
+fn write_lossy(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for chunk in bytes.utf8_chunks() {
+        f.write_str(chunk.valid())?;
+        if !chunk.invalid().is_empty() {
+            f.write_char('\u{FFFD}')?;
+        }
+    }
+    Ok(())
+}
+
+// Returns the length of the trailing prefix of a UTF-8 sequence which is cut
+// off by the end of `bytes` and may be completed by bytes which follow.
+pub(crate) fn incomplete_suffix_len(bytes: &[u8]) -> usize {
+    let tail = bytes.len().saturating_sub(3);
+    // Only bytes which begin a multi-byte sequence can be completed.
+    let start = match bytes[tail..].iter().rposition(|&byte| byte & 0xC0 != 0x80) {
+        Some(idx) if matches!(bytes[tail + idx], 0xC2..=0xF4) => tail + idx,
+        _ => return 0,
+    };
+    match bstr::decode_utf8(&bytes[start..]) {
+        (None, size) if start + size == bytes.len() => size,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::fmt;
+
+    use bstr::ByteSlice;
+
+    use super::{
+        count_invalid_subparts, fmt_lossy, incomplete_suffix_len, lead_byte_count, FMT_CHUNK_SIZE,
+    };
+
+    struct Lossy<'a>(&'a [u8]);
+
+    impl fmt::Display for Lossy<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt_lossy(self.0.iter().copied(), f)
+        }
+    }
+
+    #[test]
+    fn counts_scalars_in_valid_utf8() {
+        assert_eq!(lead_byte_count(b""), 0);
+        assert_eq!(lead_byte_count(b"abc, xyz"), 8);
+        assert_eq!(lead_byte_count("Έτος".as_bytes()), 4);
+        assert_eq!(lead_byte_count("ZȺȾ".as_bytes()), 3);
+        assert_eq!(lead_byte_count("\u{1F600}ﬃ".as_bytes()), 2);
+
+        for s in ["Αύριο", "ß ẞ İ ΐ", "𐐜 𐐔𐐇𐐝𐐀𐐡𐐇𐐓", "中文 한국어"]
+        {
+            assert_eq!(lead_byte_count(s.as_bytes()), s.chars().count());
+        }
+    }
+
+    #[test]
+    fn invalid_utf8_is_a_lower_bound() {
+        // The truncated sequence decodes as one invalid sequence.
+        assert_eq!(lead_byte_count(b"\xF0\x9F\x87"), 1);
+        assert_eq!(lead_byte_count(b"\xFF\xFE"), 2);
+        // Stray continuation bytes are not counted.
+        assert_eq!(lead_byte_count(b"a\x80\xBFz"), 2);
+    }
+
+    #[test]
+    fn invalid_subparts() {
+        assert_eq!(count_invalid_subparts(b""), 0);
+        assert_eq!(count_invalid_subparts("Αύριο \u{1F600}".as_bytes()), 0);
+        assert_eq!(count_invalid_subparts(b"aB\xF0\x9F\x87Yz"), 1);
+        assert_eq!(count_invalid_subparts(b"\xFF\xFE"), 2);
+        // A lead byte followed by a byte which cannot continue it ends the
+        // subpart, and lone continuation bytes are each their own subpart.
+        assert_eq!(count_invalid_subparts(b"\xE2\x82\xFF"), 2);
+        assert_eq!(count_invalid_subparts(b"\x80\x80"), 2);
+    }
+
+    #[test]
+    fn invalid_subparts_match_utf8_chunks() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"abc, xyz",
+            b"aB\xF0\x9F\x87Yz\xFF\xFE\xCE",
+            b"\xED\xA0\x80 \xC0\x80",
+            "\u{FFFD}".as_bytes(),
+        ];
+        for input in inputs {
+            // `bstr` ends each chunk with at most one maximal invalid subpart.
+            let expected = input
+                .utf8_chunks()
+                .filter(|chunk| !chunk.invalid().is_empty())
+                .count();
+            assert_eq!(count_invalid_subparts(input), expected);
+        }
+    }
+
+    #[test]
+    fn incomplete_suffix() {
+        assert_eq!(incomplete_suffix_len(b""), 0);
+        assert_eq!(incomplete_suffix_len(b"abc"), 0);
+        assert_eq!(incomplete_suffix_len("aΣ".as_bytes()), 0);
+        assert_eq!(incomplete_suffix_len(b"a\xCE"), 1);
+        assert_eq!(incomplete_suffix_len(b"a\xF0\x9F\x87"), 3);
+        assert_eq!(incomplete_suffix_len(b"a\xFF"), 0);
+        assert_eq!(incomplete_suffix_len(b"a\xF0\x41"), 0);
+        assert_eq!(incomplete_suffix_len(b"\x80\x80\x80\x80"), 0);
+    }
+
+    #[test]
+    fn fmt_lossy_matches_bstr() {
+        let inputs: [&[u8]; 5] = [
+            b"",
+            b"abc, xyz",
+            "Αύριο \u{1F600}".as_bytes(),
+            b"ab\xF0\x9F\x87Yz\xFF\xFE\xCE",
+            b"\xED\xA0\x80 \xC0\x80",
+        ];
+        for input in inputs {
+            let expected = alloc::format!("{}", input.as_bstr());
+            assert_eq!(alloc::format!("{}", Lossy(input)), expected);
+        }
+    }
+
+    #[test]
+    fn fmt_lossy_sequences_split_across_chunks() {
+        for offset in 0..4 {
+            let mut input = Vec::new();
+            input.resize(FMT_CHUNK_SIZE - 4 + offset, b'a');
+            input.extend_from_slice("\u{1F600}Σ".as_bytes());
+            input.extend_from_slice(b"\xF0\x9F\x87");
+            input.resize(FMT_CHUNK_SIZE * 2 - 1, b'z');
+            input.extend_from_slice("Σ".as_bytes());
+            input.extend_from_slice(b"\xE2\x84");
+            let expected = alloc::format!("{}", input.as_bstr());
+            let mut out = String::new();
+            fmt::write(&mut out, format_args!("{}", Lossy(&input))).unwrap();
+            assert_eq!(out, expected);
+        }
+    }
+}
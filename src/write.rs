@@ -0,0 +1,263 @@
+use std::io::{self, Write};
+
+use crate::{LowercaseMode, TitlecaseMode, UppercaseMode};
+
+/// Size of the stack buffer case mapped bytes are collected into before each
+/// write to the underlying writer.
+const CHUNK_SIZE: usize = 512;
+
+fn write_chunked<I, W>(iter: I, writer: &mut W) -> io::Result<()>
+where
+    I: Iterator<Item = u8>,
+    W: Write + ?Sized,
+{
+    let mut buf = [0; CHUNK_SIZE];
+    let mut len = 0;
+    for byte in iter {
+        buf[len] = byte;
+        len += 1;
+        if len == buf.len() {
+            // `write_all` retries short writes and `Interrupted` errors.
+            writer.write_all(&buf)?;
+            len = 0;
+        }
+    }
+    writer.write_all(&buf[..len])
+}
+
+/// Write a copy of the given slice with all uppercase letters replaced with
+/// their lowercase counterparts to the given writer.
+///
+/// This function streams the bytes yielded by [`lowercase`] into `writer` in
+/// bounded chunks without allocating an intermediate buffer on the heap.
+///
+/// The case mapping mode is determined by the given [`LowercaseMode`]. See
+/// [`lowercase`] for details on how the input slice is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::LowercaseMode;
+/// let mut out = Vec::new();
+/// roe::write_lowercase("ΑΎΡΙΟ".as_bytes(), LowercaseMode::Full, &mut out)?;
+/// assert_eq!(out, "αύριο".as_bytes());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// If the writer returns an error, it is propagated and writing stops. Some
+/// case mapped bytes may have already been written.
+///
+/// # Panics
+///
+/// This function panics for the same [`LowercaseMode`]s as [`lowercase`].
+///
+/// [`lowercase`]: crate::lowercase()
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn write_lowercase<W>(slice: &[u8], options: LowercaseMode, writer: &mut W) -> io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    write_chunked(crate::lowercase(slice, options), writer)
+}
+
+/// Write a copy of the given slice with all lowercase letters replaced with
+/// their uppercase counterparts to the given writer.
+///
+/// This function streams the bytes yielded by [`uppercase`] into `writer` in
+/// bounded chunks without allocating an intermediate buffer on the heap.
+///
+/// The case mapping mode is determined by the given [`UppercaseMode`]. See
+/// [`uppercase`] for details on how the input slice is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::UppercaseMode;
+/// let mut out = Vec::new();
+/// roe::write_uppercase("straße".as_bytes(), UppercaseMode::Full, &mut out)?;
+/// assert_eq!(out, b"STRASSE");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// If the writer returns an error, it is propagated and writing stops. Some
+/// case mapped bytes may have already been written.
+///
+/// [`uppercase`]: crate::uppercase()
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn write_uppercase<W>(slice: &[u8], options: UppercaseMode, writer: &mut W) -> io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    write_chunked(crate::uppercase(slice, options), writer)
+}
+
+/// Write a copy of the given slice with the leading letter replaced with its
+/// titlecase counterpart and all remaining letters replaced with their
+/// lowercase counterparts to the given writer.
+///
+/// This function streams the bytes yielded by [`titlecase`] into `writer` in
+/// bounded chunks without allocating an intermediate buffer on the heap.
+///
+/// The case mapping mode is determined by the given [`TitlecaseMode`]. See
+/// [`titlecase`] for details on how the input slice is mapped.
+///
+/// # Examples
+///
+/// ```
+/// # use roe::TitlecaseMode;
+/// let mut out = Vec::new();
+/// roe::write_titlecase("ﬃ WORLD".as_bytes(), TitlecaseMode::Full, &mut out)?;
+/// assert_eq!(out, b"Ffi world");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// If the writer returns an error, it is propagated and writing stops. Some
+/// case mapped bytes may have already been written.
+///
+/// # Panics
+///
+/// This function panics for the same [`TitlecaseMode`]s as [`titlecase`].
+///
+/// [`titlecase`]: crate::titlecase()
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn write_titlecase<W>(slice: &[u8], options: TitlecaseMode, writer: &mut W) -> io::Result<()>
+where
+    W: Write + ?Sized,
+{
+    write_chunked(crate::titlecase(slice, options), writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use std::io::{self, Write};
+
+    use bstr::ByteSlice;
+
+    use super::{write_lowercase, write_titlecase, write_uppercase, CHUNK_SIZE};
+    use crate::{LowercaseMode, TitlecaseMode, UppercaseMode};
+
+    /// A writer which accepts at most one byte per call to `write` and is
+    /// interrupted on every other call.
+    #[derive(Default)]
+    struct Trickle {
+        bytes: Vec<u8>,
+        interrupt: bool,
+    }
+
+    impl Write for Trickle {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            let Some(&byte) = buf.first() else {
+                return Ok(0);
+            };
+            self.bytes.push(byte);
+            Ok(1)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// A writer which fails after accepting `capacity` bytes.
+    struct Full {
+        capacity: usize,
+    }
+
+    impl Write for Full {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.capacity == 0 {
+                return Err(io::ErrorKind::WriteZero.into());
+            }
+            let len = buf.len().min(self.capacity);
+            self.capacity -= len;
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn inputs() -> Vec<Vec<u8>> {
+        let mut long = Vec::new();
+        while long.len() <= CHUNK_SIZE * 3 {
+            long.extend_from_slice("Straße ΑΎΡΙΟ ﬃ abc XYZ ".as_bytes());
+            long.extend_from_slice(b"\xFF\xFE");
+        }
+        alloc::vec![
+            Vec::new(),
+            b"abc XYZ".to_vec(),
+            "ﬃ Straße ΐ".as_bytes().to_vec(),
+            b"ab\xFF\xFEcd".to_vec(),
+            long,
+        ]
+    }
+
+    #[test]
+    fn matches_iterator() {
+        for input in inputs() {
+            for mode in [
+                LowercaseMode::Full,
+                LowercaseMode::Ascii,
+                LowercaseMode::Fold,
+            ] {
+                let mut out = Vec::new();
+                write_lowercase(&input, mode, &mut out).unwrap();
+                let expected = crate::lowercase(&input, mode).collect::<Vec<_>>();
+                assert_eq!(out.as_bstr(), expected.as_bstr());
+            }
+            for mode in [
+                UppercaseMode::Full,
+                UppercaseMode::Ascii,
+                UppercaseMode::Turkic,
+            ] {
+                let mut out = Vec::new();
+                write_uppercase(&input, mode, &mut out).unwrap();
+                let expected = crate::uppercase(&input, mode).collect::<Vec<_>>();
+                assert_eq!(out.as_bstr(), expected.as_bstr());
+            }
+            for mode in [TitlecaseMode::Full, TitlecaseMode::Ascii] {
+                let mut out = Vec::new();
+                write_titlecase(&input, mode, &mut out).unwrap();
+                let expected = crate::titlecase(&input, mode).collect::<Vec<_>>();
+                assert_eq!(out.as_bstr(), expected.as_bstr());
+            }
+        }
+    }
+
+    #[test]
+    fn short_and_interrupted_writes() {
+        for input in inputs() {
+            let mut out = Trickle::default();
+            write_uppercase(&input, UppercaseMode::Full, &mut out).unwrap();
+            let expected = crate::uppercase(&input, UppercaseMode::Full).collect::<Vec<_>>();
+            assert_eq!(out.bytes.as_bstr(), expected.as_bstr());
+        }
+    }
+
+    #[test]
+    fn errors_are_propagated() {
+        let input = "straße".repeat(CHUNK_SIZE);
+        let mut out = Full { capacity: 10 };
+        let err = write_uppercase(input.as_bytes(), UppercaseMode::Full, &mut out).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+
+        let mut out = Full { capacity: 0 };
+        let err = write_lowercase(b"ABC", LowercaseMode::Full, &mut out).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+
+        let mut out = Full { capacity: 3 };
+        write_titlecase(b"abc", TitlecaseMode::Full, &mut out).unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+//! Property tests asserting that case mapping an already case mapped string in
+//! full mode is a fixed point.
+//!
+//! Every scalar value is idempotent under full lowercase and uppercase
+//! mapping, so no scalars are excluded from the properties below. The
+//! `NON_IDEMPOTENT_*` lists enumerate the exceptions and an exhaustive test
+//! keeps them accurate: if a Unicode update introduces a scalar which is not a
+//! fixed point, add it to the appropriate list so the properties exclude it.
+//!
+//! The final sigma rule is the only context-dependent mapping in full mode.
+//! Lowercasing removes every `Σ`, so it cannot make a second pass map
+//! differently.
+
+use quickcheck::{Arbitrary, Gen, QuickCheck};
+use roe::{LowercaseMode, UppercaseMode};
+
+/// Scalars `c` for which lowercasing the lowercase of `c` differs from the
+/// lowercase of `c` in full mode.
+const NON_IDEMPOTENT_LOWERCASE: &[char] = &[];
+
+/// Scalars `c` for which uppercasing the uppercase of `c` differs from the
+/// uppercase of `c` in full mode.
+const NON_IDEMPOTENT_UPPERCASE: &[char] = &[];
+
+/// Scalars with expansions, context-dependent mappings, or combining marks,
+/// which uniformly random scalars rarely produce.
+const INTERESTING: &[char] = &[
+    'A', 'a', 'Z', 'z', ' ', '.', 'ß', 'ẞ', 'İ', 'ı', 'I', 'i', 'Σ', 'σ', 'ς', 'ΐ', 'ǅ', 'Ǆ', 'ǆ',
+    'ﬃ', 'ŉ', '\u{307}', '\u{345}', '\u{300}', '\u{212A}', 'Ⱥ', 'ⱥ',
+];
+
+/// A random valid UTF-8 string which is biased towards cased and
+/// context-sensitive scalars.
+#[derive(Clone, Debug)]
+struct Text(String);
+
+impl Arbitrary for Text {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let len = usize::arbitrary(g) % g.size();
+        let text = (0..len)
+            .map(|_| {
+                if bool::arbitrary(g) {
+                    *g.choose(INTERESTING).unwrap()
+                } else {
+                    char::arbitrary(g)
+                }
+            })
+            .collect();
+        Self(text)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(Self))
+    }
+}
+
+fn lowercase(s: &[u8]) -> Vec<u8> {
+    roe::lowercase(s, LowercaseMode::Full).collect()
+}
+
+fn uppercase(s: &[u8]) -> Vec<u8> {
+    roe::uppercase(s, UppercaseMode::Full).collect()
+}
+
+fn quickcheck<A>(prop: fn(A) -> bool)
+where
+    A: Arbitrary + core::fmt::Debug,
+{
+    QuickCheck::new().tests(2_000).quickcheck(prop);
+}
+
+#[test]
+fn non_idempotent_scalars_are_enumerated() {
+    let mut buf = [0; 4];
+    for ch in (0..=0x10_FFFF).filter_map(char::from_u32) {
+        let s = ch.encode_utf8(&mut buf).as_bytes();
+
+        let lower = lowercase(s);
+        let idempotent = lowercase(&lower) == lower;
+        assert_eq!(
+            idempotent,
+            !NON_IDEMPOTENT_LOWERCASE.contains(&ch),
+            "lowercase {:?}",
+            ch
+        );
+
+        let upper = uppercase(s);
+        let idempotent = uppercase(&upper) == upper;
+        assert_eq!(
+            idempotent,
+            !NON_IDEMPOTENT_UPPERCASE.contains(&ch),
+            "uppercase {:?}",
+            ch
+        );
+    }
+}
+
+#[test]
+fn lowercase_is_idempotent() {
+    fn prop(text: Text) -> bool {
+        if text
+            .0
+            .chars()
+            .any(|ch| NON_IDEMPOTENT_LOWERCASE.contains(&ch))
+        {
+            return true;
+        }
+        let lower = lowercase(text.0.as_bytes());
+        lowercase(&lower) == lower
+    }
+    quickcheck(prop as fn(Text) -> bool);
+}
+
+#[test]
+fn uppercase_is_idempotent() {
+    fn prop(text: Text) -> bool {
+        if text
+            .0
+            .chars()
+            .any(|ch| NON_IDEMPOTENT_UPPERCASE.contains(&ch))
+        {
+            return true;
+        }
+        let upper = uppercase(text.0.as_bytes());
+        uppercase(&upper) == upper
+    }
+    quickcheck(prop as fn(Text) -> bool);
+}
+
+#[test]
+fn arbitrary_strings_are_idempotent() {
+    // Uniformly random scalars exercise the scalars outside of `INTERESTING`.
+    fn prop(text: String) -> bool {
+        let lower = lowercase(text.as_bytes());
+        let upper = uppercase(text.as_bytes());
+        lowercase(&lower) == lower && uppercase(&upper) == upper
+    }
+    quickcheck(prop as fn(String) -> bool);
+}